@@ -0,0 +1,276 @@
+//! Baseline support for suppressing pre-existing diagnostics.
+//!
+//! `agnix baseline` records a fingerprint for every current diagnostic and
+//! `--baseline <path>` filters matching diagnostics out before exit codes are
+//! computed, so CI only fails on new violations.
+//!
+//! Fingerprints are `rule + file + normalized message`. Line and column are
+//! deliberately left out so edits above a violation don't resurface it.
+//! Messages are localized, so create and check baselines with the same locale.
+
+use crate::json::path_to_string;
+use agnix_core::diagnostics::Diagnostic;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Default file name written by `agnix baseline`.
+pub const DEFAULT_BASELINE_FILE: &str = ".agnix-baseline.json";
+
+const BASELINE_VERSION: u32 = 1;
+
+/// On-disk baseline file.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Baseline format version.
+    pub version: u32,
+    /// Recorded fingerprints, sorted for stable diffs.
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// A recorded diagnostic fingerprint.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Rule identifier (e.g., AS-004).
+    pub rule: String,
+    /// File path relative to the baseline root (forward slashes).
+    pub file: String,
+    /// Message with whitespace collapsed and digits masked.
+    pub message: String,
+    /// Number of identical diagnostics in the file.
+    #[serde(default = "default_count")]
+    pub count: usize,
+}
+
+fn default_count() -> usize {
+    1
+}
+
+/// Collapse whitespace and mask digit runs so counts and positions quoted in
+/// messages (e.g. "line 12", "1024 characters") don't break the fingerprint.
+fn normalize_message(message: &str) -> String {
+    let mut normalized = String::with_capacity(message.len());
+    let mut in_digits = false;
+    for word in message.split_whitespace() {
+        if !normalized.is_empty() {
+            normalized.push(' ');
+        }
+        for c in word.chars() {
+            if c.is_ascii_digit() {
+                if !in_digits {
+                    normalized.push('#');
+                }
+                in_digits = true;
+            } else {
+                normalized.push(c);
+                in_digits = false;
+            }
+        }
+        in_digits = false;
+    }
+    normalized
+}
+
+/// Fingerprint key (rule, file, normalized message) for a diagnostic.
+fn fingerprint(diag: &Diagnostic, base_path: &Path) -> (String, String, String) {
+    (
+        diag.rule.clone(),
+        path_to_string(&diag.file, base_path),
+        normalize_message(&diag.message),
+    )
+}
+
+impl Baseline {
+    /// Build a baseline from the current diagnostics.
+    pub fn from_diagnostics(diagnostics: &[Diagnostic], base_path: &Path) -> Self {
+        let mut counts: HashMap<(String, String, String), usize> = HashMap::new();
+        for diag in diagnostics {
+            *counts.entry(fingerprint(diag, base_path)).or_default() += 1;
+        }
+
+        let mut entries: Vec<BaselineEntry> = counts
+            .into_iter()
+            .map(|((rule, file, message), count)| BaselineEntry {
+                rule,
+                file,
+                message,
+                count,
+            })
+            .collect();
+        entries.sort();
+
+        Self {
+            version: BASELINE_VERSION,
+            entries,
+        }
+    }
+
+    /// Read a baseline file.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let baseline: Self = serde_json::from_str(&content)
+            .map_err(|e| anyhow::anyhow!("Invalid baseline file {}: {}", path.display(), e))?;
+        if baseline.version != BASELINE_VERSION {
+            anyhow::bail!(
+                "Unsupported baseline version {} in {} (expected {})",
+                baseline.version,
+                path.display(),
+                BASELINE_VERSION
+            );
+        }
+        Ok(baseline)
+    }
+
+    /// Write the baseline as pretty-printed JSON.
+    pub fn write(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json + "\n")?;
+        Ok(())
+    }
+
+    /// Total number of diagnostics recorded in the baseline.
+    pub fn diagnostic_count(&self) -> usize {
+        self.entries.iter().map(|e| e.count).sum()
+    }
+
+    /// Remove diagnostics covered by the baseline and return how many were
+    /// suppressed.
+    ///
+    /// Each entry suppresses at most `count` diagnostics, so a second copy of
+    /// an already-baselined violation is still reported.
+    pub fn filter(&self, diagnostics: &mut Vec<Diagnostic>, base_path: &Path) -> usize {
+        let mut remaining: HashMap<(String, String, String), usize> = self
+            .entries
+            .iter()
+            .map(|e| {
+                (
+                    (
+                        e.rule.clone(),
+                        e.file.clone(),
+                        normalize_message(&e.message),
+                    ),
+                    e.count,
+                )
+            })
+            .collect();
+
+        let before = diagnostics.len();
+        diagnostics.retain(
+            |diag| match remaining.get_mut(&fingerprint(diag, base_path)) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    false
+                }
+                _ => true,
+            },
+        );
+        before - diagnostics.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn diag(file: &str, line: usize, rule: &str, message: &str) -> Diagnostic {
+        Diagnostic::warning(
+            PathBuf::from("/project").join(file),
+            line,
+            1,
+            rule,
+            message.to_string(),
+        )
+    }
+
+    #[test]
+    fn test_normalize_message() {
+        assert_eq!(
+            normalize_message("Line  12 exceeds\t1024 chars"),
+            "Line # exceeds # chars"
+        );
+        assert_eq!(normalize_message("v1.2.3"), "v#.#.#");
+    }
+
+    #[test]
+    fn test_fingerprint_ignores_line_changes() {
+        let base = Path::new("/project");
+        let baseline =
+            Baseline::from_diagnostics(&[diag("CLAUDE.md", 3, "CC-MEM-005", "Generic")], base);
+
+        let mut current = vec![diag("CLAUDE.md", 40, "CC-MEM-005", "Generic")];
+        assert_eq!(baseline.filter(&mut current, base), 1);
+        assert!(current.is_empty());
+    }
+
+    #[test]
+    fn test_new_diagnostics_are_kept() {
+        let base = Path::new("/project");
+        let baseline =
+            Baseline::from_diagnostics(&[diag("CLAUDE.md", 3, "CC-MEM-005", "Generic")], base);
+
+        let mut current = vec![
+            diag("CLAUDE.md", 3, "CC-MEM-005", "Generic"),
+            diag("CLAUDE.md", 9, "CC-MEM-006", "Negative"),
+            diag("AGENTS.md", 3, "CC-MEM-005", "Generic"),
+        ];
+        assert_eq!(baseline.filter(&mut current, base), 1);
+        let rules: Vec<_> = current
+            .iter()
+            .map(|d| (d.rule.as_str(), d.file.clone()))
+            .collect();
+        assert_eq!(
+            rules,
+            vec![
+                ("CC-MEM-006", PathBuf::from("/project/CLAUDE.md")),
+                ("CC-MEM-005", PathBuf::from("/project/AGENTS.md")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_counts_limit_suppression() {
+        let base = Path::new("/project");
+        let baseline =
+            Baseline::from_diagnostics(&[diag("CLAUDE.md", 3, "XP-003", "Hard-coded path")], base);
+
+        let mut current = vec![
+            diag("CLAUDE.md", 3, "XP-003", "Hard-coded path"),
+            diag("CLAUDE.md", 8, "XP-003", "Hard-coded path"),
+        ];
+        assert_eq!(baseline.filter(&mut current, base), 1);
+        assert_eq!(current.len(), 1);
+    }
+
+    #[test]
+    fn test_entries_are_sorted_and_counted() {
+        let base = Path::new("/project");
+        let baseline = Baseline::from_diagnostics(
+            &[
+                diag("b.md", 1, "AS-001", "x"),
+                diag("a.md", 1, "AS-001", "x"),
+                diag("a.md", 2, "AS-001", "x"),
+            ],
+            base,
+        );
+        assert_eq!(baseline.entries.len(), 2);
+        assert_eq!(baseline.entries[0].file, "a.md");
+        assert_eq!(baseline.entries[0].count, 2);
+        assert_eq!(baseline.diagnostic_count(), 3);
+    }
+
+    #[test]
+    fn test_round_trip_and_version_check() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join(DEFAULT_BASELINE_FILE);
+        let base = Path::new("/project");
+
+        let baseline = Baseline::from_diagnostics(&[diag("CLAUDE.md", 1, "AS-001", "x")], base);
+        baseline.write(&path).unwrap();
+        let loaded = Baseline::load(&path).unwrap();
+        assert_eq!(loaded.entries, baseline.entries);
+
+        std::fs::write(&path, r#"{"version": 99, "entries": []}"#).unwrap();
+        assert!(Baseline::load(&path).is_err());
+    }
+}
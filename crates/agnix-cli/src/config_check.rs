@@ -0,0 +1,203 @@
+//! Unknown-key check for `--check-config`.
+//!
+//! serde ignores keys it does not know, so a typo such as `disable_rules`
+//! instead of `disabled_rules` silently has no effect. This walks the raw TOML
+//! of the config file against the properties of the generated JSON Schema and
+//! reports every key the schema does not define, with the closest known key at
+//! the same level as a suggestion.
+
+use crate::explain::levenshtein;
+use agnix_core::config::generate_schema;
+use colored::*;
+use rust_i18n::t;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::path::Path;
+
+/// A key in the config file that `LintConfig` does not define.
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct UnknownKey {
+    /// Dotted path of the key, e.g. `rules.disable_rules`.
+    pub key: String,
+    /// Closest known key at the same level, as a dotted path.
+    pub suggestion: Option<String>,
+}
+
+/// Unknown keys in a parsed config file, sorted by key within each table.
+pub fn unknown_keys(source: &toml::Table) -> Vec<UnknownKey> {
+    let schema = serde_json::to_value(generate_schema()).unwrap_or(Value::Null);
+    let definitions = schema
+        .get("definitions")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut unknown = Vec::new();
+    if let Some(properties) = schema.get("properties").and_then(Value::as_object) {
+        collect_unknown(source, properties, &definitions, "", &mut unknown);
+    }
+    unknown
+}
+
+fn collect_unknown(
+    table: &toml::Table,
+    properties: &Map<String, Value>,
+    definitions: &Map<String, Value>,
+    prefix: &str,
+    unknown: &mut Vec<UnknownKey>,
+) {
+    for (key, value) in table {
+        let path = format!("{prefix}{key}");
+        let Some(property) = properties.get(key) else {
+            unknown.push(UnknownKey {
+                key: path,
+                suggestion: closest_key(key, properties).map(|known| format!("{prefix}{known}")),
+            });
+            continue;
+        };
+
+        // Descend into nested structs such as [rules]; maps like
+        // [rules.severity_overrides] accept any key and are not checked
+        if let (Some(nested), Some(nested_properties)) =
+            (value.as_table(), struct_properties(property, definitions))
+        {
+            collect_unknown(
+                nested,
+                nested_properties,
+                definitions,
+                &format!("{path}."),
+                unknown,
+            );
+        }
+    }
+}
+
+/// Properties of a schema that describes a struct, following `$ref`s.
+fn struct_properties<'a>(
+    schema: &'a Value,
+    definitions: &'a Map<String, Value>,
+) -> Option<&'a Map<String, Value>> {
+    let reference = schema.get("$ref").or_else(|| {
+        schema
+            .get("allOf")
+            .and_then(Value::as_array)
+            .and_then(|all_of| all_of.first())
+            .and_then(|first| first.get("$ref"))
+    });
+    let resolved = match reference.and_then(Value::as_str) {
+        Some(reference) => definitions.get(reference.strip_prefix("#/definitions/")?)?,
+        None => schema,
+    };
+    resolved.get("properties").and_then(Value::as_object)
+}
+
+/// Closest known key to `key`, if any is reasonably close.
+fn closest_key<'a>(key: &str, properties: &'a Map<String, Value>) -> Option<&'a str> {
+    // Allow roughly one typo per three characters, never fewer than two.
+    let max_distance = (key.len() / 3).max(2);
+
+    properties
+        .keys()
+        .map(|known| (levenshtein(key, known), known.as_str()))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, known)| known)
+}
+
+/// Print the check for `path` as pretty JSON or text.
+pub fn print_report(path: &Path, unknown: &[UnknownKey], json: bool) -> anyhow::Result<()> {
+    if json {
+        let report = serde_json::json!({
+            "config": path.display().to_string(),
+            "unknown_keys": unknown,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    if unknown.is_empty() {
+        println!(
+            "{}",
+            t!("cli.config_check_ok", path = path.display().to_string()).green()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        t!(
+            "cli.config_check_unknown",
+            count = unknown.len(),
+            path = path.display().to_string()
+        )
+        .bold()
+    );
+    for entry in unknown {
+        match &entry.suggestion {
+            Some(suggestion) => println!(
+                "  {}  {}",
+                entry.key.yellow(),
+                t!(
+                    "cli.config_check_did_you_mean",
+                    suggestion = suggestion.as_str()
+                )
+                .dimmed()
+            ),
+            None => println!("  {}", entry.key.yellow()),
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(toml_str: &str) -> Vec<UnknownKey> {
+        unknown_keys(&toml::from_str(toml_str).unwrap())
+    }
+
+    #[test]
+    fn test_known_keys_pass() {
+        let unknown = check(
+            r#"
+severity = "Warning"
+tools = ["claude-code"]
+
+[rules]
+disabled_rules = ["AS-004"]
+skills = false
+
+[rules.severity_overrides]
+"AS-005" = "error"
+
+[tool_versions]
+claude_code = "1.0.0"
+"#,
+        );
+        assert!(unknown.is_empty(), "{unknown:?}");
+    }
+
+    #[test]
+    fn test_rules_typo_suggests_known_key() {
+        let unknown = check("[rules]\ndisable_rules = [\"AS-004\"]\n");
+        assert_eq!(
+            unknown,
+            vec![UnknownKey {
+                key: "rules.disable_rules".to_string(),
+                suggestion: Some("rules.disabled_rules".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_top_level_unknown_without_close_match() {
+        let unknown = check("severty = \"Error\"\ncompletely_unrelated = 1\n");
+        assert_eq!(unknown.len(), 2);
+        assert_eq!(unknown[0].key, "completely_unrelated");
+        assert_eq!(unknown[0].suggestion, None);
+        assert_eq!(unknown[1].key, "severty");
+        assert_eq!(unknown[1].suggestion.as_deref(), Some("severity"));
+    }
+}
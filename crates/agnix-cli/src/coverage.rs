@@ -0,0 +1,115 @@
+//! Rule coverage audit for `--rules-coverage-report`.
+//!
+//! Compares the effective config against the full `agnix_rules::RULES_DATA`
+//! catalog: which rules will run, which are turned off, and which rule IDs
+//! the config references that don't exist (usually typos).
+
+use agnix_core::config::LintConfig;
+use colored::*;
+use rust_i18n::t;
+use serde::Serialize;
+
+/// Rule coverage of an effective config.
+#[derive(Debug, Serialize)]
+pub struct RulesCoverage {
+    /// Total rules in the catalog.
+    pub total: usize,
+    /// Catalog rules that will run.
+    pub enabled: Vec<&'static str>,
+    /// Catalog rules turned off by `disabled_rules`, a category toggle, or target/tools.
+    pub disabled: Vec<&'static str>,
+    /// Rule IDs referenced in the config that are not in the catalog.
+    pub unknown: Vec<String>,
+}
+
+impl RulesCoverage {
+    pub fn new(config: &LintConfig) -> Self {
+        let (enabled, disabled): (Vec<&str>, Vec<&str>) = agnix_rules::RULES_DATA
+            .iter()
+            .map(|(id, _)| *id)
+            .partition(|id| config.is_rule_enabled(id));
+
+        let mut unknown: Vec<String> = config
+            .rules
+            .disabled_rules
+            .iter()
+            .filter(|id| agnix_rules::get_rule_name(id).is_none())
+            .cloned()
+            .collect();
+        unknown.sort();
+        unknown.dedup();
+
+        Self {
+            total: agnix_rules::rule_count(),
+            enabled,
+            disabled,
+            unknown,
+        }
+    }
+}
+
+/// Print the report as pretty JSON or grouped text.
+pub fn print_report(coverage: &RulesCoverage, json: bool) -> anyhow::Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(coverage)?);
+        return Ok(());
+    }
+
+    print_group(
+        &t!(
+            "cli.coverage_enabled",
+            count = coverage.enabled.len(),
+            total = coverage.total
+        ),
+        &coverage.enabled,
+    );
+    print_group(
+        &t!("cli.coverage_disabled", count = coverage.disabled.len()),
+        &coverage.disabled,
+    );
+    print_group(
+        &t!("cli.coverage_unknown", count = coverage.unknown.len()),
+        &coverage.unknown,
+    );
+
+    Ok(())
+}
+
+fn print_group<S: AsRef<str>>(heading: &str, ids: &[S]) {
+    println!("{}", heading.bold());
+    for id in ids {
+        println!("  {}", id.as_ref());
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config_enables_catalog() {
+        let coverage = RulesCoverage::new(&LintConfig::default());
+        assert_eq!(
+            coverage.enabled.len() + coverage.disabled.len(),
+            coverage.total
+        );
+        assert!(coverage.enabled.contains(&"AS-004"));
+        assert!(coverage.unknown.is_empty());
+    }
+
+    #[test]
+    fn test_typo_in_disabled_rules_is_unknown() {
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec![
+            "AS-005".to_string(),
+            "AS-0005".to_string(),
+            "AS-0005".to_string(),
+        ];
+
+        let coverage = RulesCoverage::new(&config);
+        assert!(coverage.disabled.contains(&"AS-005"));
+        assert!(!coverage.enabled.contains(&"AS-005"));
+        assert_eq!(coverage.unknown, vec!["AS-0005".to_string()]);
+    }
+}
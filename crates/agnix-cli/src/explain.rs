@@ -0,0 +1,160 @@
+//! `agnix explain <RULE-ID>`: a local reference for a single rule.
+//!
+//! Prints the rule name, the tool its prefix belongs to, and the localized
+//! message and suggestion templates. Unknown IDs get a "did you mean"
+//! suggestion based on edit distance over all known rule IDs.
+
+use agnix_rules::RULES_DATA;
+use colored::*;
+use rust_i18n::t;
+
+/// Everything `agnix explain` prints for a known rule.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RuleExplanation {
+    pub id: &'static str,
+    pub name: &'static str,
+    /// Tool the rule's prefix belongs to, if the prefix is tool-specific.
+    pub tool: Option<&'static str>,
+    /// Message template with `%{...}` placeholders left in place.
+    pub message: Option<String>,
+    /// Suggestion template with `%{...}` placeholders left in place.
+    pub suggestion: Option<String>,
+}
+
+/// Look up a rule by ID (case-insensitive).
+pub fn explain(rule_id: &str) -> Option<RuleExplanation> {
+    let wanted = rule_id.trim().to_ascii_uppercase();
+    let (id, name) = RULES_DATA.iter().find(|(id, _)| *id == wanted)?;
+
+    Some(RuleExplanation {
+        id,
+        name,
+        tool: agnix_rules::rule_prefix(id).and_then(agnix_rules::get_tool_for_prefix),
+        message: rule_template(id, "message"),
+        suggestion: rule_template(id, "suggestion"),
+    })
+}
+
+/// Closest known rule ID to `rule_id`, if any is reasonably close.
+pub fn closest_rule_id(rule_id: &str) -> Option<&'static str> {
+    let wanted = rule_id.trim().to_ascii_uppercase();
+    // Allow roughly one typo per three characters, never fewer than two.
+    let max_distance = (wanted.len() / 3).max(2);
+
+    RULES_DATA
+        .iter()
+        .map(|(id, _)| (levenshtein(&wanted, id), *id))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min()
+        .map(|(_, id)| id)
+}
+
+/// Localized `rules.<id>.<field>` template, if the locale defines one.
+fn rule_template(id: &str, field: &str) -> Option<String> {
+    let key = format!(
+        "rules.{}.{}",
+        id.to_ascii_lowercase().replace('-', "_"),
+        field
+    );
+    let value = t!(&key).to_string();
+    // rust-i18n echoes the key back when it is missing
+    (!value.ends_with(&key)).then_some(value)
+}
+
+pub(crate) fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        curr[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    prev[b.len()]
+}
+
+/// Run `agnix explain`.
+pub fn explain_command(rule_id: &str) -> anyhow::Result<()> {
+    let Some(rule) = explain(rule_id) else {
+        let mut message = t!("cli.explain_unknown_rule", rule = rule_id).to_string();
+        if let Some(closest) = closest_rule_id(rule_id) {
+            message.push_str(&format!(
+                " {}",
+                t!("cli.explain_did_you_mean", rule = closest)
+            ));
+        }
+        anyhow::bail!(message);
+    };
+
+    println!("{} {}", rule.id.bold(), rule.name);
+    println!();
+    println!(
+        "  {} {}",
+        t!("cli.explain_tool_label").dimmed(),
+        rule.tool.unwrap_or("generic")
+    );
+    if let Some(message) = &rule.message {
+        println!("  {} {}", t!("cli.explain_message_label").dimmed(), message);
+    }
+    if let Some(suggestion) = &rule.suggestion {
+        println!("  {} {}", t!("cli.help_label").cyan(), suggestion);
+    }
+    if let Some(url) = agnix_rules::docs_url(rule.id) {
+        println!("  {} {}", t!("cli.explain_docs_label").dimmed(), url);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_explain_known_rule() {
+        let rule = explain("CC-SK-006").unwrap();
+        assert_eq!(rule.id, "CC-SK-006");
+        assert_eq!(rule.tool, Some("claude-code"));
+        assert!(
+            rule.message
+                .as_deref()
+                .is_some_and(|m| m.contains("disable-model-invocation"))
+        );
+        assert!(rule.suggestion.is_some());
+    }
+
+    #[test]
+    fn test_explain_is_case_insensitive() {
+        assert_eq!(explain("cc-sk-006").map(|r| r.id), Some("CC-SK-006"));
+    }
+
+    #[test]
+    fn test_explain_generic_prefix_has_no_tool() {
+        let rule = explain("MCP-001").unwrap();
+        assert_eq!(rule.tool, None);
+    }
+
+    #[test]
+    fn test_explain_unknown_rule() {
+        assert!(explain("XX-999").is_none());
+    }
+
+    #[test]
+    fn test_closest_rule_id() {
+        assert_eq!(closest_rule_id("CC-SK-06"), Some("CC-SK-006"));
+        assert_eq!(closest_rule_id("as-0004"), Some("AS-004"));
+        assert_eq!(closest_rule_id("TOTALLY-UNRELATED-NAME"), None);
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("AS-004", "AS-004"), 0);
+    }
+}
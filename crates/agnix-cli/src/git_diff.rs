@@ -0,0 +1,213 @@
+//! `--diff <ref>`: validate only what changed relative to a git ref.
+//!
+//! `git diff --name-only` picks the files to validate and
+//! `git diff --unified=0` gives the changed line ranges in each of them.
+//! Diagnostics are kept only when their line falls inside one of those
+//! ranges, so legacy issues elsewhere in a touched file stay quiet.
+//!
+//! Only the working tree is compared with the ref; untracked files are not
+//! part of `git diff` and are skipped until they are added.
+
+use agnix_core::diagnostics::Diagnostic;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Files changed relative to a git ref and the new-side lines changed in each.
+#[derive(Debug, Default)]
+pub struct ChangedLines {
+    files: HashMap<PathBuf, Vec<RangeInclusive<usize>>>,
+}
+
+impl ChangedLines {
+    /// Ask git for the changes under `dir` relative to `git_ref`.
+    pub fn from_git(dir: &Path, git_ref: &str) -> anyhow::Result<Self> {
+        // Deleted files have nothing left to validate
+        let names = run_git(
+            dir,
+            &[
+                "diff",
+                "--name-only",
+                "--relative",
+                "--diff-filter=d",
+                git_ref,
+                "--",
+            ],
+        )?;
+        let hunks = run_git(
+            dir,
+            &[
+                "diff",
+                "--unified=0",
+                "--relative",
+                "--no-color",
+                "--no-ext-diff",
+                // Fixed prefixes whatever diff.noprefix / diff.mnemonicPrefix say
+                "--src-prefix=a/",
+                "--dst-prefix=b/",
+                git_ref,
+                "--",
+            ],
+        )?;
+
+        let mut ranges = parse_changed_ranges(&hunks);
+        let files = names
+            .lines()
+            .filter(|name| !name.is_empty())
+            .map(|name| {
+                let changed = ranges.remove(Path::new(name)).unwrap_or_default();
+                (canonical(&dir.join(name)), changed)
+            })
+            .collect();
+        Ok(Self { files })
+    }
+
+    /// Changed files, for `validate_files`.
+    pub fn files(&self) -> Vec<PathBuf> {
+        let mut files: Vec<PathBuf> = self.files.keys().cloned().collect();
+        files.sort();
+        files
+    }
+
+    /// Whether `diagnostic` points at a changed line of a changed file.
+    pub fn contains(&self, diagnostic: &Diagnostic) -> bool {
+        self.files
+            .get(&canonical(&diagnostic.file))
+            .is_some_and(|ranges| ranges.iter().any(|range| range.contains(&diagnostic.line)))
+    }
+}
+
+fn canonical(path: &Path) -> PathBuf {
+    std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn run_git(dir: &Path, args: &[&str]) -> anyhow::Result<String> {
+    // quotePath=false keeps non-ASCII file names unescaped in both outputs
+    let output = Command::new("git")
+        .args(["-c", "core.quotePath=false", "-C"])
+        .arg(dir)
+        .args(args)
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// New-side line ranges per file from `git diff --unified=0` output.
+///
+/// Hunks that only delete lines (`+N,0`) add no range: there is no new line
+/// left to attach a diagnostic to.
+fn parse_changed_ranges(diff: &str) -> HashMap<PathBuf, Vec<RangeInclusive<usize>>> {
+    let mut ranges: HashMap<PathBuf, Vec<RangeInclusive<usize>>> = HashMap::new();
+    let mut current: Option<PathBuf> = None;
+
+    for line in diff.lines() {
+        if let Some(target) = line.strip_prefix("+++ ") {
+            // `+++ /dev/null` for deleted files; otherwise `+++ b/<path>`
+            current = target
+                .strip_prefix("b/")
+                .map(|path| PathBuf::from(path.trim_end_matches('\t')));
+        } else if let Some(hunk) = line.strip_prefix("@@ ") {
+            let Some(path) = current.as_ref() else {
+                continue;
+            };
+            if let Some(range) = hunk
+                .split_whitespace()
+                .find_map(|part| part.strip_prefix('+'))
+                .and_then(parse_new_range)
+            {
+                ranges.entry(path.clone()).or_default().push(range);
+            }
+        }
+    }
+
+    ranges
+}
+
+/// `start[,count]` from a hunk header; a missing count means one line.
+fn parse_new_range(spec: &str) -> Option<RangeInclusive<usize>> {
+    let (start, count) = match spec.split_once(',') {
+        Some((start, count)) => (start.parse::<usize>().ok()?, count.parse::<usize>().ok()?),
+        None => (spec.parse::<usize>().ok()?, 1),
+    };
+    if count == 0 {
+        return None;
+    }
+    Some(start..=start + count - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/CLAUDE.md b/CLAUDE.md
+index 1111111..2222222 100644
+--- a/CLAUDE.md
++++ b/CLAUDE.md
+@@ -3,0 +4,2 @@ # Project
++new line one
++new line two
+@@ -10 +12 @@ ## Commands
+-old
++new
+@@ -20,3 +21,0 @@ ## Notes
+-gone
+-gone
+-gone
+diff --git a/old.md b/old.md
+deleted file mode 100644
+--- a/old.md
++++ /dev/null
+@@ -1,2 +0,0 @@
+-a
+-b
+diff --git a/.claude/skills/x/SKILL.md b/.claude/skills/x/SKILL.md
+new file mode 100644
+--- /dev/null
++++ b/.claude/skills/x/SKILL.md
+@@ -0,0 +1,3 @@
++---
++name: x
++---
+";
+
+    #[test]
+    fn test_parse_changed_ranges() {
+        let ranges = parse_changed_ranges(DIFF);
+
+        assert_eq!(ranges[Path::new("CLAUDE.md")], vec![4..=5, 12..=12]);
+        assert_eq!(ranges[Path::new(".claude/skills/x/SKILL.md")], vec![1..=3]);
+        assert!(!ranges.contains_key(Path::new("old.md")));
+    }
+
+    #[test]
+    fn test_parse_new_range() {
+        assert_eq!(parse_new_range("7"), Some(7..=7));
+        assert_eq!(parse_new_range("7,3"), Some(7..=9));
+        assert_eq!(parse_new_range("7,0"), None);
+        assert_eq!(parse_new_range("x"), None);
+    }
+
+    #[test]
+    fn test_contains_only_changed_lines_of_changed_files() {
+        let mut files = HashMap::new();
+        files.insert(PathBuf::from("/project/CLAUDE.md"), vec![4..=5, 12..=12]);
+        let changed = ChangedLines { files };
+
+        let diag = |file: &str, line: usize| {
+            Diagnostic::warning(PathBuf::from(file), line, 0, "CC-MEM-005", "msg")
+        };
+        assert!(changed.contains(&diag("/project/CLAUDE.md", 4)));
+        assert!(changed.contains(&diag("/project/CLAUDE.md", 12)));
+        assert!(!changed.contains(&diag("/project/CLAUDE.md", 6)));
+        assert!(!changed.contains(&diag("/project/AGENTS.md", 4)));
+    }
+}
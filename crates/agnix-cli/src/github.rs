@@ -0,0 +1,153 @@
+//! GitHub Actions output format support.
+//!
+//! Renders diagnostics as workflow commands (`::error file=...::message`) so
+//! that GitHub annotates the offending lines directly in pull requests.
+//! See <https://docs.github.com/actions/using-workflows/workflow-commands-for-github-actions>.
+
+use crate::json::path_to_string;
+use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel};
+use std::path::Path;
+
+/// Map a diagnostic level to its workflow command name.
+fn level_to_command(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        DiagnosticLevel::Info => "notice",
+    }
+}
+
+/// Escape the message portion of a workflow command.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value (file, title) of a workflow command.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Format a single diagnostic as a workflow command line.
+pub fn format_diagnostic(diag: &Diagnostic, base_path: &Path) -> String {
+    let message = match &diag.suggestion {
+        Some(suggestion) => format!("{}\n{}", diag.message, suggestion),
+        None => diag.message.clone(),
+    };
+    format!(
+        "::{} file={},line={},col={},title={}::{}",
+        level_to_command(diag.level),
+        escape_property(&path_to_string(&diag.file, base_path)),
+        diag.line.max(1),
+        diag.column.max(1),
+        escape_property(&diag.rule),
+        escape_data(&message)
+    )
+}
+
+/// Render all diagnostics as workflow commands, one per line.
+pub fn diagnostics_to_github(diagnostics: &[Diagnostic], base_path: &Path) -> String {
+    diagnostics
+        .iter()
+        .map(|diag| format_diagnostic(diag, base_path))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn diag(level: DiagnosticLevel, message: &str) -> Diagnostic {
+        let file = PathBuf::from("/project/.claude/skills/demo/SKILL.md");
+        match level {
+            DiagnosticLevel::Error => Diagnostic::error(file, 3, 5, "AS-004", message.to_string()),
+            DiagnosticLevel::Warning => {
+                Diagnostic::warning(file, 3, 5, "AS-004", message.to_string())
+            }
+            DiagnosticLevel::Info => Diagnostic::info(file, 3, 5, "AS-004", message.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_level_mapping() {
+        assert_eq!(level_to_command(DiagnosticLevel::Error), "error");
+        assert_eq!(level_to_command(DiagnosticLevel::Warning), "warning");
+        assert_eq!(level_to_command(DiagnosticLevel::Info), "notice");
+    }
+
+    #[test]
+    fn test_format_error() {
+        let line = format_diagnostic(
+            &diag(DiagnosticLevel::Error, "Bad name"),
+            Path::new("/project"),
+        );
+        assert_eq!(
+            line,
+            "::error file=.claude/skills/demo/SKILL.md,line=3,col=5,title=AS-004::Bad name"
+        );
+    }
+
+    #[test]
+    fn test_info_uses_notice() {
+        let line = format_diagnostic(&diag(DiagnosticLevel::Info, "FYI"), Path::new("/project"));
+        assert!(line.starts_with("::notice "));
+    }
+
+    #[test]
+    fn test_message_escaping() {
+        let line = format_diagnostic(
+            &diag(DiagnosticLevel::Warning, "50% done\r\nnext"),
+            Path::new("/project"),
+        );
+        assert!(line.ends_with("::50%25 done%0D%0Anext"));
+    }
+
+    #[test]
+    fn test_property_escaping() {
+        assert_eq!(escape_property("a,b:c%"), "a%2Cb%3Ac%25");
+        assert_eq!(escape_property("x\ny"), "x%0Ay");
+    }
+
+    #[test]
+    fn test_suggestion_appended() {
+        let d = diag(DiagnosticLevel::Error, "Bad name").with_suggestion("Rename it");
+        let line = format_diagnostic(&d, Path::new("/project"));
+        assert!(line.ends_with("::Bad name%0ARename it"));
+    }
+
+    #[test]
+    fn test_zero_positions_clamped() {
+        let d = Diagnostic::error(
+            PathBuf::from("/project/CLAUDE.md"),
+            0,
+            0,
+            "CC-MEM-001",
+            "msg".to_string(),
+        );
+        let line = format_diagnostic(&d, Path::new("/project"));
+        assert!(line.contains("line=1,col=1"));
+    }
+
+    #[test]
+    fn test_windows_paths_normalized() {
+        assert_eq!(
+            path_to_string(Path::new("foo\\bar\\baz.md"), Path::new("/project")),
+            "foo/bar/baz.md"
+        );
+    }
+
+    #[test]
+    fn test_multiple_diagnostics_one_per_line() {
+        let diags = vec![
+            diag(DiagnosticLevel::Error, "one"),
+            diag(DiagnosticLevel::Warning, "two"),
+        ];
+        let output = diagnostics_to_github(&diags, Path::new("/project"));
+        assert_eq!(output.lines().count(), 2);
+        assert!(diagnostics_to_github(&[], Path::new("/project")).is_empty());
+    }
+}
@@ -0,0 +1,272 @@
+//! Standalone HTML report for `--report`.
+//!
+//! Produces a single self-contained file (inline CSS and JS, no external
+//! assets) so results can be shared with people who don't run the CLI.
+
+use crate::json::path_to_string;
+use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel};
+use std::fmt::Write as _;
+use std::path::Path;
+
+const RULE_DOCS_URL: &str =
+    "https://github.com/avifenesh/agnix/blob/main/knowledge-base/VALIDATION-RULES.md";
+
+const STYLE: &str = r#"body{font-family:-apple-system,Segoe UI,Helvetica,Arial,sans-serif;margin:2rem;color:#1f2328}
+h1{margin-bottom:.25rem}
+.meta{color:#59636e;margin-top:0}
+.summary{display:flex;gap:1rem;margin:1rem 0}
+.summary div{border:1px solid #d1d9e0;border-radius:6px;padding:.5rem 1rem}
+.summary strong{display:block;font-size:1.5rem}
+.filters{margin:1rem 0;display:flex;gap:.5rem}
+table{border-collapse:collapse;width:100%}
+th,td{border-bottom:1px solid #d1d9e0;padding:.4rem .6rem;text-align:left;vertical-align:top}
+th{background:#f6f8fa}
+tr.file td{background:#f6f8fa;font-weight:600}
+.error{color:#d1242f}.warning{color:#9a6700}.info{color:#0969da}
+code{font-family:ui-monospace,Menlo,Consolas,monospace}"#;
+
+const SCRIPT: &str = r#"function applyFilters(){
+var level=document.getElementById('level').value;
+var text=document.getElementById('search').value.toLowerCase();
+document.querySelectorAll('tr.diag').forEach(function(row){
+var show=(!level||row.dataset.level===level)&&(!text||row.textContent.toLowerCase().indexOf(text)!==-1);
+row.style.display=show?'':'none';
+});
+document.querySelectorAll('tr.file').forEach(function(header){
+var any=document.querySelectorAll('tr.diag[data-file="'+header.dataset.file+'"]');
+header.style.display=Array.prototype.some.call(any,function(r){return r.style.display!=='none';})?'':'none';
+});
+}"#;
+
+fn level_to_string(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Error => "error",
+        DiagnosticLevel::Warning => "warning",
+        DiagnosticLevel::Info => "info",
+    }
+}
+
+fn level_rank(level: DiagnosticLevel) -> u8 {
+    match level {
+        DiagnosticLevel::Error => 0,
+        DiagnosticLevel::Warning => 1,
+        DiagnosticLevel::Info => 2,
+    }
+}
+
+/// Escape text for use in HTML element content and quoted attributes.
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Render diagnostics as a standalone HTML document.
+///
+/// Rows are grouped by file, then ordered by severity and line.
+pub fn diagnostics_to_html(
+    diagnostics: &[Diagnostic],
+    base_path: &Path,
+    files_checked: usize,
+) -> String {
+    let count = |level| diagnostics.iter().filter(|d| d.level == level).count();
+    let errors = count(DiagnosticLevel::Error);
+    let warnings = count(DiagnosticLevel::Warning);
+    let info = count(DiagnosticLevel::Info);
+
+    let mut sorted: Vec<(String, &Diagnostic)> = diagnostics
+        .iter()
+        .map(|d| (path_to_string(&d.file, base_path), d))
+        .collect();
+    sorted.sort_by(|(a_file, a), (b_file, b)| {
+        a_file
+            .cmp(b_file)
+            .then(level_rank(a.level).cmp(&level_rank(b.level)))
+            .then(a.line.cmp(&b.line))
+            .then(a.column.cmp(&b.column))
+    });
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    html.push_str("<title>agnix report</title>\n");
+    let _ = writeln!(html, "<style>\n{}\n</style>", STYLE);
+    html.push_str("</head>\n<body>\n<h1>agnix report</h1>\n");
+    let _ = writeln!(
+        html,
+        "<p class=\"meta\">agnix {} &middot; {} files checked &middot; {} diagnostics</p>",
+        env!("CARGO_PKG_VERSION"),
+        files_checked,
+        diagnostics.len()
+    );
+    let _ = writeln!(
+        html,
+        "<div class=\"summary\"><div class=\"error\"><strong>{}</strong>errors</div>\
+         <div class=\"warning\"><strong>{}</strong>warnings</div>\
+         <div class=\"info\"><strong>{}</strong>info</div></div>",
+        errors, warnings, info
+    );
+
+    if sorted.is_empty() {
+        html.push_str("<p>No issues found.</p>\n");
+    } else {
+        html.push_str(
+            "<div class=\"filters\">\
+             <select id=\"level\" onchange=\"applyFilters()\">\
+             <option value=\"\">All severities</option>\
+             <option value=\"error\">Errors</option>\
+             <option value=\"warning\">Warnings</option>\
+             <option value=\"info\">Info</option></select>\
+             <input id=\"search\" type=\"search\" placeholder=\"Filter by file, rule, or message\" oninput=\"applyFilters()\">\
+             </div>\n",
+        );
+        html.push_str(
+            "<table>\n<thead><tr><th>Severity</th><th>Rule</th><th>Location</th><th>Message</th></tr></thead>\n<tbody>\n",
+        );
+
+        let mut current_file: Option<&str> = None;
+        for (file, diag) in &sorted {
+            let file_attr = escape_html(file);
+            if current_file != Some(file.as_str()) {
+                let _ = writeln!(
+                    html,
+                    "<tr class=\"file\" data-file=\"{0}\"><td colspan=\"4\"><code>{0}</code></td></tr>",
+                    file_attr
+                );
+                current_file = Some(file.as_str());
+            }
+
+            let level = level_to_string(diag.level);
+            let mut message = escape_html(&diag.message);
+            if let Some(suggestion) = &diag.suggestion {
+                let _ = write!(message, "<br><em>{}</em>", escape_html(suggestion));
+            }
+            let _ = writeln!(
+                html,
+                "<tr class=\"diag\" data-file=\"{file}\" data-level=\"{level}\">\
+                 <td class=\"{level}\">{level}</td>\
+                 <td><a href=\"{url}#{anchor}\"><code>{rule}</code></a></td>\
+                 <td>{line}:{column}</td><td>{message}</td></tr>",
+                file = file_attr,
+                level = level,
+                url = RULE_DOCS_URL,
+                anchor = escape_html(&diag.rule.to_lowercase()),
+                rule = escape_html(&diag.rule),
+                line = diag.line,
+                column = diag.column,
+                message = message,
+            );
+        }
+
+        html.push_str("</tbody>\n</table>\n");
+        let _ = writeln!(html, "<script>\n{}\n</script>", SCRIPT);
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/// Write the HTML report to `path`.
+pub fn write_report(
+    path: &Path,
+    diagnostics: &[Diagnostic],
+    base_path: &Path,
+    files_checked: usize,
+) -> anyhow::Result<()> {
+    std::fs::write(
+        path,
+        diagnostics_to_html(diagnostics, base_path, files_checked),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn sample() -> Vec<Diagnostic> {
+        vec![
+            Diagnostic::warning(
+                PathBuf::from("/project/CLAUDE.md"),
+                4,
+                1,
+                "CC-MEM-005",
+                "Generic instruction".to_string(),
+            ),
+            Diagnostic::error(
+                PathBuf::from("/project/skills/a/SKILL.md"),
+                2,
+                1,
+                "AS-004",
+                "Invalid name <Bad>".to_string(),
+            )
+            .with_suggestion("Use lowercase"),
+            Diagnostic::info(
+                PathBuf::from("/project/CLAUDE.md"),
+                1,
+                1,
+                "XP-003",
+                "Hard-coded path".to_string(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_report_contains_count_and_rule_ids() {
+        let diagnostics = sample();
+        let html = diagnostics_to_html(&diagnostics, Path::new("/project"), 2);
+
+        assert!(html.contains("3 diagnostics"));
+        assert!(html.contains("2 files checked"));
+        for diag in &diagnostics {
+            assert!(html.contains(&diag.rule), "missing rule {}", diag.rule);
+        }
+        assert!(html.contains(&format!("{}#as-004", RULE_DOCS_URL)));
+    }
+
+    #[test]
+    fn test_report_groups_by_file_then_severity() {
+        let html = diagnostics_to_html(&sample(), Path::new("/project"), 2);
+
+        let claude = html.find("data-file=\"CLAUDE.md\"><td").unwrap();
+        let skill = html.find("data-file=\"skills/a/SKILL.md\"><td").unwrap();
+        assert!(claude < skill);
+
+        let warning = html.find("CC-MEM-005</code>").unwrap();
+        let info = html.find("XP-003</code>").unwrap();
+        assert!(warning < info);
+    }
+
+    #[test]
+    fn test_report_escapes_html() {
+        let html = diagnostics_to_html(&sample(), Path::new("/project"), 2);
+        assert!(html.contains("Invalid name &lt;Bad&gt;"));
+        assert!(!html.contains("<Bad>"));
+    }
+
+    #[test]
+    fn test_report_is_self_contained() {
+        let html = diagnostics_to_html(&sample(), Path::new("/project"), 2);
+        assert!(!html.contains("<link"));
+        assert!(!html.contains("src=\""));
+        assert!(html.contains("<style>"));
+        assert!(html.contains("<script>"));
+    }
+
+    #[test]
+    fn test_report_without_diagnostics() {
+        let html = diagnostics_to_html(&[], Path::new("/project"), 5);
+        assert!(html.contains("0 diagnostics"));
+        assert!(html.contains("No issues found."));
+        assert!(!html.contains("<table>"));
+    }
+}
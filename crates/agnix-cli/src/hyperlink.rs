@@ -0,0 +1,69 @@
+//! OSC 8 terminal hyperlink support for text output.
+//!
+//! Terminals that understand OSC 8 render the wrapped text as a clickable
+//! link. Terminals that do not simply show the text, but the escape bytes
+//! would still corrupt piped output, so links are only emitted for an
+//! interactive, colorized stdout.
+
+use std::io::IsTerminal;
+
+/// Wrap `text` in an OSC 8 hyperlink pointing at `url`.
+pub fn osc8(url: &str, text: &str) -> String {
+    format!("\x1b]8;;{}\x1b\\{}\x1b]8;;\x1b\\", url, text)
+}
+
+/// Returns true when hyperlinks were requested and stdout can render them.
+pub fn should_emit(requested: bool) -> bool {
+    requested
+        && colored::control::SHOULD_COLORIZE.should_colorize()
+        && std::io::stdout().is_terminal()
+}
+
+/// Render a rule code, linking it to its documentation page when `linked` is set.
+///
+/// Rules without a documentation page (e.g. internal `file::read` diagnostics)
+/// are always rendered as plain text.
+pub fn rule_code(rule: &str, linked: bool) -> String {
+    match agnix_rules::docs_url(rule) {
+        Some(url) if linked => osc8(&url, rule),
+        _ => rule.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_osc8_wraps_text() {
+        assert_eq!(
+            osc8("https://example.com", "link"),
+            "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_rule_code_linked() {
+        let rendered = rule_code("AS-004", true);
+        let url = agnix_rules::docs_url("AS-004").unwrap();
+        assert_eq!(
+            rendered,
+            format!("\x1b]8;;{}\x1b\\AS-004\x1b]8;;\x1b\\", url)
+        );
+    }
+
+    #[test]
+    fn test_rule_code_plain_when_disabled() {
+        assert_eq!(rule_code("AS-004", false), "AS-004");
+    }
+
+    #[test]
+    fn test_rule_code_plain_for_unknown_rule() {
+        assert_eq!(rule_code("file::read", true), "file::read");
+    }
+
+    #[test]
+    fn test_should_emit_requires_request() {
+        assert!(!should_emit(false));
+    }
+}
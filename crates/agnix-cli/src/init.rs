@@ -0,0 +1,233 @@
+//! `agnix init --interactive`: infer the tools in use and scaffold a config.
+//!
+//! The project directory is scanned for marker files (`.claude/`,
+//! `.cursor/`, `.github/copilot-instructions.md`, ...). Each detected tool is
+//! confirmed with the user before it is written to the `tools` array, and
+//! build output directories that exist are added to `exclude`.
+
+use std::io::{BufRead, Write};
+use std::path::Path;
+
+use agnix_core::config::LintConfig;
+use colored::*;
+use rust_i18n::t;
+
+/// Marker paths, relative to the project root, that show a tool is in use.
+/// Tool names match the values accepted by the `tools` config array.
+const TOOL_MARKERS: &[(&str, &[&str])] = &[
+    (
+        "claude-code",
+        &[".claude", "CLAUDE.md", "CLAUDE.local.md", ".mcp.json"],
+    ),
+    ("cursor", &[".cursor", ".cursorrules"]),
+    (
+        "copilot",
+        &[".github/copilot-instructions.md", ".github/instructions"],
+    ),
+    ("codex", &[".codex", "AGENTS.override.md"]),
+    ("cline", &[".clinerules"]),
+    ("opencode", &["opencode.json"]),
+    ("gemini-cli", &["GEMINI.md", ".gemini"]),
+    ("aider", &[".aider.conf.yml", "CONVENTIONS.md"]),
+];
+
+/// Build and dependency directories excluded when present, on top of the
+/// default `exclude` patterns.
+const OUTPUT_DIRS: &[&str] = &["dist", "build", "out", "vendor", ".venv"];
+
+/// A tool inferred from a marker file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DetectedTool {
+    pub tool: &'static str,
+    /// First marker found for the tool.
+    pub marker: &'static str,
+}
+
+/// Tools whose markers exist under `root`, in `TOOL_MARKERS` order.
+pub fn detect_tools(root: &Path) -> Vec<DetectedTool> {
+    TOOL_MARKERS
+        .iter()
+        .filter_map(|(tool, markers)| {
+            markers
+                .iter()
+                .find(|marker| root.join(marker).exists())
+                .map(|marker| DetectedTool { tool, marker })
+        })
+        .collect()
+}
+
+/// Default exclude patterns plus `<dir>/**` for each output directory under `root`.
+pub fn detect_excludes(root: &Path) -> Vec<String> {
+    let mut exclude = LintConfig::default().exclude;
+    for dir in OUTPUT_DIRS {
+        let pattern = format!("{}/**", dir);
+        if root.join(dir).is_dir() && !exclude.contains(&pattern) {
+            exclude.push(pattern);
+        }
+    }
+    exclude
+}
+
+/// Default config with the given tools and exclude patterns.
+pub fn scaffold_config(tools: &[&str], exclude: Vec<String>) -> LintConfig {
+    let mut config = LintConfig::default();
+    config.tools = tools.iter().map(|tool| tool.to_string()).collect();
+    config.exclude = exclude;
+    config
+}
+
+/// Parse a yes/no reply; an empty reply means "yes".
+fn parse_confirm(reply: &str) -> Option<bool> {
+    match reply.trim().to_ascii_lowercase().as_str() {
+        "" | "y" | "yes" => Some(true),
+        "n" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Prompt until a valid reply is read. End of input counts as "no".
+fn confirm(prompt: &str, input: &mut impl BufRead) -> anyhow::Result<bool> {
+    loop {
+        print!("  {} ", prompt.bold());
+        std::io::stdout().flush()?;
+
+        let mut reply = String::new();
+        if input.read_line(&mut reply)? == 0 {
+            println!();
+            return Ok(false);
+        }
+        if let Some(answer) = parse_confirm(&reply) {
+            return Ok(answer);
+        }
+    }
+}
+
+/// Detect tools under `root`, ask which to keep, and build the config.
+pub fn interactive_config(root: &Path, input: &mut impl BufRead) -> anyhow::Result<LintConfig> {
+    let detected = detect_tools(root);
+    if detected.is_empty() {
+        println!("{}", t!("cli.init_no_tools_detected").yellow());
+    }
+
+    let mut tools = Vec::new();
+    for detected in &detected {
+        let prompt = t!(
+            "cli.init_confirm_tool",
+            tool = detected.tool,
+            marker = detected.marker
+        );
+        if confirm(&prompt, input)? {
+            tools.push(detected.tool);
+        }
+    }
+
+    let exclude = detect_excludes(root);
+    println!(
+        "{} {}",
+        t!("cli.init_exclude_label").cyan(),
+        exclude.join(", ")
+    );
+
+    Ok(scaffold_config(&tools, exclude))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn project(paths: &[&str]) -> tempfile::TempDir {
+        let temp = tempfile::TempDir::new().unwrap();
+        for path in paths {
+            let full = temp.path().join(path);
+            if path.ends_with('/') {
+                std::fs::create_dir_all(&full).unwrap();
+            } else {
+                std::fs::create_dir_all(full.parent().unwrap()).unwrap();
+                std::fs::write(&full, "").unwrap();
+            }
+        }
+        temp
+    }
+
+    fn tool_names(detected: &[DetectedTool]) -> Vec<&str> {
+        detected.iter().map(|d| d.tool).collect()
+    }
+
+    #[test]
+    fn test_detect_tools_from_markers() {
+        let temp = project(&[
+            ".claude/settings.json",
+            ".cursor/rules/",
+            ".github/copilot-instructions.md",
+        ]);
+        let detected = detect_tools(temp.path());
+        assert_eq!(
+            tool_names(&detected),
+            vec!["claude-code", "cursor", "copilot"]
+        );
+        assert_eq!(detected[0].marker, ".claude");
+    }
+
+    #[test]
+    fn test_detect_tools_mcp_json_means_claude_code() {
+        let temp = project(&[".mcp.json"]);
+        let detected = detect_tools(temp.path());
+        assert_eq!(
+            detected,
+            vec![DetectedTool {
+                tool: "claude-code",
+                marker: ".mcp.json"
+            }]
+        );
+    }
+
+    #[test]
+    fn test_detect_tools_empty_project() {
+        let temp = project(&["README.md"]);
+        assert!(detect_tools(temp.path()).is_empty());
+    }
+
+    #[test]
+    fn test_detect_excludes_adds_existing_output_dirs() {
+        let temp = project(&["dist/", "node_modules/", "build"]);
+        let exclude = detect_excludes(temp.path());
+        assert!(exclude.contains(&"node_modules/**".to_string()));
+        assert!(exclude.contains(&"target/**".to_string()));
+        assert!(exclude.contains(&"dist/**".to_string()));
+        // A file named `build` is not an output directory
+        assert!(!exclude.contains(&"build/**".to_string()));
+    }
+
+    #[test]
+    fn test_parse_confirm() {
+        assert_eq!(parse_confirm("\n"), Some(true));
+        assert_eq!(parse_confirm(" Y "), Some(true));
+        assert_eq!(parse_confirm("no"), Some(false));
+        assert_eq!(parse_confirm("maybe"), None);
+    }
+
+    #[test]
+    fn test_interactive_config_keeps_confirmed_tools() {
+        let temp = project(&[".claude/", ".cursorrules", "GEMINI.md", "dist/"]);
+        let config = interactive_config(temp.path(), &mut Cursor::new("y\nn\nwhat\n\n")).unwrap();
+        assert_eq!(config.tools, vec!["claude-code", "gemini-cli"]);
+        assert!(config.exclude.contains(&"dist/**".to_string()));
+    }
+
+    #[test]
+    fn test_interactive_config_eof_declines_remaining() {
+        let temp = project(&[".claude/", ".cursor/"]);
+        let config = interactive_config(temp.path(), &mut Cursor::new("y\n")).unwrap();
+        assert_eq!(config.tools, vec!["claude-code"]);
+    }
+
+    #[test]
+    fn test_scaffolded_config_round_trips() {
+        let config = scaffold_config(&["claude-code", "cursor"], detect_excludes(Path::new(".")));
+        let toml = toml::to_string_pretty(&config).unwrap();
+        let (parsed, warnings) = LintConfig::from_toml_str(&toml).unwrap();
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(parsed.tools, vec!["claude-code", "cursor"]);
+    }
+}
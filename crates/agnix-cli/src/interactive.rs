@@ -0,0 +1,241 @@
+//! `--interactive`: approve fixes one at a time before they are applied.
+//!
+//! Each fix is previewed as a diff and answered with y/n/a/q. Accepted fixes
+//! are returned as a narrowed diagnostic set for `apply_fixes`, which applies
+//! them per file in reverse-offset order and skips any that overlap a fix
+//! already applied.
+
+use std::io::{BufRead, Write};
+
+use agnix_core::apply_fixes;
+use agnix_core::diagnostics::{Diagnostic, Fix};
+use colored::*;
+use rust_i18n::t;
+
+/// Answer to the per-fix prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Answer {
+    Yes,
+    No,
+    All,
+    Quit,
+}
+
+/// Parse a prompt reply; an empty reply means "no".
+fn parse_answer(reply: &str) -> Option<Answer> {
+    match reply.trim().to_ascii_lowercase().as_str() {
+        "y" | "yes" => Some(Answer::Yes),
+        "" | "n" | "no" => Some(Answer::No),
+        "a" | "all" => Some(Answer::All),
+        "q" | "quit" => Some(Answer::Quit),
+        _ => None,
+    }
+}
+
+/// Prompt until a valid answer is read. End of input counts as "quit".
+fn ask(input: &mut impl BufRead) -> anyhow::Result<Answer> {
+    loop {
+        print!("  {} ", t!("cli.interactive_prompt").bold());
+        std::io::stdout().flush()?;
+
+        let mut reply = String::new();
+        if input.read_line(&mut reply)? == 0 {
+            println!();
+            return Ok(Answer::Quit);
+        }
+        if let Some(answer) = parse_answer(&reply) {
+            return Ok(answer);
+        }
+    }
+}
+
+/// Show one fix and its diff against the file on disk.
+fn preview(diag: &Diagnostic, fix: &Fix) -> anyhow::Result<()> {
+    println!();
+    println!(
+        "{}:{}:{} [{}] {}",
+        diag.file.display().to_string().bold(),
+        diag.line,
+        diag.column,
+        diag.rule.cyan(),
+        diag.message
+    );
+    println!("  {} {}", t!("cli.help_label").cyan(), fix.description);
+
+    let single = Diagnostic {
+        fixes: vec![fix.clone()],
+        ..diag.clone()
+    };
+    if let Some(result) = apply_fixes(std::slice::from_ref(&single), true, false)?.first() {
+        crate::show_diff(&result.original, &result.fixed);
+    }
+    Ok(())
+}
+
+/// Walk every fix in `diagnostics` and return the diagnostics narrowed to the
+/// fixes the user accepted. With `safe_only`, unsafe fixes are not offered.
+pub fn select_fixes(
+    diagnostics: &[Diagnostic],
+    safe_only: bool,
+    input: &mut impl BufRead,
+) -> anyhow::Result<Vec<Diagnostic>> {
+    let mut selected = Vec::new();
+    let mut accept_rest = false;
+
+    'diagnostics: for diag in diagnostics {
+        let mut accepted = Vec::new();
+        for fix in diag.fixes.iter().filter(|f| !safe_only || f.safe) {
+            if !accept_rest {
+                preview(diag, fix)?;
+                match ask(input)? {
+                    Answer::Yes => {}
+                    Answer::No => continue,
+                    Answer::All => accept_rest = true,
+                    Answer::Quit => {
+                        if !accepted.is_empty() {
+                            selected.push(narrowed(diag, accepted));
+                        }
+                        break 'diagnostics;
+                    }
+                }
+            }
+            accepted.push(fix.clone());
+        }
+        if !accepted.is_empty() {
+            selected.push(narrowed(diag, accepted));
+        }
+    }
+
+    Ok(selected)
+}
+
+fn narrowed(diag: &Diagnostic, fixes: Vec<Fix>) -> Diagnostic {
+    Diagnostic {
+        fixes,
+        ..diag.clone()
+    }
+}
+
+/// Number of fixes in a selection.
+pub fn fix_count(selected: &[Diagnostic]) -> usize {
+    selected.iter().map(|d| d.fixes.len()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agnix_core::diagnostics::DiagnosticLevel;
+    use std::io::Cursor;
+    use std::path::{Path, PathBuf};
+
+    fn diag(file: &Path, fixes: Vec<Fix>) -> Diagnostic {
+        let mut diag = Diagnostic::warning(file.to_path_buf(), 1, 1, "AS-004", "test");
+        diag.fixes = fixes;
+        diag
+    }
+
+    fn replace(start: usize, end: usize, text: &str, safe: bool) -> Fix {
+        Fix::replace(start, end, text, format!("replace with {}", text), safe)
+    }
+
+    fn setup() -> (tempfile::TempDir, PathBuf) {
+        let temp = tempfile::TempDir::new().unwrap();
+        let file = temp.path().join("SKILL.md");
+        std::fs::write(&file, "alpha beta gamma\n").unwrap();
+        (temp, file)
+    }
+
+    #[test]
+    fn test_parse_answer() {
+        assert_eq!(parse_answer("y\n"), Some(Answer::Yes));
+        assert_eq!(parse_answer(" YES "), Some(Answer::Yes));
+        assert_eq!(parse_answer("\n"), Some(Answer::No));
+        assert_eq!(parse_answer("a"), Some(Answer::All));
+        assert_eq!(parse_answer("quit"), Some(Answer::Quit));
+        assert_eq!(parse_answer("maybe"), None);
+    }
+
+    #[test]
+    fn test_select_yes_and_no() {
+        let (_temp, file) = setup();
+        let diagnostics = vec![diag(
+            &file,
+            vec![replace(0, 5, "ALPHA", true), replace(6, 10, "BETA", true)],
+        )];
+
+        let selected = select_fixes(&diagnostics, false, &mut Cursor::new("y\nn\n")).unwrap();
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].fixes.len(), 1);
+        assert_eq!(selected[0].fixes[0].replacement, "ALPHA");
+        assert_eq!(selected[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_select_invalid_reply_reprompts() {
+        let (_temp, file) = setup();
+        let diagnostics = vec![diag(&file, vec![replace(0, 5, "ALPHA", true)])];
+        let selected = select_fixes(&diagnostics, false, &mut Cursor::new("what\ny\n")).unwrap();
+        assert_eq!(fix_count(&selected), 1);
+    }
+
+    #[test]
+    fn test_select_all_accepts_remaining() {
+        let (_temp, file) = setup();
+        let diagnostics = vec![
+            diag(&file, vec![replace(0, 5, "ALPHA", true)]),
+            diag(&file, vec![replace(6, 10, "BETA", true)]),
+            diag(&file, vec![replace(11, 16, "GAMMA", false)]),
+        ];
+        let selected = select_fixes(&diagnostics, false, &mut Cursor::new("n\na\n")).unwrap();
+        let replacements: Vec<_> = selected
+            .iter()
+            .flat_map(|d| &d.fixes)
+            .map(|f| f.replacement.as_str())
+            .collect();
+        assert_eq!(replacements, vec!["BETA", "GAMMA"]);
+    }
+
+    #[test]
+    fn test_select_quit_and_eof_stop() {
+        let (_temp, file) = setup();
+        let diagnostics = vec![
+            diag(&file, vec![replace(0, 5, "ALPHA", true)]),
+            diag(&file, vec![replace(6, 10, "BETA", true)]),
+        ];
+        let selected = select_fixes(&diagnostics, false, &mut Cursor::new("y\nq\n")).unwrap();
+        assert_eq!(fix_count(&selected), 1);
+
+        let selected = select_fixes(&diagnostics, false, &mut Cursor::new("")).unwrap();
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn test_select_safe_only_skips_unsafe() {
+        let (_temp, file) = setup();
+        let diagnostics = vec![diag(
+            &file,
+            vec![replace(0, 5, "ALPHA", false), replace(6, 10, "BETA", true)],
+        )];
+        // Only the safe fix is offered, so a single answer covers everything
+        let selected = select_fixes(&diagnostics, true, &mut Cursor::new("y\n")).unwrap();
+        assert_eq!(selected[0].fixes.len(), 1);
+        assert_eq!(selected[0].fixes[0].replacement, "BETA");
+    }
+
+    #[test]
+    fn test_overlapping_accepted_fixes_apply_in_reverse_offset_order() {
+        let (_temp, file) = setup();
+        let diagnostics = vec![
+            diag(&file, vec![replace(0, 10, "ONE", true)]),
+            diag(&file, vec![replace(6, 16, "TWO", true)]),
+        ];
+        let selected = select_fixes(&diagnostics, false, &mut Cursor::new("y\ny\n")).unwrap();
+        assert_eq!(fix_count(&selected), 2);
+
+        let results = apply_fixes(&selected, false, false).unwrap();
+        assert_eq!(results.len(), 1);
+        // The later fix wins; the overlapping earlier one is skipped, not corrupted
+        assert_eq!(results[0].applied, vec!["replace with TWO".to_string()]);
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "alpha TWO\n");
+    }
+}
@@ -40,6 +40,14 @@ pub struct JsonDiagnostic {
     /// Optional assumption note for version-aware validation.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assumption: Option<String>,
+    /// Confidence that the finding is real: high, medium, or low.
+    pub confidence: String,
+    /// Rule category derived from the rule prefix (e.g., mcp, claude-hooks).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Tool the rule prefix targets (e.g., claude-code); omitted for generic rules.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool: Option<String>,
 }
 
 /// Summary counts by diagnostic level.
@@ -61,7 +69,7 @@ fn level_to_string(level: DiagnosticLevel) -> &'static str {
     }
 }
 
-fn path_to_string(path: &Path, base_path: &Path) -> String {
+pub(crate) fn path_to_string(path: &Path, base_path: &Path) -> String {
     // Convert to relative path if possible, use forward slashes for cross-platform consistency
     path.strip_prefix(base_path)
         .unwrap_or(path)
@@ -69,6 +77,35 @@ fn path_to_string(path: &Path, base_path: &Path) -> String {
         .replace('\\', "/")
 }
 
+/// Convert a single diagnostic to its JSON representation.
+pub fn diagnostic_to_json(diag: &Diagnostic, base_path: &Path) -> JsonDiagnostic {
+    JsonDiagnostic {
+        level: level_to_string(diag.level).to_string(),
+        rule: diag.rule.clone(),
+        file: path_to_string(&diag.file, base_path),
+        line: diag.line.max(1),
+        column: diag.column.max(1),
+        message: diag.message.clone(),
+        suggestion: diag.suggestion.clone(),
+        assumption: diag.assumption.clone(),
+        confidence: diag.confidence.as_str().to_string(),
+        category: agnix_rules::get_rule_category(&diag.rule).map(str::to_string),
+        tool: agnix_rules::rule_prefix(&diag.rule)
+            .and_then(agnix_rules::get_tool_for_prefix)
+            .map(str::to_string),
+    }
+}
+
+impl JsonSummary {
+    fn count(&mut self, level: DiagnosticLevel) {
+        match level {
+            DiagnosticLevel::Error => self.errors += 1,
+            DiagnosticLevel::Warning => self.warnings += 1,
+            DiagnosticLevel::Info => self.info += 1,
+        }
+    }
+}
+
 /// Convert diagnostics to JSON output format.
 ///
 /// `files_checked` is the total number of recognized files validated,
@@ -78,28 +115,17 @@ pub fn diagnostics_to_json(
     base_path: &Path,
     files_checked: usize,
 ) -> JsonOutput {
-    let mut errors = 0;
-    let mut warnings = 0;
-    let mut info = 0;
+    let mut summary = JsonSummary {
+        errors: 0,
+        warnings: 0,
+        info: 0,
+    };
 
     let json_diagnostics: Vec<JsonDiagnostic> = diagnostics
         .iter()
         .map(|diag| {
-            match diag.level {
-                DiagnosticLevel::Error => errors += 1,
-                DiagnosticLevel::Warning => warnings += 1,
-                DiagnosticLevel::Info => info += 1,
-            }
-            JsonDiagnostic {
-                level: level_to_string(diag.level).to_string(),
-                rule: diag.rule.clone(),
-                file: path_to_string(&diag.file, base_path),
-                line: diag.line.max(1),
-                column: diag.column.max(1),
-                message: diag.message.clone(),
-                suggestion: diag.suggestion.clone(),
-                assumption: diag.assumption.clone(),
-            }
+            summary.count(diag.level);
+            diagnostic_to_json(diag, base_path)
         })
         .collect();
 
@@ -107,17 +133,137 @@ pub fn diagnostics_to_json(
         version: env!("CARGO_PKG_VERSION").to_string(),
         files_checked,
         diagnostics: json_diagnostics,
-        summary: JsonSummary {
-            errors,
-            warnings,
-            info,
-        },
+        summary,
+    }
+}
+
+/// Final line of JSON Lines output, tagged with `"type": "summary"`.
+#[derive(Debug, Serialize)]
+pub struct JsonlSummary {
+    /// Always `"summary"`; diagnostic lines carry no `type` field.
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    /// Version of agnix that produced this output.
+    pub version: String,
+    /// Total number of recognized files validated.
+    pub files_checked: usize,
+    /// Summary counts by level.
+    pub summary: JsonSummary,
+}
+
+/// Write diagnostics as JSON Lines: one object per diagnostic, matching the
+/// elements of the `diagnostics` array in [`JsonOutput`], followed by a
+/// [`JsonlSummary`] line.
+///
+/// Each line is written as soon as it is serialized, so no array of the
+/// whole result is built.
+pub fn write_jsonl<W: std::io::Write>(
+    out: &mut W,
+    diagnostics: &[Diagnostic],
+    base_path: &Path,
+    files_checked: usize,
+) -> anyhow::Result<JsonSummary> {
+    let mut summary = JsonSummary {
+        errors: 0,
+        warnings: 0,
+        info: 0,
+    };
+
+    for diag in diagnostics {
+        summary.count(diag.level);
+        serde_json::to_writer(&mut *out, &diagnostic_to_json(diag, base_path))?;
+        out.write_all(b"\n")?;
+    }
+
+    let summary_line = JsonlSummary {
+        kind: "summary",
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        files_checked,
+        summary,
+    };
+    serde_json::to_writer(&mut *out, &summary_line)?;
+    out.write_all(b"\n")?;
+    out.flush()?;
+
+    Ok(summary_line.summary)
+}
+
+/// Proposed fixes emitted by `--fix --dry-run --format json`.
+#[derive(Debug, Serialize)]
+pub struct JsonFixPlan {
+    /// Version of agnix that produced this output.
+    pub version: String,
+    /// Total number of recognized files validated.
+    pub files_checked: usize,
+    /// Every proposed fix, in diagnostic order.
+    pub fixes: Vec<JsonFix>,
+}
+
+/// A single proposed fix. Byte ranges index the file's current content.
+#[derive(Debug, Serialize)]
+pub struct JsonFix {
+    /// Rule that produced the fix (e.g., AS-004).
+    pub rule: String,
+    /// File path (forward slashes for cross-platform consistency).
+    pub file: String,
+    /// Byte offset start (inclusive).
+    pub start_byte: usize,
+    /// Byte offset end (exclusive).
+    pub end_byte: usize,
+    /// Text that replaces the byte range.
+    pub replacement: String,
+    /// Whether the fix is safe to apply without review.
+    pub safe: bool,
+    /// Human-readable description of the fix.
+    pub description: String,
+    /// When set, the fix creates this file with `replacement` as its content
+    /// instead of editing `file`; the byte range is unused.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_file: Option<String>,
+}
+
+/// Collect the fixes attached to `diagnostics` into a fix plan.
+///
+/// With `safe_only`, unsafe fixes are left out, matching `--fix-safe`.
+pub fn diagnostics_to_fix_plan(
+    diagnostics: &[Diagnostic],
+    base_path: &Path,
+    files_checked: usize,
+    safe_only: bool,
+) -> JsonFixPlan {
+    let fixes = diagnostics
+        .iter()
+        .flat_map(|diag| {
+            diag.fixes
+                .iter()
+                .filter(move |fix| !safe_only || fix.safe)
+                .map(move |fix| JsonFix {
+                    rule: diag.rule.clone(),
+                    file: path_to_string(&diag.file, base_path),
+                    start_byte: fix.start_byte,
+                    end_byte: fix.end_byte,
+                    replacement: fix.replacement.clone(),
+                    safe: fix.safe,
+                    description: fix.description.clone(),
+                    create_file: fix
+                        .create_file
+                        .as_deref()
+                        .map(|path| path_to_string(path, base_path)),
+                })
+        })
+        .collect();
+
+    JsonFixPlan {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        files_checked,
+        fixes,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use agnix_core::diagnostics::Confidence;
     use std::path::PathBuf;
 
     #[test]
@@ -192,6 +338,21 @@ mod tests {
         assert_eq!(json_diag.message, "Missing frontmatter");
     }
 
+    #[test]
+    fn test_confidence_is_emitted() {
+        let diags = vec![
+            Diagnostic::warning(PathBuf::from("/p/a.md"), 1, 1, "XP-004", "A".to_string())
+                .with_confidence(Confidence::Low),
+            Diagnostic::error(PathBuf::from("/p/b.md"), 1, 1, "AS-001", "B".to_string()),
+        ];
+
+        let output = diagnostics_to_json(&diags, Path::new("/p"), 2);
+        let value = serde_json::to_value(&output).unwrap();
+
+        assert_eq!(value["diagnostics"][0]["confidence"], "low");
+        assert_eq!(value["diagnostics"][1]["confidence"], "high");
+    }
+
     #[test]
     fn test_summary_counts() {
         let diags = vec![
@@ -208,6 +369,11 @@ mod tests {
                 suggestion: None,
                 fixes: vec![],
                 assumption: None,
+                confidence: Confidence::High,
+                end_line: None,
+                end_column: None,
+                related: Vec::new(),
+                related_rules: Vec::new(),
             },
         ];
 
@@ -265,6 +431,34 @@ mod tests {
         assert!(output.diagnostics[0].suggestion.is_none());
     }
 
+    #[test]
+    fn test_category_and_tool_from_rule_prefix() {
+        let diagnostics = vec![
+            Diagnostic::error(
+                PathBuf::from("/p/.claude/settings.json"),
+                1,
+                1,
+                "CC-HK-001",
+                "e",
+            ),
+            Diagnostic::warning(PathBuf::from("/p/.mcp.json"), 1, 1, "MCP-001", "w"),
+            Diagnostic::error(PathBuf::from("/p/a.md"), 1, 1, "file::read", "e"),
+        ];
+
+        let output = diagnostics_to_json(&diagnostics, Path::new("/p"), 3);
+        assert_eq!(
+            output.diagnostics[0].category.as_deref(),
+            Some("claude-hooks")
+        );
+        assert_eq!(output.diagnostics[0].tool.as_deref(), Some("claude-code"));
+        assert_eq!(output.diagnostics[1].category.as_deref(), Some("mcp"));
+        assert!(output.diagnostics[1].tool.is_none());
+
+        let json = serde_json::to_value(&output.diagnostics[2]).unwrap();
+        assert!(json.get("category").is_none());
+        assert!(json.get("tool").is_none());
+    }
+
     #[test]
     fn test_json_serialization() {
         let output = diagnostics_to_json(&[], Path::new("."), 0);
@@ -292,4 +486,115 @@ mod tests {
         assert_eq!(output.diagnostics[0].line, 1);
         assert_eq!(output.diagnostics[0].column, 1);
     }
+
+    #[test]
+    fn test_jsonl_lines_match_array_elements() {
+        let diagnostics = vec![
+            Diagnostic::error(
+                PathBuf::from("/p/a/SKILL.md"),
+                2,
+                1,
+                "AS-004",
+                "Bad name".to_string(),
+            )
+            .with_suggestion("Use lowercase"),
+            Diagnostic::info(
+                PathBuf::from("/p/CLAUDE.md"),
+                1,
+                1,
+                "XP-003",
+                "Path".to_string(),
+            ),
+        ];
+
+        let mut out = Vec::new();
+        let summary = write_jsonl(&mut out, &diagnostics, Path::new("/p"), 2).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<serde_json::Value> = text
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        let array =
+            serde_json::to_value(diagnostics_to_json(&diagnostics, Path::new("/p"), 2)).unwrap();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], array["diagnostics"][0]);
+        assert_eq!(lines[1], array["diagnostics"][1]);
+        assert!(lines[0].get("type").is_none());
+
+        assert_eq!(lines[2]["type"], "summary");
+        assert_eq!(lines[2]["files_checked"], 2);
+        assert_eq!(lines[2]["summary"], array["summary"]);
+        assert_eq!((summary.errors, summary.warnings, summary.info), (1, 0, 1));
+    }
+
+    #[test]
+    fn test_jsonl_empty_has_only_summary() {
+        let mut out = Vec::new();
+        write_jsonl(&mut out, &[], Path::new("."), 0).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert_eq!(text.lines().count(), 1);
+        assert!(text.ends_with('\n'));
+        let summary: serde_json::Value = serde_json::from_str(text.trim_end()).unwrap();
+        assert_eq!(summary["type"], "summary");
+        assert_eq!(summary["summary"]["errors"], 0);
+    }
+
+    #[test]
+    fn test_fix_plan_lists_each_fix() {
+        use agnix_core::diagnostics::Fix;
+
+        let diagnostics = vec![
+            Diagnostic::error(
+                PathBuf::from("/p/a/SKILL.md"),
+                2,
+                7,
+                "AS-004",
+                "Bad name".to_string(),
+            )
+            .with_fix(Fix::replace(10, 19, "bad-skill", "Lowercase name", true))
+            .with_fix(Fix::delete(30, 35, "Drop suffix", false)),
+            Diagnostic::warning(
+                PathBuf::from("/p/CLAUDE.md"),
+                1,
+                1,
+                "XP-003",
+                "Path".to_string(),
+            ),
+        ];
+
+        let plan = diagnostics_to_fix_plan(&diagnostics, Path::new("/p"), 2, false);
+        assert_eq!(plan.files_checked, 2);
+        assert_eq!(plan.fixes.len(), 2);
+        let first = &plan.fixes[0];
+        assert_eq!(first.rule, "AS-004");
+        assert_eq!(first.file, "a/SKILL.md");
+        assert_eq!((first.start_byte, first.end_byte), (10, 19));
+        assert_eq!(first.replacement, "bad-skill");
+        assert!(first.safe);
+        assert_eq!(first.description, "Lowercase name");
+        assert!(!plan.fixes[1].safe);
+
+        let value = serde_json::to_value(&plan.fixes[0]).unwrap();
+        assert!(value.get("create_file").is_none());
+    }
+
+    #[test]
+    fn test_fix_plan_safe_only_drops_unsafe_fixes() {
+        use agnix_core::diagnostics::Fix;
+
+        let diag = Diagnostic::error(
+            PathBuf::from("/p/SKILL.md"),
+            1,
+            1,
+            "AS-004",
+            "Bad name".to_string(),
+        )
+        .with_fix(Fix::replace(0, 1, "a", "Safe", true))
+        .with_fix(Fix::replace(2, 3, "b", "Unsafe", false));
+
+        let plan = diagnostics_to_fix_plan(&[diag], Path::new("/p"), 1, true);
+        assert_eq!(plan.fixes.len(), 1);
+        assert_eq!(plan.fixes[0].description, "Safe");
+    }
 }
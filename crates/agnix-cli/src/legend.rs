@@ -0,0 +1,148 @@
+//! Rule-family legend for `--legend`.
+//!
+//! Text output identifies rules only by ID, so a newcomer seeing `CC-AG-004`
+//! next to `XP-002` has no way to tell which tool each belongs to. The legend
+//! lists the families present in the results, with their tool when the prefix
+//! is tool-specific.
+
+use agnix_core::diagnostics::Diagnostic;
+use colored::*;
+use rust_i18n::t;
+use std::io::Write;
+
+/// Rule-family prefixes and display names, in catalog order.
+const FAMILIES: &[(&str, &str)] = &[
+    ("AS-", "Agent Skills"),
+    ("CC-SK-", "Claude Code Skills"),
+    ("CC-HK-", "Claude Code Hooks"),
+    ("CC-AG-", "Claude Code Subagents"),
+    ("CC-MEM-", "Claude Code Memory"),
+    ("AGM-", "AGENTS.md"),
+    ("CC-PL-", "Claude Code Plugins"),
+    ("MCP-", "Model Context Protocol"),
+    ("COP-", "GitHub Copilot"),
+    ("CUR-", "Cursor"),
+    ("CLN-", "Cline"),
+    ("XML-", "XML"),
+    ("REF-", "References"),
+    ("PE-", "Prompt Engineering"),
+    ("XP-", "Cross-Platform"),
+    ("OC-", "OpenCode"),
+    ("GM-", "Gemini CLI"),
+    ("CDX-", "Codex CLI"),
+    ("WS-", "Windsurf"),
+    ("AIDER-", "Aider"),
+    ("VER-", "Version Awareness"),
+    ("GEN-", "General"),
+];
+
+/// One line of the legend.
+#[derive(Debug, PartialEq, Eq)]
+pub struct LegendEntry {
+    pub prefix: &'static str,
+    pub family: &'static str,
+    /// Tool the prefix belongs to, if the prefix is tool-specific.
+    pub tool: Option<&'static str>,
+}
+
+/// Prefix of a rule ID, e.g. `CC-SK-` for `CC-SK-006`.
+fn rule_prefix(id: &str) -> Option<&str> {
+    id.rfind('-').map(|idx| &id[..=idx])
+}
+
+/// Legend entries for the families that appear in `diagnostics`, in catalog order.
+pub fn legend_entries(diagnostics: &[Diagnostic]) -> Vec<LegendEntry> {
+    let present: Vec<&str> = diagnostics
+        .iter()
+        .filter_map(|d| rule_prefix(&d.rule))
+        .collect();
+
+    FAMILIES
+        .iter()
+        .filter(|(prefix, _)| present.contains(prefix))
+        .map(|(prefix, family)| LegendEntry {
+            prefix,
+            family,
+            tool: agnix_rules::get_tool_for_prefix(prefix),
+        })
+        .collect()
+}
+
+/// Write the legend followed by a blank line. Writes nothing if no known family is present.
+pub fn write_legend(out: &mut dyn Write, diagnostics: &[Diagnostic]) -> std::io::Result<()> {
+    let entries = legend_entries(diagnostics);
+    if entries.is_empty() {
+        return Ok(());
+    }
+
+    let width = entries.iter().map(|e| e.prefix.len()).max().unwrap_or(0);
+    writeln!(out, "{}", t!("cli.legend_heading").bold())?;
+    for entry in &entries {
+        let prefix = format!("{:<width$}", entry.prefix, width = width);
+        match entry.tool {
+            Some(tool) => writeln!(
+                out,
+                "  {}  {} {}",
+                prefix.cyan(),
+                entry.family,
+                format!("({})", tool).dimmed()
+            )?,
+            None => writeln!(out, "  {}  {}", prefix.cyan(), entry.family)?,
+        }
+    }
+    writeln!(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn diag(rule: &str) -> Diagnostic {
+        Diagnostic::warning(PathBuf::from("CLAUDE.md"), 1, 1, rule, "test")
+    }
+
+    #[test]
+    fn test_legend_lists_only_present_families() {
+        let entries = legend_entries(&[diag("XP-002"), diag("CC-SK-006"), diag("CC-SK-007")]);
+        let prefixes: Vec<&str> = entries.iter().map(|e| e.prefix).collect();
+        assert_eq!(prefixes, vec!["CC-SK-", "XP-"]);
+    }
+
+    #[test]
+    fn test_legend_includes_tool_for_tool_specific_prefix() {
+        let entries = legend_entries(&[diag("CC-AG-004"), diag("MCP-001")]);
+        assert_eq!(
+            entries,
+            vec![
+                LegendEntry {
+                    prefix: "CC-AG-",
+                    family: "Claude Code Subagents",
+                    tool: Some("claude-code"),
+                },
+                LegendEntry {
+                    prefix: "MCP-",
+                    family: "Model Context Protocol",
+                    tool: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_legend_ignores_unknown_prefixes() {
+        assert!(legend_entries(&[diag("config"), diag("XX-001")]).is_empty());
+    }
+
+    #[test]
+    fn test_every_catalog_prefix_has_a_family() {
+        for (id, _) in agnix_rules::RULES_DATA {
+            let prefix = rule_prefix(id).unwrap();
+            assert!(
+                FAMILIES.iter().any(|(p, _)| *p == prefix),
+                "no legend family for {}",
+                id
+            );
+        }
+    }
+}
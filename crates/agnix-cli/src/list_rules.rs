@@ -0,0 +1,137 @@
+//! Rule catalog listing for `--list-rules`.
+//!
+//! Prints every rule in `agnix_rules::RULES_DATA` grouped by category, with
+//! its name, the tool its prefix belongs to, and whether the effective config
+//! runs it.
+
+use agnix_core::config::LintConfig;
+use colored::*;
+use rust_i18n::t;
+use serde::Serialize;
+
+/// One catalog rule as seen by the effective config.
+#[derive(Debug, Serialize)]
+pub struct ListedRule {
+    pub id: &'static str,
+    pub name: &'static str,
+    /// Category from rules.json, e.g. `claude-hooks`.
+    pub category: Option<&'static str>,
+    /// Tool the rule's prefix belongs to, if the prefix is tool-specific.
+    pub tool: Option<&'static str>,
+    pub enabled: bool,
+}
+
+/// Every catalog rule, in catalog order.
+pub fn list_rules(config: &LintConfig) -> Vec<ListedRule> {
+    agnix_rules::RULES_DATA
+        .iter()
+        .map(|(id, name)| ListedRule {
+            id,
+            name,
+            category: agnix_rules::get_rule_category(id),
+            tool: agnix_rules::rule_prefix(id).and_then(agnix_rules::get_tool_for_prefix),
+            enabled: config.is_rule_enabled(id),
+        })
+        .collect()
+}
+
+/// Rules grouped by category, categories in order of first appearance.
+fn group_by_category(rules: &[ListedRule]) -> Vec<(&'static str, Vec<&ListedRule>)> {
+    let mut groups: Vec<(&'static str, Vec<&ListedRule>)> = Vec::new();
+    for rule in rules {
+        let category = rule.category.unwrap_or("other");
+        match groups.iter_mut().find(|(name, _)| *name == category) {
+            Some((_, members)) => members.push(rule),
+            None => groups.push((category, vec![rule])),
+        }
+    }
+    groups
+}
+
+/// Print the listing as pretty JSON or grouped text.
+pub fn print_rules(rules: &[ListedRule], json: bool) -> anyhow::Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(rules)?);
+        return Ok(());
+    }
+
+    let id_width = rules.iter().map(|r| r.id.len()).max().unwrap_or(0);
+    let name_width = rules.iter().map(|r| r.name.len()).max().unwrap_or(0);
+    let enabled_label = t!("cli.list_rules_enabled");
+    let disabled_label = t!("cli.list_rules_disabled");
+
+    for (category, members) in group_by_category(rules) {
+        println!(
+            "{}",
+            t!(
+                "cli.list_rules_category",
+                category = category,
+                count = members.len()
+            )
+            .bold()
+        );
+        for rule in members {
+            let status = if rule.enabled {
+                enabled_label.green()
+            } else {
+                disabled_label.dimmed()
+            };
+            println!(
+                "  {:<id_width$}  {:<name_width$}  {:<12}  {}",
+                rule.id,
+                rule.name,
+                rule.tool.unwrap_or("-"),
+                status
+            );
+        }
+        println!();
+    }
+
+    let enabled = rules.iter().filter(|r| r.enabled).count();
+    println!(
+        "{}",
+        t!(
+            "cli.list_rules_summary",
+            enabled = enabled,
+            total = rules.len()
+        )
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lists_whole_catalog_with_tool() {
+        let rules = list_rules(&LintConfig::default());
+        assert_eq!(rules.len(), agnix_rules::rule_count());
+
+        let hook = rules.iter().find(|r| r.id == "CC-HK-001").unwrap();
+        assert_eq!(hook.category, Some("claude-hooks"));
+        assert_eq!(hook.tool, Some("claude-code"));
+        assert!(hook.enabled);
+    }
+
+    #[test]
+    fn test_disabled_rules_and_grouping() {
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["AS-005".to_string()];
+
+        let rules = list_rules(&config);
+        assert!(!rules.iter().find(|r| r.id == "AS-005").unwrap().enabled);
+
+        let groups = group_by_category(&rules);
+        let skills = groups
+            .iter()
+            .find(|(name, _)| *name == "agent-skills")
+            .unwrap();
+        assert!(skills.1.iter().all(|r| r.id.starts_with("AS-")));
+        assert_eq!(
+            groups.iter().map(|(_, m)| m.len()).sum::<usize>(),
+            rules.len()
+        );
+    }
+}
@@ -3,23 +3,38 @@
 
 rust_i18n::i18n!("../../locales", fallback = "en");
 
+mod baseline;
+mod config_check;
+mod coverage;
+mod explain;
+mod git_diff;
+mod github;
+mod html;
+mod hyperlink;
+mod init;
+mod interactive;
 mod json;
+mod legend;
+mod list_rules;
 mod locale;
+mod pretty;
 mod sarif;
+mod stats;
 #[cfg(feature = "telemetry")]
 pub mod telemetry;
 #[cfg(not(feature = "telemetry"))]
 mod telemetry_stub;
+mod timings;
 mod watch;
 #[cfg(not(feature = "telemetry"))]
 use telemetry_stub as telemetry;
 
 use agnix_core::{
     ValidationResult, apply_fixes,
-    config::{LintConfig, TargetTool},
-    diagnostics::{Diagnostic, DiagnosticLevel},
+    config::{ConfigWarning, DEFAULT_ENV_PREFIX, LintConfig, TargetTool},
+    diagnostics::{Confidence, Diagnostic, DiagnosticLevel},
     eval::{EvalFormat, evaluate_manifest_file},
-    generate_schema, validate_project,
+    generate_schema, validate_files, validate_project,
 };
 use clap::{Parser, Subcommand, ValueEnum};
 use colored::*;
@@ -27,6 +42,7 @@ use rust_i18n::t;
 use similar::{ChangeTag, TextDiff};
 use std::collections::HashMap;
 use std::env;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process;
 use std::time::Instant;
@@ -36,7 +52,28 @@ pub enum OutputFormat {
     #[default]
     Text,
     Json,
+    Jsonl,
     Sarif,
+    Github,
+}
+
+/// Minimum confidence a diagnostic needs to be reported.
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum ConfidenceArg {
+    High,
+    Medium,
+    #[default]
+    Low,
+}
+
+impl From<ConfidenceArg> for Confidence {
+    fn from(arg: ConfidenceArg) -> Self {
+        match arg {
+            ConfidenceArg::High => Confidence::High,
+            ConfidenceArg::Medium => Confidence::Medium,
+            ConfidenceArg::Low => Confidence::Low,
+        }
+    }
 }
 
 /// CLI target argument enum with kebab-case names for command line ergonomics.
@@ -49,6 +86,9 @@ pub enum TargetArg {
     ClaudeCode,
     Cursor,
     Codex,
+    Windsurf,
+    #[value(alias = "gemini-cli")]
+    Gemini,
 }
 
 impl From<TargetArg> for TargetTool {
@@ -58,6 +98,8 @@ impl From<TargetArg> for TargetTool {
             TargetArg::ClaudeCode => TargetTool::ClaudeCode,
             TargetArg::Cursor => TargetTool::Cursor,
             TargetArg::Codex => TargetTool::Codex,
+            TargetArg::Windsurf => TargetTool::Windsurf,
+            TargetArg::Gemini => TargetTool::Gemini,
         }
     }
 }
@@ -81,38 +123,146 @@ struct Cli {
     #[arg(short, long)]
     strict: bool,
 
-    /// Target tool (generic, claude-code, cursor, codex)
-    #[arg(short, long, value_enum, default_value_t = TargetArg::Generic)]
-    target: TargetArg,
+    /// Target tool (generic, claude-code, cursor, codex, windsurf, gemini); overrides config and environment
+    #[arg(short, long, value_enum)]
+    target: Option<TargetArg>,
 
     /// Config file path
     #[arg(short, long)]
     config: Option<PathBuf>,
 
+    /// Prefix of environment variables that override config values (e.g. AGNIX_SEVERITY); empty disables them
+    #[arg(long, value_name = "PREFIX", default_value = DEFAULT_ENV_PREFIX)]
+    config_env_prefix: String,
+
+    /// Suppress diagnostics recorded in this baseline file (see `agnix baseline`)
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
     /// Verbose output
     #[arg(short, long)]
     verbose: bool,
 
+    /// Print only the diagnostic lines in text output: no header, summary, or "No issues found"
+    #[arg(short, long)]
+    quiet: bool,
+
     /// Apply automatic fixes
-    #[arg(long, group = "fix_mode")]
+    #[arg(long)]
     fix: bool,
 
-    /// Show what would be fixed without modifying files
-    #[arg(long, group = "fix_mode")]
+    /// Show what would be fixed without modifying files (also with --fix or --fix-safe)
+    #[arg(long)]
     dry_run: bool,
 
     /// Only apply safe (HIGH certainty) fixes (implies --fix)
     #[arg(long)]
     fix_safe: bool,
 
+    /// Review each fix as a diff and choose y/n/a/q before applying (implies --fix, text output only)
+    #[arg(long, conflicts_with = "dry_run")]
+    interactive: bool,
+
     /// Output format (text, json, or sarif)
     #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
     format: OutputFormat,
 
+    /// Only report diagnostics that have an automatic fix (exit code still reflects all diagnostics)
+    #[arg(long)]
+    only_fixable: bool,
+
+    /// Only report diagnostics whose rule ID starts with this prefix, e.g. MCP- or AS-004 (repeatable; also narrows the exit code)
+    #[arg(long, value_name = "RULE-PREFIX")]
+    only: Vec<String>,
+
+    /// Exit with code 0 even when errors (or warnings in strict mode) are found
+    #[arg(long)]
+    exit_zero: bool,
+
+    /// Only report diagnostics at or above this confidence (high, medium, low)
+    #[arg(long, value_enum, default_value_t = ConfidenceArg::Low)]
+    min_confidence: ConfidenceArg,
+
+    /// Show rule codes as clickable OSC 8 hyperlinks to rule docs (text output)
+    #[arg(long)]
+    hyperlinks: bool,
+
+    /// Render text diagnostics with the source excerpt, caret underline and help note
+    #[arg(long)]
+    pretty_errors: bool,
+
+    /// Print what each fix would change under fixable text diagnostics, without --verbose
+    #[arg(long)]
+    show_fixes_inline: bool,
+
+    /// Prefix text diagnostics with a severity emoji (❌/⚠️/ℹ️)
+    #[arg(long, overrides_with = "no_emoji")]
+    emoji: bool,
+
+    /// Disable severity emoji in text output (default)
+    #[arg(long, overrides_with = "emoji")]
+    no_emoji: bool,
+
+    /// Print a legend of the rule families in the results before text diagnostics
+    #[arg(long)]
+    legend: bool,
+
+    /// Print which catalog rules the effective config enables, disables, or references but don't exist, then exit
+    #[arg(long)]
+    rules_coverage_report: bool,
+
+    /// List every rule grouped by category, with its tool and whether the effective config enables it, then exit
+    #[arg(long)]
+    list_rules: bool,
+
+    /// Report keys in the config file that agnix does not know (with the closest known key), then exit
+    #[arg(long)]
+    check_config: bool,
+
+    /// Skip the project-level checks that compare files against each other (AGM-006, XP-*, REF-009, ...); per-file rules still run
+    #[arg(long)]
+    no_cross_file: bool,
+
+    /// Validate only the newline-separated paths read from stdin instead of walking PATH (skips cross-file checks)
+    #[arg(long, conflicts_with_all = ["interactive", "watch"])]
+    stdin_paths: bool,
+
+    /// Validate only files changed relative to this git ref and report only diagnostics on changed lines (skips cross-file checks)
+    #[arg(long, value_name = "REF", conflicts_with_all = ["stdin_paths", "watch"])]
+    diff: Option<String>,
+
+    /// Persist per-file cross-layer analysis in this directory so unchanged instruction files are not re-analyzed
+    #[arg(long, value_name = "DIR")]
+    cache_dir: Option<PathBuf>,
+
+    /// Reuse cached diagnostics for unchanged files (stored in --cache-dir, default .agnix/cache under PATH)
+    #[arg(long)]
+    cache: bool,
+
+    /// Write validation timings (duration, files checked, files/sec) as JSON to this path
+    #[arg(long, value_name = "PATH")]
+    dump_timings: Option<PathBuf>,
+
+    /// Print the N slowest files and validators to stderr after validating (default 10)
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "10")]
+    timings: Option<usize>,
+
+    /// Write the report to this file instead of stdout; stdout then only gets a pass/fail summary
+    #[arg(long, value_name = "PATH", conflicts_with = "watch")]
+    output: Option<PathBuf>,
+
+    /// Also write a standalone HTML report of the diagnostics to this path
+    #[arg(long, value_name = "PATH")]
+    report: Option<PathBuf>,
+
     /// Watch mode - re-validate on file changes
     #[arg(short, long)]
     watch: bool,
 
+    /// Validate once when watch mode starts (use --initial-scan=false to only validate on changes)
+    #[arg(long, default_value_t = true, action = clap::ArgAction::Set, value_name = "BOOL")]
+    initial_scan: bool,
+
     /// Set output locale (e.g., en, es, zh-CN)
     #[arg(long)]
     locale: Option<String>,
@@ -146,6 +296,14 @@ impl From<EvalOutputFormat> for EvalFormat {
     }
 }
 
+/// Output format for `agnix stats`
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum StatsOutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
 /// Telemetry action for the CLI subcommand.
 #[derive(Debug, Clone, Copy, Default, ValueEnum)]
 pub enum TelemetryAction {
@@ -167,11 +325,44 @@ enum Commands {
         path: PathBuf,
     },
 
+    /// Record current diagnostics in a baseline file for use with --baseline
+    Baseline {
+        /// Path to validate
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output path for the baseline
+        #[arg(short, long, default_value = baseline::DEFAULT_BASELINE_FILE)]
+        output: PathBuf,
+    },
+
+    /// Summarize rule hits, file types and severities across a project
+    Stats {
+        /// Path to validate
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output format (text or json)
+        #[arg(long, value_enum, default_value_t = StatsOutputFormat::Text)]
+        format: StatsOutputFormat,
+    },
+
+    /// Check the project's agent setup: missing hook scripts, skill directories without SKILL.md, empty rules directories
+    Doctor {
+        /// Project root to check
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+
     /// Initialize config file
     Init {
         /// Output path for config
         #[arg(default_value = ".agnix.toml")]
         output: PathBuf,
+
+        /// Detect the tools used in the output's directory and confirm each before writing `tools`
+        #[arg(short, long)]
+        interactive: bool,
     },
 
     /// Evaluate rule efficacy against labeled test cases
@@ -199,6 +390,12 @@ enum Commands {
         action: TelemetryAction,
     },
 
+    /// Explain a rule: name, tool, and message templates
+    Explain {
+        /// Rule ID (e.g., CC-SK-006)
+        rule_id: String,
+    },
+
     /// Output JSON Schema for configuration files
     Schema {
         /// Output file path (defaults to stdout)
@@ -244,7 +441,8 @@ fn main() {
     // Watch mode doesn't allow format or fix flags, so we can safely load config here
     if cli.watch {
         let config_path = resolve_config_path(&cli.path, &cli);
-        let (config, _) = LintConfig::load_or_default(config_path.as_ref());
+        let (mut config, _) = LintConfig::load_or_default(config_path.as_ref());
+        config.apply_env_overrides(&cli.config_env_prefix);
 
         // Re-initialize locale if config specifies one and no --locale flag was given
         if cli.locale.is_none() {
@@ -256,7 +454,13 @@ fn main() {
 
     let result = match &cli.command {
         Some(Commands::Validate { path }) => validate_command(path, &cli),
-        Some(Commands::Init { output }) => init_command(output),
+        Some(Commands::Baseline { path, output }) => baseline_command(path, output, &cli),
+        Some(Commands::Stats { path, format }) => stats_command(path, *format, &cli),
+        Some(Commands::Doctor { path }) => doctor_command(path),
+        Some(Commands::Init {
+            output,
+            interactive,
+        }) => init_command(output, *interactive),
         Some(Commands::Eval {
             path,
             format,
@@ -264,6 +468,7 @@ fn main() {
             verbose,
         }) => eval_command(path, *format, filter.as_deref(), *verbose),
         Some(Commands::Telemetry { action }) => telemetry_command(*action),
+        Some(Commands::Explain { rule_id }) => explain::explain_command(rule_id),
         Some(Commands::Schema { output }) => schema_command(output.as_ref()),
         None => validate_command(&cli.path, &cli),
     };
@@ -274,6 +479,26 @@ fn main() {
     }
 }
 
+/// "Found N errors, M warnings", with singular/plural words.
+fn found_summary(errors: usize, warnings: usize) -> String {
+    t!(
+        "cli.found_errors_warnings",
+        errors = errors,
+        error_word = if errors == 1 {
+            t!("cli.error_singular")
+        } else {
+            t!("cli.error_plural")
+        },
+        warnings = warnings,
+        warning_word = if warnings == 1 {
+            t!("cli.warning_singular")
+        } else {
+            t!("cli.warning_plural")
+        }
+    )
+    .to_string()
+}
+
 fn count_errors_warnings(diagnostics: &[Diagnostic]) -> (usize, usize) {
     let errors = diagnostics
         .iter()
@@ -286,36 +511,167 @@ fn count_errors_warnings(diagnostics: &[Diagnostic]) -> (usize, usize) {
     (errors, warnings)
 }
 
+/// Drop the diagnostics the user asked not to see. Applied to the report and
+/// to the post-`--fix` re-validation so both decide the exit code the same way.
+fn retain_reported(
+    diagnostics: &mut Vec<Diagnostic>,
+    cli: &Cli,
+    baseline: Option<&baseline::Baseline>,
+    base_path: &Path,
+) {
+    let min_confidence: Confidence = cli.min_confidence.into();
+    diagnostics.retain(|d| d.confidence >= min_confidence);
+
+    if let Some(baseline) = baseline {
+        let suppressed = baseline.filter(diagnostics, base_path);
+        tracing::debug!(suppressed = suppressed, "Applied baseline");
+    }
+
+    if !cli.only.is_empty() {
+        diagnostics.retain(|d| matches_only(&d.rule, &cli.only));
+    }
+}
+
+/// Whether `rule` matches one of the `--only` prefixes (case-insensitive)
+fn matches_only(rule: &str, only: &[String]) -> bool {
+    let rule = rule.to_ascii_uppercase();
+    only.iter()
+        .any(|prefix| rule.starts_with(&prefix.trim().to_ascii_uppercase()))
+}
+
+/// Newline-separated paths for `--stdin-paths`; blank lines are ignored
+fn read_stdin_paths(input: impl std::io::BufRead) -> anyhow::Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for line in input.lines() {
+        let line = line?;
+        let line = line.trim();
+        if !line.is_empty() {
+            paths.push(PathBuf::from(line));
+        }
+    }
+    Ok(paths)
+}
+
+/// Default `--cache` directory, relative to the validated path
+const DEFAULT_RESULT_CACHE_DIR: &str = ".agnix/cache";
+
+/// Longest snippet shown on each side of a `--show-fixes-inline` line
+const INLINE_FIX_MAX_CHARS: usize = 60;
+
+/// Quote a fix snippet for `--show-fixes-inline`, escaping newlines and
+/// truncating long text so each preview stays on one line.
+fn inline_fix_snippet(text: &str) -> String {
+    if text.chars().count() <= INLINE_FIX_MAX_CHARS {
+        return format!("{:?}", text);
+    }
+    let head: String = text.chars().take(INLINE_FIX_MAX_CHARS).collect();
+    format!("{:?}…", head)
+}
+
+/// `→ would change "<old>" to "<new>"` lines for each previewable fix
+fn inline_fix_lines(diag: &Diagnostic, source: Option<&str>) -> Vec<String> {
+    let Some(source) = source else {
+        return Vec::new();
+    };
+    diag.fixes
+        .iter()
+        .filter_map(|fix| fix.preview(source))
+        .map(|(old, new)| {
+            format!(
+                "  → {}",
+                t!(
+                    "cli.would_change",
+                    old = inline_fix_snippet(old),
+                    new = inline_fix_snippet(new)
+                )
+            )
+        })
+        .collect()
+}
+
+/// Severity emoji shown before the level in text output with `--emoji`
+fn severity_emoji(level: DiagnosticLevel) -> &'static str {
+    match level {
+        DiagnosticLevel::Error => "❌ ",
+        DiagnosticLevel::Warning => "⚠️ ",
+        DiagnosticLevel::Info => "ℹ️ ",
+    }
+}
+
+/// Whether `<prefix>STRICT` requests strict mode.
+fn env_strict(prefix: &str) -> bool {
+    !prefix.is_empty()
+        && env::var(format!("{}STRICT", prefix)).is_ok_and(|value| {
+            matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "yes" | "on"
+            )
+        })
+}
+
+fn print_config_warnings(warnings: &[ConfigWarning]) {
+    if warnings.is_empty() {
+        return;
+    }
+    for warning in warnings {
+        eprintln!(
+            "{} [{}] {}",
+            t!("cli.config_warning_label").yellow().bold(),
+            warning.field.dimmed(),
+            warning.message
+        );
+        if let Some(suggestion) = &warning.suggestion {
+            eprintln!("  {} {}", t!("cli.hint_label").cyan(), suggestion);
+        }
+    }
+    eprintln!();
+}
+
 #[tracing::instrument(skip(cli), fields(path = %path.display()))]
 fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
     tracing::debug!("Starting validation");
 
+    // --strict wins; otherwise fall back to the environment
+    let strict = cli.strict || env_strict(&cli.config_env_prefix);
+
     // Watch mode validation
     if cli.watch {
         if !matches!(cli.format, OutputFormat::Text) {
             return Err(anyhow::anyhow!("{}", t!("cli.watch_error_text_only")));
         }
-        let should_fix = cli.fix || cli.fix_safe || cli.dry_run;
+        let should_fix = cli.fix || cli.fix_safe || cli.dry_run || cli.interactive;
         if should_fix {
             return Err(anyhow::anyhow!("{}", t!("cli.watch_error_fix")));
         }
 
         let path = path.to_path_buf();
         let path_for_watch = path.clone();
-        let strict = cli.strict;
         let verbose = cli.verbose;
         let target = cli.target;
         let config_override = cli.config.clone();
-
-        return watch::watch_and_validate(&path_for_watch, move || {
-            run_single_validation(&path, strict, verbose, target, config_override.as_ref())
+        let env_prefix = cli.config_env_prefix.clone();
+
+        return watch::watch_and_validate(&path_for_watch, cli.initial_scan, move || {
+            run_single_validation(
+                &path,
+                strict,
+                verbose,
+                target,
+                config_override.as_ref(),
+                &env_prefix,
+            )
         });
     }
 
     let config_path = resolve_config_path(path, cli);
     tracing::debug!(config_path = ?config_path, "Resolved config path");
 
+    if cli.check_config {
+        return check_config_command(config_path.as_deref(), cli);
+    }
+
     let (mut config, config_warning) = LintConfig::load_or_default(config_path.as_ref());
+    let env_warnings = config.apply_env_overrides(&cli.config_env_prefix);
 
     // Re-initialize locale if config specifies one and no --locale flag was given
     if cli.locale.is_none() {
@@ -329,25 +685,43 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
         eprintln!("{} {}", t!("cli.warning_label").yellow().bold(), warning);
         eprintln!();
     }
-    config.target = cli.target.into();
+    if let Some(target) = cli.target {
+        config.target = target.into();
+    }
+    if cli.no_cross_file {
+        config.cross_file_rules = false;
+    }
 
     // Validate config semantics and display warnings (only for text output)
     if matches!(cli.format, OutputFormat::Text) {
-        let config_warnings = config.validate();
-        if !config_warnings.is_empty() {
-            for warning in &config_warnings {
-                eprintln!(
-                    "{} [{}] {}",
-                    t!("cli.config_warning_label").yellow().bold(),
-                    warning.field.dimmed(),
-                    warning.message
-                );
-                if let Some(suggestion) = &warning.suggestion {
-                    eprintln!("  {} {}", t!("cli.hint_label").cyan(), suggestion);
-                }
-            }
-            eprintln!();
-        }
+        let mut config_warnings = env_warnings;
+        config_warnings.extend(config.validate());
+        print_config_warnings(&config_warnings);
+    }
+
+    if cli.rules_coverage_report {
+        let coverage = coverage::RulesCoverage::new(&config);
+        return coverage::print_report(&coverage, matches!(cli.format, OutputFormat::Json));
+    }
+
+    if cli.list_rules {
+        let rules = list_rules::list_rules(&config);
+        return list_rules::print_rules(&rules, matches!(cli.format, OutputFormat::Json));
+    }
+
+    if let Some(ref cache_dir) = cli.cache_dir {
+        config.set_cache_dir(cache_dir.clone());
+    }
+    if cli.cache {
+        let cache_dir = cli.cache_dir.clone().unwrap_or_else(|| {
+            let root = if path.is_file() {
+                path.parent().unwrap_or(Path::new("."))
+            } else {
+                path
+            };
+            root.join(DEFAULT_RESULT_CACHE_DIR)
+        });
+        config.set_result_cache_dir(cache_dir);
     }
 
     // Apply --max-files override if specified
@@ -371,19 +745,24 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
             config.max_files_to_validate = Some(max_files);
         }
     }
-    let should_fix = cli.fix || cli.fix_safe || cli.dry_run;
-    if should_fix && !matches!(cli.format, OutputFormat::Text) {
+    let should_fix = cli.fix || cli.fix_safe || cli.dry_run || cli.interactive;
+    // --dry-run with --format json emits a fix plan instead of applying anything
+    let fix_plan = cli.dry_run && matches!(cli.format, OutputFormat::Json);
+    if should_fix && !fix_plan && !matches!(cli.format, OutputFormat::Text) {
         return Err(anyhow::anyhow!("{}", t!("cli.fix_error_text_only")));
     }
 
     // Resolve absolute path for consistent relative output (prefer repo root)
     let base_path = std::fs::canonicalize(".").unwrap_or_else(|_| PathBuf::from("."));
 
-    // For machine-readable output (JSON/SARIF), force English locale so that
+    // For machine-readable output (JSON/SARIF/GitHub), force English locale so that
     // diagnostic messages are always in English for tooling interoperability.
     // Save and restore the user's locale so that any subsequent stderr output
     // (e.g., error messages) remains in their chosen locale.
-    let is_machine_output = matches!(cli.format, OutputFormat::Json | OutputFormat::Sarif);
+    let is_machine_output = matches!(
+        cli.format,
+        OutputFormat::Json | OutputFormat::Jsonl | OutputFormat::Sarif | OutputFormat::Github
+    );
     let saved_locale = if is_machine_output {
         let current = rust_i18n::locale().to_string();
         rust_i18n::set_locale("en");
@@ -392,21 +771,68 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
         None
     };
 
-    // Time the validation for telemetry
+    // --diff narrows validation to the files and lines changed since the ref
+    let changed_lines = match cli.diff {
+        Some(ref git_ref) => {
+            let dir = if path.is_file() {
+                path.parent().unwrap_or(Path::new("."))
+            } else {
+                path
+            };
+            Some(git_diff::ChangedLines::from_git(dir, git_ref)?)
+        }
+        None => None,
+    };
+
+    let baseline = cli
+        .baseline
+        .as_deref()
+        .map(baseline::Baseline::load)
+        .transpose()?;
+
+    // Read once so the post-fix re-validation checks the same files
+    let stdin_paths = if cli.stdin_paths {
+        Some(read_stdin_paths(std::io::stdin().lock())?)
+    } else {
+        None
+    };
+
+    // Time only the validation call for telemetry and --dump-timings
     let validation_start = Instant::now();
 
     let ValidationResult {
-        diagnostics,
+        mut diagnostics,
         files_checked,
-    } = validate_project(path, &config)?;
+        file_timings,
+        validator_timings,
+        ..
+    } = if let Some(ref changed) = changed_lines {
+        validate_files(path, &changed.files(), &config)?
+    } else if let Some(ref paths) = stdin_paths {
+        validate_files(path, paths, &config)?
+    } else {
+        validate_project(path, &config)?
+    };
+    let validation_duration = validation_start.elapsed();
+
+    if let Some(ref changed) = changed_lines {
+        diagnostics.retain(|d| changed.contains(d));
+    }
+
+    retain_reported(&mut diagnostics, cli, baseline.as_ref(), &base_path);
+
+    // The exit code is decided on the full set, before --only-fixable narrows the output
+    let (total_errors, total_warnings) = count_errors_warnings(&diagnostics);
+    let should_fail = !cli.exit_zero && (total_errors > 0 || (strict && total_warnings > 0));
+    if cli.only_fixable {
+        diagnostics.retain(|d| d.has_fixes());
+    }
 
     // Restore user locale after validation so stderr messages use their language
     if let Some(ref locale) = saved_locale {
         rust_i18n::set_locale(locale);
     }
 
-    let validation_duration = validation_start.elapsed();
-
     tracing::debug!(
         files_checked = files_checked,
         diagnostics_count = diagnostics.len(),
@@ -416,46 +842,104 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
     // Record telemetry (non-blocking, respects opt-in)
     record_telemetry_event(&diagnostics, validation_duration);
 
+    if let Some(ref timings_path) = cli.dump_timings {
+        let timings = timings::Timings::new(validation_duration, files_checked, &validator_timings);
+        timings::write_timings(timings_path, &timings)?;
+    }
+
+    // Printed to stderr so JSON/SARIF output on stdout stays parseable
+    if let Some(limit) = cli.timings {
+        eprint!(
+            "{}",
+            timings::render_slowest(&file_timings, &validator_timings, limit, &base_path)
+        );
+    }
+
+    if let Some(ref report_path) = cli.report {
+        html::write_report(report_path, &diagnostics, &base_path, files_checked)?;
+    }
+
+    // --output sends the report to a file; stdout then only gets a short summary
+    let mut out: Box<dyn Write> = match cli.output {
+        Some(ref output_path) => {
+            Box::new(std::io::BufWriter::new(std::fs::File::create(output_path)?))
+        }
+        None => Box::new(std::io::stdout()),
+    };
+
+    if fix_plan {
+        let plan =
+            json::diagnostics_to_fix_plan(&diagnostics, &base_path, files_checked, cli.fix_safe);
+        writeln!(out, "{}", serde_json::to_string_pretty(&plan)?)?;
+
+        return finish_report(out, cli.output.as_deref(), &diagnostics, should_fail);
+    }
+
     // Handle JSON output format
     if matches!(cli.format, OutputFormat::Json) {
         let json_output = json::diagnostics_to_json(&diagnostics, &base_path, files_checked);
         let json_str = serde_json::to_string_pretty(&json_output)?;
-        println!("{}", json_str);
+        writeln!(out, "{}", json_str)?;
 
-        // Exit with error code if there are errors (use summary to avoid re-iterating)
-        if json_output.summary.errors > 0 || (cli.strict && json_output.summary.warnings > 0) {
-            process::exit(1);
-        }
-        return Ok(());
+        return finish_report(out, cli.output.as_deref(), &diagnostics, should_fail);
+    }
+
+    // Handle JSON Lines output format (one diagnostic per line, then a summary line)
+    if matches!(cli.format, OutputFormat::Jsonl) {
+        json::write_jsonl(&mut out, &diagnostics, &base_path, files_checked)?;
+
+        return finish_report(out, cli.output.as_deref(), &diagnostics, should_fail);
     }
 
     // Handle SARIF output format
     if matches!(cli.format, OutputFormat::Sarif) {
         let sarif = sarif::diagnostics_to_sarif(&diagnostics, &base_path);
         let json = serde_json::to_string_pretty(&sarif)?;
-        println!("{}", json);
-
-        // Exit with error code if there are errors
-        let has_errors = diagnostics
-            .iter()
-            .any(|d| d.level == DiagnosticLevel::Error);
-        let has_warnings = diagnostics
-            .iter()
-            .any(|d| d.level == DiagnosticLevel::Warning);
-
-        if has_errors || (cli.strict && has_warnings) {
-            process::exit(1);
+        writeln!(out, "{}", json)?;
+
+        return finish_report(out, cli.output.as_deref(), &diagnostics, should_fail);
+    }
+
+    // Handle GitHub Actions annotation output format
+    if matches!(cli.format, OutputFormat::Github) {
+        if !diagnostics.is_empty() {
+            writeln!(
+                out,
+                "{}",
+                github::diagnostics_to_github(&diagnostics, &base_path)
+            )?;
         }
-        return Ok(());
+
+        return finish_report(out, cli.output.as_deref(), &diagnostics, should_fail);
     }
 
-    // Text output format
-    println!("{} {}", t!("cli.validating").cyan().bold(), path.display());
-    println!();
+    // Text output format; a report file gets no ANSI colors or hyperlinks
+    if cli.output.is_some() {
+        colored::control::set_override(false);
+    }
+    if !cli.quiet {
+        writeln!(
+            out,
+            "{} {}",
+            t!("cli.validating").cyan().bold(),
+            path.display()
+        )?;
+        writeln!(out)?;
+    }
 
     if diagnostics.is_empty() {
-        println!("{}", t!("cli.no_issues_found").green().bold());
-        return Ok(());
+        if cli.quiet {
+            // A clean run prints nothing
+        } else if cli.only_fixable {
+            writeln!(out, "{}", t!("cli.no_fixable_issues_found").green().bold())?;
+        } else {
+            writeln!(out, "{}", t!("cli.no_issues_found").green().bold())?;
+        }
+        return finish_report(out, cli.output.as_deref(), &diagnostics, should_fail);
+    }
+
+    if cli.legend {
+        legend::write_legend(&mut out, &diagnostics)?;
     }
 
     let (errors, warnings) = count_errors_warnings(&diagnostics);
@@ -464,13 +948,36 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
         .filter(|d| d.level == DiagnosticLevel::Info)
         .count();
     let fixable = diagnostics.iter().filter(|d| d.has_fixes()).count();
+    let link_rules = hyperlink::should_emit(cli.hyperlinks);
+    let mut sources: HashMap<PathBuf, Option<String>> = HashMap::new();
 
     for diag in &diagnostics {
+        if cli.pretty_errors {
+            let source = sources
+                .entry(diag.file.clone())
+                .or_insert_with(|| std::fs::read_to_string(&diag.file).ok());
+            let rule_tag = format!("[{}]", hyperlink::rule_code(&diag.rule, link_rules));
+            if let Some(rendered) = pretty::render(diag, source.as_deref(), &rule_tag) {
+                writeln!(out, "{}", rendered)?;
+                if cli.show_fixes_inline {
+                    for line in inline_fix_lines(diag, source.as_deref()) {
+                        writeln!(out, "{}", line.green())?;
+                    }
+                }
+                continue;
+            }
+        }
+
         let level_str = match diag.level {
             DiagnosticLevel::Error => "error".red().bold(),
             DiagnosticLevel::Warning => "warning".yellow().bold(),
             DiagnosticLevel::Info => "info".blue().bold(),
         };
+        let emoji = if cli.emoji && !cli.no_emoji {
+            severity_emoji(diag.level)
+        } else {
+            ""
+        };
 
         let fixable_marker = if diag.has_fixes() {
             format!(" {}", t!("cli.fixable")).green().to_string()
@@ -478,23 +985,48 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
             String::new()
         };
 
-        println!(
-            "{}:{}:{} {}: {}{}",
+        // --hyperlinks shows the rule code inline (rustc-style) so it can be
+        // clicked; --legend shows it so the listed prefixes can be matched up
+        let rule_tag = if cli.hyperlinks || cli.legend {
+            format!("[{}]", hyperlink::rule_code(&diag.rule, link_rules))
+        } else {
+            String::new()
+        };
+
+        writeln!(
+            out,
+            "{}:{}:{} {}{}{}: {}{}",
             diag.file.display().to_string().dimmed(),
             diag.line,
             diag.column,
+            emoji,
             level_str,
+            rule_tag,
             diag.message,
             fixable_marker
-        );
+        )?;
+
+        if cli.show_fixes_inline && diag.has_fixes() {
+            let source = sources
+                .entry(diag.file.clone())
+                .or_insert_with(|| std::fs::read_to_string(&diag.file).ok());
+            for line in inline_fix_lines(diag, source.as_deref()) {
+                writeln!(out, "{}", line.green())?;
+            }
+        }
 
         if cli.verbose {
-            println!("  {} {}", t!("cli.rule_label").dimmed(), diag.rule.dimmed());
+            writeln!(
+                out,
+                "  {} {}",
+                t!("cli.rule_label").dimmed(),
+                diag.rule.dimmed()
+            )?;
             if let Some(suggestion) = &diag.suggestion {
-                println!("  {} {}", t!("cli.help_label").cyan(), suggestion);
+                writeln!(out, "  {} {}", t!("cli.help_label").cyan(), suggestion)?;
             }
             if let Some(assumption) = &diag.assumption {
-                println!("  {} {}", t!("cli.note_label").yellow(), assumption);
+                writeln!(out, "  {} {}", t!("cli.note_label").yellow(), assumption)?;
             }
             for fix in &diag.fixes {
                 let safety = if fix.safe {
@@ -502,43 +1034,32 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
                 } else {
                     t!("cli.unsafe")
                 };
-                println!(
+                writeln!(
+                    out,
                     "  {} {} ({})",
                     t!("cli.fix_label").green(),
                     fix.description,
                     safety
-                );
+                )?;
             }
         }
-        println!();
+        if !cli.quiet {
+            writeln!(out)?;
+        }
     }
 
-    println!("{}", "-".repeat(60).dimmed());
-    println!(
-        "{}",
-        t!(
-            "cli.found_errors_warnings",
-            errors = errors,
-            error_word = if errors == 1 {
-                t!("cli.error_singular")
-            } else {
-                t!("cli.error_plural")
-            },
-            warnings = warnings,
-            warning_word = if warnings == 1 {
-                t!("cli.warning_singular")
-            } else {
-                t!("cli.warning_plural")
-            }
-        )
-    );
+    if !cli.quiet {
+        writeln!(out, "{}", "-".repeat(60).dimmed())?;
+        writeln!(out, "{}", found_summary(errors, warnings))?;
+    }
 
-    if infos > 0 {
-        println!("{}", t!("cli.info_messages", count = infos));
+    if infos > 0 && !cli.quiet {
+        writeln!(out, "{}", t!("cli.info_messages", count = infos))?;
     }
 
-    if fixable > 0 {
-        println!(
+    if fixable > 0 && !cli.quiet {
+        writeln!(
+            out,
             "{}",
             t!(
                 "cli.fixable_issues",
@@ -549,15 +1070,14 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
                     t!("cli.issues_are")
                 }
             )
-        );
+        )?;
     }
 
-    let mut final_errors = errors;
-    let mut final_warnings = warnings;
+    let mut should_fail = should_fail;
 
     // --fix-safe implies --fix
     if should_fix {
-        println!();
+        writeln!(out)?;
         let mode = if cli.dry_run {
             t!("cli.preview")
         } else {
@@ -568,23 +1088,46 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
         } else {
             "".into()
         };
-        println!(
+        writeln!(
+            out,
             "{}",
             t!(
                 "cli.applying_fixes",
                 mode = mode.cyan().bold(),
                 safe_mode = safe_mode
             )
-        );
-
-        let results = apply_fixes(&diagnostics, cli.dry_run, cli.fix_safe)?;
+        )?;
+
+        let results = if cli.interactive {
+            let selected = interactive::select_fixes(
+                &diagnostics,
+                cli.fix_safe,
+                &mut std::io::stdin().lock(),
+            )?;
+            let results = apply_fixes(&selected, false, cli.fix_safe)?;
+            // Overlapping accepted fixes are skipped by apply_fixes; say so
+            let applied: usize = results.iter().map(|r| r.applied.len()).sum();
+            let skipped = interactive::fix_count(&selected).saturating_sub(applied);
+            if skipped > 0 {
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "{}",
+                    t!("cli.interactive_skipped", count = skipped).yellow()
+                )?;
+            }
+            results
+        } else {
+            apply_fixes(&diagnostics, cli.dry_run, cli.fix_safe)?
+        };
 
         if results.is_empty() {
-            println!("{}", t!("cli.no_fixes"));
+            writeln!(out, "{}", t!("cli.no_fixes"))?;
         } else {
             for result in &results {
-                println!();
-                println!(
+                writeln!(out)?;
+                writeln!(
+                    out,
                     "  {} {}",
                     if cli.dry_run {
                         t!("cli.would_fix")
@@ -593,25 +1136,26 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
                     }
                     .green(),
                     result.path.display()
-                );
+                )?;
                 for desc in &result.applied {
-                    println!("    - {}", desc);
+                    writeln!(out, "    - {}", desc)?;
                 }
 
                 if cli.dry_run && cli.verbose {
-                    println!();
-                    println!("  {}:", t!("cli.diff_label").yellow());
-                    show_diff(&result.original, &result.fixed);
+                    writeln!(out)?;
+                    writeln!(out, "  {}:", t!("cli.diff_label").yellow())?;
+                    write_diff(&mut out, &result.original, &result.fixed)?;
                 }
             }
 
-            println!();
+            writeln!(out)?;
             let action = if cli.dry_run {
                 t!("cli.would_fix")
             } else {
                 t!("cli.fixed")
             };
-            println!(
+            writeln!(
+                out,
                 "{}",
                 t!(
                     "cli.fix_summary",
@@ -623,32 +1167,72 @@ fn validate_command(path: &Path, cli: &Cli) -> anyhow::Result<()> {
                         t!("cli.file_plural")
                     }
                 )
-            );
+            )?;
         }
 
         // Re-run validation after applying fixes so exit code reflects remaining issues.
         if !cli.dry_run {
-            let ValidationResult {
-                diagnostics: post_fix_diagnostics,
-                files_checked: _,
-            } = validate_project(path, &config)?;
+            let mut post_fix_diagnostics = if let Some(ref changed) = changed_lines {
+                let mut diagnostics = validate_files(path, &changed.files(), &config)?.diagnostics;
+                diagnostics.retain(|d| changed.contains(d));
+                diagnostics
+            } else if let Some(ref paths) = stdin_paths {
+                validate_files(path, paths, &config)?.diagnostics
+            } else {
+                validate_project(path, &config)?.diagnostics
+            };
+            retain_reported(
+                &mut post_fix_diagnostics,
+                cli,
+                baseline.as_ref(),
+                &base_path,
+            );
 
-            (final_errors, final_warnings) = count_errors_warnings(&post_fix_diagnostics);
+            let (final_errors, final_warnings) = count_errors_warnings(&post_fix_diagnostics);
+            should_fail = !cli.exit_zero && (final_errors > 0 || (strict && final_warnings > 0));
         }
-    } else if fixable > 0 {
-        println!();
-        println!(
+    } else if fixable > 0 && !cli.quiet {
+        writeln!(out)?;
+        writeln!(
+            out,
             "{} {}",
             t!("cli.hint_label").cyan(),
             t!("cli.hint_run_fix", flag = "--fix".bold())
-        );
+        )?;
     }
 
     // Exit with error if errors remain (even after fixing) or strict mode with warnings
-    if final_errors > 0 || (cli.strict && final_warnings > 0) {
-        process::exit(1);
+    finish_report(out, cli.output.as_deref(), &diagnostics, should_fail)
+}
+
+/// Flush the report and exit with code 1 if `should_fail`.
+///
+/// When the report went to `--output`, stdout gets the file path and the
+/// error/warning totals so the run's outcome is still visible in CI logs.
+fn finish_report(
+    mut out: Box<dyn Write>,
+    output: Option<&Path>,
+    diagnostics: &[Diagnostic],
+    should_fail: bool,
+) -> anyhow::Result<()> {
+    out.flush()?;
+    drop(out);
+
+    if let Some(path) = output {
+        // Colors were disabled while writing the text report to the file
+        colored::control::unset_override();
+        let (errors, warnings) = count_errors_warnings(diagnostics);
+        println!(
+            "{} {}",
+            t!("cli.output_written").green().bold(),
+            path.display()
+        );
+        println!("{}", found_summary(errors, warnings));
     }
 
+    if should_fail {
+        process::exit(1);
+    }
     Ok(())
 }
 
@@ -658,8 +1242,9 @@ fn run_single_validation(
     path: &Path,
     strict: bool,
     verbose: bool,
-    target: TargetArg,
+    target: Option<TargetArg>,
     config_override: Option<&PathBuf>,
+    env_prefix: &str,
 ) -> anyhow::Result<bool> {
     let config_path = if let Some(c) = config_override {
         Some(c.clone())
@@ -668,17 +1253,18 @@ fn run_single_validation(
     };
 
     let (mut config, config_warning) = LintConfig::load_or_default(config_path.as_ref());
+    let env_warnings = config.apply_env_overrides(env_prefix);
 
     if let Some(warning) = config_warning {
         eprintln!("{} {}", t!("cli.warning_label").yellow().bold(), warning);
         eprintln!();
     }
-    config.target = target.into();
+    print_config_warnings(&env_warnings);
+    if let Some(target) = target {
+        config.target = target.into();
+    }
 
-    let ValidationResult {
-        diagnostics,
-        files_checked: _,
-    } = validate_project(path, &config)?;
+    let ValidationResult { diagnostics, .. } = validate_project(path, &config)?;
 
     println!("{} {}", t!("cli.validating").cyan().bold(), path.display());
     println!();
@@ -723,28 +1309,37 @@ fn run_single_validation(
     }
 
     println!("{}", "-".repeat(60).dimmed());
-    println!(
-        "{}",
-        t!(
-            "cli.found_errors_warnings",
-            errors = errors,
-            error_word = if errors == 1 {
-                t!("cli.error_singular")
-            } else {
-                t!("cli.error_plural")
-            },
-            warnings = warnings,
-            warning_word = if warnings == 1 {
-                t!("cli.warning_singular")
-            } else {
-                t!("cli.warning_plural")
-            }
-        )
-    );
+    println!("{}", found_summary(errors, warnings));
 
     Ok(errors > 0 || (strict && warnings > 0))
 }
 
+/// `--check-config`: report unknown keys in the resolved config file.
+///
+/// Exits with code 1 when any key is unknown so CI can catch config typos.
+fn check_config_command(config_path: Option<&Path>, cli: &Cli) -> anyhow::Result<()> {
+    let Some(config_path) = config_path else {
+        println!("{}", t!("cli.config_check_no_config"));
+        return Ok(());
+    };
+
+    let content = std::fs::read_to_string(config_path)?;
+    let source: toml::Table = toml::from_str(&content)
+        .map_err(|e| anyhow::anyhow!("{}: {}", config_path.display(), e))?;
+
+    let unknown = config_check::unknown_keys(&source);
+    config_check::print_report(
+        config_path,
+        &unknown,
+        matches!(cli.format, OutputFormat::Json),
+    )?;
+
+    if !unknown.is_empty() {
+        process::exit(1);
+    }
+    Ok(())
+}
+
 fn resolve_config_path_simple(path: &Path) -> Option<PathBuf> {
     let mut candidates = Vec::new();
     if path.is_dir() {
@@ -794,19 +1389,134 @@ fn resolve_config_path(path: &Path, cli: &Cli) -> Option<PathBuf> {
 }
 
 fn show_diff(original: &str, fixed: &str) {
+    // Writing to stdout only fails on a closed pipe, where there is no one to tell
+    let _ = write_diff(&mut std::io::stdout(), original, fixed);
+}
+
+fn write_diff(out: &mut dyn Write, original: &str, fixed: &str) -> std::io::Result<()> {
     let diff = TextDiff::from_lines(original, fixed);
     for change in diff.iter_all_changes() {
         match change.tag() {
-            ChangeTag::Delete => print!("    {} {}", "-".red(), change.to_string().red()),
-            ChangeTag::Insert => print!("    {} {}", "+".green(), change.to_string().green()),
+            ChangeTag::Delete => write!(out, "    {} {}", "-".red(), change.to_string().red())?,
+            ChangeTag::Insert => write!(out, "    {} {}", "+".green(), change.to_string().green())?,
             ChangeTag::Equal => {}
         }
     }
+    Ok(())
+}
+
+fn baseline_command(path: &Path, output: &Path, cli: &Cli) -> anyhow::Result<()> {
+    let config_path = resolve_config_path(path, cli);
+    let (mut config, config_warning) = LintConfig::load_or_default(config_path.as_ref());
+    let env_warnings = config.apply_env_overrides(&cli.config_env_prefix);
+
+    if let Some(warning) = config_warning {
+        eprintln!("{} {}", t!("cli.warning_label").yellow().bold(), warning);
+        eprintln!();
+    }
+    print_config_warnings(&env_warnings);
+    if let Some(target) = cli.target {
+        config.target = target.into();
+    }
+    if cli.no_cross_file {
+        config.cross_file_rules = false;
+    }
+
+    let base_path = std::fs::canonicalize(".").unwrap_or_else(|_| PathBuf::from("."));
+    let ValidationResult {
+        mut diagnostics, ..
+    } = validate_project(path, &config)?;
+
+    let min_confidence: Confidence = cli.min_confidence.into();
+    diagnostics.retain(|d| d.confidence >= min_confidence);
+
+    let baseline = baseline::Baseline::from_diagnostics(&diagnostics, &base_path);
+    baseline.write(output)?;
+
+    println!(
+        "{} {}",
+        t!("cli.baseline_written", count = baseline.diagnostic_count())
+            .green()
+            .bold(),
+        output.display()
+    );
+
+    Ok(())
+}
+
+fn stats_command(path: &Path, format: StatsOutputFormat, cli: &Cli) -> anyhow::Result<()> {
+    let config_path = resolve_config_path(path, cli);
+    let (mut config, config_warning) = LintConfig::load_or_default(config_path.as_ref());
+    let env_warnings = config.apply_env_overrides(&cli.config_env_prefix);
+
+    if let Some(warning) = config_warning {
+        eprintln!("{} {}", t!("cli.warning_label").yellow().bold(), warning);
+        eprintln!();
+    }
+    print_config_warnings(&env_warnings);
+    if let Some(target) = cli.target {
+        config.target = target.into();
+    }
+    if cli.no_cross_file {
+        config.cross_file_rules = false;
+    }
+
+    let mut result = validate_project(path, &config)?;
+    let min_confidence: Confidence = cli.min_confidence.into();
+    result
+        .diagnostics
+        .retain(|d| d.confidence >= min_confidence);
+
+    let stats = stats::ProjectStats::new(&result);
+    stats::print_report(&stats, matches!(format, StatsOutputFormat::Json))
+}
+
+fn doctor_command(path: &Path) -> anyhow::Result<()> {
+    let findings = agnix_core::doctor::diagnose(path, &LintConfig::default());
+
+    println!(
+        "{} {}",
+        t!("cli.doctor_checking").cyan().bold(),
+        path.display()
+    );
+    println!();
+
+    if findings.is_empty() {
+        println!("{}", t!("cli.doctor_no_issues").green().bold());
+        return Ok(());
+    }
+
+    for finding in &findings {
+        println!(
+            "{} {}: {}",
+            finding.path.display().to_string().dimmed(),
+            format!("[{}]", finding.check.id()).yellow(),
+            finding.message
+        );
+        println!("  {} {}", t!("cli.help_label").cyan(), finding.suggestion);
+        println!();
+    }
+    println!(
+        "{}",
+        t!("cli.doctor_found", count = findings.len())
+            .yellow()
+            .bold()
+    );
+    process::exit(1);
 }
 
-fn init_command(output: &PathBuf) -> anyhow::Result<()> {
-    let default_config = LintConfig::default();
-    let toml_content = toml::to_string_pretty(&default_config)?;
+fn init_command(output: &PathBuf, interactive: bool) -> anyhow::Result<()> {
+    let config = if interactive {
+        // Scan the directory the config is written to
+        let root = match output.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        init::interactive_config(root, &mut std::io::stdin().lock())?
+    } else {
+        LintConfig::default()
+    };
+    let toml_content = toml::to_string_pretty(&config)?;
 
     std::fs::write(output, toml_content)?;
 
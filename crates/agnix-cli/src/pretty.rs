@@ -0,0 +1,201 @@
+//! Rustc-style rendering of diagnostics for `--pretty-errors`.
+//!
+//! Each diagnostic is shown with the offending source line, a caret underline
+//! beneath the reported span and the suggestion as a `help` note. Diagnostics
+//! that cannot be located in the source (no line, unreadable file) return
+//! `None` so the caller can fall back to the plain one-line format.
+
+use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel};
+use colored::*;
+
+/// Render `diag` against the contents of its file.
+///
+/// Returns `None` when the diagnostic has no usable position in `source`.
+pub fn render(diag: &Diagnostic, source: Option<&str>, rule_tag: &str) -> Option<String> {
+    let source = source?;
+    if diag.line == 0 {
+        return None;
+    }
+    let line_start = line_start_offset(source, diag.line)?;
+    let line_text = source[line_start..].lines().next().unwrap_or("");
+
+    let (span_start, span_len) = span_in_line(diag, line_start, line_text);
+    let caret_pad = line_text[..span_start].chars().count();
+    let caret_len = line_text[span_start..span_start + span_len]
+        .chars()
+        .count()
+        .max(1);
+
+    let (level, color): (&str, fn(&str) -> ColoredString) = match diag.level {
+        DiagnosticLevel::Error => ("error", |s| s.red().bold()),
+        DiagnosticLevel::Warning => ("warning", |s| s.yellow().bold()),
+        DiagnosticLevel::Info => ("info", |s| s.blue().bold()),
+    };
+
+    let gutter_width = diag.line.to_string().len();
+    let gutter = " ".repeat(gutter_width);
+    let bar = "|".blue().bold();
+
+    let mut out = String::new();
+    out.push_str(&format!(
+        "{}{}: {}\n",
+        color(level),
+        rule_tag,
+        diag.message.bold()
+    ));
+    out.push_str(&format!(
+        "{}{} {}:{}:{}\n",
+        gutter,
+        "-->".blue().bold(),
+        diag.file.display(),
+        diag.line,
+        diag.column
+    ));
+    out.push_str(&format!("{} {}\n", gutter, bar));
+    out.push_str(&format!(
+        "{} {} {}\n",
+        diag.line.to_string().blue().bold(),
+        bar,
+        line_text
+    ));
+    out.push_str(&format!(
+        "{} {} {}{} {}\n",
+        gutter,
+        bar,
+        " ".repeat(caret_pad),
+        color(&"^".repeat(caret_len)),
+        color(&diag.rule)
+    ));
+    if let Some(suggestion) = &diag.suggestion {
+        out.push_str(&format!("{} {}\n", gutter, bar));
+        out.push_str(&format!(
+            "{} {} {}: {}\n",
+            gutter,
+            "=".blue().bold(),
+            "help".bold(),
+            suggestion
+        ));
+    }
+    if let Some(assumption) = &diag.assumption {
+        out.push_str(&format!(
+            "{} {} {}: {}\n",
+            gutter,
+            "=".blue().bold(),
+            "note".bold(),
+            assumption
+        ));
+    }
+    Some(out)
+}
+
+/// Byte offset of the start of 1-based `line`, if the source has that many lines.
+fn line_start_offset(source: &str, line: usize) -> Option<usize> {
+    if line == 1 {
+        return Some(0);
+    }
+    source
+        .match_indices('\n')
+        .nth(line - 2)
+        .map(|(idx, _)| idx + 1)
+        .filter(|&start| start < source.len())
+}
+
+/// Byte range within `line_text` to underline, as (start, len).
+///
+/// Uses the first fix whose byte range falls on this line, otherwise the
+/// whitespace-delimited token starting at the reported column.
+fn span_in_line(diag: &Diagnostic, line_start: usize, line_text: &str) -> (usize, usize) {
+    let line_end = line_start + line_text.len();
+    let fix_span = diag.fixes.iter().find(|fix| {
+        fix.start_byte >= line_start && fix.end_byte <= line_end && fix.end_byte > fix.start_byte
+    });
+    if let Some(fix) = fix_span {
+        let start = fix.start_byte - line_start;
+        let end = fix.end_byte - line_start;
+        if line_text.is_char_boundary(start) && line_text.is_char_boundary(end) {
+            return (start, end - start);
+        }
+    }
+
+    let mut start = diag.column.saturating_sub(1).min(line_text.len());
+    while !line_text.is_char_boundary(start) {
+        start -= 1;
+    }
+    let len = line_text[start..]
+        .find(char::is_whitespace)
+        .unwrap_or(line_text.len() - start);
+    (start, len)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agnix_core::diagnostics::Fix;
+    use std::path::PathBuf;
+
+    const SKILL: &str = "---\nname: deep-reference\ndescription: Use when validating deep references\n---\n\nSee references/deep/nested/guide.md for details.\n";
+
+    fn as_013() -> Diagnostic {
+        Diagnostic::error(
+            PathBuf::from("skills/deep-reference/SKILL.md"),
+            6,
+            5,
+            "AS-013",
+            "File reference 'references/deep/nested/guide.md' is deeper than one level",
+        )
+        .with_suggestion("Flatten the references/ directory structure")
+    }
+
+    #[test]
+    fn test_render_as_013_snapshot() {
+        colored::control::set_override(false);
+        let rendered = render(&as_013(), Some(SKILL), "[AS-013]").unwrap();
+        assert_eq!(
+            rendered,
+            "\
+error[AS-013]: File reference 'references/deep/nested/guide.md' is deeper than one level
+ --> skills/deep-reference/SKILL.md:6:5
+  |
+6 | See references/deep/nested/guide.md for details.
+  |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ AS-013
+  |
+  = help: Flatten the references/ directory structure
+"
+        );
+    }
+
+    #[test]
+    fn test_render_uses_fix_span() {
+        colored::control::set_override(false);
+        let line_start = SKILL.find("See").unwrap();
+        let start = line_start + 4;
+        let diag = as_013().with_fix(Fix::replace(
+            start,
+            start + "references".len(),
+            "refs",
+            "Shorten",
+            false,
+        ));
+        let rendered = render(&diag, Some(SKILL), "").unwrap();
+        assert!(rendered.contains("  |     ^^^^^^^^^^ AS-013\n"));
+    }
+
+    #[test]
+    fn test_render_falls_back_without_source() {
+        assert!(render(&as_013(), None, "").is_none());
+    }
+
+    #[test]
+    fn test_render_falls_back_without_line() {
+        let mut diag = as_013();
+        diag.line = 0;
+        assert!(render(&diag, Some(SKILL), "").is_none());
+    }
+
+    #[test]
+    fn test_render_falls_back_when_line_out_of_range() {
+        let mut diag = as_013();
+        diag.line = 42;
+        assert!(render(&diag, Some(SKILL), "").is_none());
+    }
+}
@@ -65,12 +65,20 @@ pub struct SarifResult {
     pub level: String,
     pub message: Message,
     pub locations: Vec<Location>,
+    /// Other files involved in a cross-file finding
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub related_locations: Vec<Location>,
 }
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Location {
+    /// Identifies a related location within its result
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<usize>,
     pub physical_location: PhysicalLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
 }
 
 #[derive(Debug, Serialize)]
@@ -110,6 +118,24 @@ fn path_to_uri(path: &Path, base_path: &Path) -> String {
     uri_path
 }
 
+fn physical_location(
+    file: &Path,
+    line: usize,
+    column: usize,
+    base_path: &Path,
+) -> PhysicalLocation {
+    PhysicalLocation {
+        artifact_location: ArtifactLocation {
+            uri: path_to_uri(file, base_path),
+        },
+        region: Region {
+            // SARIF requires 1-based positions; clamp to 1 for diagnostics without location
+            start_line: line.max(1),
+            start_column: column.max(1),
+        },
+    }
+}
+
 static RULES: LazyLock<Vec<ReportingDescriptor>> = LazyLock::new(|| {
     // Rules loaded from knowledge-base/rules.json at compile time via build.rs
     RULES_DATA
@@ -141,17 +167,22 @@ pub fn diagnostics_to_sarif(diagnostics: &[Diagnostic], base_path: &Path) -> Sar
                 text: diag.message.clone(),
             },
             locations: vec![Location {
-                physical_location: PhysicalLocation {
-                    artifact_location: ArtifactLocation {
-                        uri: path_to_uri(&diag.file, base_path),
-                    },
-                    region: Region {
-                        // SARIF requires 1-based positions; clamp to 1 for diagnostics without location
-                        start_line: diag.line.max(1),
-                        start_column: diag.column.max(1),
-                    },
-                },
+                id: None,
+                physical_location: physical_location(&diag.file, diag.line, diag.column, base_path),
+                message: None,
             }],
+            related_locations: diag
+                .related
+                .iter()
+                .enumerate()
+                .map(|(id, (file, line, column, message))| Location {
+                    id: Some(id),
+                    physical_location: physical_location(file, *line, *column, base_path),
+                    message: Some(Message {
+                        text: message.clone(),
+                    }),
+                })
+                .collect(),
         })
         .collect();
 
@@ -175,6 +206,7 @@ pub fn diagnostics_to_sarif(diagnostics: &[Diagnostic], base_path: &Path) -> Sar
 #[cfg(test)]
 mod tests {
     use super::*;
+    use agnix_core::diagnostics::Confidence;
     use std::path::PathBuf;
 
     #[test]
@@ -234,8 +266,8 @@ mod tests {
     fn test_rules_array_populated() {
         let sarif = diagnostics_to_sarif(&[], Path::new("."));
         let rules = &sarif.runs[0].tool.driver.rules;
-        // Should have 145 rules based on VALIDATION-RULES.md
-        assert_eq!(rules.len(), 145, "Expected 145 rules in SARIF driver");
+        // Should have 181 rules based on VALIDATION-RULES.md
+        assert_eq!(rules.len(), 181, "Expected 181 rules in SARIF driver");
 
         // Verify some specific rules exist
         let rule_ids: Vec<&str> = rules.iter().map(|r| r.id.as_str()).collect();
@@ -273,6 +305,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_related_locations() {
+        let diag = Diagnostic::warning(
+            PathBuf::from("/project/CLAUDE.md"),
+            3,
+            0,
+            "XP-004",
+            "Conflicting package managers",
+        )
+        .with_related(
+            PathBuf::from("/project/AGENTS.md"),
+            7,
+            0,
+            "pnpm is used here: pnpm install",
+        );
+
+        let sarif = diagnostics_to_sarif(&[diag], Path::new("/project"));
+        let result = &sarif.runs[0].results[0];
+        assert_eq!(result.related_locations.len(), 1);
+        let related = &result.related_locations[0];
+        assert_eq!(related.id, Some(0));
+        assert_eq!(related.physical_location.artifact_location.uri, "AGENTS.md");
+        assert_eq!(related.physical_location.region.start_line, 7);
+        assert_eq!(related.physical_location.region.start_column, 1);
+
+        let json = serde_json::to_value(&sarif).unwrap();
+        let result = &json["runs"][0]["results"][0];
+        assert_eq!(
+            result["relatedLocations"][0]["message"]["text"],
+            "pnpm is used here: pnpm install"
+        );
+        assert!(result["locations"][0].get("id").is_none());
+    }
+
+    #[test]
+    fn test_related_locations_omitted_when_empty() {
+        let diag = Diagnostic::error(
+            PathBuf::from("/project/test.md"),
+            1,
+            1,
+            "AS-001",
+            "Missing frontmatter",
+        );
+        let json =
+            serde_json::to_string(&diagnostics_to_sarif(&[diag], Path::new("/project"))).unwrap();
+        assert!(!json.contains("relatedLocations"));
+    }
+
     #[test]
     fn test_sarif_json_serialization() {
         let sarif = diagnostics_to_sarif(&[], Path::new("."));
@@ -337,6 +417,11 @@ mod tests {
             suggestion: None,
             fixes: vec![],
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         };
         let sarif = diagnostics_to_sarif(&[diag], Path::new("/project"));
         assert_eq!(sarif.runs[0].results[0].level, "note");
@@ -430,6 +515,11 @@ mod tests {
             suggestion: None,
             fixes: vec![],
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         };
 
         let sarif = diagnostics_to_sarif(&[diag], Path::new("/project"));
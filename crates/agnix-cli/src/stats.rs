@@ -0,0 +1,215 @@
+//! Project-wide rule hit summary for `agnix stats`.
+//!
+//! Aggregates one `validate_project` run by rule ID and by detected file type
+//! so the noisiest rules can be prioritized.
+
+use agnix_core::{Diagnostic, DiagnosticLevel, FileType, ValidationResult};
+use colored::*;
+use rust_i18n::t;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+
+/// Diagnostic counts split by severity.
+#[derive(Debug, Default, Clone, Copy, Serialize)]
+pub struct LevelCounts {
+    pub errors: usize,
+    pub warnings: usize,
+    pub info: usize,
+}
+
+impl LevelCounts {
+    fn add(&mut self, level: DiagnosticLevel) {
+        match level {
+            DiagnosticLevel::Error => self.errors += 1,
+            DiagnosticLevel::Warning => self.warnings += 1,
+            DiagnosticLevel::Info => self.info += 1,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.errors + self.warnings + self.info
+    }
+}
+
+/// Hits for one rule ID.
+#[derive(Debug, Serialize)]
+pub struct RuleStats {
+    pub rule: String,
+    pub total: usize,
+    #[serde(flatten)]
+    pub levels: LevelCounts,
+}
+
+/// Number of checked files of one type.
+#[derive(Debug, Serialize)]
+pub struct FileTypeStats {
+    pub file_type: String,
+    pub files: usize,
+}
+
+/// Summary of a project validation run.
+#[derive(Debug, Serialize)]
+pub struct ProjectStats {
+    pub files_checked: usize,
+    pub diagnostics: LevelCounts,
+    /// Rules ordered by hit count, most frequent first.
+    pub rules: Vec<RuleStats>,
+    /// File types ordered by file count, most common first.
+    pub file_types: Vec<FileTypeStats>,
+}
+
+impl ProjectStats {
+    pub fn new(result: &ValidationResult) -> Self {
+        Self::from_parts(
+            &result.diagnostics,
+            result.files_checked,
+            &result.files_by_type,
+        )
+    }
+
+    fn from_parts(
+        diagnostics: &[Diagnostic],
+        files_checked: usize,
+        files_by_type: &HashMap<FileType, usize>,
+    ) -> Self {
+        let mut totals = LevelCounts::default();
+        let mut by_rule: BTreeMap<&str, LevelCounts> = BTreeMap::new();
+        for diagnostic in diagnostics {
+            totals.add(diagnostic.level);
+            by_rule
+                .entry(diagnostic.rule.as_str())
+                .or_default()
+                .add(diagnostic.level);
+        }
+
+        let mut rules: Vec<RuleStats> = by_rule
+            .into_iter()
+            .map(|(rule, levels)| RuleStats {
+                rule: rule.to_string(),
+                total: levels.total(),
+                levels,
+            })
+            .collect();
+        // Stable sort keeps ties in rule ID order
+        rules.sort_by_key(|rule| std::cmp::Reverse(rule.total));
+
+        let mut file_types: Vec<FileTypeStats> = files_by_type
+            .iter()
+            .map(|(file_type, files)| FileTypeStats {
+                file_type: format!("{:?}", file_type),
+                files: *files,
+            })
+            .collect();
+        file_types.sort_by(|a, b| {
+            b.files
+                .cmp(&a.files)
+                .then_with(|| a.file_type.cmp(&b.file_type))
+        });
+
+        Self {
+            files_checked,
+            diagnostics: totals,
+            rules,
+            file_types,
+        }
+    }
+}
+
+/// Print the summary as pretty JSON or as text tables.
+pub fn print_report(stats: &ProjectStats, json: bool) -> anyhow::Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(stats)?);
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        t!("cli.stats_files", count = stats.files_checked).bold()
+    );
+    for file_type in &stats.file_types {
+        println!("  {:<20} {:>6}", file_type.file_type, file_type.files);
+    }
+    println!();
+
+    let totals = &stats.diagnostics;
+    println!(
+        "{}",
+        t!(
+            "cli.stats_diagnostics",
+            errors = totals.errors,
+            warnings = totals.warnings,
+            info = totals.info
+        )
+        .bold()
+    );
+    if stats.rules.is_empty() {
+        return Ok(());
+    }
+    println!(
+        "  {:<20} {:>6} {:>6} {:>8} {:>6}",
+        t!("cli.stats_rule_column"),
+        t!("cli.stats_total_column"),
+        t!("cli.stats_errors_column"),
+        t!("cli.stats_warnings_column"),
+        t!("cli.stats_info_column")
+    );
+    for rule in &stats.rules {
+        println!(
+            "  {:<20} {:>6} {:>6} {:>8} {:>6}",
+            rule.rule, rule.total, rule.levels.errors, rule.levels.warnings, rule.levels.info
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn diagnostic(rule: &str, level: DiagnosticLevel) -> Diagnostic {
+        let file = PathBuf::from("SKILL.md");
+        match level {
+            DiagnosticLevel::Error => Diagnostic::error(file, 1, 0, rule, "e"),
+            DiagnosticLevel::Warning => Diagnostic::warning(file, 1, 0, rule, "w"),
+            DiagnosticLevel::Info => Diagnostic::info(file, 1, 0, rule, "i"),
+        }
+    }
+
+    #[test]
+    fn test_rules_sorted_by_hits_then_id() {
+        let diagnostics = vec![
+            diagnostic("CC-SK-006", DiagnosticLevel::Error),
+            diagnostic("AS-004", DiagnosticLevel::Warning),
+            diagnostic("AS-004", DiagnosticLevel::Error),
+            diagnostic("AS-010", DiagnosticLevel::Info),
+        ];
+        let stats = ProjectStats::from_parts(&diagnostics, 2, &HashMap::new());
+
+        let order: Vec<&str> = stats.rules.iter().map(|r| r.rule.as_str()).collect();
+        assert_eq!(order, vec!["AS-004", "AS-010", "CC-SK-006"]);
+        assert_eq!(stats.rules[0].total, 2);
+        assert_eq!(stats.rules[0].levels.errors, 1);
+        assert_eq!(stats.rules[0].levels.warnings, 1);
+        assert_eq!(stats.diagnostics.errors, 2);
+        assert_eq!(stats.diagnostics.info, 1);
+    }
+
+    #[test]
+    fn test_file_types_sorted_by_count() {
+        let files_by_type = HashMap::from([
+            (FileType::Skill, 1),
+            (FileType::Mcp, 3),
+            (FileType::ClaudeMd, 1),
+        ]);
+        let stats = ProjectStats::from_parts(&[], 5, &files_by_type);
+
+        let order: Vec<(&str, usize)> = stats
+            .file_types
+            .iter()
+            .map(|f| (f.file_type.as_str(), f.files))
+            .collect();
+        assert_eq!(order, vec![("Mcp", 3), ("ClaudeMd", 1), ("Skill", 1)]);
+    }
+}
@@ -58,25 +58,8 @@ pub fn chrono_timestamp() -> String {
     )
 }
 
-/// Check if a string is a valid rule ID format.
-///
-/// Rule IDs are in format: XX-NNN or XX-YY-NNN
-/// Examples: AS-001, CC-HK-001, MCP-002
-pub fn is_valid_rule_id(s: &str) -> bool {
-    let parts: Vec<&str> = s.split('-').collect();
-    if parts.len() < 2 || parts.len() > 3 {
-        return false;
-    }
-
-    for part in &parts[..parts.len() - 1] {
-        if part.is_empty() || !part.chars().all(|c| c.is_ascii_uppercase()) {
-            return false;
-        }
-    }
-
-    let last = parts.last().unwrap();
-    !last.is_empty() && last.chars().all(|c| c.is_ascii_digit())
-}
+/// Check if a string is a valid rule ID format (XX-NNN or XX-YY-NNN).
+pub use agnix_core::diagnostics::is_valid_rule_id;
 
 fn is_leap_year(year: i32) -> bool {
     (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
@@ -0,0 +1,172 @@
+//! Validation timings: machine-readable totals for `--dump-timings` and the
+//! slowest files and validators for `--timings`.
+//!
+//! `--dump-timings` keys are camelCase and never renamed so CI dashboards can
+//! trend them across releases.
+
+use agnix_core::timing::{FileTiming, ValidatorTiming};
+use rust_i18n::t;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+/// Timing summary written by `--dump-timings`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timings {
+    /// Version of agnix that produced the timings.
+    pub version: String,
+    /// Wall-clock duration of the validation pass in milliseconds.
+    pub duration_ms: u64,
+    /// Total number of recognized files validated.
+    pub files_checked: usize,
+    /// Validation throughput (files checked per second).
+    pub files_per_second: f64,
+    /// Time each validator spent across all files, keyed by validator name.
+    pub validator_timings: BTreeMap<&'static str, ValidatorTimingEntry>,
+}
+
+/// One validator's entry in [`Timings::validator_timings`].
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidatorTimingEntry {
+    /// Total time in milliseconds, summed over threads.
+    pub duration_ms: f64,
+    /// Number of files the validator ran on.
+    pub files: usize,
+}
+
+impl Timings {
+    pub fn new(duration: Duration, files_checked: usize, validators: &[ValidatorTiming]) -> Self {
+        let secs = duration.as_secs_f64();
+        let files_per_second = if secs > 0.0 {
+            files_checked as f64 / secs
+        } else {
+            0.0
+        };
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            duration_ms: duration.as_millis() as u64,
+            files_checked,
+            files_per_second,
+            validator_timings: validators
+                .iter()
+                .map(|v| {
+                    (
+                        v.validator,
+                        ValidatorTimingEntry {
+                            duration_ms: v.duration.as_secs_f64() * 1000.0,
+                            files: v.files,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Write timings as pretty-printed JSON to `path`.
+pub fn write_timings(path: &Path, timings: &Timings) -> anyhow::Result<()> {
+    let json = serde_json::to_string_pretty(timings)?;
+    std::fs::write(path, json + "\n")?;
+    Ok(())
+}
+
+fn format_ms(duration: Duration) -> String {
+    format!("{:>9.2} ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// The `limit` slowest files and validators as a plain-text table. File paths
+/// are shown relative to `base_path`.
+pub fn render_slowest(
+    files: &[FileTiming],
+    validators: &[ValidatorTiming],
+    limit: usize,
+    base_path: &Path,
+) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "{}", t!("cli.timings_slowest_files", count = limit));
+    for file in files.iter().take(limit) {
+        let path = file.path.strip_prefix(base_path).unwrap_or(&file.path);
+        let _ = writeln!(
+            out,
+            "{}  {} ({:?})",
+            format_ms(file.duration),
+            path.display(),
+            file.file_type
+        );
+    }
+
+    let _ = writeln!(
+        out,
+        "{}",
+        t!("cli.timings_slowest_validators", count = limit)
+    );
+    for validator in validators.iter().take(limit) {
+        let _ = writeln!(
+            out,
+            "{}  {} ({})",
+            format_ms(validator.duration),
+            validator.validator,
+            t!("cli.timings_files", count = validator.files)
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use agnix_core::FileType;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_timings_keys_are_camel_case() {
+        let validators = vec![ValidatorTiming {
+            validator: "SkillValidator",
+            files: 4,
+            duration: Duration::from_micros(2500),
+        }];
+        let timings = Timings::new(Duration::from_millis(500), 10, &validators);
+        let value = serde_json::to_value(&timings).unwrap();
+        assert_eq!(value["durationMs"], 500);
+        assert_eq!(value["filesChecked"], 10);
+        assert_eq!(value["filesPerSecond"], 20.0);
+        assert!(value["version"].is_string());
+        assert_eq!(
+            value["validatorTimings"]["SkillValidator"]["durationMs"],
+            2.5
+        );
+        assert_eq!(value["validatorTimings"]["SkillValidator"]["files"], 4);
+    }
+
+    #[test]
+    fn test_timings_zero_duration() {
+        let timings = Timings::new(Duration::ZERO, 3, &[]);
+        assert_eq!(timings.files_per_second, 0.0);
+    }
+
+    #[test]
+    fn test_render_slowest_limits_rows_and_strips_base_path() {
+        let files: Vec<FileTiming> = (1..=3)
+            .map(|i| FileTiming {
+                path: PathBuf::from(format!("/project/skills/s{i}/SKILL.md")),
+                file_type: FileType::Skill,
+                duration: Duration::from_millis(10 - i),
+            })
+            .collect();
+        let validators = vec![ValidatorTiming {
+            validator: "SkillValidator",
+            files: 3,
+            duration: Duration::from_micros(1500),
+        }];
+
+        let out = render_slowest(&files, &validators, 2, Path::new("/project"));
+        assert!(out.contains("9.00 ms  skills/s1/SKILL.md (Skill)"), "{out}");
+        assert!(out.contains("skills/s2/SKILL.md"));
+        assert!(!out.contains("skills/s3/SKILL.md"));
+        assert!(out.contains("1.50 ms  SkillValidator (3 files)"), "{out}");
+    }
+}
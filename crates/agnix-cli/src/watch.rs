@@ -5,11 +5,14 @@ use rust_i18n::t;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::mpsc::channel;
+use std::sync::mpsc::{Receiver, RecvTimeoutError, channel};
 use std::time::Duration;
 
 /// Run validation in watch mode, re-running on file changes
-pub fn watch_and_validate<F>(path: &Path, mut validate_fn: F) -> anyhow::Result<()>
+///
+/// When `initial_scan` is false the startup validation is skipped and the
+/// first run happens on the first relevant file change.
+pub fn watch_and_validate<F>(path: &Path, initial_scan: bool, validate_fn: F) -> anyhow::Result<()>
 where
     F: FnMut() -> anyhow::Result<bool>,
 {
@@ -20,16 +23,35 @@ where
         r.store(false, Ordering::SeqCst);
     })?;
 
-    // Initial validation
-    println!("{}\n", t!("cli.watch_starting"));
-    let _ = validate_fn();
-
     // Set up file watcher
     let (tx, rx) = channel::<DebounceEventResult>();
 
     let mut debouncer = new_debouncer(Duration::from_millis(500), tx)?;
     debouncer.watcher().watch(path, RecursiveMode::Recursive)?;
 
+    run_watch_loop(&rx, &running, initial_scan, validate_fn);
+
+    println!("\n{}", t!("cli.watch_stopped"));
+    Ok(())
+}
+
+/// Drive validation from debounced watcher events until stopped or the
+/// event channel disconnects.
+fn run_watch_loop<F>(
+    rx: &Receiver<DebounceEventResult>,
+    running: &AtomicBool,
+    initial_scan: bool,
+    mut validate_fn: F,
+) where
+    F: FnMut() -> anyhow::Result<bool>,
+{
+    if initial_scan {
+        println!("{}\n", t!("cli.watch_starting"));
+        let _ = validate_fn();
+    } else {
+        println!("{}\n", t!("cli.watch_starting_no_scan"));
+    }
+
     // Watch loop
     while running.load(Ordering::SeqCst) {
         match rx.recv_timeout(Duration::from_millis(100)) {
@@ -45,17 +67,14 @@ where
             Ok(Err(e)) => {
                 eprintln!("{}", t!("cli.watch_error", error = format!("{:?}", e)));
             }
-            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {
+            Err(RecvTimeoutError::Timeout) => {
                 // Continue watching
             }
-            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => {
+            Err(RecvTimeoutError::Disconnected) => {
                 break;
             }
         }
     }
-
-    println!("\n{}", t!("cli.watch_stopped"));
-    Ok(())
 }
 
 fn is_relevant_file(path: &Path) -> bool {
@@ -81,6 +100,9 @@ fn is_relevant_file(path: &Path) -> bool {
         || filename.ends_with(".mcp.json")
         || filename.ends_with(".mdc")
         || filename.ends_with(".instructions.md")
+        // Split hook files (.claude/hooks/*.json)
+        || (extension == "json"
+            && path.parent().is_some_and(|p| p.ends_with(".claude/hooks")))
         // Also watch for agent files
         || (extension == "md"
             && path
@@ -94,3 +116,53 @@ fn clear_screen() {
     // ANSI escape code to clear screen and move cursor to top
     print!("\x1B[2J\x1B[1;1H");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use notify_debouncer_mini::{DebouncedEvent, DebouncedEventKind};
+    use std::path::PathBuf;
+
+    fn change_event(path: &str) -> DebounceEventResult {
+        Ok(vec![DebouncedEvent {
+            path: PathBuf::from(path),
+            kind: DebouncedEventKind::Any,
+        }])
+    }
+
+    fn count_runs(initial_scan: bool, events: Vec<DebounceEventResult>) -> usize {
+        let (tx, rx) = channel::<DebounceEventResult>();
+        for event in events {
+            tx.send(event).unwrap();
+        }
+        // Dropping the sender disconnects the channel once events are drained
+        drop(tx);
+
+        let running = AtomicBool::new(true);
+        let mut runs = 0;
+        run_watch_loop(&rx, &running, initial_scan, || {
+            runs += 1;
+            Ok(false)
+        });
+        runs
+    }
+
+    #[test]
+    fn test_initial_scan_validates_on_startup() {
+        assert_eq!(count_runs(true, Vec::new()), 1);
+    }
+
+    #[test]
+    fn test_no_initial_scan_waits_for_change_event() {
+        assert_eq!(count_runs(false, Vec::new()), 0);
+        assert_eq!(
+            count_runs(false, vec![change_event("/project/CLAUDE.md")]),
+            1
+        );
+    }
+
+    #[test]
+    fn test_irrelevant_change_does_not_validate() {
+        assert_eq!(count_runs(false, vec![change_event("/project/main.rs")]), 0);
+    }
+}
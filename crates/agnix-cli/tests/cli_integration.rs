@@ -15,10 +15,10 @@ fn workspace_root() -> &'static std::path::Path {
         let manifest_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         for ancestor in manifest_dir.ancestors() {
             let cargo_toml = ancestor.join("Cargo.toml");
-            if let Ok(content) = std::fs::read_to_string(&cargo_toml) {
-                if content.contains("[workspace]") || content.contains("[workspace.") {
-                    return ancestor.to_path_buf();
-                }
+            if let Ok(content) = std::fs::read_to_string(&cargo_toml)
+                && (content.contains("[workspace]") || content.contains("[workspace."))
+            {
+                return ancestor.to_path_buf();
             }
         }
         panic!(
@@ -141,11 +141,15 @@ fn test_format_sarif_produces_valid_json() {
 
 #[test]
 fn test_fix_flags_rejected_for_json_and_sarif() {
-    let formats = ["json", "sarif"];
-    let flags = ["--fix", "--dry-run", "--fix-safe"];
+    let formats = ["json", "jsonl", "sarif", "github"];
+    let flags = ["--fix", "--dry-run", "--fix-safe", "--interactive"];
 
     for format in formats {
         for flag in flags {
+            // --dry-run --format json emits a fix plan instead
+            if format == "json" && flag == "--dry-run" {
+                continue;
+            }
             assert_fix_flags_rejected(format, flag);
         }
     }
@@ -194,8 +198,8 @@ fn test_format_sarif_has_all_rules() {
         rules.len()
     );
     assert!(
-        rules.len() <= 150,
-        "Expected at most 150 validation rules, found {} (unexpected rule explosion)",
+        rules.len() <= 250,
+        "Expected at most 250 validation rules, found {} (unexpected rule explosion)",
         rules.len()
     );
 
@@ -226,6 +230,101 @@ fn test_format_sarif_exit_code_on_success() {
         .success();
 }
 
+#[test]
+fn test_format_github_exit_code_on_success() {
+    let mut cmd = agnix();
+    cmd.arg("tests/fixtures/valid")
+        .arg("--format")
+        .arg("github")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Validating").not());
+}
+
+#[test]
+fn test_format_github_emits_workflow_commands() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("bad-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+    fs::write(
+        skills_dir.join("SKILL.md"),
+        "---\nname: Bad-Skill\ndescription: test\n---\nContent\n",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--format")
+        .arg("github")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.lines().all(|line| line.starts_with("::error ")
+            || line.starts_with("::warning ")
+            || line.starts_with("::notice ")),
+        "Every line should be a workflow command, got: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("::error file=") && stdout.contains("SKILL.md,line="),
+        "Expected an error annotation for SKILL.md, got: {}",
+        stdout
+    );
+    assert_eq!(output.status.code(), Some(1));
+}
+
+#[test]
+fn test_format_jsonl_one_object_per_line() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("bad-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+    fs::write(
+        skills_dir.join("SKILL.md"),
+        "---\nname: Bad-Skill\ndescription: test\n---\nContent\n",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--format")
+        .arg("jsonl")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<serde_json::Value> = stdout
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line should be JSON"))
+        .collect();
+    assert!(
+        lines.len() >= 2,
+        "Expected diagnostics and a summary, got: {}",
+        stdout
+    );
+
+    let (summary, diagnostics) = lines.split_last().unwrap();
+    assert_eq!(summary["type"], "summary");
+    assert_eq!(
+        summary["summary"]["errors"].as_u64().unwrap()
+            + summary["summary"]["warnings"].as_u64().unwrap()
+            + summary["summary"]["info"].as_u64().unwrap(),
+        diagnostics.len() as u64
+    );
+    for diag in diagnostics {
+        assert!(diag["rule"].is_string() && diag["file"].is_string());
+        assert!(diag.get("type").is_none());
+    }
+    assert_eq!(output.status.code(), Some(1));
+}
+
 #[test]
 fn test_format_text_is_default() {
     let mut cmd = agnix();
@@ -900,837 +999,841 @@ fn test_format_text_verbose_shows_rule() {
 }
 
 #[test]
-fn test_format_text_verbose_shows_suggestion() {
+fn test_format_text_hyperlinks_falls_back_to_plain_rule_code() {
+    // assert_cmd captures stdout through a pipe, so no OSC 8 sequences may be emitted
     let mut cmd = agnix();
     let output = cmd
         .arg("tests/fixtures/invalid/skills/unknown-tool")
-        .arg("--verbose")
+        .arg("--hyperlinks")
         .output()
         .unwrap();
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    // Verbose mode should show additional help/suggestion info
     assert!(
-        stdout.contains("help") || stdout.contains("suggestion") || stdout.contains("-->"),
-        "Verbose output should contain help or suggestion info, got: {}",
+        regex::Regex::new(r"error\[(AS|CC)-[\w-]+\]: ")
+            .unwrap()
+            .is_match(&stdout),
+        "--hyperlinks should show the rule code inline, got: {}",
         stdout
     );
+    assert!(
+        !stdout.contains("\x1b]8;;"),
+        "OSC 8 sequences must not be written to a non-terminal stdout"
+    );
 }
 
-// ============================================================================
-// Fix and Dry-Run Tests
-// ============================================================================
-
 #[test]
-fn test_dry_run_no_file_modification() {
-    use std::fs;
-    use std::io::Write;
-
-    let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("bad-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
-
-    let skill_path = skills_dir.join("SKILL.md");
-    let original_content = "---\nname: Bad-Skill\ndescription: test\n---\nContent";
-    {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        write!(file, "{}", original_content).unwrap();
-    }
-
+fn test_format_text_pretty_errors_renders_source_excerpt() {
     let mut cmd = agnix();
     let output = cmd
-        .arg(temp_dir.path().to_str().unwrap())
-        .arg("--dry-run")
+        .arg("tests/fixtures/skills/deep-reference")
+        .arg("--pretty-errors")
         .output()
         .unwrap();
 
-    // Verify file was not modified
-    let content_after = fs::read_to_string(&skill_path).unwrap();
-    assert_eq!(
-        content_after, original_content,
-        "File should not be modified with --dry-run"
-    );
-
-    // Verify the flag was recognized (not just silently ignored)
-    // CLI should still produce diagnostic output
     let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
-        !stdout.is_empty() || !output.status.success(),
-        "--dry-run flag should be recognized and produce output or error"
+        stdout.contains("error[AS-013]: "),
+        "--pretty-errors should show a rustc-style header, got: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("6 | See references/deep/nested/guide.md for details."),
+        "--pretty-errors should show the source line, got: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("  |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ AS-013"),
+        "--pretty-errors should underline the reference, got: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("  = help: "),
+        "--pretty-errors should show the suggestion as help, got: {}",
+        stdout
     );
 }
 
 #[test]
-fn test_fix_exit_code_on_remaining_errors() {
-    let mut cmd = agnix();
-    // Invalid fixtures have errors that cannot be auto-fixed
-    let output = cmd
-        .arg("tests/fixtures/invalid/skills/unknown-tool")
-        .arg("--fix")
-        .output()
-        .unwrap();
+fn test_format_text_emoji_only_when_enabled() {
+    let fixture = "tests/fixtures/skills/deep-reference";
 
-    // Should still exit with error since errors remain
+    let output = agnix().arg(fixture).arg("--emoji").output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
-        !output.status.success(),
-        "Should exit with error code when non-fixable errors remain"
+        stdout.contains("❌ error"),
+        "--emoji should prefix errors with an emoji, got: {}",
+        stdout
     );
 
-    // Verify the flag was recognized (produces diagnostic output, not clap error)
+    let output = agnix().arg(fixture).output().unwrap();
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        !stdout.is_empty() || stderr.is_empty(),
-        "--fix flag should be recognized and run fix mode, got stderr: {}",
-        stderr
+        !stdout.contains('❌'),
+        "emoji should be off by default, got: {}",
+        stdout
     );
-}
-
-#[test]
-fn test_fix_exit_code_when_all_issues_are_fixed() {
-    use std::fs;
-    use std::io::Write;
-
-    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir for test");
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).expect("Failed to create skill dir for test");
-
-    let skill_path = skills_dir.join("SKILL.md");
-    {
-        let mut file = fs::File::create(&skill_path).expect("Failed to create skill file for test");
-        // Only AS-004 should fail here, and it is auto-fixable.
-        write!(
-            file,
-            "---\nname: Test_Skill_Name\ndescription: Use when testing\n---\nContent"
-        )
-        .expect("Failed to write to skill file for test");
-    }
 
-    let mut cmd = agnix();
-    let output = cmd
-        .arg(
-            temp_dir
-                .path()
-                .to_str()
-                .expect("Temp path is not valid UTF-8"),
-        )
-        .arg("--fix")
+    let output = agnix()
+        .arg(fixture)
+        .arg("--emoji")
+        .arg("--no-emoji")
         .output()
-        .expect("Failed to execute agnix command");
-
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
-        output.status.success(),
-        "Expected --fix to exit 0 when all issues are fixed, stdout: {}, stderr: {}",
-        String::from_utf8_lossy(&output.stdout),
-        String::from_utf8_lossy(&output.stderr)
+        !stdout.contains('❌'),
+        "--no-emoji should override --emoji, got: {}",
+        stdout
     );
 
-    let fixed_content = fs::read_to_string(&skill_path).expect("Failed to read fixed skill file");
+    let output = agnix()
+        .arg(fixture)
+        .arg("--emoji")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
     assert!(
-        fixed_content.contains("name: test-skill-name"),
-        "Expected AS-004 fix to be applied, got: {}",
-        fixed_content
+        !stdout.contains('❌') && !stdout.contains("⚠️"),
+        "JSON output should never contain emoji, got: {}",
+        stdout
     );
 }
 
 #[test]
-fn test_fix_safe_exit_code() {
-    let mut cmd = agnix();
-    let output = cmd
-        .arg("tests/fixtures/invalid/skills/unknown-tool")
-        .arg("--fix-safe")
-        .output()
-        .unwrap();
+fn test_format_text_legend_lists_present_families() {
+    let fixture = "tests/fixtures/skills/deep-reference";
 
-    // Should still exit with error since errors remain
+    let output = agnix().arg(fixture).arg("--legend").output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let legend: Vec<&str> = stdout
+        .lines()
+        .skip_while(|line| !line.contains("Rule families:"))
+        .skip(1)
+        .take_while(|line| !line.trim().is_empty())
+        .collect();
     assert!(
-        !output.status.success(),
-        "Should exit with error code when errors remain after --fix-safe"
+        !legend.is_empty(),
+        "--legend should print a rule-family legend, got: {}",
+        stdout
+    );
+    for line in &legend {
+        let prefix = line.split_whitespace().next().unwrap();
+        assert!(
+            stdout.contains(&format!("[{}", prefix)),
+            "legend lists {} but no diagnostic uses it, got: {}",
+            prefix,
+            stdout
+        );
+    }
+    assert!(
+        !legend
+            .iter()
+            .any(|line| line.trim_start().starts_with("WS-")),
+        "legend should not list families absent from the results, got: {}",
+        stdout
     );
 
-    // Verify the flag was recognized (produces diagnostic output, not clap error)
+    let output = agnix().arg(fixture).output().unwrap();
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        !stdout.is_empty() || stderr.is_empty(),
-        "--fix-safe flag should be recognized and run fix mode, got stderr: {}",
-        stderr
+        !stdout.contains("Rule families:"),
+        "legend should be off by default, got: {}",
+        stdout
     );
 }
 
-// ============================================================================
-// Flag Combination Tests
-// ============================================================================
-
 #[test]
-fn test_strict_with_sarif_format() {
-    use std::fs;
-    use std::io::Write;
-
-    let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
-
-    let skill_path = skills_dir.join("SKILL.md");
-    let mut file = fs::File::create(&skill_path).unwrap();
-    // Valid skill name but missing trigger phrase (AS-010 warning)
-    writeln!(
-        file,
-        "---\nname: test-skill\ndescription: A test skill\n---\nContent"
+fn test_cache_dir_writes_project_analysis() {
+    let project = tempfile::TempDir::new().unwrap();
+    let cache = tempfile::TempDir::new().unwrap();
+    std::fs::write(
+        project.path().join("CLAUDE.md"),
+        "# Project\n\nRun `npm test`.\n",
+    )
+    .unwrap();
+    std::fs::write(
+        project.path().join("AGENTS.md"),
+        "# Project\n\nRun `npm test`.\n",
     )
     .unwrap();
 
-    // With --strict, warnings should cause exit code 1
-    let mut cmd = agnix();
-    let output = cmd
-        .arg(temp_dir.path().to_str().unwrap())
-        .arg("--format")
-        .arg("sarif")
-        .arg("--strict")
+    agnix()
+        .arg(project.path().to_str().unwrap())
+        .arg("--cache-dir")
+        .arg(cache.path().join("agnix"))
         .output()
         .unwrap();
 
-    // Verify it's valid SARIF
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
-    assert!(json["runs"].is_array(), "Should produce valid SARIF");
-
-    // Should fail due to warnings in strict mode
     assert!(
-        !output.status.success(),
-        "With --strict and warnings, should exit with error code"
+        cache
+            .path()
+            .join("agnix")
+            .join("project-analysis.json")
+            .exists(),
+        "--cache-dir should persist project analysis"
     );
 }
 
 #[test]
-fn test_verbose_with_json_ignored() {
-    let mut cmd = agnix();
-    let output = cmd
-        .arg("tests/fixtures/valid")
-        .arg("--verbose")
-        .arg("--format")
-        .arg("json")
-        .output()
-        .unwrap();
+fn test_cache_flag_reuses_diagnostics() {
+    let project = tempfile::TempDir::new().unwrap();
+    let skill_dir = project.path().join("skills").join("bad-skill");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: Bad-Skill\ndescription: test\n---\nContent\n",
+    )
+    .unwrap();
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    // Should still be valid JSON (verbose doesn't corrupt JSON output)
-    let json: Result<serde_json::Value, _> = serde_json::from_str(&stdout);
-    assert!(
-        json.is_ok(),
-        "--verbose should not corrupt JSON output, got: {}",
-        stdout
+    let run = || {
+        let output = agnix()
+            .arg(project.path().to_str().unwrap())
+            .args(["--cache", "--format", "json"])
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        let mut rules: Vec<String> = json["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["rule"].as_str().unwrap().to_string())
+            .collect();
+        rules.sort();
+        rules
+    };
+
+    let first = run();
+    assert!(first.iter().any(|rule| rule == "AS-004"));
+    let cache_file = project.path().join(".agnix/cache/diagnostics.json");
+    assert!(cache_file.exists(), "--cache should write .agnix/cache");
+    assert_eq!(
+        run(),
+        first,
+        "cached run should report the same diagnostics"
     );
 }
 
 #[test]
-fn test_target_cursor_disables_cc_rules() {
-    use std::fs;
-    use std::io::Write;
-
-    let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("deploy-prod");
-    fs::create_dir_all(&skills_dir).unwrap();
-
-    let skill_path = skills_dir.join("SKILL.md");
-    let mut file = fs::File::create(&skill_path).unwrap();
-    // This would normally trigger CC-SK-006 (Claude-specific rule)
-    writeln!(
-        file,
-        "---\nname: deploy-prod\ndescription: Deploy to production\n---\nDeploy the application"
-    )
-    .unwrap();
+fn test_dump_timings_writes_json() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let timings_path = temp.path().join("timings.json");
 
-    // With --target cursor, CC-* rules should be disabled
     let mut cmd = agnix();
-    let output = cmd
-        .arg(temp_dir.path().to_str().unwrap())
+    cmd.arg("tests/fixtures/valid")
         .arg("--format")
         .arg("json")
-        .arg("--target")
-        .arg("cursor")
+        .arg("--dump-timings")
+        .arg(&timings_path)
         .output()
         .unwrap();
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
-    let diagnostics = json["diagnostics"].as_array().unwrap();
+    let content = std::fs::read_to_string(&timings_path).expect("timings file should exist");
+    let timings: serde_json::Value = serde_json::from_str(&content).unwrap();
+    assert!(
+        timings["filesChecked"].as_u64().unwrap() > 0,
+        "filesChecked should count validated files: {}",
+        content
+    );
+    assert!(
+        timings["durationMs"].is_u64(),
+        "durationMs missing: {}",
+        content
+    );
+    assert!(
+        timings["filesPerSecond"].is_number(),
+        "filesPerSecond missing: {}",
+        content
+    );
+    let validators = timings["validatorTimings"]
+        .as_object()
+        .expect("validatorTimings missing");
+    let skill = &validators["SkillValidator"];
+    assert!(skill["durationMs"].is_number(), "{}", content);
+    assert!(skill["files"].as_u64().unwrap() > 0, "{}", content);
+}
 
-    // Should not have any CC-* rules for cursor target
-    let has_cc_rule = diagnostics
-        .iter()
-        .any(|d| d["rule"].as_str().unwrap_or("").starts_with("CC-"));
+#[test]
+fn test_no_cross_file_skips_project_level_rules() {
+    let run = |extra: &[&str]| -> Vec<String> {
+        let output = agnix()
+            .args(["tests/fixtures/invalid/agents", "--format", "json"])
+            .args(extra)
+            .output()
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+        json["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|d| d["rule"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    let with_cross_file = run(&[]);
+    assert!(with_cross_file.iter().any(|rule| rule == "CC-AG-014"));
 
+    let without = run(&["--no-cross-file"]);
     assert!(
-        !has_cc_rule,
-        "With --target cursor, CC-* rules should be disabled"
+        !without
+            .iter()
+            .any(|rule| rule == "CC-AG-014" || rule == "CC-AG-015")
     );
+    assert!(without.iter().any(|rule| rule == "CC-AG-009"));
 }
 
 #[test]
-fn test_validate_subcommand() {
+fn test_timings_prints_slowest_to_stderr() {
     let mut cmd = agnix();
-    cmd.arg("validate")
-        .arg("tests/fixtures/valid")
-        .assert()
-        .success();
-}
+    let output = cmd
+        .args(["tests/fixtures/valid", "--format", "json", "--timings", "3"])
+        .output()
+        .unwrap();
 
-#[test]
-fn test_dry_run_with_format_json_rejected() {
-    assert_fix_flags_rejected("json", "--dry-run");
+    // stdout stays valid JSON
+    serde_json::from_slice::<serde_json::Value>(&output.stdout).unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Slowest files (top 3):"), "{}", stderr);
+    assert!(stderr.contains("Slowest validators"), "{}", stderr);
+    let file_rows = stderr
+        .lines()
+        .skip(1)
+        .take_while(|line| !line.starts_with("Slowest"))
+        .count();
+    assert_eq!(file_rows, 3, "{}", stderr);
 }
 
 #[test]
-fn test_fixtures_have_no_empty_placeholder_dirs() {
-    use std::fs;
-    use std::path::{Path, PathBuf};
-
-    fn check_dir(dir: &Path, empty_dirs: &mut Vec<PathBuf>) -> bool {
-        let mut has_file = false;
-        let entries = fs::read_dir(dir).unwrap_or_else(|e| {
-            panic!("Failed to read fixture directory {}: {}", dir.display(), e)
-        });
-
-        for entry in entries {
-            let entry = entry
-                .unwrap_or_else(|e| panic!("Failed to read entry under {}: {}", dir.display(), e));
-            let path = entry.path();
-            if path.is_file() {
-                has_file = true;
-                continue;
-            }
-            if path.is_dir() && check_dir(&path, empty_dirs) {
-                has_file = true;
-            }
-        }
-
-        if !has_file {
-            empty_dirs.push(dir.to_path_buf());
-        }
-
-        has_file
-    }
+fn test_report_writes_html_alongside_output() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let report_path = temp.path().join("report.html");
 
-    let root = workspace_path("tests/fixtures");
-    assert!(
-        root.is_dir(),
-        "Expected fixtures directory at {}",
-        root.display()
-    );
+    let mut cmd = agnix();
+    let output = cmd
+        .arg("tests/fixtures/invalid/skills")
+        .arg("--format")
+        .arg("json")
+        .arg("--report")
+        .arg(&report_path)
+        .output()
+        .unwrap();
 
-    let mut empty_dirs = Vec::new();
-    check_dir(&root, &mut empty_dirs);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let diagnostics = json["diagnostics"].as_array().unwrap();
+    assert!(!diagnostics.is_empty());
 
+    let html = std::fs::read_to_string(&report_path).expect("report file should exist");
     assert!(
-        empty_dirs.is_empty(),
-        "Empty fixture directories found:\n{}",
-        empty_dirs
-            .iter()
-            .map(|p| p.display().to_string())
-            .collect::<Vec<_>>()
-            .join("\n")
+        html.contains(&format!("{} diagnostics", diagnostics.len())),
+        "report should include the diagnostic count"
     );
+    for diag in diagnostics {
+        let rule = diag["rule"].as_str().unwrap();
+        assert!(html.contains(rule), "report missing rule {}", rule);
+    }
 }
 
-// ===== Config Parse Warning Tests =====
-
 #[test]
-fn test_invalid_config_displays_warning_to_stderr() {
-    let temp_dir = tempfile::tempdir().unwrap();
-    let config_path = temp_dir.path().join(".agnix.toml");
+fn test_output_writes_sarif_to_file_and_summary_to_stdout() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let output_path = temp.path().join("results.sarif");
 
-    // Create invalid TOML
-    std::fs::write(&config_path, "this is [ invalid toml").unwrap();
+    let output = agnix()
+        .arg("tests/fixtures/invalid/skills")
+        .arg("--format")
+        .arg("sarif")
+        .arg("--output")
+        .arg(&output_path)
+        .output()
+        .unwrap();
 
-    // Create a minimal valid directory to scan
-    let skill_dir = temp_dir.path().join(".claude").join("skills");
-    std::fs::create_dir_all(&skill_dir).unwrap();
-    let skill_file = skill_dir.join("test.md");
-    std::fs::write(&skill_file, "# Test\nSimple content").unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let sarif: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&output_path).unwrap()).unwrap();
+    assert!(!sarif["runs"][0]["results"].as_array().unwrap().is_empty());
 
-    let mut cmd = agnix();
-    let output = cmd
-        .arg(temp_dir.path())
-        .arg("--config")
-        .arg(&config_path)
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Results written to:"), "{}", stdout);
+    assert!(stdout.contains("results.sarif"), "{}", stdout);
+    assert!(stdout.contains("Found "), "{}", stdout);
+    assert!(!stdout.contains("\"runs\""), "{}", stdout);
+}
+
+#[test]
+fn test_output_writes_plain_text_report() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let output_path = temp.path().join("results.txt");
+
+    let output = agnix()
+        .arg("tests/fixtures/invalid/skills")
+        .arg("--output")
+        .arg(&output_path)
+        .env("CLICOLOR_FORCE", "1")
         .output()
         .unwrap();
 
-    let stderr = String::from_utf8_lossy(&output.stderr);
-
-    // Should contain warning message
+    assert_eq!(output.status.code(), Some(1));
+    let report = std::fs::read_to_string(&output_path).unwrap();
+    assert!(report.contains("Validating:"), "{}", report);
+    assert!(report.contains("error: "), "{}", report);
     assert!(
-        stderr.contains("Warning:") && stderr.contains("Failed to parse config"),
-        "Expected config warning in stderr, got: {}",
-        stderr
+        !report.contains('\x1b'),
+        "report should not contain ANSI codes"
     );
 
-    // Should still produce validation output (continues with defaults)
     let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(
-        stdout.contains("Validating:"),
-        "Should still run validation with default config"
-    );
+    assert!(!stdout.contains("Validating:"), "{}", stdout);
+    assert!(stdout.contains("Found "), "{}", stdout);
 }
 
 #[test]
-fn test_valid_config_no_warning_in_stderr() {
-    let temp_dir = tempfile::tempdir().unwrap();
-    let config_path = temp_dir.path().join(".agnix.toml");
-
-    // Create valid config
-    std::fs::write(
-        &config_path,
-        r#"
-severity = "Warning"
-target = "Generic"
-exclude = []
+fn test_baseline_suppresses_existing_diagnostics_only() {
+    use std::fs;
 
-[rules]
-"#,
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    let bad_skill = project.join("skills").join("bad-skill");
+    fs::create_dir_all(&bad_skill).unwrap();
+    fs::write(
+        bad_skill.join("SKILL.md"),
+        "---\nname: Bad-Skill\ndescription: test\n---\nContent\n",
     )
     .unwrap();
+    let baseline_path = temp.path().join(".agnix-baseline.json");
 
-    // Create a minimal valid directory to scan
-    let skill_dir = temp_dir.path().join(".claude").join("skills");
-    std::fs::create_dir_all(&skill_dir).unwrap();
-    let skill_file = skill_dir.join("test.md");
-    std::fs::write(&skill_file, "# Test\nSimple content").unwrap();
+    let mut cmd = agnix();
+    cmd.arg("baseline")
+        .arg(&project)
+        .arg("--output")
+        .arg(&baseline_path)
+        .assert()
+        .success();
+    let baseline: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&baseline_path).unwrap()).unwrap();
+    assert!(!baseline["entries"].as_array().unwrap().is_empty());
 
+    // Shifting the violation down a few lines must not resurface it
+    fs::write(
+        bad_skill.join("SKILL.md"),
+        "---\n# comment\n\nname: Bad-Skill\ndescription: test\n---\nContent\n",
+    )
+    .unwrap();
     let mut cmd = agnix();
     let output = cmd
-        .arg(temp_dir.path())
-        .arg("--config")
-        .arg(&config_path)
+        .arg(&project)
+        .arg("--format")
+        .arg("json")
+        .arg("--baseline")
+        .arg(&baseline_path)
         .output()
         .unwrap();
-
-    let stderr = String::from_utf8_lossy(&output.stderr);
-
-    // Should NOT contain any config warning
+    let json: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    assert_eq!(json["summary"]["errors"], 0, "{}", json);
+    assert!(output.status.success());
+
+    // A new violation in another file is still reported
+    let new_skill = project.join("skills").join("new-skill");
+    fs::create_dir_all(&new_skill).unwrap();
+    fs::write(
+        new_skill.join("SKILL.md"),
+        "---\nname: New-Skill\ndescription: test\n---\nContent\n",
+    )
+    .unwrap();
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(&project)
+        .arg("--format")
+        .arg("json")
+        .arg("--baseline")
+        .arg(&baseline_path)
+        .output()
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    let diagnostics = json["diagnostics"].as_array().unwrap();
+    assert!(!diagnostics.is_empty());
     assert!(
-        !stderr.contains("Failed to parse config"),
-        "Valid config should not produce warning, stderr: {}",
-        stderr
+        diagnostics
+            .iter()
+            .all(|d| d["file"].as_str().unwrap().contains("new-skill")),
+        "{}",
+        json
     );
+    assert_eq!(output.status.code(), Some(1));
 }
 
 #[test]
-fn test_config_warning_with_json_output() {
-    let temp_dir = tempfile::tempdir().unwrap();
-    let config_path = temp_dir.path().join(".agnix.toml");
+fn test_fix_exit_code_respects_baseline() {
+    use std::fs;
 
-    // Create invalid TOML
-    std::fs::write(&config_path, "invalid [[ toml").unwrap();
+    let temp = tempfile::TempDir::new().unwrap();
+    let project = temp.path().join("project");
+    // Missing description is an error --fix cannot repair
+    let old_skill = project.join("skills").join("old-skill");
+    fs::create_dir_all(&old_skill).unwrap();
+    fs::write(
+        old_skill.join("SKILL.md"),
+        "---\nname: old-skill\n---\nContent\n",
+    )
+    .unwrap();
+    let baseline_path = temp.path().join(".agnix-baseline.json");
 
-    // Create a minimal valid directory to scan
-    let skill_dir = temp_dir.path().join(".claude").join("skills");
-    std::fs::create_dir_all(&skill_dir).unwrap();
-    let skill_file = skill_dir.join("test.md");
-    std::fs::write(&skill_file, "# Test\nSimple content").unwrap();
+    let mut cmd = agnix();
+    cmd.arg("baseline")
+        .arg(&project)
+        .arg("--output")
+        .arg(&baseline_path)
+        .assert()
+        .success();
+
+    let new_skill = project.join("skills").join("new-skill");
+    fs::create_dir_all(&new_skill).unwrap();
+    fs::write(
+        new_skill.join("SKILL.md"),
+        "---\nname: New_Skill\ndescription: Use when testing\n---\nContent",
+    )
+    .unwrap();
 
+    // Only the baselined error is left after fixing, so the run passes
     let mut cmd = agnix();
     let output = cmd
-        .arg(temp_dir.path())
-        .arg("--config")
-        .arg(&config_path)
-        .arg("--format")
-        .arg("json")
+        .arg(&project)
+        .arg("--fix")
+        .arg("--baseline")
+        .arg(&baseline_path)
         .output()
         .unwrap();
-
-    let stderr = String::from_utf8_lossy(&output.stderr);
-
-    // Warning should go to stderr, not corrupt JSON output
     assert!(
-        stderr.contains("Warning:"),
-        "Warning should be in stderr for JSON output"
+        output.status.success(),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
     );
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Result<serde_json::Value, _> = serde_json::from_str(&stdout);
     assert!(
-        json.is_ok(),
-        "JSON output should be valid despite config warning, got: {}",
-        stdout
+        fs::read_to_string(new_skill.join("SKILL.md"))
+            .unwrap()
+            .contains("name: new-skill")
     );
 }
 
-// ============================================================================
-// Target Argument Validation Tests (Issue #129)
-// ============================================================================
-
-#[test]
-fn test_invalid_target_rejected() {
-    let mut cmd = agnix();
-    cmd.arg("tests/fixtures/valid")
-        .arg("--target")
-        .arg("invalid-target")
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("invalid value"));
-}
-
 #[test]
-fn test_typo_target_rejected() {
-    let mut cmd = agnix();
-    // Underscore instead of hyphen should be rejected
-    cmd.arg("tests/fixtures/valid")
-        .arg("--target")
-        .arg("claude_code")
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("invalid value"));
-}
+fn test_min_confidence_high_filters_low_confidence_heuristics() {
+    let fixture = "tests/fixtures/cross_platform/conflicting-commands";
 
-#[test]
-fn test_case_sensitive_target_rejected() {
-    let mut cmd = agnix();
-    // PascalCase should be rejected (CLI uses kebab-case)
-    cmd.arg("tests/fixtures/valid")
-        .arg("--target")
-        .arg("ClaudeCode")
-        .assert()
-        .failure()
-        .stderr(predicate::str::contains("invalid value"));
-}
+    let output = agnix()
+        .arg(fixture)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    let xp_004: Vec<_> = json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["rule"] == "XP-004")
+        .collect();
+    assert!(!xp_004.is_empty(), "fixture should trigger XP-004");
+    assert!(xp_004.iter().all(|d| d["confidence"] == "low"));
+    assert!(xp_004.iter().all(|d| d["category"] == "cross-platform"));
 
-#[test]
-fn test_valid_targets_accepted() {
-    for target in ["generic", "claude-code", "cursor", "codex"] {
-        let mut cmd = agnix();
-        cmd.arg("tests/fixtures/valid")
-            .arg("--target")
-            .arg(target)
-            .assert()
-            .success();
-    }
+    let output = agnix()
+        .arg(fixture)
+        .arg("--format")
+        .arg("json")
+        .arg("--min-confidence")
+        .arg("high")
+        .output()
+        .unwrap();
+    let json: serde_json::Value =
+        serde_json::from_str(&String::from_utf8_lossy(&output.stdout)).unwrap();
+    let diagnostics = json["diagnostics"].as_array().unwrap();
+    assert!(
+        !diagnostics.iter().any(|d| d["rule"] == "XP-004"),
+        "--min-confidence high should drop low-confidence XP-004: {:?}",
+        diagnostics
+    );
+    assert!(diagnostics.iter().all(|d| d["confidence"] == "high"));
 }
 
 #[test]
-fn test_help_shows_target_possible_values() {
+fn test_format_text_verbose_shows_suggestion() {
     let mut cmd = agnix();
-    let output = cmd.arg("--help").output().unwrap();
+    let output = cmd
+        .arg("tests/fixtures/invalid/skills/unknown-tool")
+        .arg("--verbose")
+        .output()
+        .unwrap();
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    // Help should list exact possible values for --target
+    // Verbose mode should show additional help/suggestion info
     assert!(
-        stdout.contains("[possible values: generic, claude-code, cursor, codex]"),
-        "Help should show exact possible target values, got: {}",
+        stdout.contains("help") || stdout.contains("suggestion") || stdout.contains("-->"),
+        "Verbose output should contain help or suggestion info, got: {}",
         stdout
     );
 }
 
 // ============================================================================
-// JSON Output files_checked Accuracy Tests (Issue #127)
+// Fix and Dry-Run Tests
 // ============================================================================
 
 #[test]
-fn test_format_json_files_checked_counts_all_validated_files() {
+fn test_dry_run_no_file_modification() {
     use std::fs;
+    use std::io::Write;
 
-    // Create a directory with valid files that produce no diagnostics
     let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("bad-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
 
-    // Create valid SKILL.md files (no diagnostics expected)
-    let skill_dir1 = temp_dir.path().join("skills").join("code-review");
-    fs::create_dir_all(&skill_dir1).unwrap();
-    fs::write(
-        skill_dir1.join("SKILL.md"),
-        "---\nname: code-review\ndescription: Use when reviewing code\n---\nBody",
-    )
-    .unwrap();
-
-    let skill_dir2 = temp_dir.path().join("skills").join("test-runner");
-    fs::create_dir_all(&skill_dir2).unwrap();
-    fs::write(
-        skill_dir2.join("SKILL.md"),
-        "---\nname: test-runner\ndescription: Use when running tests\n---\nBody",
-    )
-    .unwrap();
+    let skill_path = skills_dir.join("SKILL.md");
+    let original_content = "---\nname: Bad-Skill\ndescription: test\n---\nContent";
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        write!(file, "{}", original_content).unwrap();
+    }
 
     let mut cmd = agnix();
     let output = cmd
         .arg(temp_dir.path().to_str().unwrap())
-        .arg("--format")
-        .arg("json")
+        .arg("--dry-run")
         .output()
         .unwrap();
 
-    assert!(
-        output.status.success(),
-        "agnix exited with non-zero status: {:?}",
-        output.status
+    // Verify file was not modified
+    let content_after = fs::read_to_string(&skill_path).unwrap();
+    assert_eq!(
+        content_after, original_content,
+        "File should not be modified with --dry-run"
     );
 
+    // Verify the flag was recognized (not just silently ignored)
+    // CLI should still produce diagnostic output
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
-
-    let files_checked = json["files_checked"].as_u64().unwrap();
-
-    // files_checked should be exactly 2 (the two SKILL.md files we created)
-    assert_eq!(
-        files_checked, 2,
-        "Expected 2 files checked, found {}",
-        files_checked
+    assert!(
+        !stdout.is_empty() || !output.status.success(),
+        "--dry-run flag should be recognized and produce output or error"
     );
 }
 
 #[test]
-fn test_format_json_files_checked_excludes_unknown_types() {
-    use std::fs;
-
-    // Create a directory with mixed file types
+fn test_dry_run_with_fix_does_not_modify_files() {
     let temp_dir = tempfile::tempdir().unwrap();
+    let skill_dir = temp_dir.path().join("skills").join("bad-skill");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let skill_path = skill_dir.join("SKILL.md");
+    let original = "---\nname: Bad-Skill\ndescription: test\n---\nContent";
+    std::fs::write(&skill_path, original).unwrap();
 
-    // Create files of unknown type (should NOT be counted)
-    fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
-    fs::write(temp_dir.path().join("index.ts"), "console.log('hello')").unwrap();
+    // --dry-run wins over --fix
+    agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .args(["--fix", "--dry-run"])
+        .assert()
+        .stderr(predicate::str::contains("cannot be used with").not());
+    assert_eq!(std::fs::read_to_string(&skill_path).unwrap(), original);
+}
 
-    // Create one recognized file (should be counted)
-    fs::write(
-        temp_dir.path().join("SKILL.md"),
-        "---\nname: code-review\ndescription: Use when reviewing code\n---\nBody",
-    )
-    .unwrap();
+#[test]
+fn test_show_fixes_inline_prints_replacement() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skill_dir = temp_dir.path().join("skills").join("bad-skill");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let skill_path = skill_dir.join("SKILL.md");
+    let original = "---\nname: Bad-Skill\ndescription: test\n---\nContent\n";
+    std::fs::write(&skill_path, original).unwrap();
 
-    let mut cmd = agnix();
-    let output = cmd
+    let output = agnix()
         .arg(temp_dir.path().to_str().unwrap())
-        .arg("--format")
-        .arg("json")
+        .args(["--format", "text", "--show-fixes-inline"])
         .output()
         .unwrap();
 
-    assert!(
-        output.status.success(),
-        "agnix exited with non-zero status: {:?}",
-        output.status
-    );
-
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
-
-    let files_checked = json["files_checked"].as_u64().unwrap();
-
-    // Only the SKILL.md file should be counted
-    assert_eq!(
-        files_checked, 1,
-        "files_checked should only count recognized file types (SKILL.md), got {}",
-        files_checked
+    assert!(
+        stdout.contains("→ would change \"Bad-Skill\" to \"bad-skill\""),
+        "Expected inline AS-004 fix preview, got: {}",
+        stdout
     );
+    // Inline previews do not require --verbose and never modify the file
+    assert!(!stdout.contains("rule:"));
+    assert_eq!(std::fs::read_to_string(&skill_path).unwrap(), original);
 }
 
 #[test]
-fn test_init_creates_config_file_with_plain_text_output() {
-    let temp_dir = tempfile::tempdir().unwrap();
-    let config_path = temp_dir.path().join(".agnix.toml");
-
+fn test_fix_exit_code_on_remaining_errors() {
     let mut cmd = agnix();
+    // Invalid fixtures have errors that cannot be auto-fixed
     let output = cmd
-        .arg("init")
-        .arg(config_path.to_str().unwrap())
+        .arg("tests/fixtures/invalid/skills/unknown-tool")
+        .arg("--fix")
         .output()
         .unwrap();
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
-
-    // Verify output contains "Created:" (plain text, no emoji)
-    assert!(
-        stdout.contains("Created:"),
-        "Init output should contain 'Created:', got: {}",
-        stdout
-    );
-
-    // Verify output does NOT contain checkmark emoji
-    assert!(
-        !stdout.contains('\u{2713}') && !stdout.contains('\u{2714}'),
-        "Init output should not contain checkmark emoji, got: {}",
-        stdout
-    );
-
-    // Verify the config file was created
+    // Should still exit with error since errors remain
     assert!(
-        config_path.exists(),
-        "Config file should be created at {}",
-        config_path.display()
+        !output.status.success(),
+        "Should exit with error code when non-fixable errors remain"
     );
 
-    // Verify the config file contains valid TOML
-    let content = std::fs::read_to_string(&config_path).unwrap();
-    let parsed: Result<toml::Value, _> = toml::from_str(&content);
+    // Verify the flag was recognized (produces diagnostic output, not clap error)
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        parsed.is_ok(),
-        "Created config should be valid TOML, got: {}",
-        content
+        !stdout.is_empty() || stderr.is_empty(),
+        "--fix flag should be recognized and run fix mode, got stderr: {}",
+        stderr
     );
-
-    // Verify exit code is success
-    assert!(output.status.success(), "Init command should succeed");
 }
 
-// ============================================================================
-// Auto-Fix Tests for AS-004 and AS-010 (Issue #15)
-// ============================================================================
-
 #[test]
-fn test_fix_as_004_converts_name_to_kebab_case() {
+fn test_fix_exit_code_when_all_issues_are_fixed() {
     use std::fs;
     use std::io::Write;
 
-    let temp_dir = tempfile::tempdir().unwrap();
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir for test");
     let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
+    fs::create_dir_all(&skills_dir).expect("Failed to create skill dir for test");
 
     let skill_path = skills_dir.join("SKILL.md");
     {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        // Invalid name with underscores
+        let mut file = fs::File::create(&skill_path).expect("Failed to create skill file for test");
+        // Only AS-004 should fail here, and it is auto-fixable.
         write!(
             file,
-            "---\nname: Test_Skill_Name\ndescription: Use when testing\n---\nBody"
+            "---\nname: Test_Skill_Name\ndescription: Use when testing\n---\nContent"
         )
-        .unwrap();
+        .expect("Failed to write to skill file for test");
     }
 
-    // Run with --fix
     let mut cmd = agnix();
     let output = cmd
-        .arg(temp_dir.path().to_str().unwrap())
+        .arg(
+            temp_dir
+                .path()
+                .to_str()
+                .expect("Temp path is not valid UTF-8"),
+        )
         .arg("--fix")
         .output()
-        .unwrap();
+        .expect("Failed to execute agnix command");
 
-    // Read the fixed file
-    let fixed_content = fs::read_to_string(&skill_path).unwrap();
-
-    // Should convert Test_Skill_Name to test-skill-name
     assert!(
-        fixed_content.contains("name: test-skill-name"),
-        "AS-004 fix should convert name to kebab-case, got: {}",
-        fixed_content
+        output.status.success(),
+        "Expected --fix to exit 0 when all issues are fixed, stdout: {}, stderr: {}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
     );
 
-    // Output should indicate fixes applied
-    let stdout = String::from_utf8_lossy(&output.stdout);
+    let fixed_content = fs::read_to_string(&skill_path).expect("Failed to read fixed skill file");
     assert!(
-        stdout.contains("Fixed") || stdout.contains("fix"),
-        "Output should mention fix applied"
+        fixed_content.contains("name: test-skill-name"),
+        "Expected AS-004 fix to be applied, got: {}",
+        fixed_content
     );
 }
 
 #[test]
-fn test_fix_as_010_prepends_trigger_phrase() {
-    use std::fs;
-    use std::io::Write;
-
+fn test_fix_exit_code_respects_min_confidence() {
     let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("code-review");
-    fs::create_dir_all(&skills_dir).unwrap();
-
-    let skill_path = skills_dir.join("SKILL.md");
-    {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        // Valid name but missing trigger phrase
-        write!(
-            file,
-            "---\nname: code-review\ndescription: Reviews code for quality\n---\nBody"
+    for name in ["CLAUDE.md", "AGENTS.md"] {
+        std::fs::copy(
+            workspace_path("tests/fixtures/cross_platform/conflicting-commands").join(name),
+            temp_dir.path().join(name),
         )
         .unwrap();
     }
+    let skill_dir = temp_dir.path().join("skills").join("test-skill");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: Test_Skill_Name\ndescription: Use when testing\n---\nContent",
+    )
+    .unwrap();
 
-    // Run with --fix (not --fix-safe since AS-010 is not a safe fix)
-    let mut cmd = agnix();
-    cmd.arg(temp_dir.path().to_str().unwrap())
-        .arg("--fix")
+    // After AS-004 is fixed only low-confidence XP-004 warnings remain;
+    // --min-confidence hides them, so they must not fail the run in strict mode
+    let output = agnix()
+        .arg(temp_dir.path())
+        .args(["--fix", "--strict", "--min-confidence", "high"])
         .output()
         .unwrap();
-
-    // Read the fixed file
-    let fixed_content = fs::read_to_string(&skill_path).unwrap();
-
-    // Should prepend "Use when user wants to " to description
     assert!(
-        fixed_content.contains("Use when user wants to Reviews code for quality"),
-        "AS-010 fix should prepend trigger phrase, got: {}",
-        fixed_content
+        output.status.success(),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
     );
 }
 
 #[test]
-fn test_fix_safe_skips_as_010() {
-    use std::fs;
-    use std::io::Write;
-
+fn test_fix_exit_code_respects_only() {
     let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("code-review");
-    fs::create_dir_all(&skills_dir).unwrap();
-
-    let skill_path = skills_dir.join("SKILL.md");
-    let original_content = "---\nname: code-review\ndescription: Reviews code\n---\nBody";
-    {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        write!(file, "{}", original_content).unwrap();
+    let project = temp_dir.path().join("project");
+    std::fs::create_dir_all(&project).unwrap();
+    for name in ["CLAUDE.md", "AGENTS.md"] {
+        std::fs::copy(
+            workspace_path("tests/fixtures/cross_platform/conflicting-commands").join(name),
+            project.join(name),
+        )
+        .unwrap();
     }
+    let skill_dir = project.join("skills").join("test-skill");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: Test_Skill_Name\ndescription: Use when testing\n---\nContent",
+    )
+    .unwrap();
 
-    // Run with --fix-safe (should NOT fix AS-010 since it's not safe)
-    let mut cmd = agnix();
-    cmd.arg(temp_dir.path().to_str().unwrap())
-        .arg("--fix-safe")
+    // The XP-004 warnings left after fixing are outside --only, so strict mode passes
+    let output = agnix()
+        .arg(&project)
+        .args(["--fix", "--strict", "--only", "AS-"])
         .output()
         .unwrap();
-
-    // Read the file
-    let content_after = fs::read_to_string(&skill_path).unwrap();
-
-    // AS-010 fix is NOT safe, so it should NOT be applied
-    assert_eq!(
-        content_after, original_content,
-        "--fix-safe should not apply AS-010 fix"
+    assert!(
+        output.status.success(),
+        "stdout: {}",
+        String::from_utf8_lossy(&output.stdout)
     );
 }
 
 #[test]
-fn test_fix_safe_applies_case_only_as_004() {
-    use std::fs;
-    use std::io::Write;
-
-    let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
-
-    let skill_path = skills_dir.join("SKILL.md");
-    {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        // Name only needs lowercase (case-only change = safe)
-        write!(
-            file,
-            "---\nname: TestSkill\ndescription: Use when testing\n---\nBody"
-        )
-        .unwrap();
-    }
-
-    // Run with --fix-safe
+fn test_fix_safe_exit_code() {
     let mut cmd = agnix();
-    cmd.arg(temp_dir.path().to_str().unwrap())
+    let output = cmd
+        .arg("tests/fixtures/invalid/skills/unknown-tool")
         .arg("--fix-safe")
         .output()
         .unwrap();
 
-    // Read the fixed file
-    let fixed_content = fs::read_to_string(&skill_path).unwrap();
+    // Should still exit with error since errors remain
+    assert!(
+        !output.status.success(),
+        "Should exit with error code when errors remain after --fix-safe"
+    );
 
-    // Case-only fix IS safe, so it should be applied
+    // Verify the flag was recognized (produces diagnostic output, not clap error)
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
     assert!(
-        fixed_content.contains("name: testskill"),
-        "--fix-safe should apply case-only AS-004 fix, got: {}",
-        fixed_content
+        !stdout.is_empty() || stderr.is_empty(),
+        "--fix-safe flag should be recognized and run fix mode, got stderr: {}",
+        stderr
     );
 }
 
+// ============================================================================
+// Flag Combination Tests
+// ============================================================================
+
 #[test]
-fn test_dry_run_shows_as_004_fix_without_applying() {
+fn test_strict_with_sarif_format() {
     use std::fs;
     use std::io::Write;
 
@@ -1739,115 +1842,887 @@ fn test_dry_run_shows_as_004_fix_without_applying() {
     fs::create_dir_all(&skills_dir).unwrap();
 
     let skill_path = skills_dir.join("SKILL.md");
-    let original_content = "---\nname: Test_Skill\ndescription: Use when testing\n---\nBody";
-    {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        write!(file, "{}", original_content).unwrap();
-    }
+    let mut file = fs::File::create(&skill_path).unwrap();
+    // Valid skill name but missing trigger phrase (AS-010 warning)
+    writeln!(
+        file,
+        "---\nname: test-skill\ndescription: A test skill\n---\nContent"
+    )
+    .unwrap();
 
-    // Run with --dry-run
+    // With --strict, warnings should cause exit code 1
     let mut cmd = agnix();
     let output = cmd
         .arg(temp_dir.path().to_str().unwrap())
-        .arg("--dry-run")
+        .arg("--format")
+        .arg("sarif")
+        .arg("--strict")
         .output()
         .unwrap();
 
-    // File should NOT be modified
-    let content_after = fs::read_to_string(&skill_path).unwrap();
-    assert_eq!(
-        content_after, original_content,
-        "--dry-run should not modify files"
-    );
-
-    // Output should show what would be fixed
+    // Verify it's valid SARIF
     let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(json["runs"].is_array(), "Should produce valid SARIF");
+
+    // Should fail due to warnings in strict mode
     assert!(
-        stdout.contains("Would fix") || stdout.contains("dry-run") || stdout.contains("test-skill"),
-        "--dry-run should show what would be fixed"
+        !output.status.success(),
+        "With --strict and warnings, should exit with error code"
     );
 }
 
 #[test]
-fn test_fix_both_as_004_and_as_010_simultaneously() {
-    use std::fs;
-    use std::io::Write;
-
-    let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
-
-    let skill_path = skills_dir.join("SKILL.md");
-    {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        // Both AS-004 (invalid name) and AS-010 (missing trigger)
-        write!(
-            file,
-            "---\nname: Test_Skill\ndescription: Does testing\n---\nBody"
-        )
-        .unwrap();
-    }
-
-    // Run with --fix
+fn test_verbose_with_json_ignored() {
     let mut cmd = agnix();
-    cmd.arg(temp_dir.path().to_str().unwrap())
-        .arg("--fix")
+    let output = cmd
+        .arg("tests/fixtures/valid")
+        .arg("--verbose")
+        .arg("--format")
+        .arg("json")
         .output()
         .unwrap();
 
-    // Read the fixed file
-    let fixed_content = fs::read_to_string(&skill_path).unwrap();
-
-    // Both fixes should be applied
-    assert!(
-        fixed_content.contains("name: test-skill"),
-        "AS-004 fix should be applied, got: {}",
-        fixed_content
-    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Should still be valid JSON (verbose doesn't corrupt JSON output)
+    let json: Result<serde_json::Value, _> = serde_json::from_str(&stdout);
     assert!(
-        fixed_content.contains("Use when user wants to Does testing"),
-        "AS-010 fix should be applied, got: {}",
-        fixed_content
+        json.is_ok(),
+        "--verbose should not corrupt JSON output, got: {}",
+        stdout
     );
 }
 
 #[test]
-fn test_fix_safe_skips_structural_as_004() {
+fn test_target_cursor_disables_cc_rules() {
     use std::fs;
     use std::io::Write;
 
     let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    let skills_dir = temp_dir.path().join("skills").join("deploy-prod");
     fs::create_dir_all(&skills_dir).unwrap();
 
     let skill_path = skills_dir.join("SKILL.md");
-    let original_content = "---\nname: test_skill_name\ndescription: Use when testing\n---\nBody";
-    {
-        let mut file = fs::File::create(&skill_path).unwrap();
-        // Name with underscores = structural change (not just case)
-        write!(file, "{}", original_content).unwrap();
-    }
+    let mut file = fs::File::create(&skill_path).unwrap();
+    // This would normally trigger CC-SK-006 (Claude-specific rule)
+    writeln!(
+        file,
+        "---\nname: deploy-prod\ndescription: Deploy to production\n---\nDeploy the application"
+    )
+    .unwrap();
 
-    // Run with --fix-safe
+    // With --target cursor, CC-* rules should be disabled
     let mut cmd = agnix();
-    cmd.arg(temp_dir.path().to_str().unwrap())
-        .arg("--fix-safe")
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--format")
+        .arg("json")
+        .arg("--target")
+        .arg("cursor")
         .output()
         .unwrap();
 
-    // Read the file
-    let content_after = fs::read_to_string(&skill_path).unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let diagnostics = json["diagnostics"].as_array().unwrap();
 
-    // Structural AS-004 fix is NOT safe, should NOT be applied
-    assert_eq!(
-        content_after, original_content,
-        "--fix-safe should not apply structural AS-004 fix"
+    // Should not have any CC-* rules for cursor target
+    let has_cc_rule = diagnostics
+        .iter()
+        .any(|d| d["rule"].as_str().unwrap_or("").starts_with("CC-"));
+
+    assert!(
+        !has_cc_rule,
+        "With --target cursor, CC-* rules should be disabled"
     );
 }
 
-// ============================================================================
-// Telemetry Command Tests (Issue #209)
-// ============================================================================
+#[test]
+fn test_validate_subcommand() {
+    let mut cmd = agnix();
+    cmd.arg("validate")
+        .arg("tests/fixtures/valid")
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_dry_run_with_format_json_emits_fix_plan() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skill_dir = temp_dir.path().join("skills").join("bad-skill");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let skill_path = skill_dir.join("SKILL.md");
+    let original = "---\nname: Bad-Skill\ndescription: test\n---\nContent\n";
+    std::fs::write(&skill_path, original).unwrap();
+
+    let output = agnix()
+        .current_dir(temp_dir.path())
+        .arg(temp_dir.path().to_str().unwrap())
+        .args(["--fix", "--dry-run", "--format", "json"])
+        .output()
+        .unwrap();
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let fixes = json["fixes"].as_array().unwrap();
+    let fix = fixes
+        .iter()
+        .find(|f| f["rule"] == "AS-004")
+        .expect("AS-004 fix should be in the plan");
+    assert!(
+        fix["file"]
+            .as_str()
+            .unwrap()
+            .ends_with("skills/bad-skill/SKILL.md")
+    );
+    let start = fix["start_byte"].as_u64().unwrap() as usize;
+    let end = fix["end_byte"].as_u64().unwrap() as usize;
+    assert_eq!(&original[start..end], "Bad-Skill");
+    assert_eq!(fix["replacement"], "bad-skill");
+    assert!(fix["safe"].is_boolean());
+    assert!(fix["description"].is_string());
+
+    // Nothing is written
+    assert_eq!(std::fs::read_to_string(&skill_path).unwrap(), original);
+}
+
+#[test]
+fn test_fixtures_have_no_empty_placeholder_dirs() {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn check_dir(dir: &Path, empty_dirs: &mut Vec<PathBuf>) -> bool {
+        let mut has_file = false;
+        let entries = fs::read_dir(dir).unwrap_or_else(|e| {
+            panic!("Failed to read fixture directory {}: {}", dir.display(), e)
+        });
+
+        for entry in entries {
+            let entry = entry
+                .unwrap_or_else(|e| panic!("Failed to read entry under {}: {}", dir.display(), e));
+            let path = entry.path();
+            if path.is_file() {
+                has_file = true;
+                continue;
+            }
+            if path.is_dir() && check_dir(&path, empty_dirs) {
+                has_file = true;
+            }
+        }
+
+        if !has_file {
+            empty_dirs.push(dir.to_path_buf());
+        }
+
+        has_file
+    }
+
+    let root = workspace_path("tests/fixtures");
+    assert!(
+        root.is_dir(),
+        "Expected fixtures directory at {}",
+        root.display()
+    );
+
+    let mut empty_dirs = Vec::new();
+    check_dir(&root, &mut empty_dirs);
+
+    assert!(
+        empty_dirs.is_empty(),
+        "Empty fixture directories found:\n{}",
+        empty_dirs
+            .iter()
+            .map(|p| p.display().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+}
+
+// ===== Config Parse Warning Tests =====
+
+#[test]
+fn test_invalid_config_displays_warning_to_stderr() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config_path = temp_dir.path().join(".agnix.toml");
+
+    // Create invalid TOML
+    std::fs::write(&config_path, "this is [ invalid toml").unwrap();
+
+    // Create a minimal valid directory to scan
+    let skill_dir = temp_dir.path().join(".claude").join("skills");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let skill_file = skill_dir.join("test.md");
+    std::fs::write(&skill_file, "# Test\nSimple content").unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path())
+        .arg("--config")
+        .arg(&config_path)
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Should contain warning message
+    assert!(
+        stderr.contains("Warning:") && stderr.contains("Failed to parse config"),
+        "Expected config warning in stderr, got: {}",
+        stderr
+    );
+
+    // Should still produce validation output (continues with defaults)
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Validating:"),
+        "Should still run validation with default config"
+    );
+}
+
+#[test]
+fn test_valid_config_no_warning_in_stderr() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config_path = temp_dir.path().join(".agnix.toml");
+
+    // Create valid config
+    std::fs::write(
+        &config_path,
+        r#"
+severity = "Warning"
+target = "Generic"
+exclude = []
+
+[rules]
+"#,
+    )
+    .unwrap();
+
+    // Create a minimal valid directory to scan
+    let skill_dir = temp_dir.path().join(".claude").join("skills");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let skill_file = skill_dir.join("test.md");
+    std::fs::write(&skill_file, "# Test\nSimple content").unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path())
+        .arg("--config")
+        .arg(&config_path)
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Should NOT contain any config warning
+    assert!(
+        !stderr.contains("Failed to parse config"),
+        "Valid config should not produce warning, stderr: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_config_warning_with_json_output() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config_path = temp_dir.path().join(".agnix.toml");
+
+    // Create invalid TOML
+    std::fs::write(&config_path, "invalid [[ toml").unwrap();
+
+    // Create a minimal valid directory to scan
+    let skill_dir = temp_dir.path().join(".claude").join("skills");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    let skill_file = skill_dir.join("test.md");
+    std::fs::write(&skill_file, "# Test\nSimple content").unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path())
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    // Warning should go to stderr, not corrupt JSON output
+    assert!(
+        stderr.contains("Warning:"),
+        "Warning should be in stderr for JSON output"
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: Result<serde_json::Value, _> = serde_json::from_str(&stdout);
+    assert!(
+        json.is_ok(),
+        "JSON output should be valid despite config warning, got: {}",
+        stdout
+    );
+}
+
+// ============================================================================
+// Target Argument Validation Tests (Issue #129)
+// ============================================================================
+
+#[test]
+fn test_invalid_target_rejected() {
+    let mut cmd = agnix();
+    cmd.arg("tests/fixtures/valid")
+        .arg("--target")
+        .arg("invalid-target")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn test_typo_target_rejected() {
+    let mut cmd = agnix();
+    // Underscore instead of hyphen should be rejected
+    cmd.arg("tests/fixtures/valid")
+        .arg("--target")
+        .arg("claude_code")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn test_case_sensitive_target_rejected() {
+    let mut cmd = agnix();
+    // PascalCase should be rejected (CLI uses kebab-case)
+    cmd.arg("tests/fixtures/valid")
+        .arg("--target")
+        .arg("ClaudeCode")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn test_valid_targets_accepted() {
+    for target in ["generic", "claude-code", "cursor", "codex"] {
+        let mut cmd = agnix();
+        cmd.arg("tests/fixtures/valid")
+            .arg("--target")
+            .arg(target)
+            .assert()
+            .success();
+    }
+}
+
+#[test]
+fn test_help_shows_target_possible_values() {
+    let mut cmd = agnix();
+    let output = cmd.arg("--help").output().unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Help should list exact possible values for --target
+    assert!(
+        stdout.contains("[possible values: generic, claude-code, cursor, codex, windsurf, gemini]"),
+        "Help should show exact possible target values, got: {}",
+        stdout
+    );
+}
+
+// ============================================================================
+// JSON Output files_checked Accuracy Tests (Issue #127)
+// ============================================================================
+
+#[test]
+fn test_format_json_files_checked_counts_all_validated_files() {
+    use std::fs;
+
+    // Create a directory with valid files that produce no diagnostics
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    // Create valid SKILL.md files (no diagnostics expected)
+    let skill_dir1 = temp_dir.path().join("skills").join("code-review");
+    fs::create_dir_all(&skill_dir1).unwrap();
+    fs::write(
+        skill_dir1.join("SKILL.md"),
+        "---\nname: code-review\ndescription: Use when reviewing code\n---\nBody",
+    )
+    .unwrap();
+
+    let skill_dir2 = temp_dir.path().join("skills").join("test-runner");
+    fs::create_dir_all(&skill_dir2).unwrap();
+    fs::write(
+        skill_dir2.join("SKILL.md"),
+        "---\nname: test-runner\ndescription: Use when running tests\n---\nBody",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "agnix exited with non-zero status: {:?}",
+        output.status
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let files_checked = json["files_checked"].as_u64().unwrap();
+
+    // files_checked should be exactly 2 (the two SKILL.md files we created)
+    assert_eq!(
+        files_checked, 2,
+        "Expected 2 files checked, found {}",
+        files_checked
+    );
+}
+
+#[test]
+fn test_format_json_files_checked_excludes_unknown_types() {
+    use std::fs;
+
+    // Create a directory with mixed file types
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    // Create files of unknown type (should NOT be counted)
+    fs::write(temp_dir.path().join("main.rs"), "fn main() {}").unwrap();
+    fs::write(temp_dir.path().join("index.ts"), "console.log('hello')").unwrap();
+
+    // Create one recognized file (should be counted)
+    fs::write(
+        temp_dir.path().join("SKILL.md"),
+        "---\nname: code-review\ndescription: Use when reviewing code\n---\nBody",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--format")
+        .arg("json")
+        .output()
+        .unwrap();
+
+    assert!(
+        output.status.success(),
+        "agnix exited with non-zero status: {:?}",
+        output.status
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let files_checked = json["files_checked"].as_u64().unwrap();
+
+    // Only the SKILL.md file should be counted
+    assert_eq!(
+        files_checked, 1,
+        "files_checked should only count recognized file types (SKILL.md), got {}",
+        files_checked
+    );
+}
+
+#[test]
+fn test_init_creates_config_file_with_plain_text_output() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let config_path = temp_dir.path().join(".agnix.toml");
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg("init")
+        .arg(config_path.to_str().unwrap())
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // Verify output contains "Created:" (plain text, no emoji)
+    assert!(
+        stdout.contains("Created:"),
+        "Init output should contain 'Created:', got: {}",
+        stdout
+    );
+
+    // Verify output does NOT contain checkmark emoji
+    assert!(
+        !stdout.contains('\u{2713}') && !stdout.contains('\u{2714}'),
+        "Init output should not contain checkmark emoji, got: {}",
+        stdout
+    );
+
+    // Verify the config file was created
+    assert!(
+        config_path.exists(),
+        "Config file should be created at {}",
+        config_path.display()
+    );
+
+    // Verify the config file contains valid TOML
+    let content = std::fs::read_to_string(&config_path).unwrap();
+    let parsed: Result<toml::Value, _> = toml::from_str(&content);
+    assert!(
+        parsed.is_ok(),
+        "Created config should be valid TOML, got: {}",
+        content
+    );
+
+    // Verify exit code is success
+    assert!(output.status.success(), "Init command should succeed");
+}
+
+#[test]
+fn test_init_interactive_writes_confirmed_tools() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir_all(temp_dir.path().join(".claude")).unwrap();
+    std::fs::create_dir_all(temp_dir.path().join(".cursor")).unwrap();
+    std::fs::create_dir_all(temp_dir.path().join("dist")).unwrap();
+    let config_path = temp_dir.path().join(".agnix.toml");
+
+    let mut cmd = agnix();
+    cmd.args(["init", "--interactive", config_path.to_str().unwrap()])
+        .write_stdin("y\nn\n")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created:"));
+
+    let content = std::fs::read_to_string(&config_path).unwrap();
+    let parsed: toml::Value = toml::from_str(&content).unwrap();
+    assert_eq!(
+        parsed["tools"].as_array().unwrap(),
+        &vec![toml::Value::String("claude-code".into())]
+    );
+    let exclude = parsed["exclude"].as_array().unwrap();
+    assert!(exclude.contains(&toml::Value::String("dist/**".into())));
+}
+
+// ============================================================================
+// Auto-Fix Tests for AS-004 and AS-010 (Issue #15)
+// ============================================================================
+
+#[test]
+fn test_interactive_applies_only_accepted_fixes() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    std::fs::create_dir_all(&skills_dir).unwrap();
+    let skill_path = skills_dir.join("SKILL.md");
+    let original = "---\nname: Test_Skill_Name\ndescription: Use when testing\n---\nBody";
+    std::fs::write(&skill_path, original).unwrap();
+
+    // Rejecting the first fix and then ending input leaves the file untouched
+    let output = agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--interactive")
+        .write_stdin("n\n")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Apply this fix?"), "got: {}", stdout);
+    assert!(
+        stdout.contains("+ "),
+        "fix should be previewed as a diff, got: {}",
+        stdout
+    );
+    assert_eq!(std::fs::read_to_string(&skill_path).unwrap(), original);
+
+    // "a" accepts this and every remaining fix
+    agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--interactive")
+        .write_stdin("a\n")
+        .output()
+        .unwrap();
+    let fixed = std::fs::read_to_string(&skill_path).unwrap();
+    assert!(fixed.contains("name: test-skill-name"), "got: {}", fixed);
+}
+
+#[test]
+fn test_interactive_conflicts_with_dry_run() {
+    agnix()
+        .arg("tests/fixtures/valid")
+        .arg("--interactive")
+        .arg("--dry-run")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_fix_as_004_converts_name_to_kebab_case() {
+    use std::fs;
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        // Invalid name with underscores
+        write!(
+            file,
+            "---\nname: Test_Skill_Name\ndescription: Use when testing\n---\nBody"
+        )
+        .unwrap();
+    }
+
+    // Run with --fix
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix")
+        .output()
+        .unwrap();
+
+    // Read the fixed file
+    let fixed_content = fs::read_to_string(&skill_path).unwrap();
+
+    // Should convert Test_Skill_Name to test-skill-name
+    assert!(
+        fixed_content.contains("name: test-skill-name"),
+        "AS-004 fix should convert name to kebab-case, got: {}",
+        fixed_content
+    );
+
+    // Output should indicate fixes applied
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Fixed") || stdout.contains("fix"),
+        "Output should mention fix applied"
+    );
+}
+
+#[test]
+fn test_fix_as_010_prepends_trigger_phrase() {
+    use std::fs;
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("code-review");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        // Valid name but missing trigger phrase
+        write!(
+            file,
+            "---\nname: code-review\ndescription: Reviews code for quality\n---\nBody"
+        )
+        .unwrap();
+    }
+
+    // Run with --fix (not --fix-safe since AS-010 is not a safe fix)
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix")
+        .output()
+        .unwrap();
+
+    // Read the fixed file
+    let fixed_content = fs::read_to_string(&skill_path).unwrap();
+
+    // Should prepend "Use when user wants to " to description
+    assert!(
+        fixed_content.contains("Use when user wants to Reviews code for quality"),
+        "AS-010 fix should prepend trigger phrase, got: {}",
+        fixed_content
+    );
+}
+
+#[test]
+fn test_fix_safe_skips_as_010() {
+    use std::fs;
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("code-review");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    let original_content = "---\nname: code-review\ndescription: Reviews code\n---\nBody";
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        write!(file, "{}", original_content).unwrap();
+    }
+
+    // Run with --fix-safe (should NOT fix AS-010 since it's not safe)
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix-safe")
+        .output()
+        .unwrap();
+
+    // Read the file
+    let content_after = fs::read_to_string(&skill_path).unwrap();
+
+    // AS-010 fix is NOT safe, so it should NOT be applied
+    assert_eq!(
+        content_after, original_content,
+        "--fix-safe should not apply AS-010 fix"
+    );
+}
+
+#[test]
+fn test_fix_safe_applies_case_only_as_004() {
+    use std::fs;
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        // Name only needs lowercase (case-only change = safe)
+        write!(
+            file,
+            "---\nname: TestSkill\ndescription: Use when testing\n---\nBody"
+        )
+        .unwrap();
+    }
+
+    // Run with --fix-safe
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix-safe")
+        .output()
+        .unwrap();
+
+    // Read the fixed file
+    let fixed_content = fs::read_to_string(&skill_path).unwrap();
+
+    // Case-only fix IS safe, so it should be applied
+    assert!(
+        fixed_content.contains("name: testskill"),
+        "--fix-safe should apply case-only AS-004 fix, got: {}",
+        fixed_content
+    );
+}
+
+#[test]
+fn test_dry_run_shows_as_004_fix_without_applying() {
+    use std::fs;
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    let original_content = "---\nname: Test_Skill\ndescription: Use when testing\n---\nBody";
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        write!(file, "{}", original_content).unwrap();
+    }
+
+    // Run with --dry-run
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--dry-run")
+        .output()
+        .unwrap();
+
+    // File should NOT be modified
+    let content_after = fs::read_to_string(&skill_path).unwrap();
+    assert_eq!(
+        content_after, original_content,
+        "--dry-run should not modify files"
+    );
+
+    // Output should show what would be fixed
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("Would fix") || stdout.contains("dry-run") || stdout.contains("test-skill"),
+        "--dry-run should show what would be fixed"
+    );
+}
+
+#[test]
+fn test_fix_both_as_004_and_as_010_simultaneously() {
+    use std::fs;
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        // Both AS-004 (invalid name) and AS-010 (missing trigger)
+        write!(
+            file,
+            "---\nname: Test_Skill\ndescription: Does testing\n---\nBody"
+        )
+        .unwrap();
+    }
+
+    // Run with --fix
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix")
+        .output()
+        .unwrap();
+
+    // Read the fixed file
+    let fixed_content = fs::read_to_string(&skill_path).unwrap();
+
+    // Both fixes should be applied
+    assert!(
+        fixed_content.contains("name: test-skill"),
+        "AS-004 fix should be applied, got: {}",
+        fixed_content
+    );
+    assert!(
+        fixed_content.contains("Use when user wants to Does testing"),
+        "AS-010 fix should be applied, got: {}",
+        fixed_content
+    );
+}
+
+#[test]
+fn test_fix_safe_skips_structural_as_004() {
+    use std::fs;
+    use std::io::Write;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+
+    let skill_path = skills_dir.join("SKILL.md");
+    let original_content = "---\nname: test_skill_name\ndescription: Use when testing\n---\nBody";
+    {
+        let mut file = fs::File::create(&skill_path).unwrap();
+        // Name with underscores = structural change (not just case)
+        write!(file, "{}", original_content).unwrap();
+    }
+
+    // Run with --fix-safe
+    let mut cmd = agnix();
+    cmd.arg(temp_dir.path().to_str().unwrap())
+        .arg("--fix-safe")
+        .output()
+        .unwrap();
+
+    // Read the file
+    let content_after = fs::read_to_string(&skill_path).unwrap();
+
+    // Structural AS-004 fix is NOT safe, should NOT be applied
+    assert_eq!(
+        content_after, original_content,
+        "--fix-safe should not apply structural AS-004 fix"
+    );
+}
+
+// ============================================================================
+// Telemetry Command Tests (Issue #209)
+// ============================================================================
 
 #[test]
 fn test_telemetry_status_shows_disabled_by_default() {
@@ -2016,6 +2891,27 @@ fn test_schema_command_help_shows_output_option() {
         .stdout(predicate::str::contains("--output"));
 }
 
+#[test]
+fn test_explain_command_known_rule() {
+    let mut cmd = agnix();
+    cmd.args(["explain", "cc-sk-006"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("CC-SK-006"))
+        .stdout(predicate::str::contains("claude-code"))
+        .stdout(predicate::str::contains("disable-model-invocation"));
+}
+
+#[test]
+fn test_explain_command_unknown_rule_suggests_closest() {
+    let mut cmd = agnix();
+    cmd.args(["explain", "CC-SK-06"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown rule 'CC-SK-06'"))
+        .stderr(predicate::str::contains("Did you mean 'CC-SK-006'?"));
+}
+
 // ============================================================================
 // Config Validation Warning Display Integration Tests (Issue #206)
 // ============================================================================
@@ -2283,29 +3179,257 @@ fn test_agnix_locale_env_var_switches_output() {
 
     let stdout = String::from_utf8_lossy(&output.stdout);
 
-    // AGNIX_LOCALE=es should produce Spanish output
+    // AGNIX_LOCALE=es should produce Spanish output
+    assert!(
+        stdout.contains("Validando:"),
+        "AGNIX_LOCALE=es should produce Spanish output, got: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_config_file_locale_field() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    // Create a config file with locale = "es"
+    let config_path = temp_dir.path().join(".agnix.toml");
+    fs::write(
+        &config_path,
+        "severity = \"Warning\"\ntarget = \"Generic\"\nlocale = \"es\"\nexclude = []\n\n[rules]\n",
+    )
+    .unwrap();
+
+    // Create a minimal valid fixture
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+    fs::write(
+        skills_dir.join("SKILL.md"),
+        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--config")
+        .arg(&config_path)
+        // Clear env vars so config locale takes effect
+        .env_remove("AGNIX_LOCALE")
+        .env_remove("LANG")
+        .env_remove("LC_ALL")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // Config locale = "es" should produce Spanish output
+    assert!(
+        stdout.contains("Validando:"),
+        "Config locale = 'es' should produce Spanish output, got: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_locale_priority_cli_flag_overrides_env_var() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+    fs::write(
+        skills_dir.join("SKILL.md"),
+        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    )
+    .unwrap();
+
+    // Set env to Spanish, but CLI flag to English
+    let mut cmd = agnix();
+    let output = cmd
+        .env("AGNIX_LOCALE", "es")
+        .env_remove("LANG")
+        .env_remove("LC_ALL")
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--locale")
+        .arg("en")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // --locale en should override AGNIX_LOCALE=es
+    assert!(
+        stdout.contains("Validating:"),
+        "--locale en should override AGNIX_LOCALE=es, got: {}",
+        stdout
+    );
+    assert!(
+        !stdout.contains("Validando:"),
+        "--locale en should NOT show Spanish output, got: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_locale_priority_cli_flag_overrides_config() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    // Config file with locale = "es"
+    let config_path = temp_dir.path().join(".agnix.toml");
+    fs::write(
+        &config_path,
+        "severity = \"Warning\"\ntarget = \"Generic\"\nlocale = \"es\"\nexclude = []\n\n[rules]\n",
+    )
+    .unwrap();
+
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+    fs::write(
+        skills_dir.join("SKILL.md"),
+        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    )
+    .unwrap();
+
+    // CLI flag should override config
+    let mut cmd = agnix();
+    let output = cmd
+        .env_remove("AGNIX_LOCALE")
+        .env_remove("LANG")
+        .env_remove("LC_ALL")
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--config")
+        .arg(&config_path)
+        .arg("--locale")
+        .arg("en")
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // --locale en should override config locale = "es"
+    assert!(
+        stdout.contains("Validating:"),
+        "--locale en should override config locale=es, got: {}",
+        stdout
+    );
+    assert!(
+        !stdout.contains("Validando:"),
+        "--locale en should NOT show Spanish output when overriding config, got: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_locale_priority_env_var_overrides_config() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    // Config file with locale = "en"
+    let config_path = temp_dir.path().join(".agnix.toml");
+    fs::write(
+        &config_path,
+        "severity = \"Warning\"\ntarget = \"Generic\"\nlocale = \"en\"\nexclude = []\n\n[rules]\n",
+    )
+    .unwrap();
+
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+    fs::write(
+        skills_dir.join("SKILL.md"),
+        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    )
+    .unwrap();
+
+    // AGNIX_LOCALE=es should be picked up by detect_locale() which runs before
+    // config is loaded, so env var takes effect for initial output.
+    // However, locale re-initialization happens after config load only when no --locale flag.
+    // The priority is: --locale > config > env var (inside detect_locale).
+    // Actually per the code: locale::init(cli_locale, None) first, then
+    // if cli.locale.is_none() && config.locale.is_some(), re-init with config locale.
+    // So config locale overrides env var when no --locale flag is set.
+    // This test documents that behavior.
+    let mut cmd = agnix();
+    let output = cmd
+        .env("AGNIX_LOCALE", "es")
+        .env_remove("LANG")
+        .env_remove("LC_ALL")
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--config")
+        .arg(&config_path)
+        .output()
+        .unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // When no --locale flag: config locale takes precedence over AGNIX_LOCALE
+    // because the code re-initializes locale from config after loading it.
+    // The initial locale::init picks up AGNIX_LOCALE but then config overrides it.
+    // This verifies the re-initialization logic works.
+    assert!(
+        stdout.contains("Validating:"),
+        "Config locale='en' should override AGNIX_LOCALE=es (no --locale flag), got: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_invalid_locale_warns_and_falls_back_to_english() {
+    use std::fs;
+
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    fs::create_dir_all(&skills_dir).unwrap();
+    fs::write(
+        skills_dir.join("SKILL.md"),
+        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    let output = cmd
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--locale")
+        .arg("xx-INVALID")
+        .output()
+        .unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    // Should warn about unsupported locale on stderr
     assert!(
-        stdout.contains("Validando:"),
-        "AGNIX_LOCALE=es should produce Spanish output, got: {}",
+        stderr.contains("unsupported locale") || stderr.contains("Warning"),
+        "Invalid locale should produce warning on stderr, got stderr: {}",
+        stderr
+    );
+
+    // Should fall back to English output
+    assert!(
+        stdout.contains("Validating:"),
+        "Invalid locale should fall back to English output, got: {}",
         stdout
     );
 }
 
 #[test]
-fn test_config_file_locale_field() {
+fn test_invalid_locale_in_config_warns_and_falls_back() {
     use std::fs;
 
     let temp_dir = tempfile::tempdir().unwrap();
 
-    // Create a config file with locale = "es"
+    // Config with invalid locale
     let config_path = temp_dir.path().join(".agnix.toml");
     fs::write(
         &config_path,
-        "severity = \"Warning\"\ntarget = \"Generic\"\nlocale = \"es\"\nexclude = []\n\n[rules]\n",
+        "severity = \"Warning\"\ntarget = \"Generic\"\nlocale = \"xx-INVALID\"\nexclude = []\n\n[rules]\n",
     )
     .unwrap();
 
-    // Create a minimal valid fixture
     let skills_dir = temp_dir.path().join("skills").join("test-skill");
     fs::create_dir_all(&skills_dir).unwrap();
     fs::write(
@@ -2316,30 +3440,38 @@ fn test_config_file_locale_field() {
 
     let mut cmd = agnix();
     let output = cmd
-        .arg(temp_dir.path().to_str().unwrap())
-        .arg("--config")
-        .arg(&config_path)
-        // Clear env vars so config locale takes effect
         .env_remove("AGNIX_LOCALE")
         .env_remove("LANG")
         .env_remove("LC_ALL")
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--config")
+        .arg(&config_path)
         .output()
         .unwrap();
 
+    let stderr = String::from_utf8_lossy(&output.stderr);
     let stdout = String::from_utf8_lossy(&output.stdout);
 
-    // Config locale = "es" should produce Spanish output
+    // Should warn about unsupported locale
     assert!(
-        stdout.contains("Validando:"),
-        "Config locale = 'es' should produce Spanish output, got: {}",
+        stderr.contains("unsupported locale") || stderr.contains("Warning"),
+        "Invalid config locale should produce warning, got stderr: {}",
+        stderr
+    );
+
+    // Should fall back to English
+    assert!(
+        stdout.contains("Validating:"),
+        "Invalid config locale should fall back to English, got stdout: {}",
         stdout
     );
 }
 
 #[test]
-fn test_locale_priority_cli_flag_overrides_env_var() {
+fn test_locale_flag_with_json_output_still_works() {
     use std::fs;
 
+    // Locale flag should not affect JSON structure (JSON always uses keys not messages)
     let temp_dir = tempfile::tempdir().unwrap();
     let skills_dir = temp_dir.path().join("skills").join("test-skill");
     fs::create_dir_all(&skills_dir).unwrap();
@@ -2349,325 +3481,755 @@ fn test_locale_priority_cli_flag_overrides_env_var() {
     )
     .unwrap();
 
-    // Set env to Spanish, but CLI flag to English
     let mut cmd = agnix();
     let output = cmd
-        .env("AGNIX_LOCALE", "es")
-        .env_remove("LANG")
-        .env_remove("LC_ALL")
         .arg(temp_dir.path().to_str().unwrap())
         .arg("--locale")
-        .arg("en")
+        .arg("es")
+        .arg("--format")
+        .arg("json")
         .output()
         .unwrap();
 
     let stdout = String::from_utf8_lossy(&output.stdout);
+    let json: Result<serde_json::Value, _> = serde_json::from_str(&stdout);
+    assert!(
+        json.is_ok(),
+        "--locale es with --format json should produce valid JSON, got: {}",
+        stdout
+    );
 
-    // --locale en should override AGNIX_LOCALE=es
+    let json = json.unwrap();
     assert!(
-        stdout.contains("Validating:"),
-        "--locale en should override AGNIX_LOCALE=es, got: {}",
+        json["version"].is_string(),
+        "JSON should have version field"
+    );
+    assert!(
+        json["diagnostics"].is_array(),
+        "JSON should have diagnostics array"
+    );
+}
+
+#[test]
+fn test_help_shows_locale_flags() {
+    let mut cmd = agnix();
+    let output = cmd.arg("--help").output().unwrap();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(
+        stdout.contains("--locale"),
+        "Help should show --locale flag, got: {}",
         stdout
     );
     assert!(
-        !stdout.contains("Validando:"),
-        "--locale en should NOT show Spanish output, got: {}",
+        stdout.contains("--list-locales"),
+        "Help should show --list-locales flag, got: {}",
         stdout
     );
 }
 
 #[test]
-fn test_locale_priority_cli_flag_overrides_config() {
+fn test_locale_es_translates_diagnostic_messages() {
     use std::fs;
+    use std::io::Write;
 
     let temp_dir = tempfile::tempdir().unwrap();
-
-    // Config file with locale = "es"
-    let config_path = temp_dir.path().join(".agnix.toml");
-    fs::write(
-        &config_path,
-        "severity = \"Warning\"\ntarget = \"Generic\"\nlocale = \"es\"\nexclude = []\n\n[rules]\n",
-    )
-    .unwrap();
-
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
+    let skills_dir = temp_dir.path().join("skills").join("bad-skill");
     fs::create_dir_all(&skills_dir).unwrap();
-    fs::write(
-        skills_dir.join("SKILL.md"),
-        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+
+    let skill_path = skills_dir.join("SKILL.md");
+    let mut file = fs::File::create(&skill_path).unwrap();
+    // Invalid name triggers AS-004 error
+    writeln!(
+        file,
+        "---\nname: Bad-Skill\ndescription: test\n---\nContent"
     )
     .unwrap();
 
-    // CLI flag should override config
     let mut cmd = agnix();
     let output = cmd
-        .env_remove("AGNIX_LOCALE")
-        .env_remove("LANG")
-        .env_remove("LC_ALL")
         .arg(temp_dir.path().to_str().unwrap())
-        .arg("--config")
-        .arg(&config_path)
         .arg("--locale")
-        .arg("en")
+        .arg("es")
         .output()
         .unwrap();
 
     let stdout = String::from_utf8_lossy(&output.stdout);
 
-    // --locale en should override config locale = "es"
+    // Spanish AS-004 message contains "debe tener" (must have) or
+    // the Spanish summary "Encontrados" (Found)
     assert!(
-        stdout.contains("Validating:"),
-        "--locale en should override config locale=es, got: {}",
+        stdout.contains("Encontrados")
+            || stdout.contains("debe tener")
+            || stdout.contains("minusculas"),
+        "--locale es should produce Spanish diagnostic output, got: {}",
         stdout
     );
-    assert!(
-        !stdout.contains("Validando:"),
-        "--locale en should NOT show Spanish output when overriding config, got: {}",
-        stdout
+}
+
+#[test]
+fn test_rules_coverage_report_flags_typo_as_unknown() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".agnix.toml"),
+        "[rules]\ndisabled_rules = [\"AS-005\", \"CC-SK-0066\"]\n",
+    )
+    .unwrap();
+
+    let output = agnix()
+        .current_dir(temp_dir.path())
+        .arg(temp_dir.path().to_str().unwrap())
+        .args(["--rules-coverage-report", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ids = |key: &str| -> Vec<String> {
+        json[key]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|id| id.as_str().unwrap().to_string())
+            .collect()
+    };
+    assert_eq!(ids("unknown"), vec!["CC-SK-0066".to_string()]);
+    assert!(ids("disabled").contains(&"AS-005".to_string()));
+    assert!(ids("enabled").contains(&"AS-004".to_string()));
+    assert_eq!(
+        ids("enabled").len() + ids("disabled").len(),
+        json["total"].as_u64().unwrap() as usize
     );
 }
 
 #[test]
-fn test_locale_priority_env_var_overrides_config() {
-    use std::fs;
+fn test_rules_coverage_report_text_groups() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".agnix.toml"),
+        "[rules]\ndisabled_rules = [\"AS-0005\"]\n",
+    )
+    .unwrap();
+
+    let mut cmd = agnix();
+    cmd.current_dir(temp_dir.path())
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--rules-coverage-report")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Enabled rules"))
+        .stdout(predicate::str::contains("Disabled rules"))
+        .stdout(predicate::str::contains(
+            "Unknown rule IDs referenced in config (1)",
+        ))
+        .stdout(predicate::str::contains("AS-0005"));
+}
 
+#[test]
+fn test_list_rules_json_reports_tool_and_enabled() {
     let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".agnix.toml"),
+        "[rules]\ndisabled_rules = [\"CC-HK-001\"]\n",
+    )
+    .unwrap();
 
-    // Config file with locale = "en"
-    let config_path = temp_dir.path().join(".agnix.toml");
-    fs::write(
-        &config_path,
-        "severity = \"Warning\"\ntarget = \"Generic\"\nlocale = \"en\"\nexclude = []\n\n[rules]\n",
+    let output = agnix()
+        .current_dir(temp_dir.path())
+        .arg(temp_dir.path().to_str().unwrap())
+        .args(["--list-rules", "--format", "json"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let rules = json.as_array().unwrap();
+    let rule = |id: &str| rules.iter().find(|r| r["id"] == id).unwrap().clone();
+
+    let hook = rule("CC-HK-001");
+    assert_eq!(hook["category"], "claude-hooks");
+    assert_eq!(hook["tool"], "claude-code");
+    assert_eq!(hook["enabled"], false);
+    assert_eq!(rule("AS-004")["enabled"], true);
+    assert!(rule("MCP-001")["tool"].is_null());
+}
+
+#[test]
+fn test_list_rules_text_groups_by_category() {
+    let temp_dir = tempfile::tempdir().unwrap();
+
+    let mut cmd = agnix();
+    cmd.current_dir(temp_dir.path())
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--list-rules")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("claude-hooks ("))
+        .stdout(predicate::str::contains("CC-HK-001"))
+        .stdout(predicate::str::contains("claude-code"))
+        .stdout(predicate::str::contains("rules enabled"));
+}
+
+#[test]
+fn test_check_config_reports_unknown_keys() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".agnix.toml"),
+        "severity = \"Warning\"\n\n[rules]\ndisable_rules = [\"AS-005\"]\n",
     )
     .unwrap();
 
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
-    fs::write(
-        skills_dir.join("SKILL.md"),
-        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    let output = agnix()
+        .current_dir(temp_dir.path())
+        .arg(temp_dir.path().to_str().unwrap())
+        .args(["--check-config", "--format", "json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let unknown = json["unknown_keys"].as_array().unwrap();
+    assert_eq!(unknown.len(), 1);
+    assert_eq!(unknown[0]["key"], "rules.disable_rules");
+    assert_eq!(unknown[0]["suggestion"], "rules.disabled_rules");
+
+    let mut cmd = agnix();
+    cmd.current_dir(temp_dir.path())
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--check-config")
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains("1 unknown key(s)"))
+        .stdout(predicate::str::contains(
+            "did you mean 'rules.disabled_rules'?",
+        ));
+}
+
+#[test]
+fn test_check_config_clean_config_succeeds() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        temp_dir.path().join(".agnix.toml"),
+        "[rules]\ndisabled_rules = [\"AS-005\"]\n",
     )
     .unwrap();
 
-    // AGNIX_LOCALE=es should be picked up by detect_locale() which runs before
-    // config is loaded, so env var takes effect for initial output.
-    // However, locale re-initialization happens after config load only when no --locale flag.
-    // The priority is: --locale > config > env var (inside detect_locale).
-    // Actually per the code: locale::init(cli_locale, None) first, then
-    // if cli.locale.is_none() && config.locale.is_some(), re-init with config locale.
-    // So config locale overrides env var when no --locale flag is set.
-    // This test documents that behavior.
     let mut cmd = agnix();
-    let output = cmd
-        .env("AGNIX_LOCALE", "es")
-        .env_remove("LANG")
-        .env_remove("LC_ALL")
+    cmd.current_dir(temp_dir.path())
         .arg(temp_dir.path().to_str().unwrap())
-        .arg("--config")
-        .arg(&config_path)
+        .arg("--check-config")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No unknown keys"));
+}
+
+// ============================================================================
+// Environment-variable config overrides
+// ============================================================================
+
+fn env_override_project() -> tempfile::TempDir {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let skill_dir = temp_dir.path().join("skills").join("bad-skill");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: Bad-Skill\ndescription: test\n---\nContent\n",
+    )
+    .unwrap();
+    let claude_dir = temp_dir.path().join(".claude");
+    std::fs::create_dir_all(&claude_dir).unwrap();
+    std::fs::write(
+        claude_dir.join("settings.json"),
+        r#"{"hooks": {"NotARealEvent": [{"hooks": [{"type": "command", "command": "echo hi"}]}]}}"#,
+    )
+    .unwrap();
+    temp_dir
+}
+
+fn env_override_rules(cmd: &mut Command, dir: &std::path::Path) -> Vec<String> {
+    let output = cmd
+        .arg(dir.to_str().unwrap())
+        .arg("--format")
+        .arg("json")
         .output()
         .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["rule"].as_str().unwrap().to_string())
+        .collect()
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
+#[test]
+fn test_env_disabled_rules_override_config_file() {
+    let temp_dir = env_override_project();
+    std::fs::write(
+        temp_dir.path().join(".agnix.toml"),
+        "[rules]\ndisabled_rules = [\"CC-HK-001\"]\n",
+    )
+    .unwrap();
 
-    // When no --locale flag: config locale takes precedence over AGNIX_LOCALE
-    // because the code re-initializes locale from config after loading it.
-    // The initial locale::init picks up AGNIX_LOCALE but then config overrides it.
-    // This verifies the re-initialization logic works.
-    assert!(
-        stdout.contains("Validating:"),
-        "Config locale='en' should override AGNIX_LOCALE=es (no --locale flag), got: {}",
-        stdout
+    let rules = env_override_rules(agnix().current_dir(temp_dir.path()), temp_dir.path());
+    assert!(rules.contains(&"AS-004".to_string()), "{:?}", rules);
+    assert!(!rules.contains(&"CC-HK-001".to_string()), "{:?}", rules);
+
+    // The environment list replaces the file's list
+    let rules = env_override_rules(
+        agnix()
+            .current_dir(temp_dir.path())
+            .env("AGNIX_DISABLED_RULES", "AS-004"),
+        temp_dir.path(),
     );
+    assert!(!rules.contains(&"AS-004".to_string()), "{:?}", rules);
+    assert!(rules.contains(&"CC-HK-001".to_string()), "{:?}", rules);
 }
 
 #[test]
-fn test_invalid_locale_warns_and_falls_back_to_english() {
-    use std::fs;
+fn test_cli_target_overrides_env_target() {
+    let temp_dir = env_override_project();
+
+    let rules = env_override_rules(agnix().env("AGNIX_TARGET", "cursor"), temp_dir.path());
+    assert!(!rules.contains(&"CC-HK-001".to_string()), "{:?}", rules);
+
+    let rules = env_override_rules(
+        agnix()
+            .env("AGNIX_TARGET", "cursor")
+            .arg("--target")
+            .arg("claude-code"),
+        temp_dir.path(),
+    );
+    assert!(rules.contains(&"CC-HK-001".to_string()), "{:?}", rules);
+}
 
+#[test]
+fn test_env_strict_and_custom_prefix() {
     let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
-    fs::write(
-        skills_dir.join("SKILL.md"),
-        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    std::fs::write(
+        temp_dir.path().join("CLAUDE.md"),
+        "# Project\n\nBe helpful and accurate.\n",
     )
     .unwrap();
 
-    let mut cmd = agnix();
-    let output = cmd
+    let output = agnix()
         .arg(temp_dir.path().to_str().unwrap())
-        .arg("--locale")
-        .arg("xx-INVALID")
+        .arg("--format")
+        .arg("json")
         .output()
         .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["summary"]["errors"], 0);
+    assert!(json["summary"]["warnings"].as_u64().unwrap() > 0);
+    assert!(output.status.success());
 
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let stdout = String::from_utf8_lossy(&output.stdout);
-
-    // Should warn about unsupported locale on stderr
-    assert!(
-        stderr.contains("unsupported locale") || stderr.contains("Warning"),
-        "Invalid locale should produce warning on stderr, got stderr: {}",
-        stderr
-    );
+    agnix()
+        .env("AGNIX_STRICT", "true")
+        .arg(temp_dir.path().to_str().unwrap())
+        .assert()
+        .failure();
 
-    // Should fall back to English output
-    assert!(
-        stdout.contains("Validating:"),
-        "Invalid locale should fall back to English output, got: {}",
-        stdout
-    );
+    // Variables outside the configured prefix are ignored
+    agnix()
+        .env("AGNIX_STRICT", "true")
+        .arg("--config-env-prefix")
+        .arg("CI_LINT_")
+        .arg(temp_dir.path().to_str().unwrap())
+        .assert()
+        .success();
+    agnix()
+        .env("CI_LINT_STRICT", "1")
+        .arg("--config-env-prefix")
+        .arg("CI_LINT_")
+        .arg(temp_dir.path().to_str().unwrap())
+        .assert()
+        .failure();
 }
 
 #[test]
-fn test_invalid_locale_in_config_warns_and_falls_back() {
-    use std::fs;
+fn test_invalid_env_value_warns() {
+    agnix()
+        .env("AGNIX_SEVERITY", "loud")
+        .arg("tests/fixtures/valid")
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("AGNIX_SEVERITY"));
+}
 
-    let temp_dir = tempfile::tempdir().unwrap();
+// ============================================================================
+// --only-fixable / --exit-zero
+// ============================================================================
 
-    // Config with invalid locale
-    let config_path = temp_dir.path().join(".agnix.toml");
-    fs::write(
-        &config_path,
-        "severity = \"Warning\"\ntarget = \"Generic\"\nlocale = \"xx-INVALID\"\nexclude = []\n\n[rules]\n",
+fn only_fixable_project() -> tempfile::TempDir {
+    let temp_dir = tempfile::tempdir().unwrap();
+    // AS-004 (uppercase name) has an auto-fix
+    let fixable = temp_dir.path().join("skills").join("bad-skill");
+    std::fs::create_dir_all(&fixable).unwrap();
+    std::fs::write(
+        fixable.join("SKILL.md"),
+        "---\nname: Bad-Skill\ndescription: Use when testing fixes\n---\nContent\n",
     )
     .unwrap();
-
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
-    fs::write(
-        skills_dir.join("SKILL.md"),
-        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+    // AS-008 (empty description) has no auto-fix
+    let unfixable = temp_dir.path().join("skills").join("empty-description");
+    std::fs::create_dir_all(&unfixable).unwrap();
+    std::fs::write(
+        unfixable.join("SKILL.md"),
+        "---\nname: empty-description\ndescription: \"\"\n---\nContent\n",
     )
     .unwrap();
+    temp_dir
+}
 
-    let mut cmd = agnix();
-    let output = cmd
-        .env_remove("AGNIX_LOCALE")
-        .env_remove("LANG")
-        .env_remove("LC_ALL")
+#[test]
+fn test_only_fixable_filters_output_but_not_exit_code() {
+    let temp_dir = only_fixable_project();
+
+    let all = env_override_rules(&mut agnix(), temp_dir.path());
+    assert!(all.contains(&"AS-004".to_string()), "{:?}", all);
+    assert!(all.contains(&"AS-008".to_string()), "{:?}", all);
+
+    let output = agnix()
         .arg(temp_dir.path().to_str().unwrap())
-        .arg("--config")
-        .arg(&config_path)
+        .arg("--format")
+        .arg("json")
+        .arg("--only-fixable")
         .output()
         .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let rules: Vec<&str> = json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["rule"].as_str().unwrap())
+        .collect();
+    assert!(rules.contains(&"AS-004"), "{:?}", rules);
+    assert!(!rules.contains(&"AS-008"), "{:?}", rules);
+    // The hidden AS-008 error still fails the run
+    assert_eq!(output.status.code(), Some(1));
+
+    agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--only-fixable")
+        .arg("--exit-zero")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Bad-Skill"))
+        .stdout(predicate::str::contains("1-1024 characters").not());
+}
 
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let stdout = String::from_utf8_lossy(&output.stdout);
+// ============================================================================
+// --only
+// ============================================================================
 
-    // Should warn about unsupported locale
-    assert!(
-        stderr.contains("unsupported locale") || stderr.contains("Warning"),
-        "Invalid config locale should produce warning, got stderr: {}",
-        stderr
-    );
+#[test]
+fn test_only_filters_by_rule_prefix_and_exit_code() {
+    let temp_dir = only_fixable_project();
 
-    // Should fall back to English
+    let output = agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--format")
+        .arg("json")
+        .arg("--only")
+        .arg("as-004")
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let rules: Vec<&str> = json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["rule"].as_str().unwrap())
+        .collect();
+    assert!(rules.contains(&"AS-004"), "{:?}", rules);
+    assert!(!rules.contains(&"AS-008"), "{:?}", rules);
+
+    // AS-004 is an error, so the narrowed run still fails
+    assert_eq!(output.status.code(), Some(1));
+
+    // Repeated prefixes are combined; filtered-out errors no longer fail the run
+    agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--only")
+        .arg("MCP-")
+        .arg("--only")
+        .arg("XP-")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("AS-").not());
+}
+
+// ============================================================================
+// --quiet
+// ============================================================================
+
+#[test]
+fn test_quiet_prints_only_diagnostic_lines() {
+    let temp_dir = only_fixable_project();
+
+    let output = agnix()
+        .arg(temp_dir.path().to_str().unwrap())
+        .arg("--quiet")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(!stdout.contains("Validating"), "{}", stdout);
+    assert!(!stdout.contains("Found"), "{}", stdout);
+    assert!(!stdout.contains("hint"), "{}", stdout);
+    let lines: Vec<&str> = stdout.lines().collect();
+    assert!(!lines.is_empty(), "{}", stdout);
     assert!(
-        stdout.contains("Validating:"),
-        "Invalid config locale should fall back to English, got stdout: {}",
+        lines.iter().all(|line| line.contains("SKILL.md:")),
+        "{}",
         stdout
     );
 }
 
 #[test]
-fn test_locale_flag_with_json_output_still_works() {
-    use std::fs;
-
-    // Locale flag should not affect JSON structure (JSON always uses keys not messages)
+fn test_quiet_prints_nothing_for_a_clean_project() {
     let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("test-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
-    fs::write(
-        skills_dir.join("SKILL.md"),
-        "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
-    )
-    .unwrap();
+    std::fs::write(temp_dir.path().join("README.md"), "# Nothing to lint\n").unwrap();
 
-    let mut cmd = agnix();
-    let output = cmd
+    agnix()
         .arg(temp_dir.path().to_str().unwrap())
-        .arg("--locale")
-        .arg("es")
+        .arg("--quiet")
+        .assert()
+        .success()
+        .stdout(predicate::str::is_empty());
+}
+
+// ============================================================================
+// --stdin-paths
+// ============================================================================
+
+#[test]
+fn test_stdin_paths_validates_only_listed_files() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    for dir in ["staged", "unstaged"] {
+        let skill_dir = temp_dir.path().join(dir);
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
+        )
+        .unwrap();
+    }
+    std::fs::write(temp_dir.path().join("notes.txt"), "not a config").unwrap();
+
+    let output = agnix()
+        .current_dir(temp_dir.path())
+        .arg("--stdin-paths")
         .arg("--format")
         .arg("json")
+        .write_stdin("staged/SKILL.md\n\nnotes.txt\n")
         .output()
         .unwrap();
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let json: Result<serde_json::Value, _> = serde_json::from_str(&stdout);
-    assert!(
-        json.is_ok(),
-        "--locale es with --format json should produce valid JSON, got: {}",
-        stdout
-    );
-
-    let json = json.unwrap();
-    assert!(
-        json["version"].is_string(),
-        "JSON should have version field"
-    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["files_checked"], 1, "{}", json);
+    let files: Vec<&str> = json["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["file"].as_str().unwrap())
+        .collect();
+    assert!(!files.is_empty());
     assert!(
-        json["diagnostics"].is_array(),
-        "JSON should have diagnostics array"
+        files.iter().all(|f| f.ends_with("staged/SKILL.md")),
+        "{:?}",
+        files
     );
+    // CC-SK-006 is an error, so the exit code matches a full run
+    assert_eq!(output.status.code(), Some(1));
+
+    // Nothing recognized on stdin means nothing to report
+    agnix()
+        .current_dir(temp_dir.path())
+        .arg("--stdin-paths")
+        .write_stdin("notes.txt\n")
+        .assert()
+        .success();
 }
 
 #[test]
-fn test_help_shows_locale_flags() {
-    let mut cmd = agnix();
-    let output = cmd.arg("--help").output().unwrap();
-
-    let stdout = String::from_utf8_lossy(&output.stdout);
+fn test_stdin_paths_fix_revalidates_only_listed_files() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let staged = temp_dir.path().join("staged");
+    let unstaged = temp_dir.path().join("unstaged");
+    std::fs::create_dir_all(&staged).unwrap();
+    std::fs::create_dir_all(&unstaged).unwrap();
+    std::fs::write(
+        staged.join("SKILL.md"),
+        "---\nname: Test_Skill_Name\ndescription: Use when testing\n---\nContent",
+    )
+    .unwrap();
+    std::fs::write(
+        unstaged.join("SKILL.md"),
+        "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
+    )
+    .unwrap();
 
+    // The unlisted file's error must not fail the run once the listed one is fixed
+    agnix()
+        .current_dir(temp_dir.path())
+        .arg("--stdin-paths")
+        .arg("--fix")
+        .write_stdin("staged/SKILL.md\n")
+        .assert()
+        .success();
     assert!(
-        stdout.contains("--locale"),
-        "Help should show --locale flag, got: {}",
-        stdout
-    );
-    assert!(
-        stdout.contains("--list-locales"),
-        "Help should show --list-locales flag, got: {}",
-        stdout
+        std::fs::read_to_string(staged.join("SKILL.md"))
+            .unwrap()
+            .contains("name: test-skill-name")
     );
 }
 
 #[test]
-fn test_locale_es_translates_diagnostic_messages() {
-    use std::fs;
-    use std::io::Write;
+fn test_stdin_paths_conflicts_with_interactive() {
+    agnix()
+        .arg("--stdin-paths")
+        .arg("--interactive")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
 
-    let temp_dir = tempfile::tempdir().unwrap();
-    let skills_dir = temp_dir.path().join("skills").join("bad-skill");
-    fs::create_dir_all(&skills_dir).unwrap();
+fn git(dir: &std::path::Path, args: &[&str]) {
+    let status = std::process::Command::new("git")
+        .args([
+            "-c",
+            "user.name=agnix",
+            "-c",
+            "user.email=agnix@example.com",
+        ])
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .status()
+        .unwrap();
+    assert!(status.success(), "git {:?} failed", args);
+}
 
-    let skill_path = skills_dir.join("SKILL.md");
-    let mut file = fs::File::create(&skill_path).unwrap();
-    // Invalid name triggers AS-004 error
-    writeln!(
-        file,
-        "---\nname: Bad-Skill\ndescription: test\n---\nContent"
+#[test]
+fn test_diff_reports_only_changed_lines_of_changed_files() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let root = temp_dir.path();
+    let claude_md = "# Project\n\nBe helpful and accurate.\n\n## Commands\n\n- npm test\n";
+    std::fs::write(root.join("CLAUDE.md"), claude_md).unwrap();
+    std::fs::write(
+        root.join("AGENTS.md"),
+        "# Agents\n\nBe helpful and accurate.\n",
+    )
+    .unwrap();
+    git(root, &["init", "-q"]);
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "init"]);
+
+    // One new generic instruction in CLAUDE.md; AGENTS.md is untouched
+    std::fs::write(
+        root.join("CLAUDE.md"),
+        format!("{}\nAlways be concise.\n", claude_md),
     )
     .unwrap();
 
+    let output = agnix()
+        .current_dir(root)
+        .args(["--diff", "HEAD", "--format", "json"])
+        .output()
+        .unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(json["files_checked"], 1, "{}", json);
+    let diagnostics = json["diagnostics"].as_array().unwrap();
+    assert!(!diagnostics.is_empty(), "{}", json);
+    for diag in diagnostics {
+        assert!(
+            diag["file"].as_str().unwrap().ends_with("CLAUDE.md"),
+            "{}",
+            diag
+        );
+        assert_eq!(diag["line"], 9, "{}", diag);
+    }
+
+    // Nothing changed relative to the working tree itself
+    git(root, &["add", "."]);
+    git(root, &["commit", "-q", "-m", "concise"]);
+    agnix()
+        .current_dir(root)
+        .args(["--diff", "HEAD"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No issues found"));
+}
+
+#[test]
+fn test_diff_unknown_ref_fails() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    git(temp_dir.path(), &["init", "-q"]);
+
+    agnix()
+        .current_dir(temp_dir.path())
+        .args(["--diff", "no-such-ref"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("git diff"));
+}
+
+#[test]
+fn test_stats_command_aggregates_rules_and_file_types() {
     let mut cmd = agnix();
     let output = cmd
-        .arg(temp_dir.path().to_str().unwrap())
-        .arg("--locale")
-        .arg("es")
+        .args(["stats", "tests/fixtures/mcp", "--format", "json"])
         .output()
         .unwrap();
+    assert!(output.status.success());
+
+    let stats: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let files_checked = stats["files_checked"].as_u64().unwrap();
+    assert!(files_checked > 0);
+    let file_types = stats["file_types"].as_array().unwrap();
+    assert_eq!(file_types[0]["file_type"], "Mcp");
+    assert_eq!(file_types[0]["files"].as_u64(), Some(files_checked));
+
+    let rules = stats["rules"].as_array().unwrap();
+    assert!(rules.iter().any(|r| r["rule"] == "MCP-005"));
+    let totals: Vec<u64> = rules.iter().map(|r| r["total"].as_u64().unwrap()).collect();
+    assert!(totals.windows(2).all(|pair| pair[0] >= pair[1]));
+}
 
-    let stdout = String::from_utf8_lossy(&output.stdout);
+#[test]
+fn test_doctor_reports_setup_issues() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let root = temp_dir.path();
+    std::fs::create_dir_all(root.join(".claude/skills/orphan")).unwrap();
+    std::fs::create_dir_all(root.join(".cursor/rules")).unwrap();
+    std::fs::write(
+        root.join(".claude/settings.json"),
+        r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "./hooks/done.sh"}]}]}}"#,
+    )
+    .unwrap();
 
-    // Spanish AS-004 message contains "debe tener" (must have) or
-    // the Spanish summary "Encontrados" (Found)
-    assert!(
-        stdout.contains("Encontrados")
-            || stdout.contains("debe tener")
-            || stdout.contains("minusculas"),
-        "--locale es should produce Spanish diagnostic output, got: {}",
-        stdout
-    );
+    agnix()
+        .arg("doctor")
+        .arg(root)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("[missing-hook-script]"))
+        .stdout(predicate::str::contains("./hooks/done.sh"))
+        .stdout(predicate::str::contains("[skill-without-manifest]"))
+        .stdout(predicate::str::contains("[empty-rules-directory]"))
+        .stdout(predicate::str::contains("Found 3 setup issue(s)"));
+
+    std::fs::create_dir_all(root.join("hooks")).unwrap();
+    std::fs::write(root.join("hooks/done.sh"), "#!/bin/sh\n").unwrap();
+    std::fs::write(root.join(".claude/skills/orphan/SKILL.md"), "---\n---\n").unwrap();
+    std::fs::write(root.join(".cursor/rules/style.mdc"), "---\n---\n").unwrap();
+
+    agnix()
+        .arg("doctor")
+        .arg(root)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("No setup issues found"));
 }
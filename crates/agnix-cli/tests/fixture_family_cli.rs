@@ -14,10 +14,10 @@ fn workspace_root() -> &'static std::path::Path {
         let manifest_dir = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         for ancestor in manifest_dir.ancestors() {
             let cargo_toml = ancestor.join("Cargo.toml");
-            if let Ok(content) = std::fs::read_to_string(&cargo_toml) {
-                if content.contains("[workspace]") || content.contains("[workspace.") {
-                    return ancestor.to_path_buf();
-                }
+            if let Ok(content) = std::fs::read_to_string(&cargo_toml)
+                && (content.contains("[workspace]") || content.contains("[workspace."))
+            {
+                return ancestor.to_path_buf();
             }
         }
         panic!(
@@ -83,6 +83,11 @@ make_cli_test!(
 );
 // REF-001 still fires on generic markdown (tests @import syntax)
 make_cli_test!(test_cli_reports_ref_001_fixtures, "refs", ["REF-001"]);
+make_cli_test!(
+    test_cli_reports_ref_010_fixtures,
+    "refs/undefined-reference",
+    ["REF-010"]
+);
 
 // REF-002 only fires on agent config files, so we need a CLAUDE.md with broken links
 #[test]
@@ -109,6 +114,35 @@ make_cli_test!(
     "cross_platform/hard-coded",
     ["XP-003"]
 );
+make_cli_test!(
+    test_cli_reports_xp_011_fixtures,
+    "cross_platform/missing-agent",
+    ["XP-011"]
+);
+
+#[test]
+fn test_cli_existing_agent_reference_has_no_xp_011() {
+    let path = workspace_root().join("tests/fixtures/cross_platform/existing-agent");
+    let json = run_json(&path);
+    assert_eq!(count_rule(&json, "XP-011"), 0);
+}
+make_cli_test!(
+    test_cli_reports_xp_012_fixtures,
+    "cross_platform/conflicting-models",
+    ["XP-012"]
+);
+
+#[test]
+fn test_cli_xp_012_reports_agent_and_skill() {
+    let path = workspace_root().join("tests/fixtures/cross_platform/conflicting-models");
+    let json = run_json(&path);
+    assert_eq!(count_rule(&json, "XP-012"), 2);
+}
+make_cli_test!(
+    test_cli_reports_xp_013_fixtures,
+    "cross_platform/with-imports",
+    ["XP-013"]
+);
 make_cli_test!(
     test_cli_reports_gemini_md_fixtures,
     "gemini_md-invalid",
@@ -121,6 +155,24 @@ make_cli_test!(
     ["CDX-001", "CDX-002", "CDX-003"]
 );
 
+make_cli_test!(
+    test_cli_reports_windsurf_invalid_fixtures,
+    "windsurf-invalid",
+    ["WS-001", "WS-002", "WS-003", "WS-004"]
+);
+
+make_cli_test!(
+    test_cli_reports_aider_invalid_fixtures,
+    "aider-invalid",
+    ["AIDER-001"]
+);
+
+make_cli_test!(
+    test_cli_reports_conflict_marker_fixtures,
+    "conflict-markers",
+    ["GEN-002"]
+);
+
 #[test]
 fn test_cli_codex_invalid_fixture_counts() {
     let path = workspace_root().join("tests/fixtures/codex-invalid");
@@ -1,6 +1,6 @@
 //! Rule parity integration tests.
 //!
-//! Ensures all 145 rules from knowledge-base/rules.json are:
+//! Ensures all 181 rules from knowledge-base/rules.json are:
 
 //! 1. Registered in SARIF output (sarif.rs)
 //! 2. Implemented in agnix-core/src/rules/*.rs
@@ -124,10 +124,10 @@ fn workspace_root() -> &'static Path {
         let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
         for ancestor in manifest_dir.ancestors() {
             let cargo_toml = ancestor.join("Cargo.toml");
-            if let Ok(content) = fs::read_to_string(&cargo_toml) {
-                if content.contains("[workspace]") || content.contains("[workspace.") {
-                    return ancestor.to_path_buf();
-                }
+            if let Ok(content) = fs::read_to_string(&cargo_toml)
+                && (content.contains("[workspace]") || content.contains("[workspace."))
+            {
+                return ancestor.to_path_buf();
             }
         }
         panic!(
@@ -166,7 +166,8 @@ fn extract_implemented_rule_ids() -> BTreeSet<String> {
     // Known rule ID prefixes to filter out false positives
     let valid_prefixes = [
         "AS-", "CC-SK-", "CC-HK-", "CC-AG-", "CC-MEM-", "CC-PL-", "AGM-", "MCP-", "COP-", "CUR-",
-        "CLN-", "CDX-", "OC-", "GM-", "XML-", "REF-", "PE-", "XP-", "VER-",
+        "CLN-", "CDX-", "OC-", "GM-", "WS-", "AIDER-", "XML-", "REF-", "PE-", "XP-", "VER-",
+        "GEN-",
     ];
 
     fn extract_from_file(
@@ -285,13 +286,13 @@ fn infer_fixture_coverage(rules: &[RuleEntry]) -> HashMap<String, Vec<String>> {
         ("claude-memory", vec!["valid/memory", "invalid/memory"]),
         ("claude-plugins", vec!["valid/plugins", "invalid/plugins"]),
         ("agents-md", vec!["agents_md"]),
-        ("mcp", vec!["mcp"]),
+        ("mcp", vec!["mcp", "mcp-duplicate-names"]),
         (
             "copilot",
             vec!["copilot", "copilot-invalid", "copilot-too-long"],
         ),
         ("cursor", vec!["cursor", "cursor-invalid", "cursor-legacy"]),
-        ("cline", vec!["cline", "cline-invalid"]),
+        ("cline", vec!["cline", "cline-invalid", "cline-single"]),
         ("xml", vec!["xml"]),
         ("references", vec!["refs"]),
         (
@@ -302,6 +303,12 @@ fn infer_fixture_coverage(rules: &[RuleEntry]) -> HashMap<String, Vec<String>> {
         ("opencode", vec!["opencode", "opencode-invalid"]),
         ("gemini-cli", vec!["gemini_md", "gemini_md-invalid"]),
         ("codex", vec!["codex", "codex-invalid"]),
+        (
+            "windsurf",
+            vec!["windsurf", "windsurf-invalid", "windsurf-legacy"],
+        ),
+        ("aider", vec!["aider", "aider-invalid"]),
+        ("general", vec!["conflict-markers"]),
     ]
     .into_iter()
     .collect();
@@ -452,8 +459,8 @@ fn test_rules_json_integrity() {
     // Check total count matches expected
     assert_eq!(
         rules_index.rules.len(),
-        145,
-        "Expected 145 rules in rules.json, found {}",
+        181,
+        "Expected 181 rules in rules.json, found {}",
         rules_index.rules.len()
     );
 
@@ -499,6 +506,9 @@ fn test_rules_json_integrity() {
         "cross-platform",
         "opencode",
         "version-awareness",
+        "windsurf",
+        "aider",
+        "general",
     ];
     for rule in &rules_index.rules {
         assert!(
@@ -549,12 +559,12 @@ fn test_rules_json_matches_validation_rules_md() {
 fn test_sarif_rule_count() {
     let sarif_rules = extract_sarif_rule_ids();
 
-    // SARIF should have exactly 145 rules to match rules.json
+    // SARIF should have exactly 181 rules to match rules.json
 
     assert_eq!(
         sarif_rules.len(),
-        145,
-        "SARIF should have 145 rules, found {}. Missing or extra rules detected.",
+        181,
+        "SARIF should have 181 rules, found {}. Missing or extra rules detected.",
         sarif_rules.len()
     );
 }
@@ -216,6 +216,25 @@ pub fn create_scale_project(file_count: usize) -> TempDir {
     temp
 }
 
+/// Create a project of `skill_count` minimal skills, one per directory.
+///
+/// Each SKILL.md is a few lines, so validation is dominated by per-file
+/// scheduling overhead rather than validator work, as in skill-heavy monorepos.
+pub fn create_small_skill_project(skill_count: usize) -> TempDir {
+    let temp = TempDir::new().expect("Failed to create temp directory");
+
+    for i in 0..skill_count {
+        let skill_dir = temp.path().join("skills").join(format!("skill-{}", i));
+        fs::create_dir_all(&skill_dir).expect("Failed to create skill directory");
+        let content = format!(
+            "---\nname: skill-{i}\ndescription: Use when running task {i}\n---\nRun task {i}.\n"
+        );
+        fs::write(skill_dir.join("SKILL.md"), content).expect("Failed to write SKILL.md");
+    }
+
+    temp
+}
+
 /// Create a project optimized for memory tracking benchmarks.
 ///
 /// Features deep import chains to stress the ImportCache.
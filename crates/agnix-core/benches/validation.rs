@@ -5,10 +5,11 @@
 //! These benchmarks measure:
 //! - File type detection speed
 //! - Validator registry construction
+//! - Rule enablement lookup (on-demand vs precomputed)
 //! - Single file validation (various file types)
 //! - Project validation throughput
 //! - Frontmatter parsing speed
-//! - Scale testing (100, 1000 files)
+//! - Scale testing (100, 1000 files, 5000 small skills)
 //! - Memory usage tracking
 //!
 //! For deterministic CI benchmarks, use iai_validation.rs instead.
@@ -25,7 +26,7 @@ use agnix_core::{
     validate_project,
 };
 
-use fixtures::{create_memory_test_project, create_scale_project};
+use fixtures::{create_memory_test_project, create_scale_project, create_small_skill_project};
 
 /// Benchmark file type detection - the first step in validation dispatch.
 fn bench_detect_file_type(c: &mut Criterion) {
@@ -290,6 +291,37 @@ fn bench_registry_caching(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark rule enablement checks against a long `disabled_rules` list.
+fn bench_rule_lookup(c: &mut Criterion) {
+    let mut config = LintConfig::default();
+    config.rules.disabled_rules = (0..5_000).map(|i| format!("XP-{:05}", i)).collect();
+    let mut precomputed = config.clone();
+    precomputed.precompute_rule_lookup();
+
+    let checks = ["AS-001", "CC-HK-010", "MCP-003", "XP-04999", "PE-001"];
+    let mut group = c.benchmark_group("rule_lookup");
+
+    group.bench_function("on_demand", |b| {
+        b.iter(|| {
+            checks
+                .iter()
+                .filter(|rule_id| config.is_rule_enabled(black_box(rule_id)))
+                .count()
+        })
+    });
+
+    group.bench_function("precomputed", |b| {
+        b.iter(|| {
+            checks
+                .iter()
+                .filter(|rule_id| precomputed.is_rule_enabled(black_box(rule_id)))
+                .count()
+        })
+    });
+
+    group.finish();
+}
+
 /// Benchmark frontmatter parsing speed.
 fn bench_frontmatter_parsing(c: &mut Criterion) {
     use agnix_core::parsers::frontmatter::split_frontmatter;
@@ -427,6 +459,23 @@ fn bench_scale_1000_files(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark scale testing with 5000 tiny SKILL.md files.
+///
+/// Per-file work is small, so this measures how much the parallel file
+/// batching saves over scheduling one task per file.
+fn bench_scale_5000_small_skills(c: &mut Criterion) {
+    let temp = create_small_skill_project(5000);
+    let config = LintConfig::default();
+
+    let mut group = c.benchmark_group("scale_testing");
+    group.sample_size(10);
+    group.throughput(Throughput::Elements(5000));
+    group.bench_function("5000_small_skills", |b| {
+        b.iter(|| validate_project(black_box(temp.path()), &config))
+    });
+    group.finish();
+}
+
 /// Benchmark memory usage during validation.
 ///
 /// Tests validation performance with a project optimized for memory stress:
@@ -482,10 +531,12 @@ criterion_group!(
     bench_validate_single_file,
     bench_validate_project,
     bench_registry_caching,
+    bench_rule_lookup,
     bench_frontmatter_parsing,
     bench_import_cache,
     bench_scale_100_files,
     bench_scale_1000_files,
+    bench_scale_5000_small_skills,
     bench_memory_usage,
     bench_single_file_target,
 );
@@ -0,0 +1,266 @@
+//! Persistent cache of per-file cross-layer analysis (XP-004/005/006).
+//!
+//! The project-level XP pass extracts build commands, tool constraints and
+//! precedence documentation from every instruction file. That extraction only
+//! depends on file content, so results are stored keyed by a content hash in
+//! `<cache_dir>/project-analysis.json` and reused for unchanged files on the
+//! next run. Without a cache dir the cache lives in memory for one run only.
+//!
+//! The cache is best-effort: a missing, unreadable or stale-format file is
+//! treated as empty, and write failures are logged and ignored.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::file_utils;
+use crate::schemas::cross_platform::{
+    self, BuildCommand, InstructionLayer, ToolConstraint, layer_type_for_path,
+};
+
+const CACHE_FILE_NAME: &str = "project-analysis.json";
+
+/// Bumped whenever `FileAnalysis` changes shape; entries from other formats
+/// or agnix versions are discarded since extraction logic may differ.
+const CACHE_FORMAT: u32 = 1;
+
+/// Content-derived analysis of one instruction file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct FileAnalysis {
+    pub build_commands: Vec<BuildCommand>,
+    pub tool_constraints: Vec<ToolConstraint>,
+    pub has_precedence_doc: bool,
+}
+
+impl FileAnalysis {
+    /// Run every XP extraction over `content`.
+    pub fn extract(content: &str) -> Self {
+        Self {
+            build_commands: cross_platform::extract_build_commands(content),
+            tool_constraints: cross_platform::extract_tool_constraints(content),
+            has_precedence_doc: cross_platform::has_precedence_doc(content),
+        }
+    }
+
+    /// Instruction layer for the file at `path` (XP-006).
+    pub fn layer(&self, path: &Path) -> InstructionLayer {
+        InstructionLayer {
+            path: path.to_path_buf(),
+            layer_type: layer_type_for_path(path),
+            has_precedence_doc: self.has_precedence_doc,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    format: u32,
+    version: String,
+    entries: HashMap<String, FileAnalysis>,
+}
+
+/// Stable hash of file content used as the cache key (64-bit FNV-1a plus length).
+pub(crate) fn content_hash(content: &str) -> String {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in content.as_bytes() {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    format!("{:016x}-{}", hash, content.len())
+}
+
+/// Per-file analysis cache for one project validation run.
+pub(crate) struct AnalysisCache {
+    path: Option<PathBuf>,
+    entries: HashMap<String, FileAnalysis>,
+    /// Keys looked up this run; only these are written back, dropping stale entries.
+    used: HashSet<String>,
+    dirty: bool,
+}
+
+impl AnalysisCache {
+    /// Load the cache from `cache_dir`, or start an in-memory cache when `None`.
+    pub fn load(cache_dir: Option<&Path>) -> Self {
+        let path = cache_dir.map(|dir| dir.join(CACHE_FILE_NAME));
+        let entries = path
+            .as_deref()
+            .and_then(|path| file_utils::safe_read_file(path).ok())
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|file| file.format == CACHE_FORMAT && file.version == env!("CARGO_PKG_VERSION"))
+            .map(|file| file.entries)
+            .unwrap_or_default();
+
+        Self {
+            path,
+            entries,
+            used: HashSet::new(),
+            dirty: false,
+        }
+    }
+
+    /// Analysis for `content`, running `extract` only on a cache miss.
+    pub fn get_or_extract(
+        &mut self,
+        content: &str,
+        extract: impl FnOnce(&str) -> FileAnalysis,
+    ) -> FileAnalysis {
+        let key = content_hash(content);
+        self.used.insert(key.clone());
+        if let Some(analysis) = self.entries.get(&key) {
+            return analysis.clone();
+        }
+
+        let analysis = extract(content);
+        self.entries.insert(key, analysis.clone());
+        self.dirty = true;
+        analysis
+    }
+
+    /// Write entries used this run back to the cache dir, if one was given.
+    pub fn save(mut self) {
+        let Some(path) = self.path.take() else {
+            return;
+        };
+        // Nothing new and nothing stale to drop
+        if !self.dirty && self.used.len() == self.entries.len() {
+            return;
+        }
+
+        let used = self.used;
+        self.entries.retain(|key, _| used.contains(key));
+        let file = CacheFile {
+            format: CACHE_FORMAT,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            entries: self.entries,
+        };
+        let json = match serde_json::to_string(&file) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::debug!(error = %e, "Failed to serialize analysis cache");
+                return;
+            }
+        };
+
+        let result = match path.parent() {
+            Some(dir) => std::fs::create_dir_all(dir).map_err(|e| e.to_string()),
+            None => Ok(()),
+        }
+        .and_then(|_| {
+            if path.exists() {
+                file_utils::safe_write_file(&path, &json)
+            } else {
+                file_utils::safe_create_file(&path, &json)
+            }
+            .map_err(|e| e.to_string())
+        });
+        if let Err(e) = result {
+            tracing::debug!(path = %path.display(), error = %e, "Failed to write analysis cache");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    const CONTENT: &str = "# Project\n\nRun `npm install` then `npm test`.\n";
+
+    fn counting_extract(calls: &Cell<usize>) -> impl Fn(&str) -> FileAnalysis + '_ {
+        move |content| {
+            calls.set(calls.get() + 1);
+            FileAnalysis::extract(content)
+        }
+    }
+
+    #[test]
+    fn test_cache_hit_skips_extraction_across_runs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let calls = Cell::new(0);
+
+        let mut cache = AnalysisCache::load(Some(temp.path()));
+        let first = cache.get_or_extract(CONTENT, counting_extract(&calls));
+        cache.save();
+        assert_eq!(calls.get(), 1);
+        assert!(temp.path().join(CACHE_FILE_NAME).exists());
+
+        let mut cache = AnalysisCache::load(Some(temp.path()));
+        let second = cache.get_or_extract(CONTENT, counting_extract(&calls));
+        assert_eq!(calls.get(), 1, "unchanged content must not be re-extracted");
+        assert_eq!(second.build_commands.len(), first.build_commands.len());
+        assert_eq!(
+            second.build_commands[0].raw_command,
+            first.build_commands[0].raw_command
+        );
+
+        cache.get_or_extract(
+            "# Changed\n\nUse `pnpm install`.\n",
+            counting_extract(&calls),
+        );
+        assert_eq!(calls.get(), 2, "changed content must be re-extracted");
+    }
+
+    #[test]
+    fn test_without_cache_dir_only_dedupes_within_run() {
+        let calls = Cell::new(0);
+        let mut cache = AnalysisCache::load(None);
+        cache.get_or_extract(CONTENT, counting_extract(&calls));
+        cache.get_or_extract(CONTENT, counting_extract(&calls));
+        cache.save();
+        assert_eq!(calls.get(), 1);
+
+        let mut cache = AnalysisCache::load(None);
+        cache.get_or_extract(CONTENT, counting_extract(&calls));
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_stale_entries_dropped_on_save() {
+        let temp = tempfile::TempDir::new().unwrap();
+
+        let mut cache = AnalysisCache::load(Some(temp.path()));
+        cache.get_or_extract("old content\n", FileAnalysis::extract);
+        cache.get_or_extract(CONTENT, FileAnalysis::extract);
+        cache.save();
+
+        let mut cache = AnalysisCache::load(Some(temp.path()));
+        cache.get_or_extract(CONTENT, FileAnalysis::extract);
+        cache.save();
+
+        let cache = AnalysisCache::load(Some(temp.path()));
+        assert_eq!(cache.entries.len(), 1);
+        assert!(cache.entries.contains_key(&content_hash(CONTENT)));
+    }
+
+    #[test]
+    fn test_corrupt_cache_file_is_ignored() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join(CACHE_FILE_NAME), "not json").unwrap();
+
+        let calls = Cell::new(0);
+        let mut cache = AnalysisCache::load(Some(temp.path()));
+        cache.get_or_extract(CONTENT, counting_extract(&calls));
+        cache.save();
+        assert_eq!(calls.get(), 1);
+
+        let cache = AnalysisCache::load(Some(temp.path()));
+        assert_eq!(cache.entries.len(), 1);
+    }
+
+    #[test]
+    fn test_layer_uses_path_not_cached_content() {
+        let analysis = FileAnalysis::extract("CLAUDE.md takes precedence over AGENTS.md\n");
+        let claude = analysis.layer(Path::new("CLAUDE.md"));
+        let agents = analysis.layer(Path::new("AGENTS.md"));
+        assert_eq!(claude.layer_type, cross_platform::LayerType::ClaudeMd);
+        assert_eq!(agents.layer_type, cross_platform::LayerType::AgentsMd);
+        assert_eq!(claude.has_precedence_doc, agents.has_precedence_doc);
+    }
+
+    #[test]
+    fn test_content_hash_is_stable() {
+        assert_eq!(content_hash(""), "cbf29ce484222325-0");
+        assert_ne!(content_hash("a"), content_hash("b"));
+    }
+}
@@ -50,6 +50,9 @@ struct AuthoringFamily {
 #[derive(Debug, Clone, Default, Deserialize)]
 struct AuthoringKey {
     key: String,
+    /// Expected value type, shown next to key completions and in hover docs
+    #[serde(default, rename = "type")]
+    value_type: Option<String>,
     #[serde(default)]
     docs: String,
     #[serde(default)]
@@ -209,6 +212,23 @@ fn value_insert_text(file_type: FileType, value: &str) -> String {
     }
 }
 
+/// Allowed values for `key`. `model` comes from the validators' lists so
+/// completions never offer a value CC-SK-001 / CC-AG-003 would reject.
+fn key_values(file_type: FileType, key: &AuthoringKey) -> Vec<&str> {
+    match (file_type, key.key.as_str()) {
+        (FileType::Skill, "model") => crate::rules::skill::VALID_MODELS.to_vec(),
+        (FileType::Agent, "model") => crate::rules::agent::VALID_MODELS.to_vec(),
+        _ => key.values.iter().map(String::as_str).collect(),
+    }
+}
+
+fn key_detail(key: &AuthoringKey) -> String {
+    match &key.value_type {
+        Some(value_type) => format!("Field: {}", value_type),
+        None => "Field".to_string(),
+    }
+}
+
 fn dedupe_candidates(candidates: &mut Vec<CompletionCandidate>) {
     let mut seen = std::collections::HashSet::new();
     candidates.retain(|candidate| {
@@ -236,7 +256,7 @@ pub fn completion_candidates(
                 out.push(CompletionCandidate {
                     label: key.key.clone(),
                     insert_text: key_insert_text(file_type, &key.key),
-                    detail: Some("Field".to_string()),
+                    detail: Some(key_detail(key)),
                     documentation: Some(key.docs.clone()),
                     kind: CompletionKind::Key,
                     rule_links: key.rules.clone(),
@@ -256,9 +276,9 @@ pub fn completion_candidates(
         }
         CursorContext::Value(key_name) => {
             if let Some(key) = family.keys.iter().find(|key| key.key == key_name) {
-                for value in &key.values {
+                for value in key_values(file_type, key) {
                     out.push(CompletionCandidate {
-                        label: value.clone(),
+                        label: value.to_string(),
                         insert_text: value_insert_text(file_type, value),
                         detail: Some("Allowed value".to_string()),
                         documentation: Some(key.docs.clone()),
@@ -308,8 +328,14 @@ pub fn hover_doc(file_type: FileType, key: &str) -> Option<HoverDoc> {
         format!("\n\nRules: {}", entry.rules.join(", "))
     };
 
+    let value_type = entry
+        .value_type
+        .as_ref()
+        .map(|value_type| format!(" (`{}`)", value_type))
+        .unwrap_or_default();
+
     Some(HoverDoc {
-        markdown: format!("**{}**\n\n{}{}", entry.key, entry.docs, rules),
+        markdown: format!("**{}**{}\n\n{}{}", entry.key, value_type, entry.docs, rules),
     })
 }
 
@@ -347,6 +373,44 @@ mod tests {
         assert!(candidates.iter().any(|c| c.label == "opus"));
     }
 
+    #[test]
+    fn test_completion_key_detail_shows_value_type() {
+        let content = "---\n\n---\n";
+        let candidates = completion_candidates(FileType::Skill, content, 4);
+        let detail = |label: &str| {
+            candidates
+                .iter()
+                .find(|c| c.kind == CompletionKind::Key && c.label == label)
+                .and_then(|c| c.detail.clone())
+        };
+        assert_eq!(detail("name").as_deref(), Some("Field: string"));
+        assert_eq!(detail("user-invocable").as_deref(), Some("Field: boolean"));
+    }
+
+    #[test]
+    fn test_completion_model_values_match_validators() {
+        let content = "---\nmodel: \n---\n";
+        let byte = content.find("model: ").unwrap() + "model: ".len();
+        for (file_type, valid) in [
+            (FileType::Skill, crate::rules::skill::VALID_MODELS),
+            (FileType::Agent, crate::rules::agent::VALID_MODELS),
+        ] {
+            let values: Vec<String> = completion_candidates(file_type, content, byte)
+                .into_iter()
+                .filter(|c| c.kind == CompletionKind::Value)
+                .map(|c| c.label)
+                .collect();
+            assert_eq!(values, valid, "{:?}", file_type);
+        }
+    }
+
+    #[test]
+    fn test_completion_outside_frontmatter_has_no_keys() {
+        let content = "---\nname: x\n---\nBody text\n";
+        let candidates = completion_candidates(FileType::Agent, content, content.len());
+        assert!(!candidates.iter().any(|c| c.kind == CompletionKind::Key));
+    }
+
     #[test]
     fn test_completion_mcp_json_value_context_quotes_strings() {
         let content = "{\n  \"jsonrpc\": \n}";
@@ -377,6 +441,7 @@ mod tests {
         let markdown = hover.unwrap().markdown;
         assert!(markdown.contains("model"));
         assert!(markdown.contains("CC-SK-001"));
+        assert!(markdown.contains("(`string`)"));
     }
 
     #[test]
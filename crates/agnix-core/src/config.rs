@@ -1,13 +1,16 @@
 //! Linter configuration
 
+use crate::diagnostics::{LintError, LintResult};
 use crate::file_utils::safe_read_file;
 use crate::fs::{FileSystem, RealFileSystem};
+use crate::result_cache::{Access, DependencyRecorder, RecordingFileSystem};
 use crate::schemas::mcp::DEFAULT_MCP_PROTOCOL_VERSION;
 use rust_i18n::t;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 /// Tool version pinning for version-aware validation
 ///
@@ -72,6 +75,7 @@ pub struct SpecRevisions {
 //
 // - RuntimeContext: Groups non-serialized runtime state (root_dir, import_cache, fs)
 // - DefaultRuleFilter: Encapsulates rule filtering logic (~100 lines)
+// - RuleLookup: Precomputed form of DefaultRuleFilter for hot validation loops
 //
 // This pattern provides:
 // 1. Better code organization without breaking changes
@@ -109,12 +113,52 @@ struct RuntimeContext {
     /// Validators use this to perform file system operations. Defaults to
     /// `RealFileSystem` which delegates to `std::fs` and `file_utils`.
     fs: Arc<dyn FileSystem>,
+
+    /// Precomputed rule enablement, set by `LintConfig::precompute_rule_lookup`.
+    rule_lookup: Option<Arc<RuleLookup>>,
+
+    /// Raw TOML table the config was loaded from, used by `LintConfig::merge`
+    /// to tell keys written in the file apart from serde defaults.
+    source: Option<Arc<toml::Table>>,
+
+    /// Environment-variable overrides applied by
+    /// `LintConfig::apply_env_overrides_from`, replayed by `LintConfig::merge`
+    /// so they stay above nested config files.
+    env_overrides: Option<Arc<toml::Table>>,
+
+    /// Exclude patterns compiled once by project validation, shared so the
+    /// imports validator matches exactly what the file walk skipped.
+    exclude_patterns: Option<Arc<Vec<crate::ExcludePattern>>>,
+
+    /// Directory where project validation persists per-file analysis between
+    /// runs, set by `LintConfig::set_cache_dir`.
+    cache_dir: Option<PathBuf>,
+
+    /// Directory where project validation persists per-file diagnostics
+    /// between runs, set by `LintConfig::set_result_cache_dir`.
+    result_cache_dir: Option<PathBuf>,
+
+    /// Files below `root_dir`, walked on first use by `LintConfig::project_paths`
+    /// and shared by the rest of the project run.
+    project_paths: Option<Arc<OnceLock<Arc<Vec<String>>>>>,
+
+    /// Collects what validators read while the result cache validates a file,
+    /// set by `LintConfig::set_dependency_recorder`.
+    dependency_recorder: Option<Arc<DependencyRecorder>>,
 }
 
 impl Default for RuntimeContext {
     fn default() -> Self {
         Self {
             fs: Arc::new(RealFileSystem),
+            rule_lookup: None,
+            source: None,
+            env_overrides: None,
+            exclude_patterns: None,
+            cache_dir: None,
+            result_cache_dir: None,
+            project_paths: None,
+            dependency_recorder: None,
         }
     }
 }
@@ -123,6 +167,14 @@ impl std::fmt::Debug for RuntimeContext {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("RuntimeContext")
             .field("fs", &"Arc<dyn FileSystem>")
+            .field("rule_lookup", &self.rule_lookup.is_some())
+            .field("source", &self.source.is_some())
+            .field("env_overrides", &self.env_overrides.is_some())
+            .field("exclude_patterns", &self.exclude_patterns.is_some())
+            .field("cache_dir", &self.cache_dir)
+            .field("result_cache_dir", &self.result_cache_dir)
+            .field("project_paths", &self.project_paths.is_some())
+            .field("dependency_recorder", &self.dependency_recorder.is_some())
             .finish()
     }
 }
@@ -139,9 +191,10 @@ trait RuleFilter {
 /// Default implementation of rule filtering logic.
 ///
 /// Determines whether a rule is enabled based on:
-/// 1. Explicit disabled_rules list
-/// 2. Target tool or tools array filtering
-/// 3. Category enablement flags
+/// 1. Explicit enabled_rules allowlist (when non-empty)
+/// 2. Explicit disabled_rules list
+/// 3. Target tool or tools array filtering
+/// 4. Category enablement flags
 struct DefaultRuleFilter<'a> {
     rules: &'a RuleConfig,
     target: TargetTool,
@@ -211,32 +264,145 @@ impl<'a> DefaultRuleFilter<'a> {
 
     /// Check if a rule's category is enabled
     fn is_category_enabled(&self, rule_id: &str) -> bool {
-        match rule_id {
-            s if s.starts_with("AS-") || s.starts_with("CC-SK-") => self.rules.skills,
-            s if s.starts_with("CC-HK-") => self.rules.hooks,
-            s if s.starts_with("CC-AG-") => self.rules.agents,
-            s if s.starts_with("CC-MEM-") => self.rules.memory,
-            s if s.starts_with("CC-PL-") => self.rules.plugins,
-            s if s.starts_with("XML-") => self.rules.xml,
-            s if s.starts_with("MCP-") => self.rules.mcp,
-            s if s.starts_with("REF-") || s.starts_with("imports::") => self.rules.imports,
-            s if s.starts_with("XP-") => self.rules.cross_platform,
-            s if s.starts_with("AGM-") => self.rules.agents_md,
-            s if s.starts_with("COP-") => self.rules.copilot,
-            s if s.starts_with("CUR-") => self.rules.cursor,
-            s if s.starts_with("CLN-") => self.rules.cline,
-            s if s.starts_with("OC-") => self.rules.opencode,
-            s if s.starts_with("GM-") => self.rules.gemini_md,
-            s if s.starts_with("CDX-") => self.rules.codex,
-            s if s.starts_with("PE-") => self.rules.prompt_engineering,
+        match category_index(rule_id) {
+            Some(idx) => (RULE_CATEGORIES[idx].1)(self.rules),
             // Unknown rules are enabled by default
-            _ => true,
+            None => true,
+        }
+    }
+}
+
+/// Reads a category's enable flag from `RuleConfig`.
+type CategoryFlag = fn(&RuleConfig) -> bool;
+
+/// Rule ID prefixes paired with the `RuleConfig` flag that enables them.
+///
+/// Order matters only in that the first matching prefix wins.
+const RULE_CATEGORIES: &[(&[&str], CategoryFlag)] = &[
+    (&["AS-", "CC-SK-"], |r| r.skills),
+    (&["CC-HK-"], |r| r.hooks),
+    (&["CC-AG-"], |r| r.agents),
+    (&["CC-MEM-"], |r| r.memory),
+    (&["CC-PL-"], |r| r.plugins),
+    (&["XML-"], |r| r.xml),
+    (&["MCP-"], |r| r.mcp),
+    (&["REF-", "imports::"], |r| r.imports),
+    (&["XP-"], |r| r.cross_platform),
+    (&["AGM-"], |r| r.agents_md),
+    (&["COP-"], |r| r.copilot),
+    (&["CUR-"], |r| r.cursor),
+    (&["CLN-"], |r| r.cline),
+    (&["OC-"], |r| r.opencode),
+    (&["GM-"], |r| r.gemini_md),
+    (&["CDX-"], |r| r.codex),
+    (&["WS-"], |r| r.windsurf),
+    (&["AIDER-"], |r| r.aider),
+    (&["PE-"], |r| r.prompt_engineering),
+];
+
+/// Index into `RULE_CATEGORIES` for a rule ID, or `None` for unknown families.
+fn category_index(rule_id: &str) -> Option<usize> {
+    RULE_CATEGORIES
+        .iter()
+        .position(|(prefixes, _)| prefixes.iter().any(|p| rule_id.starts_with(p)))
+}
+
+/// Index into `TOOL_RULE_PREFIXES` for a rule ID, or `None` for generic rules.
+fn tool_prefix_index(rule_id: &str) -> Option<usize> {
+    agnix_rules::TOOL_RULE_PREFIXES
+        .iter()
+        .position(|(prefix, _)| rule_id.starts_with(prefix))
+}
+
+/// Precomputed rule enablement for a finalized config.
+///
+/// `DefaultRuleFilter` scans `disabled_rules` and the tools list on every
+/// call. Validation calls `is_rule_enabled` many times per file, so
+/// project-level validation builds this once and answers each check with a
+/// hash lookup and two bit tests.
+#[derive(Debug)]
+struct RuleLookup {
+    /// `None` when `enabled_rules` is empty and every rule may run.
+    enabled: Option<HashSet<String>>,
+    disabled: HashSet<String>,
+    /// Bit `i` is set when the category at `RULE_CATEGORIES[i]` is enabled.
+    enabled_categories: u64,
+    /// Bit `i` is set when `TOOL_RULE_PREFIXES[i]` targets a selected tool.
+    /// Only consulted when the `tools` array is non-empty.
+    enabled_tool_prefixes: u64,
+    use_tools: bool,
+    /// Whether legacy `target` filtering allows CC-* rules.
+    claude_rules_for_target: bool,
+}
+
+impl RuleLookup {
+    fn new(rules: &RuleConfig, target: TargetTool, tools: &[String]) -> Self {
+        debug_assert!(RULE_CATEGORIES.len() <= 64);
+        debug_assert!(agnix_rules::TOOL_RULE_PREFIXES.len() <= 64);
+
+        let enabled_categories = RULE_CATEGORIES
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, enabled))| enabled(rules))
+            .fold(0u64, |bits, (idx, _)| bits | (1 << idx));
+        let enabled_tool_prefixes = agnix_rules::TOOL_RULE_PREFIXES
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, tool))| {
+                tools.iter().any(|t| {
+                    t.eq_ignore_ascii_case(tool) || DefaultRuleFilter::is_tool_alias(t, tool)
+                })
+            })
+            .fold(0u64, |bits, (idx, _)| bits | (1 << idx));
+
+        Self {
+            enabled: (!rules.enabled_rules.is_empty())
+                .then(|| rules.enabled_rules.iter().cloned().collect()),
+            disabled: rules.disabled_rules.iter().cloned().collect(),
+            enabled_categories,
+            enabled_tool_prefixes,
+            use_tools: !tools.is_empty(),
+            claude_rules_for_target: matches!(target, TargetTool::ClaudeCode | TargetTool::Generic),
         }
     }
 }
 
+impl RuleFilter for RuleLookup {
+    fn is_rule_enabled(&self, rule_id: &str) -> bool {
+        if self
+            .enabled
+            .as_ref()
+            .is_some_and(|enabled| !enabled.contains(rule_id))
+        {
+            return false;
+        }
+        if self.disabled.contains(rule_id) {
+            return false;
+        }
+
+        let for_target = if self.use_tools {
+            tool_prefix_index(rule_id)
+                .is_none_or(|idx| self.enabled_tool_prefixes & (1 << idx) != 0)
+        } else {
+            !rule_id.starts_with("CC-") || self.claude_rules_for_target
+        };
+        if !for_target {
+            return false;
+        }
+
+        category_index(rule_id).is_none_or(|idx| self.enabled_categories & (1 << idx) != 0)
+    }
+}
+
 impl RuleFilter for DefaultRuleFilter<'_> {
     fn is_rule_enabled(&self, rule_id: &str) -> bool {
+        // Check if an allowlist is set and the rule is not on it
+        if !self.rules.enabled_rules.is_empty()
+            && !self.rules.enabled_rules.iter().any(|r| r == rule_id)
+        {
+            return false;
+        }
+
         // Check if explicitly disabled
         if self.rules.disabled_rules.iter().any(|r| r == rule_id) {
             return false;
@@ -270,7 +436,7 @@ pub struct LintConfig {
     )]
     pub exclude: Vec<String>,
 
-    /// Target tool (claude-code, cursor, codex, generic)
+    /// Target tool (claude-code, cursor, codex, windsurf, gemini, generic)
     /// Deprecated: Use `tools` array instead for multi-tool support
     #[schemars(description = "Target tool for validation (deprecated: use 'tools' array instead)")]
     pub target: TargetTool,
@@ -278,10 +444,10 @@ pub struct LintConfig {
     /// Tools to validate for (e.g., ["claude-code", "cursor"])
     /// When specified, agnix automatically enables rules for these tools
     /// and disables rules for tools not in the list.
-    /// Valid values: "claude-code", "cursor", "codex", "copilot", "github-copilot", "cline", "opencode", "gemini-cli", "generic"
+    /// Valid values: "claude-code", "cursor", "codex", "copilot", "github-copilot", "cline", "opencode", "gemini-cli", "aider", "windsurf", "generic"
     #[serde(default)]
     #[schemars(
-        description = "Tools to validate for. Valid values: \"claude-code\", \"cursor\", \"codex\", \"copilot\", \"github-copilot\", \"cline\", \"opencode\", \"gemini-cli\", \"generic\""
+        description = "Tools to validate for. Valid values: \"claude-code\", \"cursor\", \"codex\", \"copilot\", \"github-copilot\", \"cline\", \"opencode\", \"gemini-cli\", \"aider\", \"windsurf\", \"generic\""
     )]
     pub tools: Vec<String>,
 
@@ -319,6 +485,25 @@ pub struct LintConfig {
     /// Default: 10,000 files. Set to `None` to disable the limit (not recommended).
     #[serde(default = "default_max_files")]
     pub max_files_to_validate: Option<usize>,
+
+    /// Run the project-level cross-file checks (AGM-006, XP-004/005/006,
+    /// XP-011/012, REF-009, CC-AG-014/015, MCP-023, CC-SK-016).
+    ///
+    /// These read sibling files, so turning them off speeds up single-file and
+    /// incremental runs. Per-file validators still run. Default: true.
+    #[serde(default = "default_true")]
+    #[schemars(
+        description = "Run project-level checks that compare files against each other (AGM-006, XP-004/005/006, XP-011/012, REF-009, CC-AG-014/015, MCP-023, CC-SK-016)"
+    )]
+    pub cross_file_rules: bool,
+
+    /// Rule settings for files matching a glob, applied in order
+    #[serde(default)]
+    #[schemars(
+        description = "Rule settings that apply only to files matching a glob (e.g., [[overrides]] files = \"docs/**\" disabled_rules = [\"XP-003\"])"
+    )]
+    pub overrides: Vec<ConfigOverride>,
+
     /// Project root directory for validation (not serialized).
     ///
     /// When set, validators can use this to resolve relative paths and
@@ -344,6 +529,149 @@ pub struct LintConfig {
     runtime: RuntimeContext,
 }
 
+/// Rule settings for the files matching one glob (`[[overrides]]`).
+///
+/// `files` is matched against paths relative to the project root, like
+/// `exclude`. Overrides can only narrow what runs: a diagnostic for a
+/// matching file is dropped when its rule is in `disabled_rules`, or when
+/// `enabled_rules` is non-empty and does not list it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ConfigOverride {
+    /// Glob selecting the files this override applies to
+    #[schemars(
+        description = "Glob pattern for the files this override applies to (e.g., \"docs/**\")"
+    )]
+    pub files: String,
+
+    /// When non-empty, only these rules are reported for matching files
+    #[schemars(description = "Only report these rules for matching files")]
+    pub enabled_rules: Vec<String>,
+
+    /// Rules not reported for matching files
+    #[schemars(description = "Rule IDs to disable for matching files")]
+    pub disabled_rules: Vec<String>,
+}
+
+impl ConfigOverride {
+    /// Whether this override turns `rule_id` off for the files it matches
+    pub fn disables(&self, rule_id: &str) -> bool {
+        self.disabled_rules.iter().any(|r| r == rule_id)
+            || (!self.enabled_rules.is_empty() && !self.enabled_rules.iter().any(|r| r == rule_id))
+    }
+}
+
+/// Deep-merge `overlay` into `base` following the precedence documented on
+/// [`LintConfig::merge`]. `path` is the dotted key path of `base`.
+fn merge_toml_tables(base: &mut toml::Table, overlay: &toml::Table, path: &str) {
+    for (key, value) in overlay {
+        let key_path = if path.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", path, key)
+        };
+        match (base.get_mut(key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                merge_toml_tables(base_table, overlay_table, &key_path);
+            }
+            (Some(toml::Value::Array(base_items)), toml::Value::Array(overlay_items))
+                if key_path == "rules.disabled_rules" =>
+            {
+                for item in overlay_items {
+                    if !base_items.contains(item) {
+                        base_items.push(item.clone());
+                    }
+                }
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Overlay `overlay` onto `base`, replacing every value it contains.
+///
+/// Unlike [`merge_toml_tables`], lists are replaced rather than extended.
+fn replace_toml_values(base: &mut toml::Table, overlay: &toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                replace_toml_values(base_table, overlay_table);
+            }
+            _ => {
+                base.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+/// Default prefix for environment-variable config overrides (`AGNIX_SEVERITY`, ...).
+pub const DEFAULT_ENV_PREFIX: &str = "AGNIX_";
+
+/// Shape of an environment-variable override value.
+#[derive(Clone, Copy)]
+enum EnvValue {
+    List,
+    Severity,
+    Target,
+    MaxFiles,
+}
+
+/// Environment-variable suffixes and the config keys they set.
+///
+/// `<prefix>STRICT` is not listed: strict mode is a CLI setting, so the CLI
+/// reads it directly. `AGNIX_LOCALE` is not listed either: locale detection
+/// already reads it, below the config file's `locale`.
+const ENV_OVERRIDES: &[(&str, &[&str], EnvValue)] = &[
+    ("SEVERITY", &["severity"], EnvValue::Severity),
+    ("TARGET", &["target"], EnvValue::Target),
+    ("TOOLS", &["tools"], EnvValue::List),
+    ("EXCLUDE", &["exclude"], EnvValue::List),
+    (
+        "DISABLED_RULES",
+        &["rules", "disabled_rules"],
+        EnvValue::List,
+    ),
+    ("MAX_FILES", &["max_files_to_validate"], EnvValue::MaxFiles),
+];
+
+/// Parse a single override value, returning `Err(expected)` when invalid.
+fn parse_env_value(kind: EnvValue, raw: &str) -> Result<toml::Value, &'static str> {
+    let value = raw.trim();
+    match kind {
+        EnvValue::List => Ok(toml::Value::Array(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(|item| toml::Value::String(item.to_string()))
+                .collect(),
+        )),
+        EnvValue::Severity => match value.to_ascii_lowercase().as_str() {
+            "error" => Ok("Error".into()),
+            "warning" => Ok("Warning".into()),
+            "info" => Ok("Info".into()),
+            _ => Err("error, warning, or info"),
+        },
+        EnvValue::Target => match value.to_ascii_lowercase().as_str() {
+            "generic" => Ok("Generic".into()),
+            "claude-code" | "claudecode" => Ok("ClaudeCode".into()),
+            "cursor" => Ok("Cursor".into()),
+            "codex" => Ok("Codex".into()),
+            "windsurf" => Ok("Windsurf".into()),
+            "gemini" | "gemini-cli" => Ok("Gemini".into()),
+            _ => Err("generic, claude-code, cursor, codex, windsurf, or gemini"),
+        },
+        EnvValue::MaxFiles => value
+            .parse::<i64>()
+            .ok()
+            .filter(|n| *n >= 0)
+            .map(toml::Value::Integer)
+            .ok_or("a non-negative integer (0 disables the limit)"),
+    }
+}
+
 /// Default maximum files to validate (security limit)
 ///
 /// **Design Decision**: 10,000 files was chosen as a balance between:
@@ -378,6 +706,8 @@ impl Default for LintConfig {
             spec_revisions: SpecRevisions::default(),
             locale: None,
             max_files_to_validate: Some(DEFAULT_MAX_FILES),
+            cross_file_rules: true,
+            overrides: Vec::new(),
             root_dir: None,
             import_cache: None,
             runtime: RuntimeContext::default(),
@@ -486,6 +816,16 @@ pub struct RuleConfig {
     #[schemars(description = "Enable Codex CLI validation rules (CDX-*)")]
     pub codex: bool,
 
+    /// Enable Windsurf rules validation (WS-*)
+    #[serde(default = "default_true")]
+    #[schemars(description = "Enable Windsurf rules validation (WS-*)")]
+    pub windsurf: bool,
+
+    /// Enable Aider conventions validation (AIDER-*)
+    #[serde(default = "default_true")]
+    #[schemars(description = "Enable Aider conventions validation rules (AIDER-*)")]
+    pub aider: bool,
+
     /// Enable prompt engineering validation (PE-*)
     #[serde(default = "default_true")]
     #[schemars(description = "Enable prompt engineering validation rules (PE-*)")]
@@ -511,12 +851,103 @@ pub struct RuleConfig {
     #[schemars(description = "Validate @import references (legacy: use 'imports' instead)")]
     pub import_references: bool,
 
+    /// Allowlist of rule IDs; when non-empty, only these rules run
+    #[serde(default)]
+    #[schemars(
+        description = "List of rule IDs to run; when non-empty, every other rule is off. disabled_rules, category flags and tools still apply to listed rules (e.g., [\"AS-004\", \"MCP-001\"])"
+    )]
+    pub enabled_rules: Vec<String>,
+
     /// Explicitly disabled rules by ID (e.g., ["CC-AG-001", "AS-005"])
     #[serde(default)]
     #[schemars(
         description = "List of rule IDs to explicitly disable (e.g., [\"CC-AG-001\", \"AS-005\"])"
     )]
     pub disabled_rules: Vec<String>,
+
+    /// Maximum directory nesting allowed under a skill's references/ directory (AS-024)
+    #[serde(default = "default_max_reference_tree_depth")]
+    #[schemars(
+        description = "Maximum directory nesting depth under a skill's references/ directory before AS-024 reports it (default: 3)"
+    )]
+    pub max_reference_tree_depth: usize,
+
+    /// Skill description length above which AS-026 suggests trimming
+    #[serde(default = "default_description_soft_limit")]
+    #[schemars(
+        description = "Skill description length in characters above which AS-026 suggests trimming; AS-008 still enforces the 1024-character hard limit (default: 350)"
+    )]
+    pub description_soft_limit: usize,
+
+    /// Estimated token count above which AGM-007 warns about an instruction file
+    #[serde(default = "default_instruction_token_budget")]
+    #[schemars(
+        description = "Estimated tokens (about 4 characters each) above which AGM-007 warns about a CLAUDE.md or AGENTS.md file (default: 8000)"
+    )]
+    pub instruction_token_budget: usize,
+
+    /// Skill body line count above which AS-012 warns
+    #[serde(default = "default_skill_max_lines")]
+    #[schemars(
+        description = "Number of lines in a SKILL.md body above which AS-012 warns (default: 500)"
+    )]
+    pub skill_max_lines: usize,
+
+    /// AGENTS.md character count above which AGM-003 warns
+    #[serde(default = "default_memory_max_chars")]
+    #[schemars(
+        description = "Number of characters in an AGENTS.md file above which AGM-003 warns; the default matches Windsurf's limit (default: 12000)"
+    )]
+    pub memory_max_chars: usize,
+
+    /// Promote a rule's warnings to errors once it fires more than N times project-wide
+    #[serde(default)]
+    #[schemars(
+        description = "Map of rule ID to threshold; when a rule emits more warnings than its threshold across the project, those warnings are reported as errors (e.g., { \"CC-MEM-005\" = 3 })"
+    )]
+    pub escalate: BTreeMap<String, usize>,
+
+    /// Report a rule's diagnostics at a fixed severity, applied before escalation and --strict
+    #[serde(default)]
+    #[schemars(
+        description = "Map of rule ID to the severity its diagnostics are reported at (Error, Warning, or Info), e.g. { \"CC-MEM-005\" = \"Error\" }; applied before [rules.escalate] and --strict"
+    )]
+    pub severity_overrides: BTreeMap<String, crate::diagnostics::DiagnosticLevel>,
+
+    /// Rules whose diagnostics are dropped when another rule fires at the same position
+    #[serde(default = "default_subsumes")]
+    #[schemars(
+        description = "Map of rule ID to the rules it subsumes; when both fire at the same file, line and column only the first is reported (default: { \"AS-004\" = [\"AS-005\", \"AS-006\"] })"
+    )]
+    pub subsumes: BTreeMap<String, Vec<String>>,
+}
+
+fn default_max_reference_tree_depth() -> usize {
+    3
+}
+
+fn default_description_soft_limit() -> usize {
+    350
+}
+
+fn default_instruction_token_budget() -> usize {
+    8000
+}
+
+fn default_skill_max_lines() -> usize {
+    500
+}
+
+fn default_memory_max_chars() -> usize {
+    crate::schemas::agents_md::WINDSURF_CHAR_LIMIT
+}
+
+fn default_subsumes() -> BTreeMap<String, Vec<String>> {
+    // AS-004's format check already covers stray and repeated hyphens
+    BTreeMap::from([(
+        "AS-004".to_string(),
+        vec!["AS-005".to_string(), "AS-006".to_string()],
+    )])
 }
 
 impl Default for RuleConfig {
@@ -538,12 +969,23 @@ impl Default for RuleConfig {
             opencode: true,
             gemini_md: true,
             codex: true,
+            windsurf: true,
+            aider: true,
             prompt_engineering: true,
             generic_instructions: true,
             frontmatter_validation: true,
             xml_balance: true,
             import_references: true,
+            enabled_rules: Vec::new(),
             disabled_rules: Vec::new(),
+            max_reference_tree_depth: default_max_reference_tree_depth(),
+            description_soft_limit: default_description_soft_limit(),
+            instruction_token_budget: default_instruction_token_budget(),
+            skill_max_lines: default_skill_max_lines(),
+            memory_max_chars: default_memory_max_chars(),
+            escalate: BTreeMap::new(),
+            severity_overrides: BTreeMap::new(),
+            subsumes: default_subsumes(),
         }
     }
 }
@@ -561,16 +1003,219 @@ pub enum TargetTool {
     Cursor,
     /// Codex specific
     Codex,
+    /// Windsurf specific
+    Windsurf,
+    /// Gemini CLI specific
+    Gemini,
 }
 
 impl LintConfig {
     /// Load config from file
     pub fn load<P: AsRef<Path>>(path: P) -> anyhow::Result<Self> {
         let content = safe_read_file(path.as_ref())?;
-        let config = toml::from_str(&content)?;
+        Ok(Self::parse_toml(&content)?)
+    }
+
+    /// Parse config from TOML text and run the semantic checks of
+    /// [`LintConfig::validate`], without touching the filesystem.
+    ///
+    /// Lets embedders (the LSP, editor extensions, tests) pass settings as a
+    /// string instead of writing a temporary `.agnix.toml`. The result merges
+    /// like a config returned by [`LintConfig::load`].
+    ///
+    /// # Example
+    /// ```
+    /// use agnix_core::LintConfig;
+    ///
+    /// let (config, warnings) = LintConfig::from_toml_str("[rules]\ndisabled_rules = [\"AS-004\"]\n")
+    ///     .unwrap();
+    /// assert!(!config.is_rule_enabled("AS-004"));
+    /// assert!(warnings.is_empty());
+    /// ```
+    pub fn from_toml_str(content: &str) -> LintResult<(Self, Vec<ConfigWarning>)> {
+        let config = Self::parse_toml(content).map_err(|e| LintError::Other(e.into()))?;
+        let warnings = config.validate();
+        Ok((config, warnings))
+    }
+
+    /// Deserialize config from TOML, recording which keys were written.
+    fn parse_toml(content: &str) -> Result<Self, toml::de::Error> {
+        let mut config: Self = toml::from_str(content)?;
+        let source: toml::Table = toml::from_str(content)?;
+        config.runtime.source = Some(Arc::new(source));
         Ok(config)
     }
 
+    /// Merge a child config over its parent, as for a nested `.agnix.toml`.
+    ///
+    /// Precedence is fixed and the child always wins:
+    /// - `rules.disabled_rules`: the parent's entries followed by the child's,
+    ///   with duplicates dropped.
+    /// - Tables (`rules`, `rules.escalate`, `rules.severity_overrides`,
+    ///   `rules.subsumes`, `tool_versions`, `spec_revisions`)
+    ///   are merged key by key using these same rules.
+    /// - Every other field: the child's value replaces the parent's.
+    ///
+    /// When `child` was loaded with [`LintConfig::load`], only keys written in
+    /// its file take part; omitted keys keep the parent's value. A child built
+    /// in code has no such record, so all of its fields count as written.
+    ///
+    /// Runtime state (root dir, import cache, filesystem) is taken from
+    /// `parent`, and any precomputed rule lookup is dropped.
+    pub fn merge(parent: &LintConfig, child: &LintConfig) -> LintConfig {
+        let overlay = match &child.runtime.source {
+            Some(source) => (**source).clone(),
+            None => match toml::Value::try_from(child) {
+                Ok(toml::Value::Table(table)) => table,
+                _ => return child.clone(),
+            },
+        };
+        let Ok(toml::Value::Table(mut merged_table)) = toml::Value::try_from(parent) else {
+            return child.clone();
+        };
+        merge_toml_tables(&mut merged_table, &overlay, "");
+        if let Some(env) = &parent.runtime.env_overrides {
+            replace_toml_values(&mut merged_table, env);
+        }
+
+        let mut merged: LintConfig = toml::Value::Table(merged_table)
+            .try_into()
+            .unwrap_or_else(|_| parent.clone());
+
+        // `None` is not representable in TOML, so carry the limit over directly
+        let env_sets_max_files = parent
+            .runtime
+            .env_overrides
+            .as_ref()
+            .is_some_and(|env| env.contains_key("max_files_to_validate"));
+        merged.max_files_to_validate = if !env_sets_max_files
+            && (child.runtime.source.is_none() || overlay.contains_key("max_files_to_validate"))
+        {
+            child.max_files_to_validate
+        } else {
+            parent.max_files_to_validate
+        };
+
+        merged.root_dir = parent.root_dir.clone();
+        merged.import_cache = parent.import_cache.clone();
+        merged.runtime = RuntimeContext {
+            fs: Arc::clone(&parent.runtime.fs),
+            rule_lookup: None,
+            source: None,
+            env_overrides: parent.runtime.env_overrides.clone(),
+            exclude_patterns: parent.runtime.exclude_patterns.clone(),
+            cache_dir: parent.runtime.cache_dir.clone(),
+            result_cache_dir: parent.runtime.result_cache_dir.clone(),
+            project_paths: parent.runtime.project_paths.clone(),
+            dependency_recorder: parent.runtime.dependency_recorder.clone(),
+        };
+        merged
+    }
+
+    /// Apply config overrides from `<prefix>*` environment variables.
+    ///
+    /// See [`LintConfig::apply_env_overrides_from`] for the variable mapping.
+    pub fn apply_env_overrides(&mut self, prefix: &str) -> Vec<ConfigWarning> {
+        self.apply_env_overrides_from(prefix, std::env::vars())
+    }
+
+    /// Apply config overrides from `vars`, layered above the config file and
+    /// below CLI flags.
+    ///
+    /// | Variable | Field |
+    /// |----------|-------|
+    /// | `<prefix>SEVERITY` | `severity` (`error`, `warning`, `info`) |
+    /// | `<prefix>TARGET` | `target` (`generic`, `claude-code`, `cursor`, `codex`, `windsurf`, `gemini` or `gemini-cli`) |
+    /// | `<prefix>TOOLS` | `tools` |
+    /// | `<prefix>EXCLUDE` | `exclude` |
+    /// | `<prefix>DISABLED_RULES` | `rules.disabled_rules` |
+    /// | `<prefix>MAX_FILES` | `max_files_to_validate` (`0` disables the limit) |
+    ///
+    /// List fields are comma-separated and replace the file's list. Other
+    /// variables with the prefix are ignored. Invalid values are reported as
+    /// warnings and leave the field unchanged. An empty prefix disables
+    /// overrides.
+    pub fn apply_env_overrides_from<I, K, V>(&mut self, prefix: &str, vars: I) -> Vec<ConfigWarning>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        let mut warnings = Vec::new();
+        if prefix.is_empty() {
+            return warnings;
+        }
+
+        let mut overrides = toml::Table::new();
+        for (name, raw) in vars {
+            let (name, raw) = (name.as_ref(), raw.as_ref());
+            let Some(suffix) = name.strip_prefix(prefix) else {
+                continue;
+            };
+            let Some((_, keys, kind)) = ENV_OVERRIDES.iter().find(|(s, _, _)| *s == suffix) else {
+                continue;
+            };
+            match parse_env_value(*kind, raw) {
+                Ok(value) => {
+                    let (last, parents) = keys.split_last().expect("override key path");
+                    let mut table = &mut overrides;
+                    for key in parents {
+                        table = table
+                            .entry(key.to_string())
+                            .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+                            .as_table_mut()
+                            .expect("override key path is a table");
+                    }
+                    table.insert(last.to_string(), value);
+                }
+                Err(expected) => warnings.push(ConfigWarning {
+                    field: name.to_string(),
+                    message: t!(
+                        "core.config.invalid_env_value",
+                        value = raw,
+                        var = name,
+                        expected = expected
+                    )
+                    .to_string(),
+                    suggestion: Some(t!("core.config.invalid_env_value_suggestion").to_string()),
+                }),
+            }
+        }
+
+        if overrides.is_empty() {
+            return warnings;
+        }
+
+        let Ok(toml::Value::Table(mut table)) = toml::Value::try_from(&*self) else {
+            return warnings;
+        };
+        replace_toml_values(&mut table, &overrides);
+        let Ok(mut updated) = toml::Value::Table(table).try_into::<LintConfig>() else {
+            return warnings;
+        };
+
+        updated.max_files_to_validate = match overrides.get("max_files_to_validate") {
+            Some(toml::Value::Integer(0)) => None,
+            Some(toml::Value::Integer(n)) => Some(*n as usize),
+            _ => self.max_files_to_validate,
+        };
+        updated.root_dir = self.root_dir.take();
+        updated.import_cache = self.import_cache.take();
+        updated.runtime = RuntimeContext {
+            fs: Arc::clone(&self.runtime.fs),
+            rule_lookup: None,
+            source: self.runtime.source.clone(),
+            env_overrides: Some(Arc::new(overrides)),
+            exclude_patterns: self.runtime.exclude_patterns.clone(),
+            cache_dir: self.runtime.cache_dir.clone(),
+            result_cache_dir: self.runtime.result_cache_dir.clone(),
+            project_paths: self.runtime.project_paths.clone(),
+            dependency_recorder: self.runtime.dependency_recorder.clone(),
+        };
+        *self = updated;
+        warnings
+    }
+
     /// Load config or use default, returning any parse warning
     ///
     /// Returns a tuple of (config, optional_warning). If a config path is provided
@@ -655,6 +1300,59 @@ impl LintConfig {
         self.import_cache()
     }
 
+    /// Share exclude patterns compiled by project validation.
+    pub(crate) fn set_exclude_patterns(&mut self, patterns: Arc<Vec<crate::ExcludePattern>>) {
+        self.runtime.exclude_patterns = Some(patterns);
+    }
+
+    /// Compiled `exclude` patterns.
+    ///
+    /// Returns the patterns shared by project validation, or compiles
+    /// `exclude` on demand (skipping invalid patterns) for single-file runs.
+    pub(crate) fn exclude_patterns(&self) -> Arc<Vec<crate::ExcludePattern>> {
+        match &self.runtime.exclude_patterns {
+            Some(patterns) => Arc::clone(patterns),
+            None => Arc::new(
+                self.exclude
+                    .iter()
+                    .filter_map(|pattern| {
+                        crate::compile_exclude_patterns(std::slice::from_ref(pattern))
+                            .ok()
+                            .and_then(|mut compiled| compiled.pop())
+                    })
+                    .collect(),
+            ),
+        }
+    }
+
+    /// Directory used to persist analysis between runs, if set.
+    pub fn cache_dir(&self) -> Option<&Path> {
+        self.runtime.cache_dir.as_deref()
+    }
+
+    /// Set the directory used to persist analysis between runs (not persisted).
+    ///
+    /// Project validation stores per-file cross-layer analysis (XP-004/005/006)
+    /// there, keyed by content hash, so unchanged instruction files are not
+    /// re-analyzed on the next run. The directory is created on first write.
+    pub fn set_cache_dir(&mut self, cache_dir: PathBuf) {
+        self.runtime.cache_dir = Some(cache_dir);
+    }
+
+    /// Directory used to persist per-file diagnostics between runs, if set.
+    pub fn result_cache_dir(&self) -> Option<&Path> {
+        self.runtime.result_cache_dir.as_deref()
+    }
+
+    /// Cache per-file diagnostics in `cache_dir` between project runs (not persisted).
+    ///
+    /// Files whose content and effective config are unchanged reuse their
+    /// stored diagnostics instead of being re-validated. The cache is dropped
+    /// when the agnix version, locale or root config changes.
+    pub fn set_result_cache_dir(&mut self, cache_dir: PathBuf) {
+        self.runtime.result_cache_dir = Some(cache_dir);
+    }
+
     /// Get the file system abstraction.
     ///
     /// Validators should use this for file system operations instead of
@@ -710,16 +1408,81 @@ impl LintConfig {
     /// Check if a specific rule is enabled based on config
     ///
     /// A rule is enabled if:
-    /// 1. It's not in the disabled_rules list
-    /// 2. It's applicable to the current target tool
-    /// 3. Its category is enabled
+    /// 1. `enabled_rules` is empty or lists it
+    /// 2. It's not in the disabled_rules list
+    /// 3. It's applicable to the current target tool
+    /// 4. Its category is enabled
+    ///
+    /// `enabled_rules` only narrows the set; a listed rule still needs to
+    /// pass every other check.
     ///
     /// This delegates to `DefaultRuleFilter` which encapsulates the filtering logic.
     pub fn is_rule_enabled(&self, rule_id: &str) -> bool {
+        if let Some(lookup) = &self.runtime.rule_lookup {
+            let enabled = lookup.is_rule_enabled(rule_id);
+            debug_assert_eq!(
+                enabled,
+                DefaultRuleFilter::new(&self.rules, self.target, &self.tools)
+                    .is_rule_enabled(rule_id),
+                "stale rule lookup for {}: call precompute_rule_lookup again after changing rules, target, or tools",
+                rule_id
+            );
+            return enabled;
+        }
         let filter = DefaultRuleFilter::new(&self.rules, self.target, &self.tools);
         filter.is_rule_enabled(rule_id)
     }
 
+    /// Precompute rule enablement so `is_rule_enabled` runs in O(1).
+    ///
+    /// Call this once the effective config is final. After changing `rules`,
+    /// `target`, or `tools`, call it again or drop the lookup with
+    /// `clear_rule_lookup`; debug builds assert that the lookup is current.
+    pub fn precompute_rule_lookup(&mut self) {
+        self.runtime.rule_lookup = Some(Arc::new(RuleLookup::new(
+            &self.rules,
+            self.target,
+            &self.tools,
+        )));
+    }
+
+    /// Share one walk of the project tree across the validators of this run.
+    ///
+    /// Until this is called, `project_paths` walks the tree on every call.
+    pub(crate) fn share_project_paths(&mut self) {
+        self.runtime.project_paths = Some(Arc::new(OnceLock::new()));
+    }
+
+    /// Project-relative paths of every file the project walk visits below
+    /// `root_dir` (honoring `.gitignore`, `.agnixignore` and `exclude`),
+    /// skipping `.git`. Returns `None` without a root dir.
+    pub(crate) fn project_paths(&self) -> Option<Arc<Vec<String>>> {
+        let root = self.root_dir.as_deref()?;
+        if let Some(recorder) = &self.runtime.dependency_recorder {
+            recorder.record(root, Access::Tree);
+        }
+        let walk = || Arc::new(crate::collect_project_paths(root, &self.exclude_patterns()));
+        Some(match &self.runtime.project_paths {
+            Some(paths) => Arc::clone(paths.get_or_init(walk)),
+            None => walk(),
+        })
+    }
+
+    /// Record every read validators make through `fs()` and `project_paths`
+    /// in `recorder`.
+    pub(crate) fn set_dependency_recorder(&mut self, recorder: Arc<DependencyRecorder>) {
+        self.runtime.fs = Arc::new(RecordingFileSystem::new(
+            Arc::clone(&self.runtime.fs),
+            Arc::clone(&recorder),
+        ));
+        self.runtime.dependency_recorder = Some(recorder);
+    }
+
+    /// Drop any precomputed rule lookup, reverting to on-demand filtering.
+    pub fn clear_rule_lookup(&mut self) {
+        self.runtime.rule_lookup = None;
+    }
+
     /// Check if a user-provided tool name is a backward-compatible alias
     /// for the canonical tool name from rules.json.
     ///
@@ -765,17 +1528,60 @@ impl LintConfig {
             "CLN-",
             "OC-",
             "GM-",
+            "WS-",
+            "AIDER-",
+            "GEN-",
             "PE-",
             "VER-",
             "imports::",
         ];
-        for rule_id in &self.rules.disabled_rules {
+        let configured_rules = self
+            .rules
+            .enabled_rules
+            .iter()
+            .map(|rule_id| ("rules.enabled_rules", rule_id))
+            .chain(
+                self.rules
+                    .disabled_rules
+                    .iter()
+                    .map(|rule_id| ("rules.disabled_rules", rule_id)),
+            )
+            .chain(
+                self.rules
+                    .escalate
+                    .keys()
+                    .map(|rule_id| ("rules.escalate", rule_id)),
+            )
+            .chain(
+                self.rules
+                    .severity_overrides
+                    .keys()
+                    .map(|rule_id| ("rules.severity_overrides", rule_id)),
+            )
+            .chain(
+                self.rules
+                    .subsumes
+                    .iter()
+                    .flat_map(|(rule_id, subsumed)| std::iter::once(rule_id).chain(subsumed))
+                    .map(|rule_id| ("rules.subsumes", rule_id)),
+            )
+            .chain(self.overrides.iter().flat_map(|o| {
+                o.enabled_rules
+                    .iter()
+                    .map(|rule_id| ("overrides.enabled_rules", rule_id))
+                    .chain(
+                        o.disabled_rules
+                            .iter()
+                            .map(|rule_id| ("overrides.disabled_rules", rule_id)),
+                    )
+            }));
+        for (field, rule_id) in configured_rules {
             let matches_known = known_prefixes
                 .iter()
                 .any(|prefix| rule_id.starts_with(prefix));
             if !matches_known {
                 warnings.push(ConfigWarning {
-                    field: "rules.disabled_rules".to_string(),
+                    field: field.to_string(),
                     message: t!(
                         "core.config.unknown_rule",
                         rule = rule_id.as_str(),
@@ -797,6 +1603,8 @@ impl LintConfig {
             "cline",
             "opencode",
             "gemini-cli",
+            "aider",
+            "windsurf",
             "generic",
         ];
         for tool in &self.tools {
@@ -818,6 +1626,23 @@ impl LintConfig {
             }
         }
 
+        for o in &self.overrides {
+            if let Err(e) = glob::Pattern::new(&o.files) {
+                warnings.push(ConfigWarning {
+                    field: "overrides.files".to_string(),
+                    message: t!(
+                        "core.config.invalid_override_glob",
+                        pattern = o.files.as_str(),
+                        error = e.to_string()
+                    )
+                    .to_string(),
+                    suggestion: Some(
+                        t!("core.config.invalid_override_glob_suggestion").to_string(),
+                    ),
+                });
+            }
+        }
+
         // Warn on deprecated fields
         if self.target != TargetTool::Generic && self.tools.is_empty() {
             // Only warn if target is non-default and tools is empty
@@ -1024,6 +1849,32 @@ mod tests {
         assert!(config.is_rule_enabled("AS-005"));
     }
 
+    #[test]
+    fn test_target_windsurf_and_gemini_disable_cc_rules() {
+        for target in [TargetTool::Windsurf, TargetTool::Gemini] {
+            let mut config = LintConfig::default();
+            config.target = target;
+
+            assert!(!config.is_rule_enabled("CC-AG-001"), "{:?}", target);
+            assert!(config.is_rule_enabled("WS-001"), "{:?}", target);
+            assert!(config.is_rule_enabled("GM-001"), "{:?}", target);
+            assert!(config.is_rule_enabled("AS-005"), "{:?}", target);
+
+            // The precomputed lookup agrees with on-demand filtering
+            config.precompute_rule_lookup();
+            assert!(!config.is_rule_enabled("CC-AG-001"), "{:?}", target);
+            assert!(config.is_rule_enabled("WS-001"), "{:?}", target);
+        }
+    }
+
+    #[test]
+    fn test_target_windsurf_and_gemini_round_trip_toml() {
+        let (config, _) = LintConfig::from_toml_str("target = \"Windsurf\"").unwrap();
+        assert_eq!(config.target, TargetTool::Windsurf);
+        let (config, _) = LintConfig::from_toml_str("target = \"Gemini\"").unwrap();
+        assert_eq!(config.target, TargetTool::Gemini);
+    }
+
     #[test]
     fn test_target_claude_code_enables_cc_rules() {
         let mut config = LintConfig::default();
@@ -1067,6 +1918,55 @@ mod tests {
         assert!(config.is_rule_enabled("AS-006"));
     }
 
+    #[test]
+    fn test_enabled_rules_allowlist() {
+        let mut config = LintConfig::default();
+        config.rules.enabled_rules = vec!["AS-004".to_string(), "MCP-001".to_string()];
+
+        assert!(config.is_rule_enabled("AS-004"));
+        assert!(config.is_rule_enabled("MCP-001"));
+        assert!(!config.is_rule_enabled("AS-005"));
+        assert!(!config.is_rule_enabled("CC-MEM-005"));
+        assert!(!config.is_rule_enabled("UNKNOWN-001"));
+    }
+
+    #[test]
+    fn test_enabled_rules_still_honor_disabled_and_categories() {
+        let mut config = LintConfig::default();
+        config.rules.enabled_rules = vec![
+            "AS-004".to_string(),
+            "MCP-001".to_string(),
+            "CUR-001".to_string(),
+        ];
+        config.rules.disabled_rules = vec!["AS-004".to_string()];
+        config.rules.mcp = false;
+        config.tools = vec!["claude-code".to_string()];
+
+        assert!(!config.is_rule_enabled("AS-004"));
+        assert!(!config.is_rule_enabled("MCP-001"));
+        assert!(!config.is_rule_enabled("CUR-001"));
+    }
+
+    #[test]
+    fn test_enabled_rules_from_toml() {
+        let (config, warnings) = LintConfig::from_toml_str(
+            "[rules]\nenabled_rules = [\"AS-004\", \"MCP-001\", \"NOPE-1\"]\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.rules.enabled_rules,
+            vec!["AS-004", "MCP-001", "NOPE-1"]
+        );
+        assert!(config.is_rule_enabled("AS-004"));
+        assert!(!config.is_rule_enabled("AS-005"));
+        assert!(
+            warnings
+                .iter()
+                .any(|w| w.field == "rules.enabled_rules" && w.message.contains("NOPE-1"))
+        );
+    }
+
     #[test]
     fn test_toml_deserialization_with_new_fields() {
         let toml_str = r#"
@@ -1125,6 +2025,7 @@ exclude = []
         assert!(config.rules.cross_platform);
         assert!(config.rules.prompt_engineering);
         assert!(config.rules.disabled_rules.is_empty());
+        assert!(config.cross_file_rules);
     }
 
     // ===== MCP Category Tests =====
@@ -2750,6 +3651,110 @@ disabled_rules = []
     // These tests verify the internal RuntimeContext type works correctly.
     // RuntimeContext is private, but we test it through LintConfig's public API.
 
+    // ===== Precomputed rule lookup =====
+
+    fn lookup_parity_configs() -> Vec<LintConfig> {
+        let mut configs = vec![LintConfig::default()];
+
+        let mut disabled = LintConfig::default();
+        disabled.rules.disabled_rules = vec!["AS-005".to_string(), "MCP-001".to_string()];
+        configs.push(disabled);
+
+        let mut categories = LintConfig::default();
+        categories.rules.skills = false;
+        categories.rules.imports = false;
+        categories.rules.windsurf = false;
+        configs.push(categories);
+
+        let mut cursor_target = LintConfig::default();
+        cursor_target.target = TargetTool::Cursor;
+        configs.push(cursor_target);
+
+        let mut tools = LintConfig::default();
+        tools.tools = vec!["Cursor".to_string(), "copilot".to_string()];
+        configs.push(tools);
+
+        let mut allowlist = LintConfig::default();
+        allowlist.rules.enabled_rules = vec![
+            "AS-004".to_string(),
+            "AS-005".to_string(),
+            "MCP-001".to_string(),
+        ];
+        allowlist.rules.disabled_rules = vec!["AS-005".to_string()];
+        configs.push(allowlist);
+
+        configs
+    }
+
+    #[test]
+    fn test_rule_lookup_matches_on_demand_filter() {
+        let mut rule_ids: Vec<&str> = agnix_rules::RULES_DATA.iter().map(|(id, _)| *id).collect();
+        rule_ids.extend(["imports::not_found", "UNKNOWN-001", ""]);
+
+        for config in lookup_parity_configs() {
+            let mut precomputed = config.clone();
+            precomputed.precompute_rule_lookup();
+            for rule_id in &rule_ids {
+                assert_eq!(
+                    precomputed.is_rule_enabled(rule_id),
+                    config.is_rule_enabled(rule_id),
+                    "lookup disagrees for {} with tools={:?} target={:?}",
+                    rule_id,
+                    config.tools,
+                    config.target
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_rule_lookup_refreshes_after_recompute_or_clear() {
+        let mut config = LintConfig::default();
+        config.precompute_rule_lookup();
+        config.rules.disabled_rules.push("AS-005".to_string());
+        config.precompute_rule_lookup();
+        assert!(!config.is_rule_enabled("AS-005"));
+
+        config.rules.disabled_rules.clear();
+        config.clear_rule_lookup();
+        assert!(config.is_rule_enabled("AS-005"));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "stale rule lookup")]
+    fn test_stale_rule_lookup_panics_in_debug() {
+        let mut config = LintConfig::default();
+        config.precompute_rule_lookup();
+        config.rules.disabled_rules.push("AS-005".to_string());
+        config.is_rule_enabled("AS-005");
+    }
+
+    #[test]
+    fn test_rule_lookup_fits_bitsets() {
+        assert!(RULE_CATEGORIES.len() <= 64);
+        assert!(agnix_rules::TOOL_RULE_PREFIXES.len() <= 64);
+    }
+
+    #[test]
+    fn test_rule_lookup_matches_with_long_disabled_list() {
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = (0..5_000).map(|i| format!("XP-{:05}", i)).collect();
+        let mut precomputed = config.clone();
+        precomputed.precompute_rule_lookup();
+
+        for rule_id in ["AS-001", "CC-HK-010", "MCP-003", "XP-04999", "PE-001"] {
+            assert_eq!(
+                config.is_rule_enabled(rule_id),
+                precomputed.is_rule_enabled(rule_id),
+                "{}",
+                rule_id
+            );
+        }
+        assert!(!precomputed.is_rule_enabled("XP-04999"));
+        assert!(precomputed.is_rule_enabled("AS-001"));
+    }
+
     #[test]
     fn test_runtime_context_default_values() {
         let config = LintConfig::default();
@@ -3051,6 +4056,326 @@ disabled_rules = []
         assert!(warnings[1].message.contains("UNKNOWN-999"));
     }
 
+    #[test]
+    fn test_from_toml_str_returns_config_and_warnings() {
+        let toml_str = r#"
+tools = ["claude-code"]
+
+[rules]
+disabled_rules = ["AS-004", "UNKNOWN-999"]
+"#;
+        let (config, warnings) = LintConfig::from_toml_str(toml_str).unwrap();
+
+        assert!(!config.is_rule_enabled("AS-004"));
+        assert_eq!(config.tools, vec!["claude-code".to_string()]);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].message.contains("UNKNOWN-999"));
+    }
+
+    #[test]
+    fn test_from_toml_str_rejects_invalid_toml() {
+        let err = LintConfig::from_toml_str("severity = [").unwrap_err();
+        assert!(matches!(err, LintError::Other(_)));
+
+        let err = LintConfig::from_toml_str("severity = \"Loud\"").unwrap_err();
+        assert!(err.to_string().contains("Loud"));
+    }
+
+    #[test]
+    fn test_from_toml_str_merges_written_keys_only() {
+        let mut parent = LintConfig::default();
+        parent.severity = SeverityLevel::Error;
+        let (child, _) = LintConfig::from_toml_str("[rules]\nskills = false\n").unwrap();
+
+        let merged = LintConfig::merge(&parent, &child);
+        assert_eq!(merged.severity, SeverityLevel::Error);
+        assert!(!merged.rules.skills);
+    }
+
+    // ===== Config merging (nested .agnix.toml) =====
+
+    fn load_toml(toml_str: &str) -> LintConfig {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join(".agnix.toml");
+        std::fs::write(&path, toml_str).unwrap();
+        LintConfig::load(&path).unwrap()
+    }
+
+    #[test]
+    fn test_merge_extends_disabled_rules() {
+        let parent = load_toml("[rules]\ndisabled_rules = [\"AS-005\", \"PE-003\"]\n");
+        let child = load_toml("[rules]\ndisabled_rules = [\"PE-003\", \"CC-MEM-006\"]\n");
+
+        let merged = LintConfig::merge(&parent, &child);
+
+        assert_eq!(
+            merged.rules.disabled_rules,
+            vec!["AS-005", "PE-003", "CC-MEM-006"]
+        );
+        assert!(!merged.is_rule_enabled("AS-005"));
+        assert!(!merged.is_rule_enabled("CC-MEM-006"));
+    }
+
+    #[test]
+    fn test_merge_child_scalars_override_written_keys_only() {
+        let parent = load_toml(
+            "severity = \"Error\"\ntarget = \"Cursor\"\nmax_files_to_validate = 50\n[rules]\nskills = false\nhooks = false\n",
+        );
+        let child = load_toml("severity = \"Info\"\n[rules]\nskills = true\n");
+
+        let merged = LintConfig::merge(&parent, &child);
+
+        assert_eq!(merged.severity, SeverityLevel::Info);
+        assert!(merged.rules.skills, "child re-enables skills");
+        assert!(!merged.rules.hooks, "unwritten keys keep the parent value");
+        assert_eq!(merged.target, TargetTool::Cursor);
+        assert_eq!(merged.max_files_to_validate, Some(50));
+    }
+
+    #[test]
+    fn test_merge_tables_key_by_key() {
+        let parent = load_toml(
+            "[rules.escalate]\nCC-MEM-005 = 3\nXP-003 = 5\n[tool_versions]\nclaude_code = \"1.0.0\"\n",
+        );
+        let child =
+            load_toml("[rules.escalate]\nXP-003 = 1\n[tool_versions]\ncursor = \"0.45.0\"\n");
+
+        let merged = LintConfig::merge(&parent, &child);
+
+        assert_eq!(merged.rules.escalate.get("CC-MEM-005"), Some(&3));
+        assert_eq!(merged.rules.escalate.get("XP-003"), Some(&1));
+        assert_eq!(merged.tool_versions.claude_code.as_deref(), Some("1.0.0"));
+        assert_eq!(merged.tool_versions.cursor.as_deref(), Some("0.45.0"));
+    }
+
+    #[test]
+    fn test_merge_in_code_child_overrides_all_fields() {
+        let parent = load_toml("[rules]\ndisabled_rules = [\"AS-005\"]\nskills = false\n");
+        let mut child = LintConfig::default();
+        child.rules.disabled_rules = vec!["PE-003".to_string()];
+        child.max_files_to_validate = None;
+
+        let merged = LintConfig::merge(&parent, &child);
+
+        assert!(merged.rules.skills);
+        assert_eq!(merged.rules.disabled_rules, vec!["AS-005", "PE-003"]);
+        assert_eq!(merged.max_files_to_validate, None);
+    }
+
+    #[test]
+    fn test_merge_keeps_parent_runtime_state() {
+        let mut parent = LintConfig::default();
+        parent.set_root_dir(PathBuf::from("/project"));
+        parent.precompute_rule_lookup();
+        let child = load_toml("[rules]\ndisabled_rules = [\"AS-005\"]\n");
+
+        let merged = LintConfig::merge(&parent, &child);
+
+        assert_eq!(merged.root_dir(), Some(&PathBuf::from("/project")));
+        // The parent's lookup must not leak into the merged config
+        assert!(!merged.is_rule_enabled("AS-005"));
+    }
+
+    // ===== Environment-variable overrides =====
+
+    #[test]
+    fn test_env_overrides_replace_file_values() {
+        let mut config = load_toml(
+            "severity = \"Error\"\ntarget = \"Cursor\"\n[rules]\ndisabled_rules = [\"PE-003\"]\nskills = false\n",
+        );
+
+        let warnings = config.apply_env_overrides_from(
+            DEFAULT_ENV_PREFIX,
+            [
+                ("AGNIX_SEVERITY", "info"),
+                ("AGNIX_TARGET", "claude-code"),
+                ("AGNIX_DISABLED_RULES", "AS-010, AS-012,"),
+                ("AGNIX_TOOLS", "claude-code,cursor"),
+                ("AGNIX_MAX_FILES", "0"),
+                ("AGNIX_TELEMETRY", "0"),
+                ("OTHER_SEVERITY", "error"),
+            ],
+        );
+
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(config.severity, SeverityLevel::Info);
+        assert_eq!(config.target, TargetTool::ClaudeCode);
+        assert_eq!(config.rules.disabled_rules, vec!["AS-010", "AS-012"]);
+        assert_eq!(config.tools, vec!["claude-code", "cursor"]);
+        assert_eq!(config.max_files_to_validate, None);
+        assert!(!config.rules.skills, "unmapped file values are kept");
+    }
+
+    #[test]
+    fn test_env_overrides_invalid_value_warns_and_keeps_file_value() {
+        let mut config = load_toml("severity = \"Error\"\nmax_files_to_validate = 50\n");
+
+        let warnings = config.apply_env_overrides_from(
+            DEFAULT_ENV_PREFIX,
+            [("AGNIX_SEVERITY", "loud"), ("AGNIX_MAX_FILES", "-1")],
+        );
+
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].field, "AGNIX_SEVERITY");
+        assert!(warnings[0].message.contains("loud"));
+        assert_eq!(config.severity, SeverityLevel::Error);
+        assert_eq!(config.max_files_to_validate, Some(50));
+    }
+
+    #[test]
+    fn test_env_overrides_custom_and_empty_prefix() {
+        let mut config = LintConfig::default();
+        config.apply_env_overrides_from("CI_AGNIX_", [("CI_AGNIX_SEVERITY", "error")]);
+        assert_eq!(config.severity, SeverityLevel::Error);
+
+        let mut config = LintConfig::default();
+        config.apply_env_overrides_from("", [("SEVERITY", "error")]);
+        assert_eq!(config.severity, SeverityLevel::Warning);
+    }
+
+    #[test]
+    fn test_env_overrides_survive_nested_merge() {
+        let mut parent = LintConfig::default();
+        parent.set_root_dir(PathBuf::from("/project"));
+        parent.apply_env_overrides_from(
+            DEFAULT_ENV_PREFIX,
+            [("AGNIX_SEVERITY", "error"), ("AGNIX_MAX_FILES", "7")],
+        );
+        assert_eq!(parent.root_dir(), Some(&PathBuf::from("/project")));
+
+        let child = load_toml(
+            "severity = \"Info\"\nmax_files_to_validate = 99\n[rules]\ndisabled_rules = [\"AS-005\"]\n",
+        );
+        let merged = LintConfig::merge(&parent, &child);
+
+        assert_eq!(merged.severity, SeverityLevel::Error);
+        assert_eq!(merged.max_files_to_validate, Some(7));
+        assert_eq!(merged.rules.disabled_rules, vec!["AS-005"]);
+    }
+
+    #[test]
+    fn test_validate_invalid_escalate_rule_pattern() {
+        let mut config = LintConfig::default();
+        config.rules.escalate.insert("CC-MEM-005".to_string(), 3);
+        config.rules.escalate.insert("BOGUS-1".to_string(), 1);
+
+        let warnings = config.validate();
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "rules.escalate");
+        assert!(warnings[0].message.contains("BOGUS-1"));
+    }
+
+    #[test]
+    fn test_escalate_parses_from_toml() {
+        let toml_str = r#"
+[rules.escalate]
+CC-MEM-005 = 3
+"XP-003" = 10
+"#;
+        let config: LintConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.rules.escalate.get("CC-MEM-005"), Some(&3));
+        assert_eq!(config.rules.escalate.get("XP-003"), Some(&10));
+        assert!(LintConfig::default().rules.escalate.is_empty());
+    }
+
+    #[test]
+    fn test_subsumes_default_can_be_overridden() {
+        let default = LintConfig::default();
+        assert_eq!(
+            default.rules.subsumes.get("AS-004"),
+            Some(&vec!["AS-005".to_string(), "AS-006".to_string()])
+        );
+        let config: LintConfig = toml::from_str(
+            "[rules]
+",
+        )
+        .unwrap();
+        assert_eq!(config.rules.subsumes, default.rules.subsumes);
+
+        let config: LintConfig = toml::from_str(
+            "[rules.subsumes]
+\"AS-004\" = []
+",
+        )
+        .unwrap();
+        assert_eq!(config.rules.subsumes.get("AS-004"), Some(&Vec::new()));
+    }
+
+    #[test]
+    fn test_severity_overrides_parse_from_toml() {
+        let toml_str = r#"
+[rules.severity_overrides]
+CC-MEM-005 = "Error"
+"CC-SK-006" = "Warning"
+"#;
+        let config: LintConfig = toml::from_str(toml_str).unwrap();
+        assert_eq!(
+            config.rules.severity_overrides.get("CC-MEM-005"),
+            Some(&crate::diagnostics::DiagnosticLevel::Error)
+        );
+        assert_eq!(
+            config.rules.severity_overrides.get("CC-SK-006"),
+            Some(&crate::diagnostics::DiagnosticLevel::Warning)
+        );
+        assert!(LintConfig::default().rules.severity_overrides.is_empty());
+
+        assert!(
+            toml::from_str::<LintConfig>("[rules.severity_overrides]\nAS-001 = \"Fatal\"\n")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_overrides_parse_from_toml() {
+        let config: LintConfig = toml::from_str(
+            r#"
+[[overrides]]
+files = "docs/**"
+disabled_rules = ["XP-003"]
+
+[[overrides]]
+files = "*.md"
+enabled_rules = ["CC-MEM-005"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.overrides.len(), 2);
+        assert_eq!(config.overrides[0].files, "docs/**");
+        assert!(config.overrides[0].disables("XP-003"));
+        assert!(!config.overrides[0].disables("XP-001"));
+        assert!(!config.overrides[1].disables("CC-MEM-005"));
+        assert!(config.overrides[1].disables("XP-003"));
+        assert!(LintConfig::default().overrides.is_empty());
+    }
+
+    #[test]
+    fn test_validate_overrides_glob_and_rules() {
+        let mut config = LintConfig::default();
+        config.overrides.push(ConfigOverride {
+            files: "docs/[".to_string(),
+            enabled_rules: Vec::new(),
+            disabled_rules: vec!["BOGUS-1".to_string()],
+        });
+
+        let fields: Vec<_> = config.validate().into_iter().map(|w| w.field).collect();
+        assert_eq!(fields, vec!["overrides.disabled_rules", "overrides.files"]);
+    }
+
+    #[test]
+    fn test_validate_invalid_severity_override_rule_pattern() {
+        let mut config = LintConfig::default();
+        config.rules.severity_overrides.insert(
+            "BOGUS-1".to_string(),
+            crate::diagnostics::DiagnosticLevel::Error,
+        );
+
+        let warnings = config.validate();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "rules.severity_overrides");
+    }
+
     #[test]
     fn test_validate_ver_prefix_accepted() {
         // Regression test for #233
@@ -3080,6 +4405,14 @@ disabled_rules = []
         assert!(warnings.is_empty());
     }
 
+    #[test]
+    fn test_validate_windsurf_tool() {
+        let mut config = LintConfig::default();
+        config.tools = vec!["windsurf".to_string(), "Windsurf".to_string()];
+
+        assert!(config.validate().is_empty());
+    }
+
     #[test]
     fn test_validate_invalid_tool() {
         let mut config = LintConfig::default();
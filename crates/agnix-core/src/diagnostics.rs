@@ -19,6 +19,10 @@ pub struct Fix {
     pub description: String,
     /// Whether this fix is safe (HIGH certainty, >95%)
     pub safe: bool,
+    /// When set, the fix creates this new file with `replacement` as its
+    /// content instead of editing the diagnostic's file. Byte offsets are unused.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub create_file: Option<PathBuf>,
 }
 
 impl Fix {
@@ -36,6 +40,7 @@ impl Fix {
             replacement: replacement.into(),
             description: description.into(),
             safe,
+            create_file: None,
         }
     }
 
@@ -52,6 +57,7 @@ impl Fix {
             replacement: text.into(),
             description: description.into(),
             safe,
+            create_file: None,
         }
     }
 
@@ -63,18 +69,57 @@ impl Fix {
             replacement: String::new(),
             description: description.into(),
             safe,
+            create_file: None,
+        }
+    }
+
+    /// Create a fix that writes a new file at `path` containing `content`
+    ///
+    /// Applying the fix never overwrites an existing file.
+    pub fn create_file(
+        path: impl Into<PathBuf>,
+        content: impl Into<String>,
+        description: impl Into<String>,
+        safe: bool,
+    ) -> Self {
+        Self {
+            start_byte: 0,
+            end_byte: 0,
+            replacement: content.into(),
+            description: description.into(),
+            safe,
+            create_file: Some(path.into()),
         }
     }
 
+    /// Check if this fix creates a new file rather than editing content
+    pub fn is_file_creation(&self) -> bool {
+        self.create_file.is_some()
+    }
+
     /// Check if this is an insertion (start == end)
     pub fn is_insertion(&self) -> bool {
-        self.start_byte == self.end_byte && !self.replacement.is_empty()
+        self.create_file.is_none()
+            && self.start_byte == self.end_byte
+            && !self.replacement.is_empty()
     }
 
     /// Check if this is a deletion (empty replacement)
     pub fn is_deletion(&self) -> bool {
         self.replacement.is_empty() && self.start_byte < self.end_byte
     }
+
+    /// The `(old, new)` text this fix would swap in `content`
+    ///
+    /// Returns `None` for file-creation fixes and for byte ranges that do not
+    /// fall on character boundaries of `content` (e.g. stale offsets).
+    pub fn preview<'a>(&'a self, content: &'a str) -> Option<(&'a str, &'a str)> {
+        if self.is_file_creation() || self.start_byte > self.end_byte {
+            return None;
+        }
+        let old = content.get(self.start_byte..self.end_byte)?;
+        Some((old, self.replacement.as_str()))
+    }
 }
 
 /// A diagnostic message from the linter
@@ -98,15 +143,85 @@ pub struct Diagnostic {
     /// validation.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub assumption: Option<String>,
+    /// How certain the rule is that this finding is a real problem
+    ///
+    /// Structural checks report `High`. Heuristic rules (prompt engineering,
+    /// package-manager inference, ...) lower this so users can filter noise.
+    #[serde(default)]
+    pub confidence: Confidence,
+    /// End line of the offending span (1-based), when the validator knows it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<usize>,
+    /// End column of the offending span (1-based, exclusive), when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<usize>,
+    /// Other locations involved in the finding as `(file, line, column, message)`
+    ///
+    /// Cross-file rules point at one file and list the other side of the
+    /// conflict here, so reporters can link both.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<(PathBuf, usize, usize, String)>,
+    /// Rules this finding subsumes at its position
+    ///
+    /// A diagnostic of one of these rules at the same file, line and column
+    /// is dropped, so one bad value is not reported several times.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_rules: Vec<String>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, schemars::JsonSchema,
+)]
 pub enum DiagnosticLevel {
     Error,
     Warning,
     Info,
 }
 
+/// Confidence that a diagnostic is a true positive
+///
+/// Ordered from least to most confident so `confidence >= minimum` filters work.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub enum Confidence {
+    Low,
+    Medium,
+    #[default]
+    High,
+}
+
+impl Confidence {
+    /// Lowercase name used in machine-readable output
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Confidence::Low => "low",
+            Confidence::Medium => "medium",
+            Confidence::High => "high",
+        }
+    }
+}
+
+/// Check if a string is a valid rule ID format.
+///
+/// Rule IDs are in format: XX-NNN or XX-YY-NNN
+/// Examples: AS-001, CC-HK-001, MCP-002
+pub fn is_valid_rule_id(s: &str) -> bool {
+    let parts: Vec<&str> = s.split('-').collect();
+    if parts.len() < 2 || parts.len() > 3 {
+        return false;
+    }
+
+    for part in &parts[..parts.len() - 1] {
+        if part.is_empty() || !part.chars().all(|c| c.is_ascii_uppercase()) {
+            return false;
+        }
+    }
+
+    let last = parts.last().unwrap();
+    !last.is_empty() && last.chars().all(|c| c.is_ascii_digit())
+}
+
 impl Diagnostic {
     pub fn error(
         file: PathBuf,
@@ -125,6 +240,11 @@ impl Diagnostic {
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }
     }
 
@@ -145,6 +265,11 @@ impl Diagnostic {
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }
     }
 
@@ -165,6 +290,11 @@ impl Diagnostic {
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }
     }
 
@@ -173,6 +303,33 @@ impl Diagnostic {
         self
     }
 
+    /// Set the end of the offending span so editors can highlight the exact
+    /// token instead of a single point. `end_column` is exclusive.
+    pub fn with_span(mut self, end_line: usize, end_column: usize) -> Self {
+        self.end_line = Some(end_line);
+        self.end_column = Some(end_column);
+        self
+    }
+
+    /// Add a related location in another file (or elsewhere in this one)
+    pub fn with_related(
+        mut self,
+        file: PathBuf,
+        line: usize,
+        column: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        self.related.push((file, line, column, message.into()));
+        self
+    }
+
+    /// Declare that this finding covers `rule` at the same position, so a
+    /// `rule` diagnostic there is dropped as redundant
+    pub fn with_related_rule(mut self, rule: &str) -> Self {
+        self.related_rules.push(rule.to_string());
+        self
+    }
+
     /// Add an assumption note for version-aware validation
     ///
     /// Used when tool/spec versions are not pinned to document what
@@ -182,6 +339,12 @@ impl Diagnostic {
         self
     }
 
+    /// Set how confident the rule is in this finding
+    pub fn with_confidence(mut self, confidence: Confidence) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
     /// Add an automatic fix to this diagnostic
     pub fn with_fix(mut self, fix: Fix) -> Self {
         self.fixes.push(fix);
@@ -0,0 +1,268 @@
+//! Project setup checks for `agnix doctor`.
+//!
+//! Validators look at one file at a time; these checks look at how the agent
+//! config directories fit together: hook scripts that settings point at,
+//! skill directories without a `SKILL.md`, and rules directories that hold
+//! no rule files. All lookups go through `config.fs()`.
+
+use crate::config::LintConfig;
+use crate::fs::{DirEntry, FileSystem};
+use crate::rules::hooks::missing_scripts;
+use crate::schemas::hooks::SettingsSchema;
+use crate::{FileType, detect_file_type};
+use rust_i18n::t;
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// Settings files whose hooks are checked, relative to the project root
+const SETTINGS_FILES: &[&str] = &[".claude/settings.json", ".claude/settings.local.json"];
+
+/// Directories whose subdirectories are each expected to hold a `SKILL.md`
+const SKILL_ROOTS: &[&str] = &[".claude/skills"];
+
+/// Rules directories and the file type their entries are expected to have
+const RULE_DIRS: &[(&str, FileType, &str)] = &[
+    (".cursor/rules", FileType::CursorRule, "*.mdc"),
+    (".claude/rules", FileType::ClaudeRule, "*.md"),
+    (".windsurf/rules", FileType::Windsurf, "*.md"),
+    (
+        ".github/instructions",
+        FileType::CopilotScoped,
+        "*.instructions.md",
+    ),
+];
+
+/// Kind of setup problem found by [`diagnose`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DoctorCheck {
+    /// A settings hook runs a script that does not exist
+    MissingHookScript,
+    /// A skill directory has no SKILL.md
+    SkillWithoutManifest,
+    /// A rules directory exists but holds no rule files
+    EmptyRulesDirectory,
+}
+
+impl DoctorCheck {
+    /// Stable identifier for machine-readable output
+    pub fn id(self) -> &'static str {
+        match self {
+            DoctorCheck::MissingHookScript => "missing-hook-script",
+            DoctorCheck::SkillWithoutManifest => "skill-without-manifest",
+            DoctorCheck::EmptyRulesDirectory => "empty-rules-directory",
+        }
+    }
+}
+
+/// One setup problem, pointing at the file or directory to fix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorFinding {
+    pub check: DoctorCheck,
+    pub path: PathBuf,
+    pub message: String,
+    pub suggestion: String,
+}
+
+/// Run every setup check against the project at `root`.
+///
+/// Findings are ordered by check, then by path.
+pub fn diagnose(root: &Path, config: &LintConfig) -> Vec<DoctorFinding> {
+    let fs = config.fs().as_ref();
+    let mut findings = Vec::new();
+    check_hook_scripts(root, fs, &mut findings);
+    check_skill_dirs(root, fs, &mut findings);
+    check_rule_dirs(root, fs, &mut findings);
+    findings
+}
+
+fn check_hook_scripts(root: &Path, fs: &dyn FileSystem, findings: &mut Vec<DoctorFinding>) {
+    for settings_file in SETTINGS_FILES {
+        let path = root.join(settings_file);
+        // Unreadable or invalid settings are reported by `agnix validate` (CC-HK-012)
+        let Some(settings) = fs
+            .read_to_string(&path)
+            .ok()
+            .and_then(|content| SettingsSchema::from_json(&content).ok())
+        else {
+            continue;
+        };
+
+        let mut missing = BTreeSet::new();
+        for matchers in settings.hooks.values() {
+            for hook in matchers.iter().flat_map(|matcher| &matcher.hooks) {
+                if let Some(command) = hook.command() {
+                    missing.extend(missing_scripts(command, root, fs));
+                }
+            }
+        }
+        for (script, resolved) in missing {
+            findings.push(DoctorFinding {
+                check: DoctorCheck::MissingHookScript,
+                path: path.clone(),
+                message: t!(
+                    "core.doctor.missing_hook_script",
+                    script = script.as_str(),
+                    resolved = resolved.display().to_string()
+                )
+                .to_string(),
+                suggestion: t!("core.doctor.missing_hook_script_suggestion").to_string(),
+            });
+        }
+    }
+}
+
+fn check_skill_dirs(root: &Path, fs: &dyn FileSystem, findings: &mut Vec<DoctorFinding>) {
+    for skill_root in SKILL_ROOTS {
+        for dir in sorted_entries(fs, &root.join(skill_root))
+            .into_iter()
+            .filter(|entry| entry.metadata.is_dir)
+            .map(|entry| entry.path)
+        {
+            if fs.exists(&dir.join("SKILL.md")) {
+                continue;
+            }
+            findings.push(DoctorFinding {
+                check: DoctorCheck::SkillWithoutManifest,
+                message: t!(
+                    "core.doctor.skill_without_manifest",
+                    dir = dir.display().to_string()
+                )
+                .to_string(),
+                suggestion: t!("core.doctor.skill_without_manifest_suggestion").to_string(),
+                path: dir,
+            });
+        }
+    }
+}
+
+fn check_rule_dirs(root: &Path, fs: &dyn FileSystem, findings: &mut Vec<DoctorFinding>) {
+    for (dir, file_type, pattern) in RULE_DIRS {
+        let path = root.join(dir);
+        if !fs.is_dir(&path) {
+            continue;
+        }
+        let has_rules = sorted_entries(fs, &path)
+            .iter()
+            .any(|entry| entry.metadata.is_file && detect_file_type(&entry.path) == *file_type);
+        if !has_rules {
+            findings.push(DoctorFinding {
+                check: DoctorCheck::EmptyRulesDirectory,
+                message: t!(
+                    "core.doctor.empty_rules_directory",
+                    dir = path.display().to_string(),
+                    pattern = *pattern
+                )
+                .to_string(),
+                suggestion: t!(
+                    "core.doctor.empty_rules_directory_suggestion",
+                    pattern = *pattern
+                )
+                .to_string(),
+                path,
+            });
+        }
+    }
+}
+
+/// Entries of `dir` sorted by path; a missing directory has none.
+fn sorted_entries(fs: &dyn FileSystem, dir: &Path) -> Vec<DirEntry> {
+    let mut entries = fs.read_dir(dir).unwrap_or_default();
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::MockFileSystem;
+    use std::sync::Arc;
+
+    fn diagnose_mock(mock_fs: MockFileSystem) -> Vec<DoctorFinding> {
+        let mut config = LintConfig::default();
+        config.set_fs(Arc::new(mock_fs));
+        diagnose(Path::new("/project"), &config)
+    }
+
+    #[test]
+    fn test_healthy_project_has_no_findings() {
+        let mock_fs = MockFileSystem::new();
+        mock_fs.add_file(
+            "/project/.claude/settings.json",
+            r#"{"hooks": {"Stop": [{"hooks": [{"type": "command", "command": "./hooks/done.sh"}]}]}}"#,
+        );
+        mock_fs.add_file("/project/hooks/done.sh", "#!/bin/sh\n");
+        mock_fs.add_dir("/project/.claude/skills");
+        mock_fs.add_dir("/project/.claude/skills/deploy");
+        mock_fs.add_file("/project/.claude/skills/deploy/SKILL.md", "---\n---\n");
+        mock_fs.add_dir("/project/.cursor/rules");
+        mock_fs.add_file("/project/.cursor/rules/style.mdc", "---\n---\n");
+
+        assert_eq!(diagnose_mock(mock_fs), Vec::new());
+    }
+
+    #[test]
+    fn test_missing_hook_script() {
+        let mock_fs = MockFileSystem::new();
+        mock_fs.add_file(
+            "/project/.claude/settings.local.json",
+            r#"{"hooks": {"PreToolUse": [
+                {"matcher": "Bash", "hooks": [{"type": "command", "command": "./hooks/guard.sh"}]},
+                {"matcher": "Edit", "hooks": [{"type": "command", "command": "./hooks/guard.sh && $HOME/x.sh"}]}
+            ]}}"#,
+        );
+
+        let findings = diagnose_mock(mock_fs);
+        assert_eq!(findings.len(), 1, "{:?}", findings);
+        assert_eq!(findings[0].check, DoctorCheck::MissingHookScript);
+        assert_eq!(
+            findings[0].path,
+            PathBuf::from("/project/.claude/settings.local.json")
+        );
+        assert!(findings[0].message.contains("./hooks/guard.sh"));
+    }
+
+    #[test]
+    fn test_skill_dir_without_manifest() {
+        let mock_fs = MockFileSystem::new();
+        mock_fs.add_dir("/project/.claude/skills");
+        mock_fs.add_dir("/project/.claude/skills/deploy");
+        mock_fs.add_file("/project/.claude/skills/deploy/SKILL.md", "---\n---\n");
+        mock_fs.add_dir("/project/.claude/skills/review");
+        mock_fs.add_file("/project/.claude/skills/review/skill.md", "---\n---\n");
+        mock_fs.add_file("/project/.claude/skills/README.md", "# Skills\n");
+
+        let findings = diagnose_mock(mock_fs);
+        assert_eq!(findings.len(), 1, "{:?}", findings);
+        assert_eq!(findings[0].check, DoctorCheck::SkillWithoutManifest);
+        assert_eq!(
+            findings[0].path,
+            PathBuf::from("/project/.claude/skills/review")
+        );
+    }
+
+    #[test]
+    fn test_rules_dir_without_rule_files() {
+        let mock_fs = MockFileSystem::new();
+        mock_fs.add_dir("/project/.cursor/rules");
+        mock_fs.add_file("/project/.cursor/rules/style.md", "# Not an .mdc\n");
+        mock_fs.add_dir("/project/.claude/rules");
+        mock_fs.add_dir("/project/.windsurf/rules");
+        mock_fs.add_file("/project/.windsurf/rules/style.md", "# Style\n");
+
+        let findings = diagnose_mock(mock_fs);
+        let paths: Vec<_> = findings.iter().map(|f| f.path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                PathBuf::from("/project/.cursor/rules"),
+                PathBuf::from("/project/.claude/rules"),
+            ]
+        );
+        assert!(
+            findings
+                .iter()
+                .all(|f| f.check == DoctorCheck::EmptyRulesDirectory)
+        );
+        assert!(findings[0].message.contains("*.mdc"));
+    }
+}
@@ -169,6 +169,32 @@ pub fn safe_write_file(path: &Path, content: &str) -> LintResult<()> {
     })
 }
 
+/// Safely create a new file, refusing to overwrite anything at `path`.
+///
+/// Uses `create_new` so the existence check and creation are a single atomic
+/// operation; an existing file, directory or symlink at `path` is an error.
+///
+/// # Errors
+///
+/// Returns `LintError::FileWrite` if the path already exists or cannot be written.
+pub fn safe_create_file(path: &Path, content: &str) -> LintResult<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(path)
+        .map_err(|e| LintError::FileWrite {
+            path: path.to_path_buf(),
+            source: e,
+        })?;
+
+    file.write_all(content.as_bytes())
+        .and_then(|_| file.sync_all())
+        .map_err(|e| LintError::FileWrite {
+            path: path.to_path_buf(),
+            source: e,
+        })
+}
+
 /// Safely read a file with a custom size limit.
 ///
 /// See [`safe_read_file`] for details on security checks.
@@ -394,6 +420,26 @@ mod tests {
         assert!(matches!(result.unwrap_err(), LintError::FileWrite { .. }));
     }
 
+    #[test]
+    fn test_safe_create_file_creates_new_file() {
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("SKILL.md");
+
+        safe_create_file(&file_path, "content").unwrap();
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), "content");
+    }
+
+    #[test]
+    fn test_safe_create_file_refuses_existing_file() {
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("SKILL.md");
+        fs::write(&file_path, "original").unwrap();
+
+        let result = safe_create_file(&file_path, "replacement");
+        assert!(matches!(result, Err(LintError::FileWrite { .. })));
+        assert_eq!(fs::read_to_string(&file_path).unwrap(), "original");
+    }
+
     // Symlink tests - only run on Unix-like systems where symlinks are common
     #[cfg(unix)]
     mod unix_tests {
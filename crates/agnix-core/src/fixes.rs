@@ -61,33 +61,44 @@ pub fn apply_fixes_with_fs(
 ) -> LintResult<Vec<FixResult>> {
     let fs = fs.unwrap_or_else(|| Arc::new(RealFileSystem));
 
-    // Group diagnostics by file
-    let mut by_file: HashMap<PathBuf, Vec<&Diagnostic>> = HashMap::new();
+    // Group edit fixes by file; file-creation fixes are handled separately
+    let mut by_file: HashMap<PathBuf, Vec<&Fix>> = HashMap::new();
+    let mut creations: Vec<(&PathBuf, &Fix)> = Vec::new();
     for diag in diagnostics {
-        if diag.has_fixes() {
-            by_file.entry(diag.file.clone()).or_default().push(diag);
+        for fix in diag.fixes.iter().filter(|f| !safe_only || f.safe) {
+            match &fix.create_file {
+                Some(target) => creations.push((target, fix)),
+                None => by_file.entry(diag.file.clone()).or_default().push(fix),
+            }
         }
     }
 
     let mut results = Vec::new();
 
-    for (path, file_diagnostics) in by_file {
-        let original = fs.read_to_string(&path)?;
-
-        let mut fixes: Vec<&Fix> = file_diagnostics
-            .iter()
-            .flat_map(|d| &d.fixes)
-            .filter(|f| !safe_only || f.safe)
-            .collect();
-
-        if fixes.is_empty() {
+    for (path, fix) in creations {
+        // Never overwrite: skip targets that exist or were already created
+        if fs.exists(path) || results.iter().any(|r: &FixResult| &r.path == path) {
             continue;
         }
+        if !dry_run {
+            fs.create_new(path, &fix.replacement)?;
+        }
+        results.push(FixResult {
+            path: path.clone(),
+            original: String::new(),
+            fixed: fix.replacement.clone(),
+            applied: vec![fix.description.clone()],
+        });
+    }
 
-        // Sort descending to apply from end (preserves earlier positions)
-        fixes.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+    for (path, fixes) in by_file {
+        // Edits targeting a file created above are left for the next run
+        if results.iter().any(|r| r.path == path) {
+            continue;
+        }
+        let original = fs.read_to_string(&path)?;
 
-        let (fixed, applied) = apply_fixes_to_content(&original, &fixes);
+        let (fixed, applied) = fix_content(&original, fixes);
 
         if fixed != original {
             if !dry_run {
@@ -108,9 +119,60 @@ pub fn apply_fixes_with_fs(
     Ok(results)
 }
 
+/// Apply the fixes of `diagnostics` to `content` in memory and return the result.
+///
+/// Every diagnostic is taken to refer to `content`; file-creation fixes are
+/// ignored. As with [`apply_fixes`], fixes are applied from the end backwards
+/// and a fix overlapping one already applied is skipped. Nothing is read from
+/// or written to disk, so editors can apply fixes to unsaved buffers.
+pub fn apply_fixes_to_content(
+    content: &str,
+    diagnostics: &[Diagnostic],
+    safe_only: bool,
+) -> String {
+    let fixes = diagnostics
+        .iter()
+        .flat_map(|diag| &diag.fixes)
+        .filter(|fix| fix.create_file.is_none() && (!safe_only || fix.safe))
+        .collect();
+    fix_content(content, fixes).0
+}
+
+/// Apply `fixes` to `content` from the end backwards (preserving earlier
+/// positions), returning the new content and the applied descriptions.
+fn fix_content(content: &str, mut fixes: Vec<&Fix>) -> (String, Vec<String>) {
+    sort_for_application(&mut fixes);
+    apply_ordered_fixes(content, &fixes)
+}
+
+/// Order fixes so they can be applied from the end of the content backwards.
+///
+/// Fixes are sorted by `start_byte` descending. At a shared offset the wider
+/// range goes first, so a replacement is applied before an insertion at its
+/// start and the inserted text lands in front of the replaced range instead of
+/// being skipped as overlapping. Insertions at the same offset keep the order
+/// in which they were reported; among identical ranges the first fix wins.
+fn sort_for_application(fixes: &mut Vec<&Fix>) {
+    let mut indexed: Vec<(usize, &Fix)> = fixes.drain(..).enumerate().collect();
+    indexed.sort_by(|(ia, a), (ib, b)| {
+        b.start_byte
+            .cmp(&a.start_byte)
+            .then(b.end_byte.cmp(&a.end_byte))
+            .then_with(|| {
+                // Each insertion is placed in front of the ones already applied
+                if a.is_insertion() && b.is_insertion() {
+                    ib.cmp(ia)
+                } else {
+                    ia.cmp(ib)
+                }
+            })
+    });
+    fixes.extend(indexed.into_iter().map(|(_, fix)| fix));
+}
+
 /// Apply fixes to content string, returning new content and applied descriptions.
-/// Fixes must be sorted by start_byte descending to preserve positions.
-fn apply_fixes_to_content(content: &str, fixes: &[&Fix]) -> (String, Vec<String>) {
+/// Fixes must be ordered by [`sort_for_application`] to preserve positions.
+fn apply_ordered_fixes(content: &str, fixes: &[&Fix]) -> (String, Vec<String>) {
     let mut result = content.to_string();
     let mut applied = Vec::new();
     let mut last_start = usize::MAX;
@@ -151,7 +213,8 @@ fn apply_fixes_to_content(content: &str, fixes: &[&Fix]) -> (String, Vec<String>
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::diagnostics::{DiagnosticLevel, Fix};
+    use crate::diagnostics::{Confidence, DiagnosticLevel, Fix};
+    use std::path::Path;
 
     fn make_diagnostic(path: &str, fixes: Vec<Fix>) -> Diagnostic {
         Diagnostic {
@@ -164,6 +227,11 @@ mod tests {
             suggestion: None,
             fixes,
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }
     }
 
@@ -172,7 +240,7 @@ mod tests {
         let content = "name: Bad_Name";
         let fix = Fix::replace(6, 14, "good-name", "Fix name format", true);
 
-        let (result, applied) = apply_fixes_to_content(content, &[&fix]);
+        let (result, applied) = apply_ordered_fixes(content, &[&fix]);
 
         assert_eq!(result, "name: good-name");
         assert_eq!(applied.len(), 1);
@@ -184,7 +252,7 @@ mod tests {
         let content = "hello world";
         let fix = Fix::insert(5, " beautiful", "Add word", true);
 
-        let (result, _) = apply_fixes_to_content(content, &[&fix]);
+        let (result, _) = apply_ordered_fixes(content, &[&fix]);
 
         assert_eq!(result, "hello beautiful world");
     }
@@ -194,7 +262,7 @@ mod tests {
         let content = "hello beautiful world";
         let fix = Fix::delete(5, 15, "Remove word", true);
 
-        let (result, _) = apply_fixes_to_content(content, &[&fix]);
+        let (result, _) = apply_ordered_fixes(content, &[&fix]);
 
         assert_eq!(result, "hello world");
     }
@@ -210,9 +278,9 @@ mod tests {
 
         // Sort descending by start_byte (as apply_fixes does)
         let mut sorted = fix_refs.clone();
-        sorted.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        sorted.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
 
-        let (result, applied) = apply_fixes_to_content(content, &sorted);
+        let (result, applied) = apply_ordered_fixes(content, &sorted);
 
         assert_eq!(result, "AAA bbb CCC");
         assert_eq!(applied.len(), 2);
@@ -230,9 +298,9 @@ mod tests {
 
         // Sort descending (8-11 first, then 0-3)
         let mut sorted: Vec<&Fix> = fixes.iter().collect();
-        sorted.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        sorted.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
 
-        let (result, _) = apply_fixes_to_content(content, &sorted);
+        let (result, _) = apply_ordered_fixes(content, &sorted);
 
         assert_eq!(result, "FOO bar BAZ");
     }
@@ -309,12 +377,28 @@ mod tests {
         let content = "short";
         let fix = Fix::replace(100, 200, "won't apply", "Bad fix", true);
 
-        let (result, applied) = apply_fixes_to_content(content, &[&fix]);
+        let (result, applied) = apply_ordered_fixes(content, &[&fix]);
 
         assert_eq!(result, "short");
         assert!(applied.is_empty());
     }
 
+    #[test]
+    fn test_fix_preview() {
+        let content = "name: Bad_Name";
+        let fix = Fix::replace(6, 14, "good-name", "Fix name format", true);
+        assert_eq!(fix.preview(content), Some(("Bad_Name", "good-name")));
+
+        let insert = Fix::insert(4, "s", "Pluralize", true);
+        assert_eq!(insert.preview(content), Some(("", "s")));
+
+        let stale = Fix::replace(100, 200, "won't apply", "Bad fix", true);
+        assert_eq!(stale.preview(content), None);
+
+        let create = Fix::create_file("new.md", "# New", "Create file", true);
+        assert_eq!(create.preview(content), None);
+    }
+
     #[test]
     fn test_fix_empty_diagnostics() {
         let results = apply_fixes(&[], false, false).unwrap();
@@ -333,6 +417,11 @@ mod tests {
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }];
 
         let results = apply_fixes(&diagnostics, false, false).unwrap();
@@ -370,15 +459,95 @@ mod tests {
         ];
 
         let mut sorted: Vec<&Fix> = fixes.iter().collect();
-        sorted.sort_by(|a, b| b.start_byte.cmp(&a.start_byte));
+        sorted.sort_by_key(|f| std::cmp::Reverse(f.start_byte));
 
-        let (result, applied) = apply_fixes_to_content(content, &sorted);
+        let (result, applied) = apply_ordered_fixes(content, &sorted);
 
         assert_eq!(result, "hello universe");
         assert_eq!(applied.len(), 1);
         assert_eq!(applied[0], "Fix 1");
     }
 
+    fn apply_sorted(content: &str, fixes: &[Fix]) -> (String, Vec<String>) {
+        let mut sorted: Vec<&Fix> = fixes.iter().collect();
+        sort_for_application(&mut sorted);
+        apply_ordered_fixes(content, &sorted)
+    }
+
+    #[test]
+    fn test_fix_insert_at_start() {
+        let (result, applied) =
+            apply_sorted("world", &[Fix::insert(0, "hello ", "Add greeting", true)]);
+
+        assert_eq!(result, "hello world");
+        assert_eq!(applied, vec!["Add greeting"]);
+    }
+
+    #[test]
+    fn test_fix_insert_at_end() {
+        let content = "hello";
+        let (result, applied) = apply_sorted(
+            content,
+            &[Fix::insert(content.len(), " world\n", "Add word", true)],
+        );
+
+        assert_eq!(result, "hello world\n");
+        assert_eq!(applied, vec!["Add word"]);
+    }
+
+    #[test]
+    fn test_fix_insert_before_replacement_at_same_offset() {
+        let content = "name: Bad_Name";
+        // Reported insertion-first; the replacement must not be dropped as overlapping
+        let fixes = vec![
+            Fix::insert(6, "\"", "Open quote", true),
+            Fix::replace(6, 14, "good-name", "Fix name", true),
+            Fix::insert(14, "\"", "Close quote", true),
+        ];
+
+        let (result, applied) = apply_sorted(content, &fixes);
+
+        assert_eq!(result, "name: \"good-name\"");
+        assert_eq!(applied, vec!["Open quote", "Fix name", "Close quote"]);
+    }
+
+    #[test]
+    fn test_fix_insertions_at_same_offset_keep_order() {
+        let fixes = vec![
+            Fix::insert(0, "a", "First", true),
+            Fix::insert(0, "b", "Second", true),
+        ];
+
+        let (result, applied) = apply_sorted("!", &fixes);
+
+        assert_eq!(result, "ab!");
+        assert_eq!(applied, vec!["First", "Second"]);
+    }
+
+    #[test]
+    fn test_apply_fixes_insert_and_replace_same_offset() {
+        use crate::fs::MockFileSystem;
+
+        let mock_fs = Arc::new(MockFileSystem::new());
+        mock_fs.add_file("/project/SKILL.md", "description: Reviews code");
+
+        let diagnostics = vec![
+            make_diagnostic(
+                "/project/SKILL.md",
+                vec![Fix::insert(13, "Use when ", "Add trigger", true)],
+            ),
+            make_diagnostic(
+                "/project/SKILL.md",
+                vec![Fix::replace(13, 20, "reviewing", "Reword", true)],
+            ),
+        ];
+
+        let results = apply_fixes_with_fs(&diagnostics, true, false, Some(mock_fs)).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].fixed, "description: Use when reviewing code");
+    }
+
     // ===== MockFileSystem Integration Tests =====
 
     #[test]
@@ -542,6 +711,11 @@ mod tests {
             suggestion: None,
             fixes: Vec::new(),
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }];
 
         let results =
@@ -550,4 +724,151 @@ mod tests {
         // No fixes means no results
         assert!(results.is_empty());
     }
+
+    #[test]
+    fn test_apply_fixes_creates_new_file() {
+        use crate::fs::{FileSystem, MockFileSystem};
+
+        let mock_fs = Arc::new(MockFileSystem::new());
+        mock_fs.add_dir("/project/skills/review");
+        let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
+
+        let diagnostics = vec![make_diagnostic(
+            "/project/skills/review",
+            vec![Fix::create_file(
+                "/project/skills/review/SKILL.md",
+                "---\nname: review\n---\n",
+                "Create SKILL.md",
+                true,
+            )],
+        )];
+
+        let results = apply_fixes_with_fs(&diagnostics, false, false, Some(fs_clone)).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].path,
+            PathBuf::from("/project/skills/review/SKILL.md")
+        );
+        assert_eq!(results[0].original, "");
+        assert!(results[0].has_changes());
+        let created = mock_fs
+            .read_to_string(Path::new("/project/skills/review/SKILL.md"))
+            .unwrap();
+        assert_eq!(created, "---\nname: review\n---\n");
+    }
+
+    #[test]
+    fn test_apply_fixes_create_file_dry_run_previews_only() {
+        use crate::fs::{FileSystem, MockFileSystem};
+
+        let mock_fs = Arc::new(MockFileSystem::new());
+        let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
+
+        let diagnostics = vec![make_diagnostic(
+            "/project/skills/review",
+            vec![Fix::create_file(
+                "/project/skills/review/SKILL.md",
+                "stub",
+                "Create SKILL.md",
+                true,
+            )],
+        )];
+
+        let results = apply_fixes_with_fs(&diagnostics, true, false, Some(fs_clone)).unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].original, "");
+        assert_eq!(results[0].fixed, "stub");
+        assert!(!mock_fs.exists(Path::new("/project/skills/review/SKILL.md")));
+    }
+
+    #[test]
+    fn test_apply_fixes_create_file_never_overwrites() {
+        use crate::fs::{FileSystem, MockFileSystem};
+
+        let mock_fs = Arc::new(MockFileSystem::new());
+        mock_fs.add_file("/project/skills/review/SKILL.md", "existing");
+        let fs_clone: Arc<dyn FileSystem> = Arc::clone(&mock_fs) as Arc<dyn FileSystem>;
+
+        let diagnostics = vec![make_diagnostic(
+            "/project/skills/review",
+            vec![Fix::create_file(
+                "/project/skills/review/SKILL.md",
+                "stub",
+                "Create SKILL.md",
+                true,
+            )],
+        )];
+
+        let results = apply_fixes_with_fs(&diagnostics, false, false, Some(fs_clone)).unwrap();
+
+        assert!(results.is_empty());
+        let content = mock_fs
+            .read_to_string(Path::new("/project/skills/review/SKILL.md"))
+            .unwrap();
+        assert_eq!(content, "existing");
+    }
+
+    #[test]
+    fn test_apply_fixes_to_content_adjacent_fixes() {
+        let content = "aaa bbb ccc";
+        // Fixes across two diagnostics that touch at byte 3 and byte 4
+        let diagnostics = vec![
+            make_diagnostic("test.md", vec![Fix::replace(0, 3, "AAA", "First", true)]),
+            make_diagnostic(
+                "test.md",
+                vec![
+                    Fix::replace(3, 4, "_", "Separator", true),
+                    Fix::replace(4, 7, "BBB", "Second", true),
+                ],
+            ),
+        ];
+
+        assert_eq!(
+            apply_fixes_to_content(content, &diagnostics, false),
+            "AAA_BBB ccc"
+        );
+    }
+
+    #[test]
+    fn test_apply_fixes_to_content_skips_overlapping_fix() {
+        let content = "hello world";
+        let diagnostics = vec![make_diagnostic(
+            "test.md",
+            vec![
+                Fix::replace(6, 11, "universe", "Later start wins", true),
+                Fix::replace(4, 8, "XXX", "Overlaps", true),
+                Fix::replace(0, 1, "H", "Before both", true),
+            ],
+        )];
+
+        assert_eq!(
+            apply_fixes_to_content(content, &diagnostics, false),
+            "Hello universe"
+        );
+    }
+
+    #[test]
+    fn test_apply_fixes_to_content_safe_only_and_no_disk() {
+        let content = "name: Bad_Name";
+        let diagnostics = vec![make_diagnostic(
+            "/nonexistent/SKILL.md",
+            vec![
+                Fix::replace(6, 14, "bad-name", "Safe fix", true),
+                Fix::replace(0, 4, "NAME", "Unsafe fix", false),
+                Fix::create_file("/nonexistent/other.md", "stub", "Create file", true),
+            ],
+        )];
+
+        assert_eq!(
+            apply_fixes_to_content(content, &diagnostics, true),
+            "name: bad-name"
+        );
+        assert_eq!(
+            apply_fixes_to_content(content, &diagnostics, false),
+            "NAME: bad-name"
+        );
+        assert!(!Path::new("/nonexistent/other.md").exists());
+    }
 }
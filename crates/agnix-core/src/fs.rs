@@ -154,6 +154,9 @@ pub trait FileSystem: Send + Sync + std::fmt::Debug {
     /// Write content to file (with security checks)
     fn write(&self, path: &Path, content: &str) -> LintResult<()>;
 
+    /// Create a new file with content, failing if anything already exists at the path
+    fn create_new(&self, path: &Path, content: &str) -> LintResult<()>;
+
     /// Canonicalize a path
     fn canonicalize(&self, path: &Path) -> io::Result<PathBuf>;
 
@@ -198,6 +201,10 @@ impl FileSystem for RealFileSystem {
         crate::file_utils::safe_write_file(path, content)
     }
 
+    fn create_new(&self, path: &Path, content: &str) -> LintResult<()> {
+        crate::file_utils::safe_create_file(path, content)
+    }
+
     fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
         std::fs::canonicalize(path)
     }
@@ -467,6 +474,26 @@ impl FileSystem for MockFileSystem {
         }
     }
 
+    fn create_new(&self, path: &Path, content: &str) -> LintResult<()> {
+        let path_normalized = normalize_mock_path(path);
+        let mut entries = self.entries.write().expect("MockFileSystem lock poisoned");
+
+        if entries.contains_key(&path_normalized) {
+            return Err(LintError::FileWrite {
+                path: path.to_path_buf(),
+                source: io::Error::new(io::ErrorKind::AlreadyExists, "file exists"),
+            });
+        }
+
+        entries.insert(
+            path_normalized,
+            MockEntry::File {
+                content: content.to_string(),
+            },
+        );
+        Ok(())
+    }
+
     fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
         self.canonicalize_with_depth(path, 0)
     }
@@ -665,6 +692,27 @@ mod tests {
         assert!(matches!(result, Err(LintError::FileWrite { .. })));
     }
 
+    #[test]
+    fn test_mock_fs_create_new() {
+        let fs = MockFileSystem::new();
+
+        fs.create_new(Path::new("/test/file.txt"), "content")
+            .unwrap();
+        let content = fs.read_to_string(Path::new("/test/file.txt")).unwrap();
+        assert_eq!(content, "content");
+    }
+
+    #[test]
+    fn test_mock_fs_create_new_existing_fails() {
+        let fs = MockFileSystem::new();
+        fs.add_file("/test/file.txt", "original");
+
+        let result = fs.create_new(Path::new("/test/file.txt"), "content");
+        assert!(matches!(result, Err(LintError::FileWrite { .. })));
+        let content = fs.read_to_string(Path::new("/test/file.txt")).unwrap();
+        assert_eq!(content, "original");
+    }
+
     #[test]
     fn test_mock_fs_metadata_file() {
         let fs = MockFileSystem::new();
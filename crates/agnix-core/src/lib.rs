@@ -26,9 +26,11 @@
 
 rust_i18n::i18n!("../../locales", fallback = "en");
 
+mod analysis_cache;
 pub mod authoring;
 pub mod config;
 pub mod diagnostics;
+pub mod doctor;
 pub mod eval;
 mod file_utils;
 pub mod fixes;
@@ -36,8 +38,12 @@ pub mod fs;
 pub mod i18n;
 pub mod parsers;
 mod regex_util;
+mod result_cache;
 mod rules;
 mod schemas;
+pub mod sink;
+mod suppressions;
+pub mod timing;
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
@@ -45,14 +51,16 @@ use std::path::{Path, PathBuf};
 use rayon::iter::ParallelBridge;
 use rayon::prelude::*;
 use rust_i18n::t;
-use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
 
 pub use config::{ConfigWarning, LintConfig, generate_schema};
-pub use diagnostics::{Diagnostic, DiagnosticLevel, Fix, LintError, LintResult};
-pub use fixes::{FixResult, apply_fixes, apply_fixes_with_fs};
+pub use diagnostics::{Confidence, Diagnostic, DiagnosticLevel, Fix, LintError, LintResult};
+pub use fixes::{FixResult, apply_fixes, apply_fixes_to_content, apply_fixes_with_fs};
 pub use fs::{FileSystem, MockFileSystem, RealFileSystem};
 pub use rules::Validator;
+pub use sink::{CollectingSink, DiagnosticSink};
 
 /// Result of validating a project, including diagnostics and metadata.
 #[derive(Debug, Clone)]
@@ -61,6 +69,12 @@ pub struct ValidationResult {
     pub diagnostics: Vec<Diagnostic>,
     /// Number of files that were checked (excludes Unknown file types).
     pub files_checked: usize,
+    /// Number of checked files of each detected type; sums to `files_checked`.
+    pub files_by_type: HashMap<FileType, usize>,
+    /// Wall time spent on each checked file, slowest first.
+    pub file_timings: Vec<timing::FileTiming>,
+    /// Wall time each validator spent across all files, slowest first.
+    pub validator_timings: Vec<timing::ValidatorTiming>,
 }
 
 /// Detected file type for validator dispatch
@@ -76,7 +90,8 @@ pub enum FileType {
     Hooks,
     /// plugin.json (validator checks .claude-plugin/ location)
     Plugin,
-    /// MCP configuration files (*.mcp.json, mcp.json, mcp-*.json)
+    /// MCP configuration files (*.mcp.json, mcp.json, mcp-*.json, and the
+    /// YAML/TOML forms mcp.yaml, *.mcp.yaml, mcp.toml, *.mcp.toml)
     Mcp,
     /// GitHub Copilot global instructions (.github/copilot-instructions.md)
     Copilot,
@@ -98,12 +113,84 @@ pub enum FileType {
     GeminiMd,
     /// Codex CLI configuration (.codex/config.toml)
     CodexConfig,
+    /// Windsurf rules (.windsurf/rules/*.md)
+    Windsurf,
+    /// Legacy Windsurf rules file (.windsurfrules)
+    WindsurfLegacy,
     /// Other .md files (for XML/import checks)
     GenericMarkdown,
     /// Skip validation
     Unknown,
 }
 
+impl FileType {
+    /// Every file type, in declaration order.
+    pub const ALL: &'static [FileType] = &[
+        FileType::Skill,
+        FileType::ClaudeMd,
+        FileType::Agent,
+        FileType::Hooks,
+        FileType::Plugin,
+        FileType::Mcp,
+        FileType::Copilot,
+        FileType::CopilotScoped,
+        FileType::ClaudeRule,
+        FileType::CursorRule,
+        FileType::CursorRulesLegacy,
+        FileType::ClineRules,
+        FileType::ClineRulesFolder,
+        FileType::OpenCodeConfig,
+        FileType::GeminiMd,
+        FileType::CodexConfig,
+        FileType::Windsurf,
+        FileType::WindsurfLegacy,
+        FileType::GenericMarkdown,
+        FileType::Unknown,
+    ];
+
+    /// Stable kebab-case name for machine-readable output.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FileType::Skill => "skill",
+            FileType::ClaudeMd => "claude-md",
+            FileType::Agent => "agent",
+            FileType::Hooks => "hooks",
+            FileType::Plugin => "plugin",
+            FileType::Mcp => "mcp",
+            FileType::Copilot => "copilot",
+            FileType::CopilotScoped => "copilot-scoped",
+            FileType::ClaudeRule => "claude-rule",
+            FileType::CursorRule => "cursor-rule",
+            FileType::CursorRulesLegacy => "cursor-rules-legacy",
+            FileType::ClineRules => "cline-rules",
+            FileType::ClineRulesFolder => "cline-rules-folder",
+            FileType::OpenCodeConfig => "opencode-config",
+            FileType::GeminiMd => "gemini-md",
+            FileType::CodexConfig => "codex-config",
+            FileType::Windsurf => "windsurf",
+            FileType::WindsurfLegacy => "windsurf-legacy",
+            FileType::GenericMarkdown => "generic-markdown",
+            FileType::Unknown => "unknown",
+        }
+    }
+}
+
+impl std::fmt::Display for FileType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Registry of built-in validators shared by the non-`_with_registry` entry
+/// points. It only holds `fn` pointers, so it is built once and shared freely.
+///
+/// Use this instead of [`ValidatorRegistry::with_defaults`] when the default
+/// validators are all you need.
+pub fn default_registry() -> &'static ValidatorRegistry {
+    static DEFAULT: std::sync::OnceLock<ValidatorRegistry> = std::sync::OnceLock::new();
+    DEFAULT.get_or_init(ValidatorRegistry::with_defaults)
+}
+
 /// Factory function type that creates validator instances.
 pub type ValidatorFactory = fn() -> Box<dyn Validator>;
 
@@ -116,6 +203,11 @@ pub type ValidatorFactory = fn() -> Box<dyn Validator>;
 ///
 /// Most callers should use [`ValidatorRegistry::with_defaults`] to obtain a
 /// registry pre-populated with all built-in validators.
+///
+/// The functions without a `_with_registry` suffix share one lazily built
+/// default registry, so calling [`validate_file`] per file does not rebuild
+/// it. Build your own registry only to add or replace validators, and reuse
+/// it across calls.
 pub struct ValidatorRegistry {
     validators: HashMap<FileType, Vec<ValidatorFactory>>,
 }
@@ -140,6 +232,23 @@ impl ValidatorRegistry {
         self.validators.entry(file_type).or_default().push(factory);
     }
 
+    /// Remove every validator factory registered for a given file type.
+    ///
+    /// Files of that type are then detected but produce no diagnostics until
+    /// a validator is registered again.
+    pub fn unregister(&mut self, file_type: FileType) {
+        self.validators.remove(&file_type);
+    }
+
+    /// Replace all validator factories for a given file type with `factory`.
+    ///
+    /// This also drops the shared built-ins registered for that type (e.g. the
+    /// XML and merge-conflict validators); re-register any you want to keep.
+    pub fn replace(&mut self, file_type: FileType, factory: ValidatorFactory) {
+        self.unregister(file_type);
+        self.register(file_type, factory);
+    }
+
     /// Build a fresh validator instance list for the given file type.
     pub fn validators_for(&self, file_type: FileType) -> Vec<Box<dyn Validator>> {
         self.validators
@@ -151,6 +260,30 @@ impl ValidatorRegistry {
     }
 
     fn register_defaults(&mut self) {
+        // Conflict markers break every format, so GEN-002 runs first on all
+        // recognized file types before the type-specific validators.
+        const CONFLICT_MARKER_TYPES: &[FileType] = &[
+            FileType::Skill,
+            FileType::ClaudeMd,
+            FileType::Agent,
+            FileType::Hooks,
+            FileType::Plugin,
+            FileType::Mcp,
+            FileType::Copilot,
+            FileType::CopilotScoped,
+            FileType::ClaudeRule,
+            FileType::CursorRule,
+            FileType::CursorRulesLegacy,
+            FileType::ClineRules,
+            FileType::ClineRulesFolder,
+            FileType::OpenCodeConfig,
+            FileType::GeminiMd,
+            FileType::CodexConfig,
+            FileType::Windsurf,
+            FileType::WindsurfLegacy,
+            FileType::GenericMarkdown,
+        ];
+
         const DEFAULTS: &[(FileType, ValidatorFactory)] = &[
             (FileType::Skill, skill_validator),
             (FileType::Skill, xml_validator),
@@ -178,7 +311,9 @@ impl ValidatorRegistry {
             (FileType::CursorRulesLegacy, prompt_validator),
             (FileType::CursorRulesLegacy, claude_md_validator),
             (FileType::ClineRules, cline_validator),
+            (FileType::ClineRules, cross_platform_validator),
             (FileType::ClineRulesFolder, cline_validator),
+            (FileType::ClineRulesFolder, cross_platform_validator),
             (FileType::OpenCodeConfig, opencode_validator),
             (FileType::GeminiMd, gemini_md_validator),
             (FileType::GeminiMd, prompt_validator),
@@ -186,14 +321,25 @@ impl ValidatorRegistry {
             (FileType::GeminiMd, imports_validator),
             (FileType::GeminiMd, cross_platform_validator),
             (FileType::CodexConfig, codex_validator),
+            (FileType::Windsurf, windsurf_validator),
+            (FileType::Windsurf, prompt_validator),
+            (FileType::Windsurf, claude_md_validator),
+            (FileType::WindsurfLegacy, windsurf_validator),
+            (FileType::WindsurfLegacy, prompt_validator),
+            (FileType::WindsurfLegacy, claude_md_validator),
             // CodexValidator on ClaudeMd catches AGENTS.override.md files (CDX-003).
             // The validator early-returns for all other ClaudeMd filenames.
             (FileType::ClaudeMd, codex_validator),
+            // AiderValidator early-returns for everything except CONVENTIONS.md.
+            (FileType::ClaudeMd, aider_validator),
             (FileType::GenericMarkdown, cross_platform_validator),
             (FileType::GenericMarkdown, xml_validator),
             (FileType::GenericMarkdown, imports_validator),
         ];
 
+        for &file_type in CONFLICT_MARKER_TYPES {
+            self.register(file_type, conflict_markers_validator);
+        }
         for &(file_type, factory) in DEFAULTS {
             self.register(file_type, factory);
         }
@@ -218,6 +364,14 @@ fn agents_md_validator() -> Box<dyn Validator> {
     Box::new(rules::agents_md::AgentsMdValidator)
 }
 
+fn aider_validator() -> Box<dyn Validator> {
+    Box::new(rules::aider::AiderValidator)
+}
+
+fn conflict_markers_validator() -> Box<dyn Validator> {
+    Box::new(rules::conflict_markers::ConflictMarkerValidator)
+}
+
 fn agent_validator() -> Box<dyn Validator> {
     Box::new(rules::agent::AgentValidator)
 }
@@ -278,6 +432,10 @@ fn codex_validator() -> Box<dyn Validator> {
     Box::new(rules::codex::CodexValidator)
 }
 
+fn windsurf_validator() -> Box<dyn Validator> {
+    Box::new(rules::windsurf::WindsurfValidator)
+}
+
 /// Returns true if the file is inside a documentation directory that
 /// is unlikely to contain agent configuration files. This prevents
 /// false positives from XML tags, broken links, and cross-platform
@@ -305,6 +463,42 @@ fn is_documentation_directory(path: &Path) -> bool {
     false
 }
 
+fn is_agent_directory_name(name: &std::ffi::OsStr) -> bool {
+    name == "agents" || name == ".agents"
+}
+
+/// Whether the parent or grandparent directory is an agent directory
+/// (`agents/` or `.agents/`).
+fn is_agent_directory(path: &Path) -> bool {
+    path.ancestors()
+        .skip(1)
+        .take(2)
+        .filter_map(Path::file_name)
+        .any(is_agent_directory_name)
+}
+
+/// Whether the project root or a directory between it and the file at
+/// `relative` is an agent directory.
+///
+/// Inside a project agents may be organized in arbitrarily nested
+/// subdirectories, e.g. `.agents/research/web/summarizer.md`. Directories
+/// above the root do not count, so a checkout below some unrelated `agents/`
+/// directory is not all agents.
+fn is_project_agent_directory(root: &Path, relative: &Path) -> bool {
+    let between = relative
+        .parent()
+        .into_iter()
+        .flat_map(Path::components)
+        .filter_map(|component| match component {
+            std::path::Component::Normal(name) => Some(name),
+            _ => None,
+        });
+    root.file_name()
+        .into_iter()
+        .chain(between)
+        .any(is_agent_directory_name)
+}
+
 /// Detect file type based on path patterns
 pub fn detect_file_type(path: &Path) -> FileType {
     let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
@@ -322,13 +516,35 @@ pub fn detect_file_type(path: &Path) -> FileType {
         "SKILL.md" => FileType::Skill,
         "CLAUDE.md" | "CLAUDE.local.md" | "AGENTS.md" | "AGENTS.local.md"
         | "AGENTS.override.md" => FileType::ClaudeMd,
+        // Aider conventions file, only at the project root (see
+        // detect_project_file_type); validated as memory plus AIDER-* checks
+        "CONVENTIONS.md" if path.parent() == Some(Path::new("")) => FileType::ClaudeMd,
+        // JetBrains Junie project guidelines (.junie/guidelines.md)
+        "guidelines.md" if parent == Some(".junie") => FileType::ClaudeMd,
+        // OpenAI Codex instruction files (codex.md, .codex/instructions.md)
+        "codex.md" => FileType::ClaudeMd,
+        "instructions.md" if parent == Some(".codex") => FileType::ClaudeMd,
         "settings.json" | "settings.local.json" => FileType::Hooks,
+        // Split hook files (.claude/hooks/<Event>.json)
+        name if name.ends_with(".json")
+            && parent == Some("hooks")
+            && grandparent == Some(".claude") =>
+        {
+            FileType::Hooks
+        }
         // Classify any plugin.json as Plugin - validator checks location constraint (CC-PL-001)
         "plugin.json" => FileType::Plugin,
         // MCP configuration files
         "mcp.json" => FileType::Mcp,
         name if name.ends_with(".mcp.json") => FileType::Mcp,
         name if name.starts_with("mcp-") && name.ends_with(".json") => FileType::Mcp,
+        "mcp.yaml" | "mcp.yml" | "mcp.toml" => FileType::Mcp,
+        name if name.ends_with(".mcp.yaml")
+            || name.ends_with(".mcp.yml")
+            || name.ends_with(".mcp.toml") =>
+        {
+            FileType::Mcp
+        }
         // GitHub Copilot global instructions (.github/copilot-instructions.md)
         "copilot-instructions.md" if parent == Some(".github") => FileType::Copilot,
         // GitHub Copilot scoped instructions (.github/instructions/*.instructions.md)
@@ -368,66 +584,115 @@ pub fn detect_file_type(path: &Path) -> FileType {
         // Path safety: symlink rejection and size limits are enforced upstream
         // by file_utils::safe_read_file before content reaches any validator.
         "config.toml" if parent == Some(".codex") => FileType::CodexConfig,
-        name if name.ends_with(".md") => {
-            // Agent directories take precedence over filename exclusions.
-            // Files like agents/README.md should be validated as agent configs.
-            if parent == Some("agents") || grandparent == Some("agents") {
-                FileType::Agent
-            } else {
-                // Exclude common project files that are not agent configurations.
-                // These files commonly contain HTML, @mentions, and cross-platform
-                // references that would produce false positives if validated.
-                let lower = name.to_lowercase();
-                let parent_lower = parent.map(|p| p.to_lowercase());
-                let parent_lower_str = parent_lower.as_deref();
-                if lower == "changelog.md"
-                    || lower == "history.md"
-                    || lower == "releases.md"
-                    || lower == "readme.md"
-                    || lower == "contributing.md"
-                    || lower == "license.md"
-                    || lower == "code_of_conduct.md"
-                    || lower == "security.md"
-                    || lower == "pull_request_template.md"
-                    || lower == "issue_template.md"
-                    || lower == "bug_report.md"
-                    || lower == "feature_request.md"
-                    // Developer-focused docs, not agent instructions
-                    || lower == "developer.md"
-                    || lower == "developers.md"
-                    || lower == "development.md"
-                    || lower == "hacking.md"
-                    || lower == "maintainers.md"
-                    || lower == "governance.md"
-                    || lower == "support.md"
-                    || lower == "authors.md"
-                    || lower == "credits.md"
-                    || lower == "thanks.md"
-                    || lower == "migration.md"
-                    || lower == "upgrading.md"
-                {
-                    FileType::Unknown
-                } else if is_documentation_directory(path) {
-                    // Markdown files in documentation directories are not agent configs
-                    FileType::Unknown
-                } else if parent_lower_str == Some(".github")
-                    || parent_lower_str == Some("issue_template")
-                    || parent_lower_str == Some("pull_request_template")
-                {
-                    FileType::Unknown
-                } else {
-                    FileType::GenericMarkdown
-                }
-            }
+        // Windsurf rules (.windsurf/rules/*.md)
+        name if name.ends_with(".md")
+            && parent == Some("rules")
+            && grandparent == Some(".windsurf") =>
+        {
+            FileType::Windsurf
         }
+        // Legacy Windsurf rules file (.windsurfrules)
+        ".windsurfrules" => FileType::WindsurfLegacy,
+        name if name.ends_with(".md") => markdown_file_type(path, is_agent_directory(path)),
         _ => FileType::Unknown,
     }
 }
 
+/// Classify a markdown file no more specific pattern claimed.
+///
+/// Agent directories take precedence over filename exclusions: files like
+/// `agents/README.md` are validated as agent configs.
+fn markdown_file_type(path: &Path, in_agent_directory: bool) -> FileType {
+    if in_agent_directory {
+        return FileType::Agent;
+    }
+
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    let parent = path
+        .parent()
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str());
+    // Exclude common project files that are not agent configurations.
+    // These files commonly contain HTML, @mentions, and cross-platform
+    // references that would produce false positives if validated.
+    let lower = name.to_lowercase();
+    let parent_lower = parent.map(|p| p.to_lowercase());
+    let parent_lower_str = parent_lower.as_deref();
+    if lower == "changelog.md"
+        || lower == "history.md"
+        || lower == "releases.md"
+        || lower == "readme.md"
+        || lower == "contributing.md"
+        || lower == "license.md"
+        || lower == "code_of_conduct.md"
+        || lower == "security.md"
+        || lower == "pull_request_template.md"
+        || lower == "issue_template.md"
+        || lower == "bug_report.md"
+        || lower == "feature_request.md"
+        // Developer-focused docs, not agent instructions
+        || lower == "developer.md"
+        || lower == "developers.md"
+        || lower == "development.md"
+        || lower == "hacking.md"
+        || lower == "maintainers.md"
+        || lower == "governance.md"
+        || lower == "support.md"
+        || lower == "authors.md"
+        || lower == "credits.md"
+        || lower == "thanks.md"
+        || lower == "migration.md"
+        || lower == "upgrading.md"
+    {
+        FileType::Unknown
+    } else if is_documentation_directory(path) {
+        // Markdown files in documentation directories are not agent configs
+        FileType::Unknown
+    } else if parent_lower_str == Some(".github")
+        || parent_lower_str == Some("issue_template")
+        || parent_lower_str == Some("pull_request_template")
+    {
+        FileType::Unknown
+    } else {
+        FileType::GenericMarkdown
+    }
+}
+
+/// [`detect_file_type`] for a file of the project rooted at `root`.
+///
+/// Agent directories are matched at any depth below the root (see
+/// [`is_project_agent_directory`]), and files that are only recognized
+/// directly under the root, such as Aider's `CONVENTIONS.md`, are matched on
+/// the root-relative path.
+fn detect_project_file_type(path: &Path, root: Option<&Path>) -> FileType {
+    let file_type = detect_file_type(path);
+    let Some((root, relative)) = root.and_then(|root| Some((root, path.strip_prefix(root).ok()?)))
+    else {
+        return file_type;
+    };
+
+    let is_markdown = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|name| name.ends_with(".md"));
+    let file_type = match file_type {
+        FileType::Agent | FileType::GenericMarkdown | FileType::Unknown if is_markdown => {
+            markdown_file_type(path, is_project_agent_directory(root, relative))
+        }
+        file_type => file_type,
+    };
+    if !matches!(file_type, FileType::Unknown | FileType::GenericMarkdown) {
+        return file_type;
+    }
+    match detect_file_type(relative) {
+        FileType::Unknown | FileType::GenericMarkdown => file_type,
+        root_file_type => root_file_type,
+    }
+}
+
 /// Validate a single file
 pub fn validate_file(path: &Path, config: &LintConfig) -> LintResult<Vec<Diagnostic>> {
-    let registry = ValidatorRegistry::with_defaults();
-    validate_file_with_registry(path, config, &registry)
+    validate_file_with_registry(path, config, default_registry())
 }
 
 /// Validate a single file with a custom validator registry
@@ -436,37 +701,235 @@ pub fn validate_file_with_registry(
     config: &LintConfig,
     registry: &ValidatorRegistry,
 ) -> LintResult<Vec<Diagnostic>> {
-    let file_type = detect_file_type(path);
-
-    if file_type == FileType::Unknown {
+    if detect_project_file_type(path, config.root_dir().map(PathBuf::as_path)) == FileType::Unknown
+    {
         return Ok(vec![]);
     }
 
     let content = file_utils::safe_read_file(path)?;
 
+    Ok(validate_content_with_registry(
+        path, &content, config, registry,
+    ))
+}
+
+/// Like [`validate_file_with_registry`], but also returns how long each
+/// validator took, and reuses the diagnostics stored in `cache` (with no
+/// validator timings) when the file's content, its effective config and the
+/// other paths its validators read are unchanged.
+fn validate_file_timed(
+    path: &Path,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+    cache: Option<&result_cache::ResultCache>,
+) -> LintResult<(Vec<Diagnostic>, timing::ValidatorDurations)> {
+    if detect_project_file_type(path, config.root_dir().map(PathBuf::as_path)) == FileType::Unknown
+    {
+        return Ok(Default::default());
+    }
+
+    let content = file_utils::safe_read_file(path)?;
+    let Some(cache) = cache else {
+        return Ok(run_validators(path, &content, config, registry));
+    };
+
+    let key = result_cache::result_key(&content, config);
+    if let Some(diagnostics) = cache.get(path, &key, config) {
+        return Ok((diagnostics, Vec::new()));
+    }
+
+    // Record the validators' reads, bypassing the shared import cache so
+    // imported files are read (and recorded) too
+    let recorder = Arc::new(result_cache::DependencyRecorder::default());
+    let mut recording = config.clone();
+    recording.set_dependency_recorder(Arc::clone(&recorder));
+    recording.import_cache = None;
+
+    let (diagnostics, durations) = run_validators(path, &content, &recording, registry);
+    cache.insert(path, key, &diagnostics, &recorder, config);
+    Ok((diagnostics, durations))
+}
+
+/// Validate one file in the parallel project loop, turning read failures into
+/// a `file::read` diagnostic and recording its timings in `timings`.
+fn validate_project_file(
+    file_path: &Path,
+    file_type: FileType,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+    cache: Option<&result_cache::ResultCache>,
+    timings: &timing::TimingCollector,
+) -> Vec<Diagnostic> {
+    let start = Instant::now();
+    let (diagnostics, durations) = match validate_file_timed(file_path, config, registry, cache) {
+        Ok(result) => result,
+        Err(e) => (vec![file_read_diagnostic(file_path, &e)], Vec::new()),
+    };
+    if file_type != FileType::Unknown {
+        timings.record(
+            file_path.to_path_buf(),
+            file_type,
+            start.elapsed(),
+            &durations,
+        );
+    }
+    diagnostics
+}
+
+/// Build the `file::read` diagnostic reported for a file that could not be read.
+fn file_read_diagnostic(path: &Path, error: &LintError) -> Diagnostic {
+    Diagnostic::error(
+        path.to_path_buf(),
+        0,
+        0,
+        "file::read",
+        format!("Failed to validate file: {}", error),
+    )
+}
+
+/// Detect the type of each file in `paths` and validate it.
+///
+/// Returns one `(FileType, diagnostics)` pair per input path, in order, using
+/// the shared default registry. Files of type
+/// [`FileType::Unknown`] are not read and get no diagnostics. A file that
+/// cannot be read gets a `file::read` diagnostic instead of failing the batch.
+pub fn detect_and_validate_all<P: AsRef<Path>>(
+    paths: &[P],
+    config: &LintConfig,
+) -> Vec<(FileType, Vec<Diagnostic>)> {
+    detect_and_validate_all_with_registry(paths, config, default_registry())
+}
+
+/// Detect and validate each file in `paths` with a custom validator registry
+pub fn detect_and_validate_all_with_registry<P: AsRef<Path>>(
+    paths: &[P],
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+) -> Vec<(FileType, Vec<Diagnostic>)> {
+    paths
+        .iter()
+        .map(|path| {
+            let path = path.as_ref();
+            let file_type = detect_project_file_type(path, config.root_dir().map(PathBuf::as_path));
+            let diagnostics = validate_file_with_registry(path, config, registry)
+                .unwrap_or_else(|e| vec![file_read_diagnostic(path, &e)]);
+            (file_type, diagnostics)
+        })
+        .collect()
+}
+
+/// Validate in-memory content as if it were the file at `path`.
+///
+/// `path` is only used for file type detection and diagnostic locations;
+/// nothing is read from disk. Editors use this to validate unsaved buffers.
+pub fn validate_content(path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+    validate_content_with_registry(path, content, config, default_registry())
+}
+
+/// Validate in-memory content with a custom validator registry
+pub fn validate_content_with_registry(
+    path: &Path,
+    content: &str,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+) -> Vec<Diagnostic> {
+    run_validators(path, content, config, registry).0
+}
+
+/// Run the registered validators for `path`'s file type over `content`,
+/// timing each one.
+fn run_validators(
+    path: &Path,
+    content: &str,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+) -> (Vec<Diagnostic>, timing::ValidatorDurations) {
+    let file_type = detect_project_file_type(path, config.root_dir().map(PathBuf::as_path));
+
+    if file_type == FileType::Unknown {
+        return Default::default();
+    }
+
     let validators = registry.validators_for(file_type);
     let mut diagnostics = Vec::new();
+    let mut durations = Vec::with_capacity(validators.len());
 
     for validator in validators {
-        diagnostics.extend(validator.validate(path, &content, config));
+        let start = Instant::now();
+        diagnostics.extend(validator.validate(path, content, config));
+        durations.push((validator.name(), start.elapsed()));
     }
 
-    Ok(diagnostics)
+    check_diagnostic_positions(path, content, &mut diagnostics);
+    suppressions::apply_suppressions(path, content, &mut diagnostics);
+    drop_subsumed_diagnostics(&mut diagnostics, &config.rules.subsumes);
+    apply_path_overrides(&mut diagnostics, config);
+    (diagnostics, durations)
+}
+
+/// Whether `rule` is an internal diagnostic ID such as `mcp::invalid_tool`.
+fn is_internal_rule_id(rule: &str) -> bool {
+    rule.split_once("::").is_some_and(|(category, name)| {
+        [category, name].iter().all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        })
+    })
+}
+
+/// Guard against validator bugs in the diagnostics reported for `path`.
+///
+/// Lines must fall within `content` (1-based), columns within their line (0
+/// means unknown), and rule IDs must be well-formed. Debug builds panic so
+/// tests catch the offending validator; release builds clamp the position
+/// and log a warning.
+fn check_diagnostic_positions(path: &Path, content: &str, diagnostics: &mut [Diagnostic]) {
+    let lines: Vec<&str> = content.lines().collect();
+    // One past the last line is allowed for end-of-file findings
+    let max_line = lines.len() + 1;
+    let max_column = |line: usize| lines.get(line.wrapping_sub(1)).map_or(1, |l| l.len() + 1);
+
+    for diag in diagnostics.iter_mut().filter(|d| d.file == path) {
+        let rule_ok = diagnostics::is_valid_rule_id(&diag.rule) || is_internal_rule_id(&diag.rule);
+        let line_ok = (1..=max_line).contains(&diag.line);
+        let column_ok = !line_ok || diag.column <= max_column(diag.line);
+        if rule_ok && line_ok && column_ok {
+            continue;
+        }
+
+        let problem = format!(
+            "{} reported invalid diagnostic at {}:{}:{} ({} lines, rule id valid: {})",
+            diag.rule,
+            path.display(),
+            diag.line,
+            diag.column,
+            lines.len(),
+            rule_ok
+        );
+        if cfg!(debug_assertions) {
+            panic!("validator bug: {}", problem);
+        }
+        tracing::warn!("validator bug: {}", problem);
+
+        diag.line = diag.line.clamp(1, max_line);
+        diag.column = diag.column.min(max_column(diag.line));
+    }
 }
 
 /// Main entry point for validating a project
 pub fn validate_project(path: &Path, config: &LintConfig) -> LintResult<ValidationResult> {
-    let registry = ValidatorRegistry::with_defaults();
-    validate_project_with_registry(path, config, &registry)
+    validate_project_with_registry(path, config, default_registry())
 }
 
-struct ExcludePattern {
+pub(crate) struct ExcludePattern {
     pattern: glob::Pattern,
     dir_only_prefix: Option<String>,
     allow_probe: bool,
 }
 
-fn normalize_rel_path(entry_path: &Path, root: &Path) -> String {
+pub(crate) fn normalize_rel_path(entry_path: &Path, root: &Path) -> String {
     let rel_path = entry_path.strip_prefix(root).unwrap_or(entry_path);
     let mut path_str = rel_path.to_string_lossy().replace('\\', "/");
     if let Some(stripped) = path_str.strip_prefix("./") {
@@ -475,7 +938,7 @@ fn normalize_rel_path(entry_path: &Path, root: &Path) -> String {
     path_str
 }
 
-fn compile_exclude_patterns(excludes: &[String]) -> LintResult<Vec<ExcludePattern>> {
+pub(crate) fn compile_exclude_patterns(excludes: &[String]) -> LintResult<Vec<ExcludePattern>> {
     excludes
         .iter()
         .map(|pattern| {
@@ -500,7 +963,7 @@ fn compile_exclude_patterns(excludes: &[String]) -> LintResult<Vec<ExcludePatter
         .collect()
 }
 
-fn should_prune_dir(rel_dir: &str, exclude_patterns: &[ExcludePattern]) -> bool {
+pub(crate) fn should_prune_dir(rel_dir: &str, exclude_patterns: &[ExcludePattern]) -> bool {
     if rel_dir.is_empty() {
         return false;
     }
@@ -512,59 +975,59 @@ fn should_prune_dir(rel_dir: &str, exclude_patterns: &[ExcludePattern]) -> bool
         .any(|p| p.pattern.matches(rel_dir) || (p.allow_probe && p.pattern.matches(&probe)))
 }
 
-fn is_excluded_file(path_str: &str, exclude_patterns: &[ExcludePattern]) -> bool {
+pub(crate) fn is_excluded_file(path_str: &str, exclude_patterns: &[ExcludePattern]) -> bool {
     exclude_patterns
         .iter()
         .any(|p| p.pattern.matches(path_str) && p.dir_only_prefix.as_deref() != Some(path_str))
 }
 
-/// Main entry point for validating a project with a custom validator registry
-pub fn validate_project_with_registry(
-    path: &Path,
-    config: &LintConfig,
-    registry: &ValidatorRegistry,
-) -> LintResult<ValidationResult> {
-    use ignore::WalkBuilder;
-    use std::sync::Arc;
-
-    let root_dir = resolve_validation_root(path);
-    let mut config = config.clone();
-    config.set_root_dir(root_dir.clone());
-
-    // Initialize shared import cache for project-level validation.
-    // This cache is shared across all file validations, allowing the ImportsValidator
-    // to avoid redundant parsing when traversing import chains that reference the same files.
-    let import_cache: crate::parsers::ImportCache =
-        std::sync::Arc::new(std::sync::RwLock::new(std::collections::HashMap::new()));
-    config.set_import_cache(import_cache);
-
-    // Pre-compile exclude patterns once (avoids N+1 pattern compilation)
-    let exclude_patterns = compile_exclude_patterns(&config.exclude)?;
-    let exclude_patterns = Arc::new(exclude_patterns);
-    let root_path = root_dir.clone();
+/// Whether the project walk skips `rel_path`, either because the file matches
+/// an exclude pattern or because one of its parent directories is pruned.
+pub(crate) fn is_excluded_path(rel_path: &str, exclude_patterns: &[ExcludePattern]) -> bool {
+    if is_excluded_file(rel_path, exclude_patterns) {
+        return true;
+    }
+    let mut dir = rel_path;
+    while let Some((parent, _)) = dir.rsplit_once('/') {
+        if should_prune_dir(parent, exclude_patterns) {
+            return true;
+        }
+        dir = parent;
+    }
+    false
+}
 
-    let walk_root = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+/// Per-directory ignore file read by the project walk, in gitignore syntax.
+const AGNIXIGNORE_FILENAME: &str = ".agnixignore";
 
-    // Shared state for streaming validation
-    let files_checked = Arc::new(AtomicUsize::new(0));
-    let limit_exceeded = Arc::new(AtomicBool::new(false));
-    let agents_md_paths: Arc<Mutex<Vec<PathBuf>>> = Arc::new(Mutex::new(Vec::new()));
-    let instruction_file_paths: Arc<Mutex<Vec<PathBuf>>> = Arc::new(Mutex::new(Vec::new()));
+/// Files below `walk_root` that the project walk visits, honoring
+/// `.gitignore`, `.agnixignore` and the exclude patterns (relative to `root_path`).
+fn walk_project(
+    walk_root: &Path,
+    root_path: &Path,
+    exclude_patterns: &std::sync::Arc<Vec<ExcludePattern>>,
+) -> impl Iterator<Item = PathBuf> + Send + use<> {
+    use ignore::WalkBuilder;
+    use std::sync::Arc;
 
-    // Get the file limit from config (None means no limit)
-    let max_files = config.max_files_to_validate;
+    let root_path = root_path.to_path_buf();
+    let exclude_patterns = Arc::clone(exclude_patterns);
 
-    // Stream file walk directly into parallel validation (no intermediate Vec)
-    // Note: hidden(false) includes .github, .codex, .claude, .cursor directories
+    // Note: hidden(false) includes .github, .codex, .claude, .cursor, .junie directories
     // Note: git_exclude(false) prevents .git/info/exclude from hiding config dirs
     //       that users may locally exclude (e.g. .codex/) but still need linting.
     //       Trade-off: this may surface files the user intentionally excluded locally,
     //       but security is still enforced via symlink rejection (file_utils::safe_read)
     //       and file size limits, so the exposure is limited to lint noise, not unsafe I/O.
-    let mut diagnostics: Vec<Diagnostic> = WalkBuilder::new(&walk_root)
+    // Note: .agnixignore files use gitignore syntax and apply per directory like
+    //       .gitignore, but outside git repositories too. They take precedence over
+    //       .gitignore, so `!pattern` there re-includes a git-ignored file; the
+    //       config exclude patterns are applied afterwards and always win.
+    WalkBuilder::new(walk_root)
         .hidden(false)
         .git_ignore(true)
         .git_exclude(false)
+        .add_custom_ignore_filename(AGNIXIGNORE_FILENAME)
         .filter_entry({
             let exclude_patterns = Arc::clone(&exclude_patterns);
             let root_path = root_path.clone();
@@ -583,62 +1046,183 @@ pub fn validate_project_with_registry(
         .build()
         .filter_map(|entry| entry.ok())
         .filter(|entry| entry.path().is_file())
-        .filter(|entry| {
-            let entry_path = entry.path();
-            let path_str = normalize_rel_path(entry_path, &root_path);
+        .filter(move |entry| {
+            let path_str = normalize_rel_path(entry.path(), &root_path);
             !is_excluded_file(&path_str, exclude_patterns.as_slice())
         })
         .map(|entry| entry.path().to_path_buf())
-        .par_bridge()
-        .flat_map(|file_path| {
-            // Security: Check if file limit has been exceeded
-            // Once exceeded, skip processing additional files
-            // Use SeqCst ordering for consistency with store operations
-            if limit_exceeded.load(Ordering::SeqCst) {
-                return Vec::new();
-            }
+}
 
-            // Count recognized files (detect_file_type is string-only, no I/O)
-            let file_type = detect_file_type(&file_path);
-            if file_type != FileType::Unknown {
-                let count = files_checked.fetch_add(1, Ordering::SeqCst);
-                // Security: Enforce file count limit to prevent DoS
-                if let Some(limit) = max_files {
-                    if count >= limit {
-                        limit_exceeded.store(true, Ordering::SeqCst);
-                        return Vec::new();
-                    }
-                }
-            }
+/// Project-relative paths of the files `walk_project` visits below `root`,
+/// outside `.git`.
+fn collect_project_paths(
+    root: &Path,
+    exclude_patterns: &std::sync::Arc<Vec<ExcludePattern>>,
+) -> Vec<String> {
+    walk_project(root, root, exclude_patterns)
+        .map(|path| normalize_rel_path(&path, root))
+        .filter(|rel_path| !rel_path.split('/').any(|part| part == ".git"))
+        .collect()
+}
 
-            // Collect AGENTS.md paths for AGM-006 check
-            if file_path.file_name().and_then(|n| n.to_str()) == Some("AGENTS.md") {
-                agents_md_paths.lock().unwrap().push(file_path.clone());
-            }
+/// Files validated per parallel task.
+///
+/// Most agent config files are small, so scheduling one task per file spends
+/// more time in rayon than in the validators on skill-heavy repositories.
+/// Batches of this size keep that overhead low while still splitting a few
+/// hundred files across all cores. See the `scale_testing/5000_small_skills`
+/// benchmark.
+const FILE_BATCH_SIZE: usize = 16;
+
+/// Group `iter` into `Vec`s of up to `size` items, preserving order.
+fn batched<I: Iterator>(mut iter: I, size: usize) -> impl Iterator<Item = Vec<I::Item>> {
+    std::iter::from_fn(move || {
+        let batch: Vec<_> = iter.by_ref().take(size).collect();
+        (!batch.is_empty()).then_some(batch)
+    })
+}
 
-            // Collect instruction file paths for XP-004/005/006 checks
-            if schemas::cross_platform::is_instruction_file(&file_path) {
-                instruction_file_paths
-                    .lock()
-                    .unwrap()
-                    .push(file_path.clone());
-            }
+/// Main entry point for validating a project with a custom validator registry
+pub fn validate_project_with_registry(
+    path: &Path,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+) -> LintResult<ValidationResult> {
+    let sink = CollectingSink::default();
+    let mut result = validate_project_streaming(path, config, registry, &sink)?;
+    let mut diagnostics = sink.into_diagnostics();
 
-            // Validate the file
-            match validate_file_with_registry(&file_path, &config, registry) {
-                Ok(file_diagnostics) => file_diagnostics,
-                Err(e) => {
-                    vec![Diagnostic::error(
-                        file_path.clone(),
-                        0,
-                        0,
-                        "file::read",
-                        format!("Failed to validate file: {}", e),
-                    )]
+    // Escalate rules whose warnings exceed their configured project-wide threshold
+    escalate_repeated_warnings(&mut diagnostics, &config.rules.escalate);
+
+    sort_diagnostics(&mut diagnostics);
+    result.diagnostics = diagnostics;
+    Ok(result)
+}
+
+/// Validate a project, passing each diagnostic to `sink` as soon as it is found.
+///
+/// Diagnostics of a file are emitted once that file has been validated, from
+/// the thread that validated it, with severity overrides applied. They are
+/// not sorted, and `[rules.escalate]` is not applied since it needs the whole
+/// run's counts. The returned result carries the file counts and timings; its
+/// `diagnostics` is empty. When [`DiagnosticSink::is_cancelled`] turns true,
+/// no further files are started and project-level checks are skipped.
+pub fn validate_project_streaming(
+    path: &Path,
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+    sink: &dyn DiagnosticSink,
+) -> LintResult<ValidationResult> {
+    use std::sync::Arc;
+
+    let root_dir = resolve_validation_root(path);
+    let mut config = config.clone();
+    config.set_root_dir(root_dir.clone());
+
+    // Initialize shared import cache for project-level validation.
+    // This cache is shared across all file validations, allowing the ImportsValidator
+    // to avoid redundant parsing when traversing import chains that reference the same files.
+    let import_cache: crate::parsers::ImportCache =
+        std::sync::Arc::new(std::sync::RwLock::new(std::collections::HashMap::new()));
+    config.set_import_cache(import_cache);
+
+    // The effective config is final from here on; precompute rule enablement
+    // so the many per-file is_rule_enabled checks avoid rescanning disabled_rules.
+    config.precompute_rule_lookup();
+
+    // Pre-compile exclude patterns once (avoids N+1 pattern compilation) and
+    // share them with validators so REF-008 can tell when an import is skipped
+    let exclude_patterns = compile_exclude_patterns(&config.exclude)?;
+    let exclude_patterns = Arc::new(exclude_patterns);
+    config.set_exclude_patterns(Arc::clone(&exclude_patterns));
+    // Checks that glob the whole tree (COP-007) walk it at most once per run
+    config.share_project_paths();
+
+    // Nested .agnix.toml files below the root merge over this config per directory
+    let nested_configs = NestedConfigs::new(&config, &root_dir);
+
+    // Reuse stored diagnostics for unchanged files when a result cache is enabled
+    let result_cache = config
+        .result_cache_dir()
+        .map(|dir| result_cache::ResultCache::load(dir, &config));
+
+    let root_path = root_dir.clone();
+
+    let walk_root = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+
+    // Shared state for streaming validation
+    let files_checked = Arc::new(AtomicUsize::new(0));
+    let limit_exceeded = Arc::new(AtomicBool::new(false));
+    let project_files: Mutex<ProjectFiles> = Mutex::new(ProjectFiles::default());
+    let files_by_type: Mutex<HashMap<FileType, usize>> = Mutex::new(HashMap::new());
+    let timings = timing::TimingCollector::default();
+
+    // Get the file limit from config (None means no limit)
+    let max_files = config.max_files_to_validate;
+
+    let emit_all = |mut diagnostics: Vec<Diagnostic>| {
+        apply_path_overrides(&mut diagnostics, &config);
+        apply_severity_overrides(&mut diagnostics, &config.rules.severity_overrides);
+        for diagnostic in &diagnostics {
+            sink.emit(diagnostic);
+        }
+    };
+
+    let validate_one = |file_path: PathBuf| {
+        // Security: Check if file limit has been exceeded
+        // Once exceeded, skip processing additional files
+        // Use SeqCst ordering for consistency with store operations
+        if limit_exceeded.load(Ordering::SeqCst) || sink.is_cancelled() {
+            return;
+        }
+
+        // Count recognized files (detection is string-only, no I/O)
+        let file_type = detect_project_file_type(&file_path, Some(&root_path));
+        if file_type != FileType::Unknown {
+            let count = files_checked.fetch_add(1, Ordering::SeqCst);
+            // Security: Enforce file count limit to prevent DoS
+            if let Some(limit) = max_files {
+                if count >= limit {
+                    limit_exceeded.store(true, Ordering::SeqCst);
+                    return;
                 }
             }
-        })
-        .collect();
+            *files_by_type.lock().unwrap().entry(file_type).or_insert(0) += 1;
+        }
+
+        // Collect inputs of the project-level cross-file checks
+        if config.cross_file_rules {
+            project_files.lock().unwrap().record(&file_path, file_type);
+        }
+
+        // Validate the file with the config of its nearest ancestor .agnix.toml
+        let file_config = nested_configs.config_for(&file_path);
+        emit_all(validate_project_file(
+            &file_path,
+            file_type,
+            &file_config,
+            registry,
+            result_cache.as_ref(),
+            &timings,
+        ));
+    };
+
+    // Stream the file walk into parallel validation in small batches (no
+    // intermediate Vec); a task per file costs more than validating a tiny one
+    batched(
+        walk_project(&walk_root, &root_path, &exclude_patterns),
+        FILE_BATCH_SIZE,
+    )
+    .par_bridge()
+    .for_each(|batch| batch.into_iter().for_each(&validate_one));
+
+    if let Some(cache) = result_cache {
+        cache.save();
+    }
+
+    // Report nested config files that failed to load (their parent config was used)
+    emit_all(nested_configs.into_load_errors());
 
     // Check if limit was exceeded and return error
     if limit_exceeded.load(Ordering::Relaxed) {
@@ -650,16 +1234,240 @@ pub fn validate_project_with_registry(
         }
     }
 
+    // Extract final count from atomic counter
+    let files_checked = files_checked.load(Ordering::Relaxed);
+    let (file_timings, validator_timings) = timings.finish();
+    let result = ValidationResult {
+        diagnostics: Vec::new(),
+        files_checked,
+        files_by_type: files_by_type.into_inner().unwrap(),
+        file_timings,
+        validator_timings,
+    };
+    if sink.is_cancelled() {
+        return Ok(result);
+    }
+
+    // Cross-file checks over the collected project files
+    let mut project_files = project_files.into_inner().unwrap();
+    project_files.sort();
+    emit_all(project_rule_diagnostics(&root_dir, &config, &project_files));
+
+    // VER-001: Warn when no tool/spec versions are explicitly pinned (project-level check)
+    // This helps users understand that version-dependent rules are using default assumptions
+    if config.is_rule_enabled("VER-001") {
+        let has_any_version_pinned = config.is_claude_code_version_pinned()
+            || config.tool_versions.codex.is_some()
+            || config.tool_versions.cursor.is_some()
+            || config.tool_versions.copilot.is_some()
+            || config.is_mcp_revision_pinned()
+            || config.spec_revisions.agent_skills_spec.is_some()
+            || config.spec_revisions.agents_md_spec.is_some();
+
+        if !has_any_version_pinned {
+            // Use .agnix.toml path or project root as the file reference
+            let config_file = root_dir.join(".agnix.toml");
+            let report_path = if config_file.exists() {
+                config_file
+            } else {
+                root_dir.clone()
+            };
+
+            emit_all(vec![
+                Diagnostic::info(report_path, 1, 0, "VER-001", t!("rules.ver_001.message"))
+                    .with_suggestion(t!("rules.ver_001.suggestion")),
+            ]);
+        }
+    }
+
+    Ok(result)
+}
+
+/// Sort by severity (errors first), then by file path, then by line/rule for full determinism
+fn sort_diagnostics(diagnostics: &mut [Diagnostic]) {
+    diagnostics.sort_by(|a, b| {
+        a.level
+            .cmp(&b.level)
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.line.cmp(&b.line))
+            .then_with(|| a.rule.cmp(&b.rule))
+    });
+}
+
+/// Inputs of the project-level cross-file checks, grouped by the files each
+/// check reads.
+///
+/// `validate_project` collects these during its walk. Editors that keep a
+/// project open can collect them once with [`ProjectFiles::collect`] and pass
+/// them to [`validate_project_rules`] whenever one of them changes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProjectFiles {
+    /// AGENTS.md files (AGM-006).
+    pub agents_md: Vec<PathBuf>,
+    /// Instruction files (XP-004, XP-005, XP-006, XP-011).
+    pub instruction_files: Vec<PathBuf>,
+    /// Agent definitions (XP-011, XP-012, CC-AG-014, CC-AG-015).
+    pub agent_files: Vec<PathBuf>,
+    /// Memory files such as CLAUDE.md and AGENTS.md (REF-009).
+    pub memory_files: Vec<PathBuf>,
+    /// MCP configuration files (MCP-023, CC-SK-016).
+    pub mcp_files: Vec<PathBuf>,
+    /// SKILL.md files (XP-012, CC-SK-016).
+    pub skill_files: Vec<PathBuf>,
+}
+
+impl ProjectFiles {
+    /// Walk the project at `path` like `validate_project` does, without
+    /// validating anything, and collect the project-level inputs.
+    pub fn collect(path: &Path, config: &LintConfig) -> LintResult<Self> {
+        let root_dir = resolve_validation_root(path);
+        let exclude_patterns = std::sync::Arc::new(compile_exclude_patterns(&config.exclude)?);
+        let walk_root = std::fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf());
+
+        let mut files = Self::default();
+        let mut files_checked = 0;
+        for file_path in walk_project(&walk_root, &root_dir, &exclude_patterns) {
+            let file_type = detect_project_file_type(&file_path, Some(&root_dir));
+            if file_type == FileType::Unknown {
+                continue;
+            }
+            files_checked += 1;
+            // Security: Enforce the same file count limit as validation
+            if let Some(limit) = config.max_files_to_validate {
+                if files_checked > limit {
+                    return Err(LintError::TooManyFiles {
+                        count: files_checked,
+                        limit,
+                    });
+                }
+            }
+            files.record(&file_path, file_type);
+        }
+
+        files.sort();
+        Ok(files)
+    }
+
+    /// Whether a change to `path` can affect the project-level checks.
+    pub fn is_input(path: &Path) -> bool {
+        path.file_name().and_then(|n| n.to_str()) == Some("AGENTS.md")
+            || schemas::cross_platform::is_instruction_file(path)
+            || matches!(
+                detect_file_type(path),
+                FileType::Agent | FileType::ClaudeMd | FileType::Mcp | FileType::Skill
+            )
+    }
+
+    /// Whether `path` is one of the collected inputs.
+    pub fn contains(&self, path: &Path) -> bool {
+        [
+            &self.agents_md,
+            &self.instruction_files,
+            &self.agent_files,
+            &self.memory_files,
+            &self.mcp_files,
+            &self.skill_files,
+        ]
+        .iter()
+        .any(|paths| paths.iter().any(|p| p == path))
+    }
+
+    /// Add `path` to every group it belongs to and keep the groups sorted.
+    pub fn insert(&mut self, path: &Path) {
+        if !self.contains(path) {
+            self.record(path, detect_file_type(path));
+            self.sort();
+        }
+    }
+
+    /// Drop `path` from every group, e.g. after the file was deleted.
+    pub fn remove(&mut self, path: &Path) {
+        for paths in [
+            &mut self.agents_md,
+            &mut self.instruction_files,
+            &mut self.agent_files,
+            &mut self.memory_files,
+            &mut self.mcp_files,
+            &mut self.skill_files,
+        ] {
+            paths.retain(|p| p != path);
+        }
+    }
+
+    /// Add `path`, already detected as `file_type`, to every group it belongs to.
+    fn record(&mut self, path: &Path, file_type: FileType) {
+        if path.file_name().and_then(|n| n.to_str()) == Some("AGENTS.md") {
+            self.agents_md.push(path.to_path_buf());
+        }
+        if schemas::cross_platform::is_instruction_file(path) {
+            self.instruction_files.push(path.to_path_buf());
+        }
+        match file_type {
+            FileType::Agent => self.agent_files.push(path.to_path_buf()),
+            FileType::ClaudeMd => self.memory_files.push(path.to_path_buf()),
+            FileType::Mcp => self.mcp_files.push(path.to_path_buf()),
+            FileType::Skill => self.skill_files.push(path.to_path_buf()),
+            _ => {}
+        }
+    }
+
+    /// Sort every group for deterministic ordering (parallel collection order is non-deterministic)
+    fn sort(&mut self) {
+        self.agents_md.sort();
+        self.instruction_files.sort();
+        self.agent_files.sort();
+        self.memory_files.sort();
+        self.mcp_files.sort();
+        self.skill_files.sort();
+    }
+}
+
+/// Run only the project-level cross-file checks (AGM-006, XP-004..006,
+/// XP-011/012, REF-009, CC-AG-014/015, MCP-023, CC-SK-016) over `files`.
+///
+/// Files are read through `config.fs()`, so a custom [`FileSystem`] can serve
+/// unsaved editor buffers and cached sibling contents. VER-001, severity
+/// overrides and escalation are left to `validate_project`. Returns nothing
+/// when `config.cross_file_rules` is off.
+pub fn validate_project_rules(
+    root: &Path,
+    config: &LintConfig,
+    files: &ProjectFiles,
+) -> Vec<Diagnostic> {
+    let root_dir = resolve_validation_root(root);
+    let mut config = config.clone();
+    config.set_root_dir(root_dir.clone());
+    config.precompute_rule_lookup();
+
+    let mut files = files.clone();
+    files.sort();
+    let mut diagnostics = project_rule_diagnostics(&root_dir, &config, &files);
+    sort_diagnostics(&mut diagnostics);
+    diagnostics
+}
+
+/// Run the project-level cross-file checks over `files`, which must be sorted.
+/// Returns nothing when `config.cross_file_rules` is off.
+fn project_rule_diagnostics(
+    root_dir: &Path,
+    config: &LintConfig,
+    files: &ProjectFiles,
+) -> Vec<Diagnostic> {
+    if !config.cross_file_rules {
+        return Vec::new();
+    }
+
+    let fs = config.fs();
+    let mut diagnostics = Vec::new();
+
     // AGM-006: Check for multiple AGENTS.md files in the directory tree (project-level check)
     if config.is_rule_enabled("AGM-006") {
-        // Sort for deterministic ordering (parallel collection order is non-deterministic)
-        let mut agents_md_paths = agents_md_paths.lock().unwrap().clone();
-        agents_md_paths.sort();
+        let agents_md_paths = &files.agents_md;
 
         if agents_md_paths.len() > 1 {
             for agents_file in agents_md_paths.iter() {
                 let parent_files =
-                    schemas::agents_md::check_agents_md_hierarchy(agents_file, &agents_md_paths);
+                    schemas::agents_md::check_agents_md_hierarchy(agents_file, agents_md_paths);
                 let description = if !parent_files.is_empty() {
                     let parent_paths: Vec<String> = parent_files
                         .iter()
@@ -704,18 +1512,19 @@ pub fn validate_project_with_registry(
     let xp006_enabled = config.is_rule_enabled("XP-006");
 
     if xp004_enabled || xp005_enabled || xp006_enabled {
-        // Use instruction files collected during streaming validation
-        // Sort for deterministic ordering (parallel collection order is non-deterministic)
-        let mut instruction_files = instruction_file_paths.lock().unwrap().clone();
-        instruction_files.sort();
+        let instruction_files = &files.instruction_files;
 
         if instruction_files.len() > 1 {
-            // Read content of all instruction files
-            let mut file_contents: Vec<(PathBuf, String)> = Vec::new();
+            // Extraction results are cached by content hash, so unchanged files
+            // are only re-read and hashed, not re-analyzed
+            let mut cache = analysis_cache::AnalysisCache::load(config.cache_dir());
+            let mut file_analyses: Vec<(PathBuf, analysis_cache::FileAnalysis)> = Vec::new();
             for file_path in instruction_files.iter() {
-                match file_utils::safe_read_file(file_path) {
+                match fs.read_to_string(file_path) {
                     Ok(content) => {
-                        file_contents.push((file_path.clone(), content));
+                        let analysis =
+                            cache.get_or_extract(&content, analysis_cache::FileAnalysis::extract);
+                        file_analyses.push((file_path.clone(), analysis));
                     }
                     Err(e) => {
                         diagnostics.push(Diagnostic::error(
@@ -729,22 +1538,24 @@ pub fn validate_project_with_registry(
                 }
             }
 
+            cache.save();
+
             // XP-004: Detect conflicting build/test commands
             if xp004_enabled {
-                let file_commands: Vec<_> = file_contents
+                let file_commands: Vec<_> = file_analyses
                     .iter()
-                    .map(|(path, content)| {
-                        (
-                            path.clone(),
-                            schemas::cross_platform::extract_build_commands(content),
-                        )
-                    })
+                    .map(|(path, analysis)| (path.clone(), analysis.build_commands.clone()))
                     .filter(|(_, cmds)| !cmds.is_empty())
                     .collect();
 
                 let build_conflicts =
                     schemas::cross_platform::detect_build_conflicts(&file_commands);
                 for conflict in build_conflicts {
+                    let related_message = format!(
+                        "{} is used here: {}",
+                        conflict.file2_manager.as_str(),
+                        conflict.file2_command
+                    );
                     diagnostics.push(
                         Diagnostic::warning(
                             conflict.file1.clone(),
@@ -766,23 +1577,25 @@ pub fn validate_project_with_registry(
                                 }
                             ),
                         )
+                        .with_related(
+                            conflict.file2.clone(),
+                            conflict.file2_line,
+                            0,
+                            related_message,
+                        )
                         .with_suggestion(
                             "Standardize on a single package manager across all instruction files".to_string(),
-                        ),
+                        )
+                        .with_confidence(Confidence::Low),
                     );
                 }
             }
 
             // XP-005: Detect conflicting tool constraints
             if xp005_enabled {
-                let file_constraints: Vec<_> = file_contents
+                let file_constraints: Vec<_> = file_analyses
                     .iter()
-                    .map(|(path, content)| {
-                        (
-                            path.clone(),
-                            schemas::cross_platform::extract_tool_constraints(content),
-                        )
-                    })
+                    .map(|(path, analysis)| (path.clone(), analysis.tool_constraints.clone()))
                     .filter(|(_, constraints)| !constraints.is_empty())
                     .collect();
 
@@ -802,18 +1615,28 @@ pub fn validate_project_with_registry(
                                 conflict.disallow_file.display()
                             ),
                         )
+                        .with_related(
+                            conflict.disallow_file.clone(),
+                            conflict.disallow_line,
+                            0,
+                            format!(
+                                "'{}' is disallowed here: {}",
+                                conflict.tool_name, conflict.disallow_context
+                            ),
+                        )
                         .with_suggestion(
                             "Resolve the conflict by consistently allowing or disallowing the tool".to_string(),
-                        ),
+                        )
+                        .with_confidence(Confidence::Medium),
                     );
                 }
             }
 
             // XP-006: Detect multiple layers without documented precedence
             if xp006_enabled {
-                let layers: Vec<_> = file_contents
+                let layers: Vec<_> = file_analyses
                     .iter()
-                    .map(|(path, content)| schemas::cross_platform::categorize_layer(path, content))
+                    .map(|(path, analysis)| analysis.layer(path))
                     .collect();
 
                 if let Some(issue) = schemas::cross_platform::detect_precedence_issues(&layers) {
@@ -829,7 +1652,8 @@ pub fn validate_project_with_registry(
                             )
                             .with_suggestion(
                                 "Document which file takes precedence (e.g., 'CLAUDE.md takes precedence over AGENTS.md')".to_string(),
-                            ),
+                            )
+                            .with_confidence(Confidence::Medium),
                         );
                     }
                 }
@@ -837,111 +1661,667 @@ pub fn validate_project_with_registry(
         }
     }
 
-    // VER-001: Warn when no tool/spec versions are explicitly pinned (project-level check)
-    // This helps users understand that version-dependent rules are using default assumptions
-    if config.is_rule_enabled("VER-001") {
-        let has_any_version_pinned = config.is_claude_code_version_pinned()
-            || config.tool_versions.codex.is_some()
-            || config.tool_versions.cursor.is_some()
-            || config.tool_versions.copilot.is_some()
-            || config.is_mcp_revision_pinned()
-            || config.spec_revisions.agent_skills_spec.is_some()
-            || config.spec_revisions.agents_md_spec.is_some();
+    // XP-011: Instruction files referencing agents that are not defined in the project
+    if config.is_rule_enabled("XP-011") {
+        let instruction_files = &files.instruction_files;
 
-        if !has_any_version_pinned {
-            // Use .agnix.toml path or project root as the file reference
-            let config_file = root_dir.join(".agnix.toml");
-            let report_path = if config_file.exists() {
-                config_file
-            } else {
-                root_dir.clone()
-            };
+        let mut references: Vec<(PathBuf, schemas::cross_platform::AgentReference)> = Vec::new();
+        for file_path in instruction_files.iter() {
+            if let Ok(content) = fs.read_to_string(file_path) {
+                for reference in schemas::cross_platform::extract_agent_references(&content) {
+                    references.push((file_path.clone(), reference));
+                }
+            }
+        }
+
+        if !references.is_empty() {
+            let mut defined_agents = std::collections::HashSet::new();
+            for agent_path in files.agent_files.iter() {
+                if let Ok(content) = fs.read_to_string(agent_path) {
+                    defined_agents.extend(schemas::cross_platform::agent_definition_names(
+                        agent_path, &content,
+                    ));
+                }
+            }
+
+            for (file_path, reference) in references {
+                if !defined_agents.contains(&reference.name) {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            file_path,
+                            reference.line,
+                            reference.column,
+                            "XP-011",
+                            t!("rules.xp_011.message", name = reference.name.as_str()),
+                        )
+                        .with_suggestion(t!(
+                            "rules.xp_011.suggestion",
+                            name = reference.name.as_str()
+                        ))
+                        .with_confidence(Confidence::Medium),
+                    );
+                }
+            }
+        }
+    }
 
+    // XP-012: Agents and the skills they preload pinning different models (project-level check)
+    if config.is_rule_enabled("XP-012") && !files.agent_files.is_empty() {
+        let read_all = |paths: &[PathBuf]| -> Vec<(PathBuf, String)> {
+            paths
+                .iter()
+                .filter_map(|path| {
+                    fs.read_to_string(path)
+                        .ok()
+                        .map(|content| (path.clone(), content))
+                })
+                .collect()
+        };
+        let agents = read_all(&files.agent_files);
+        let skills = read_all(&files.skill_files);
+
+        for conflict in schemas::cross_platform::detect_model_conflicts(&agents, &skills) {
+            let (agent, skill) = (&conflict.agent, &conflict.skill);
+            let skill_name = conflict.skill_name.as_str();
             diagnostics.push(
-                Diagnostic::info(report_path, 1, 0, "VER-001", t!("rules.ver_001.message"))
-                    .with_suggestion(t!("rules.ver_001.suggestion")),
+                Diagnostic::warning(
+                    agent.path.clone(),
+                    agent.line,
+                    0,
+                    "XP-012",
+                    t!(
+                        "rules.xp_012.message_agent",
+                        model = agent.model.as_str(),
+                        skill = skill_name,
+                        other_model = skill.model.as_str(),
+                        other = skill.path.display().to_string()
+                    ),
+                )
+                .with_related(
+                    skill.path.clone(),
+                    skill.line,
+                    0,
+                    t!("rules.xp_012.related", model = skill.model.as_str()),
+                )
+                .with_suggestion(t!("rules.xp_012.suggestion"))
+                .with_confidence(Confidence::Medium),
+            );
+            diagnostics.push(
+                Diagnostic::warning(
+                    skill.path.clone(),
+                    skill.line,
+                    0,
+                    "XP-012",
+                    t!(
+                        "rules.xp_012.message_skill",
+                        model = skill.model.as_str(),
+                        skill = skill_name,
+                        other_model = agent.model.as_str(),
+                        other = agent.path.display().to_string()
+                    ),
+                )
+                .with_related(
+                    agent.path.clone(),
+                    agent.line,
+                    0,
+                    t!("rules.xp_012.related", model = agent.model.as_str()),
+                )
+                .with_suggestion(t!("rules.xp_012.suggestion"))
+                .with_confidence(Confidence::Medium),
             );
         }
     }
 
-    // Sort by severity (errors first), then by file path, then by line/rule for full determinism
-    diagnostics.sort_by(|a, b| {
-        a.level
-            .cmp(&b.level)
-            .then_with(|| a.file.cmp(&b.file))
-            .then_with(|| a.line.cmp(&b.line))
-            .then_with(|| a.rule.cmp(&b.rule))
-    });
+    // REF-009: Circular @import chains between memory files (project-level check)
+    if config.is_rule_enabled("REF-009") && config.rules.import_references {
+        for cycle in rules::imports::find_import_cycles(&files.memory_files, root_dir, config) {
+            diagnostics.push(
+                Diagnostic::error(
+                    cycle.path,
+                    cycle.line,
+                    cycle.column,
+                    "REF-009",
+                    t!("rules.ref_009.message", chain = cycle.chain.as_str()),
+                )
+                .with_suggestion(t!("rules.ref_009.suggestion")),
+            );
+        }
+    }
 
-    // Extract final count from atomic counter
-    let files_checked = files_checked.load(Ordering::Relaxed);
+    // CC-AG-014, CC-AG-015: Handoff targets and delegation cycles between agents (project-level checks)
+    let ag014_enabled = config.is_rule_enabled("CC-AG-014");
+    let ag015_enabled = config.is_rule_enabled("CC-AG-015");
+    if ag014_enabled || ag015_enabled {
+        let agents: Vec<(PathBuf, String)> = files
+            .agent_files
+            .iter()
+            .filter_map(|path| {
+                fs.read_to_string(path)
+                    .ok()
+                    .map(|content| (path.clone(), content))
+            })
+            .collect();
 
-    Ok(ValidationResult {
-        diagnostics,
-        files_checked,
-    })
-}
+        for issue in schemas::agent::check_handoffs(&agents) {
+            let diagnostic = match &issue.kind {
+                schemas::agent::HandoffIssueKind::UnknownTarget { target } if ag014_enabled => {
+                    Diagnostic::warning(
+                        issue.path.clone(),
+                        issue.line,
+                        issue.column,
+                        "CC-AG-014",
+                        t!("rules.cc_ag_014.message", target = target.as_str()),
+                    )
+                    .with_suggestion(t!("rules.cc_ag_014.suggestion", target = target.as_str()))
+                }
+                schemas::agent::HandoffIssueKind::SelfHandoff { agent } if ag015_enabled => {
+                    Diagnostic::warning(
+                        issue.path.clone(),
+                        issue.line,
+                        issue.column,
+                        "CC-AG-015",
+                        t!("rules.cc_ag_015.message_self", agent = agent.as_str()),
+                    )
+                    .with_suggestion(t!("rules.cc_ag_015.suggestion"))
+                }
+                schemas::agent::HandoffIssueKind::Cycle { chain } if ag015_enabled => {
+                    Diagnostic::warning(
+                        issue.path.clone(),
+                        issue.line,
+                        issue.column,
+                        "CC-AG-015",
+                        t!("rules.cc_ag_015.message", chain = chain.as_str()),
+                    )
+                    .with_suggestion(t!("rules.cc_ag_015.suggestion"))
+                }
+                _ => continue,
+            };
+            diagnostics.push(diagnostic);
+        }
+    }
 
-fn resolve_validation_root(path: &Path) -> PathBuf {
-    let candidate = if path.is_file() {
-        path.parent().unwrap_or(Path::new("."))
-    } else {
-        path
-    };
-    std::fs::canonicalize(candidate).unwrap_or_else(|_| candidate.to_path_buf())
-}
+    // MCP-023: Server names defined in more than one MCP config file (project-level check)
+    if config.is_rule_enabled("MCP-023") {
+        if files.mcp_files.len() > 1 {
+            let mcp_files: Vec<(PathBuf, String)> = files
+                .mcp_files
+                .iter()
+                .filter_map(|path| {
+                    fs.read_to_string(path)
+                        .ok()
+                        .map(|content| (path.clone(), content))
+                })
+                .collect();
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+            for duplicate in schemas::mcp::find_duplicate_server_names(&mcp_files) {
+                let others = duplicate
+                    .others
+                    .iter()
+                    .map(|other| other.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                diagnostics.push(
+                    Diagnostic::warning(
+                        duplicate.path,
+                        duplicate.line,
+                        duplicate.column,
+                        "MCP-023",
+                        t!(
+                            "rules.mcp_023.message",
+                            name = duplicate.name.as_str(),
+                            others = others.as_str()
+                        ),
+                    )
+                    .with_suggestion(t!("rules.mcp_023.suggestion")),
+                );
+            }
+        }
+    }
 
-    fn workspace_root() -> &'static Path {
-        use std::sync::OnceLock;
+    // CC-SK-016: Skill allowed-tools naming MCP servers the project does not define (project-level check)
+    // Skipped when the project has no MCP config, since servers may then come from user scope
+    if config.is_rule_enabled("CC-SK-016") && config.rules.frontmatter_validation {
+        if !files.mcp_files.is_empty() {
+            let mut references: Vec<(PathBuf, rules::skill::McpToolReference)> = Vec::new();
+            for file_path in files.skill_files.iter() {
+                if let Ok(content) = fs.read_to_string(file_path) {
+                    for reference in rules::skill::extract_mcp_tool_references(&content) {
+                        references.push((file_path.clone(), reference));
+                    }
+                }
+            }
 
-        static ROOT: OnceLock<PathBuf> = OnceLock::new();
-        ROOT.get_or_init(|| {
-            let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
-            for ancestor in manifest_dir.ancestors() {
-                let cargo_toml = ancestor.join("Cargo.toml");
-                if let Ok(content) = std::fs::read_to_string(&cargo_toml) {
-                    if content.contains("[workspace]") || content.contains("[workspace.") {
-                        return ancestor.to_path_buf();
+            if !references.is_empty() {
+                let mcp_files: Vec<(PathBuf, String)> = files
+                    .mcp_files
+                    .iter()
+                    .filter_map(|path| {
+                        fs.read_to_string(path)
+                            .ok()
+                            .map(|content| (path.clone(), content))
+                    })
+                    .collect();
+                let defined_servers = schemas::mcp::defined_server_names(&mcp_files);
+
+                for (file_path, reference) in references {
+                    if !defined_servers.contains(&reference.server) {
+                        diagnostics.push(
+                            Diagnostic::warning(
+                                file_path,
+                                reference.line,
+                                reference.column,
+                                "CC-SK-016",
+                                t!(
+                                    "rules.cc_sk_016.message",
+                                    tool = reference.tool.as_str(),
+                                    server = reference.server.as_str()
+                                ),
+                            )
+                            .with_suggestion(t!(
+                                "rules.cc_sk_016.suggestion",
+                                server = reference.server.as_str()
+                            )),
+                        );
                     }
                 }
             }
-            panic!(
-                "Failed to locate workspace root from CARGO_MANIFEST_DIR={}",
-                manifest_dir.display()
-            );
-        })
-        .as_path()
+        }
     }
 
-    #[test]
-    fn test_detect_skill_file() {
-        assert_eq!(detect_file_type(Path::new("SKILL.md")), FileType::Skill);
-        assert_eq!(
-            detect_file_type(Path::new(".claude/skills/my-skill/SKILL.md")),
-            FileType::Skill
-        );
-    }
+    diagnostics
+}
 
-    #[test]
-    fn test_detect_claude_md() {
-        assert_eq!(detect_file_type(Path::new("CLAUDE.md")), FileType::ClaudeMd);
-        assert_eq!(detect_file_type(Path::new("AGENTS.md")), FileType::ClaudeMd);
-        assert_eq!(
-            detect_file_type(Path::new("project/CLAUDE.md")),
-            FileType::ClaudeMd
-        );
-    }
+/// Validate exactly the files in `paths` without walking `root`.
+///
+/// Intended for incremental linting (e.g. the staged files of a pre-commit
+/// hook). Files of unknown type and files matching `exclude` (relative to
+/// `root`) are skipped, and the rest are validated in parallel. Project-level
+/// cross-file checks (AGM-006, XP-004..006, XP-011, MCP-023, VER-001) need the
+/// whole tree and are not run.
+pub fn validate_files(
+    root: &Path,
+    paths: &[PathBuf],
+    config: &LintConfig,
+) -> LintResult<ValidationResult> {
+    validate_files_with_registry(root, paths, config, default_registry())
+}
 
-    #[test]
-    fn test_detect_instruction_variants() {
-        // CLAUDE.local.md variant
-        assert_eq!(
-            detect_file_type(Path::new("CLAUDE.local.md")),
-            FileType::ClaudeMd
+/// Validate exactly the files in `paths` with a custom validator registry
+pub fn validate_files_with_registry(
+    root: &Path,
+    paths: &[PathBuf],
+    config: &LintConfig,
+    registry: &ValidatorRegistry,
+) -> LintResult<ValidationResult> {
+    let root_dir = resolve_validation_root(root);
+    let mut config = config.clone();
+    config.set_root_dir(root_dir.clone());
+    let import_cache: crate::parsers::ImportCache =
+        std::sync::Arc::new(std::sync::RwLock::new(std::collections::HashMap::new()));
+    config.set_import_cache(import_cache);
+    config.precompute_rule_lookup();
+
+    let exclude_patterns = std::sync::Arc::new(compile_exclude_patterns(&config.exclude)?);
+    config.set_exclude_patterns(std::sync::Arc::clone(&exclude_patterns));
+
+    let nested_configs = NestedConfigs::new(&config, &root_dir);
+
+    // Canonicalize like the project walk so reported paths match it
+    let mut files: Vec<PathBuf> = paths
+        .iter()
+        .map(|path| std::fs::canonicalize(path).unwrap_or_else(|_| path.clone()))
+        .filter(|path| detect_project_file_type(path, Some(&root_dir)) != FileType::Unknown)
+        .filter(|path| !is_excluded_path(&normalize_rel_path(path, &root_dir), &exclude_patterns))
+        .collect();
+    files.sort();
+    files.dedup();
+
+    if let Some(limit) = config.max_files_to_validate {
+        if files.len() > limit {
+            return Err(LintError::TooManyFiles {
+                count: files.len(),
+                limit,
+            });
+        }
+    }
+
+    let timings = timing::TimingCollector::default();
+    let mut diagnostics: Vec<Diagnostic> = files
+        .par_chunks(FILE_BATCH_SIZE)
+        .flat_map_iter(|batch| {
+            batch.iter().flat_map(|file_path| {
+                let file_config = nested_configs.config_for(file_path);
+                validate_project_file(
+                    file_path,
+                    detect_project_file_type(file_path, Some(&root_dir)),
+                    &file_config,
+                    registry,
+                    None,
+                    &timings,
+                )
+            })
+        })
+        .collect();
+    diagnostics.extend(nested_configs.into_load_errors());
+
+    apply_path_overrides(&mut diagnostics, &config);
+    apply_severity_overrides(&mut diagnostics, &config.rules.severity_overrides);
+    escalate_repeated_warnings(&mut diagnostics, &config.rules.escalate);
+    sort_diagnostics(&mut diagnostics);
+
+    let mut files_by_type = HashMap::new();
+    for file in &files {
+        *files_by_type
+            .entry(detect_project_file_type(file, Some(&root_dir)))
+            .or_insert(0) += 1;
+    }
+
+    let (file_timings, validator_timings) = timings.finish();
+
+    Ok(ValidationResult {
+        diagnostics,
+        files_checked: files.len(),
+        files_by_type,
+        file_timings,
+        validator_timings,
+    })
+}
+
+/// Per-directory configs built from nested `.agnix.toml` files.
+///
+/// Each directory below the validation root inherits its parent directory's
+/// config; a `.agnix.toml` in the directory is merged over it with
+/// [`LintConfig::merge`]. The root's own config is the one passed in, and
+/// project-wide settings (`exclude`, `max_files_to_validate`,
+/// `rules.escalate`, cross-file checks) always come from it.
+struct NestedConfigs {
+    base: std::sync::Arc<LintConfig>,
+    root: PathBuf,
+    by_dir: Mutex<HashMap<PathBuf, std::sync::Arc<LintConfig>>>,
+    load_errors: Mutex<Vec<Diagnostic>>,
+}
+
+impl NestedConfigs {
+    fn new(base: &LintConfig, root: &Path) -> Self {
+        Self {
+            base: std::sync::Arc::new(base.clone()),
+            root: root.to_path_buf(),
+            by_dir: Mutex::new(HashMap::new()),
+            load_errors: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Effective config for a file.
+    fn config_for(&self, file_path: &Path) -> std::sync::Arc<LintConfig> {
+        match file_path.parent() {
+            Some(dir) => {
+                let mut by_dir = self.by_dir.lock().unwrap();
+                self.config_for_dir(dir, &mut by_dir)
+            }
+            None => std::sync::Arc::clone(&self.base),
+        }
+    }
+
+    fn config_for_dir(
+        &self,
+        dir: &Path,
+        by_dir: &mut HashMap<PathBuf, std::sync::Arc<LintConfig>>,
+    ) -> std::sync::Arc<LintConfig> {
+        if dir == self.root || !dir.starts_with(&self.root) {
+            return std::sync::Arc::clone(&self.base);
+        }
+        if let Some(config) = by_dir.get(dir) {
+            return std::sync::Arc::clone(config);
+        }
+
+        let parent = match dir.parent() {
+            Some(parent_dir) => self.config_for_dir(parent_dir, by_dir),
+            None => std::sync::Arc::clone(&self.base),
+        };
+
+        let config_path = dir.join(".agnix.toml");
+        let config = if config_path.is_file() {
+            match LintConfig::load(&config_path) {
+                Ok(child) => {
+                    let mut merged = LintConfig::merge(&parent, &child);
+                    merged.precompute_rule_lookup();
+                    std::sync::Arc::new(merged)
+                }
+                Err(e) => {
+                    self.load_errors.lock().unwrap().push(Diagnostic::error(
+                        config_path,
+                        0,
+                        0,
+                        "config::load",
+                        format!("Failed to load nested config: {}", e),
+                    ));
+                    parent
+                }
+            }
+        } else {
+            parent
+        };
+
+        by_dir.insert(dir.to_path_buf(), std::sync::Arc::clone(&config));
+        config
+    }
+
+    fn into_load_errors(self) -> Vec<Diagnostic> {
+        self.load_errors.into_inner().unwrap()
+    }
+}
+
+/// Drop diagnostics made redundant by another finding at the same position.
+///
+/// A diagnostic subsumes the rules it lists with
+/// [`Diagnostic::with_related_rule`] plus those configured for its rule in
+/// `[rules.subsumes]`; a diagnostic of one of those rules on the same file,
+/// line and column is removed as long as the subsuming diagnostic is itself
+/// kept. When two rules subsume each other the one reported first wins.
+fn drop_subsumed_diagnostics(
+    diagnostics: &mut Vec<Diagnostic>,
+    subsumes: &std::collections::BTreeMap<String, Vec<String>>,
+) {
+    let subsumes_rule = |by: &Diagnostic, rule: &str| {
+        by.rule != rule
+            && by
+                .related_rules
+                .iter()
+                .chain(subsumes.get(&by.rule).into_iter().flatten())
+                .any(|related| related == rule)
+    };
+
+    let mut positions: HashMap<(&Path, usize, usize), Vec<usize>> = HashMap::new();
+    for (index, diag) in diagnostics.iter().enumerate() {
+        positions
+            .entry((diag.file.as_path(), diag.line, diag.column))
+            .or_default()
+            .push(index);
+    }
+
+    let mut dropped = vec![false; diagnostics.len()];
+    for mut pending in positions.into_values() {
+        while !pending.is_empty() {
+            // Keep a diagnostic no other pending one subsumes, or the first
+            // one if they all subsume each other, then drop what it subsumes
+            let next = pending
+                .iter()
+                .position(|&i| {
+                    !pending
+                        .iter()
+                        .any(|&j| subsumes_rule(&diagnostics[j], &diagnostics[i].rule))
+                })
+                .unwrap_or(0);
+            let kept = pending.remove(next);
+            pending.retain(|&i| {
+                let subsumed = subsumes_rule(&diagnostics[kept], &diagnostics[i].rule);
+                dropped[i] |= subsumed;
+                !subsumed
+            });
+        }
+    }
+
+    let mut index = 0;
+    diagnostics.retain(|_| {
+        index += 1;
+        !dropped[index - 1]
+    });
+}
+
+/// Drop diagnostics whose rule an `[[overrides]]` entry turns off for
+/// their file.
+///
+/// Paths are matched relative to the config's root dir; overrides with an
+/// invalid glob are skipped (`LintConfig::validate` reports them).
+fn apply_path_overrides(diagnostics: &mut Vec<Diagnostic>, config: &LintConfig) {
+    if config.overrides.is_empty() {
+        return;
+    }
+    let overrides: Vec<_> = config
+        .overrides
+        .iter()
+        .filter_map(|o| {
+            glob::Pattern::new(&o.files)
+                .ok()
+                .map(|pattern| (pattern, o))
+        })
+        .collect();
+    let root = config
+        .root_dir()
+        .map(PathBuf::as_path)
+        .unwrap_or(Path::new(""));
+    diagnostics.retain(|diag| {
+        let rel_path = normalize_rel_path(&diag.file, root);
+        !overrides
+            .iter()
+            .any(|(pattern, o)| pattern.matches(&rel_path) && o.disables(&diag.rule))
+    });
+}
+
+/// Rewrite the level of diagnostics whose rule has a configured severity.
+///
+/// Runs before escalation and `--strict`, so a rule overridden to a warning
+/// can still be escalated by either.
+fn apply_severity_overrides(
+    diagnostics: &mut [Diagnostic],
+    overrides: &std::collections::BTreeMap<String, DiagnosticLevel>,
+) {
+    if overrides.is_empty() {
+        return;
+    }
+    for diag in diagnostics.iter_mut() {
+        if let Some(level) = overrides.get(&diag.rule) {
+            diag.level = *level;
+        }
+    }
+}
+
+/// Promote warnings to errors for rules that fire more often than their
+/// `[rules.escalate]` threshold, treating widespread hits as systemic.
+fn escalate_repeated_warnings(
+    diagnostics: &mut [Diagnostic],
+    escalate: &std::collections::BTreeMap<String, usize>,
+) {
+    if escalate.is_empty() {
+        return;
+    }
+
+    let mut warning_counts: HashMap<&str, usize> = HashMap::new();
+    for diag in diagnostics.iter() {
+        if diag.level == DiagnosticLevel::Warning && escalate.contains_key(&diag.rule) {
+            *warning_counts.entry(diag.rule.as_str()).or_default() += 1;
+        }
+    }
+
+    let escalated: Vec<String> = warning_counts
+        .into_iter()
+        .filter(|(rule, count)| {
+            escalate
+                .get(*rule)
+                .is_some_and(|threshold| count > threshold)
+        })
+        .map(|(rule, _)| rule.to_string())
+        .collect();
+
+    for diag in diagnostics.iter_mut() {
+        if diag.level == DiagnosticLevel::Warning && escalated.contains(&diag.rule) {
+            diag.level = DiagnosticLevel::Error;
+        }
+    }
+}
+
+fn resolve_validation_root(path: &Path) -> PathBuf {
+    let candidate = if path.is_file() {
+        path.parent().unwrap_or(Path::new("."))
+    } else {
+        path
+    };
+    std::fs::canonicalize(candidate).unwrap_or_else(|_| candidate.to_path_buf())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workspace_root() -> &'static Path {
+        use std::sync::OnceLock;
+
+        static ROOT: OnceLock<PathBuf> = OnceLock::new();
+        ROOT.get_or_init(|| {
+            let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+            for ancestor in manifest_dir.ancestors() {
+                let cargo_toml = ancestor.join("Cargo.toml");
+                if let Ok(content) = std::fs::read_to_string(&cargo_toml) {
+                    if content.contains("[workspace]") || content.contains("[workspace.") {
+                        return ancestor.to_path_buf();
+                    }
+                }
+            }
+            panic!(
+                "Failed to locate workspace root from CARGO_MANIFEST_DIR={}",
+                manifest_dir.display()
+            );
+        })
+        .as_path()
+    }
+
+    #[test]
+    fn test_file_type_names_are_unique() {
+        let names: std::collections::HashSet<_> =
+            FileType::ALL.iter().map(|t| t.as_str()).collect();
+        assert_eq!(names.len(), FileType::ALL.len());
+        assert_eq!(FileType::ClaudeMd.to_string(), "claude-md");
+        assert_eq!(
+            format!("{}", FileType::CopilotScoped),
+            FileType::CopilotScoped.as_str()
+        );
+    }
+
+    #[test]
+    fn test_detect_skill_file() {
+        assert_eq!(detect_file_type(Path::new("SKILL.md")), FileType::Skill);
+        assert_eq!(
+            detect_file_type(Path::new(".claude/skills/my-skill/SKILL.md")),
+            FileType::Skill
+        );
+    }
+
+    #[test]
+    fn test_detect_claude_md() {
+        assert_eq!(detect_file_type(Path::new("CLAUDE.md")), FileType::ClaudeMd);
+        assert_eq!(detect_file_type(Path::new("AGENTS.md")), FileType::ClaudeMd);
+        assert_eq!(
+            detect_file_type(Path::new("project/CLAUDE.md")),
+            FileType::ClaudeMd
+        );
+    }
+
+    #[test]
+    fn test_detect_instruction_variants() {
+        // CLAUDE.local.md variant
+        assert_eq!(
+            detect_file_type(Path::new("CLAUDE.local.md")),
+            FileType::ClaudeMd
         );
         assert_eq!(
             detect_file_type(Path::new("project/CLAUDE.local.md")),
@@ -995,6 +2375,87 @@ mod tests {
             detect_file_type(Path::new(".claude/agents/helper.md")),
             FileType::Agent
         );
+        assert_eq!(
+            detect_file_type(Path::new(".agents/reviewer.md")),
+            FileType::Agent
+        );
+    }
+
+    #[test]
+    fn test_detect_nested_agents() {
+        let root = Path::new("/project");
+        // Three levels below the agents directory
+        assert_eq!(
+            detect_project_file_type(
+                Path::new("/project/.agents/research/web/deep/summarizer.md"),
+                Some(root)
+            ),
+            FileType::Agent
+        );
+        assert_eq!(
+            detect_project_file_type(
+                Path::new("/project/agents/team/backend/ops/deployer.md"),
+                Some(root)
+            ),
+            FileType::Agent
+        );
+        assert_eq!(
+            detect_project_file_type(
+                Path::new("/project/.claude/agents/a/b/c/helper.md"),
+                Some(root)
+            ),
+            FileType::Agent
+        );
+        // Validating an agents directory itself
+        assert_eq!(
+            detect_project_file_type(
+                Path::new("/project/agents/helper.md"),
+                Some(Path::new("/project/agents"))
+            ),
+            FileType::Agent
+        );
+        // Without a root only the parent and grandparent count
+        assert_ne!(
+            detect_file_type(Path::new("agents/team/backend/deployer.md")),
+            FileType::Agent
+        );
+        // A file merely named like the directory is not an agent
+        assert_ne!(
+            detect_file_type(Path::new("notes/agents.md")),
+            FileType::Agent
+        );
+        assert_ne!(
+            detect_file_type(Path::new("my-agents/helper.md")),
+            FileType::Agent
+        );
+    }
+
+    #[test]
+    fn test_agents_directory_above_project_root_is_ignored() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = temp.path().join("agents").join("proj");
+        std::fs::create_dir_all(project.join("notes").join("deep")).unwrap();
+        std::fs::write(project.join("guide.md"), "# Guide\n\nPlain notes.\n").unwrap();
+        std::fs::write(
+            project.join("notes").join("deep").join("guide.md"),
+            "# Guide\n\nPlain notes.\n",
+        )
+        .unwrap();
+
+        let result = validate_project(&project, &LintConfig::default()).unwrap();
+        assert_eq!(
+            result.files_by_type.get(&FileType::GenericMarkdown),
+            Some(&2)
+        );
+        assert!(!result.files_by_type.contains_key(&FileType::Agent));
+        assert!(
+            !result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule.starts_with("CC-AG-")),
+            "{:?}",
+            result.diagnostics
+        );
     }
 
     #[test]
@@ -1007,6 +2468,19 @@ mod tests {
             detect_file_type(Path::new(".claude/settings.local.json")),
             FileType::Hooks
         );
+        assert_eq!(
+            detect_file_type(Path::new(".claude/hooks/PreToolUse.json")),
+            FileType::Hooks
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.claude/hooks/Stop.json")),
+            FileType::Hooks
+        );
+        // Only .claude/hooks/ counts; other hooks/ directories are not hook configs
+        assert_ne!(
+            detect_file_type(Path::new("src/hooks/PreToolUse.json")),
+            FileType::Hooks
+        );
     }
 
     #[test]
@@ -1129,6 +2603,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_detect_mcp_yaml_and_toml() {
+        assert_eq!(detect_file_type(Path::new("mcp.yaml")), FileType::Mcp);
+        assert_eq!(detect_file_type(Path::new("mcp.yml")), FileType::Mcp);
+        assert_eq!(detect_file_type(Path::new("mcp.toml")), FileType::Mcp);
+        assert_eq!(
+            detect_file_type(Path::new("servers.mcp.yaml")),
+            FileType::Mcp
+        );
+        assert_eq!(
+            detect_file_type(Path::new("servers.mcp.toml")),
+            FileType::Mcp
+        );
+        assert_ne!(detect_file_type(Path::new("config.yaml")), FileType::Mcp);
+        assert_ne!(detect_file_type(Path::new("mcp-tools.yaml")), FileType::Mcp);
+    }
+
     #[test]
     fn test_detect_codex() {
         assert_eq!(
@@ -1151,144 +2642,939 @@ mod tests {
     }
 
     #[test]
-    fn test_detect_unknown() {
-        assert_eq!(detect_file_type(Path::new("main.rs")), FileType::Unknown);
-        assert_eq!(
-            detect_file_type(Path::new("package.json")),
-            FileType::Unknown
-        );
+    fn test_detect_unknown() {
+        assert_eq!(detect_file_type(Path::new("main.rs")), FileType::Unknown);
+        assert_eq!(
+            detect_file_type(Path::new("package.json")),
+            FileType::Unknown
+        );
+    }
+
+    #[test]
+    fn test_detect_gemini_md() {
+        assert_eq!(detect_file_type(Path::new("GEMINI.md")), FileType::GeminiMd);
+        assert_eq!(
+            detect_file_type(Path::new("GEMINI.local.md")),
+            FileType::GeminiMd
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/GEMINI.md")),
+            FileType::GeminiMd
+        );
+        assert_eq!(
+            detect_file_type(Path::new("subdir/GEMINI.local.md")),
+            FileType::GeminiMd
+        );
+        assert_eq!(
+            detect_file_type(Path::new("deep/nested/GEMINI.md")),
+            FileType::GeminiMd
+        );
+
+        // Gemini CLI only loads the exact upper-case filenames
+        assert_ne!(detect_file_type(Path::new("gemini.md")), FileType::GeminiMd);
+        assert_ne!(
+            detect_file_type(Path::new("GEMINI.override.md")),
+            FileType::GeminiMd
+        );
+    }
+
+    #[test]
+    fn test_detect_windsurf() {
+        assert_eq!(
+            detect_file_type(Path::new(".windsurf/rules/typescript.md")),
+            FileType::Windsurf
+        );
+        assert_eq!(
+            detect_file_type(Path::new("packages/web/.windsurf/rules/style.md")),
+            FileType::Windsurf
+        );
+        assert_eq!(
+            detect_file_type(Path::new(".windsurfrules")),
+            FileType::WindsurfLegacy
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.windsurfrules")),
+            FileType::WindsurfLegacy
+        );
+        // rules/ outside .windsurf is not a Windsurf rule
+        assert_ne!(
+            detect_file_type(Path::new("docs/rules/style.md")),
+            FileType::Windsurf
+        );
+    }
+
+    #[test]
+    fn test_detect_aider_conventions() {
+        assert_eq!(
+            detect_file_type(Path::new("CONVENTIONS.md")),
+            FileType::ClaudeMd
+        );
+        // Only the project root's CONVENTIONS.md belongs to Aider
+        assert_ne!(
+            detect_file_type(Path::new("project/CONVENTIONS.md")),
+            FileType::ClaudeMd
+        );
+        assert_eq!(
+            detect_project_file_type(
+                Path::new("/project/CONVENTIONS.md"),
+                Some(Path::new("/project"))
+            ),
+            FileType::ClaudeMd
+        );
+        assert_ne!(
+            detect_project_file_type(
+                Path::new("/project/src/CONVENTIONS.md"),
+                Some(Path::new("/project"))
+            ),
+            FileType::ClaudeMd
+        );
+        assert_ne!(
+            detect_file_type(Path::new("conventions.md")),
+            FileType::ClaudeMd
+        );
+    }
+
+    #[test]
+    fn test_validators_for_windsurf() {
+        let registry = ValidatorRegistry::with_defaults();
+        assert_eq!(registry.validators_for(FileType::Windsurf).len(), 4);
+        assert_eq!(registry.validators_for(FileType::WindsurfLegacy).len(), 4);
+    }
+
+    #[test]
+    fn test_validators_for_gemini_md() {
+        let registry = ValidatorRegistry::with_defaults();
+        let validators = registry.validators_for(FileType::GeminiMd);
+        assert_eq!(validators.len(), 6);
+    }
+
+    #[test]
+    fn test_validators_for_skill() {
+        let registry = ValidatorRegistry::with_defaults();
+        let validators = registry.validators_for(FileType::Skill);
+        assert_eq!(validators.len(), 4);
+    }
+
+    #[test]
+    fn test_validators_for_claude_md() {
+        let registry = ValidatorRegistry::with_defaults();
+        let validators = registry.validators_for(FileType::ClaudeMd);
+        assert_eq!(validators.len(), 9);
+    }
+
+    #[test]
+    fn test_validators_for_mcp() {
+        let registry = ValidatorRegistry::with_defaults();
+        let validators = registry.validators_for(FileType::Mcp);
+        assert_eq!(validators.len(), 2);
+    }
+
+    #[test]
+    fn test_validators_for_unknown() {
+        let registry = ValidatorRegistry::with_defaults();
+        let validators = registry.validators_for(FileType::Unknown);
+        assert_eq!(validators.len(), 0);
+    }
+
+    #[test]
+    fn test_validate_file_with_custom_registry() {
+        struct DummyValidator;
+
+        impl Validator for DummyValidator {
+            fn validate(
+                &self,
+                path: &Path,
+                _content: &str,
+                _config: &LintConfig,
+            ) -> Vec<Diagnostic> {
+                vec![Diagnostic::error(
+                    path.to_path_buf(),
+                    1,
+                    1,
+                    "TEST-001",
+                    "Registry override".to_string(),
+                )]
+            }
+        }
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_path = temp.path().join("SKILL.md");
+        std::fs::write(&skill_path, "---\nname: test\n---\nBody").unwrap();
+
+        let mut registry = ValidatorRegistry::new();
+        registry.register(FileType::Skill, || Box::new(DummyValidator));
+
+        let diagnostics =
+            validate_file_with_registry(&skill_path, &LintConfig::default(), &registry).unwrap();
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "TEST-001");
+    }
+
+    #[test]
+    fn test_registry_unregister_clears_file_type() {
+        let mut registry = ValidatorRegistry::with_defaults();
+        assert!(!registry.validators_for(FileType::Skill).is_empty());
+
+        registry.unregister(FileType::Skill);
+
+        assert!(registry.validators_for(FileType::Skill).is_empty());
+        // Other file types keep their validators
+        assert_eq!(registry.validators_for(FileType::Mcp).len(), 2);
+        // Unregistering a type with no validators is a no-op
+        registry.unregister(FileType::Unknown);
+        assert!(registry.validators_for(FileType::Unknown).is_empty());
+    }
+
+    #[test]
+    fn test_registry_replace_swaps_built_in_validators() {
+        struct CustomSkillValidator;
+
+        impl Validator for CustomSkillValidator {
+            fn validate(
+                &self,
+                path: &Path,
+                _content: &str,
+                _config: &LintConfig,
+            ) -> Vec<Diagnostic> {
+                vec![Diagnostic::warning(
+                    path.to_path_buf(),
+                    1,
+                    1,
+                    "CUSTOM-001",
+                    "Custom skill check".to_string(),
+                )]
+            }
+        }
+
+        let mut registry = ValidatorRegistry::with_defaults();
+        registry.replace(FileType::Skill, || Box::new(CustomSkillValidator));
+        assert_eq!(registry.validators_for(FileType::Skill).len(), 1);
+        assert_eq!(registry.validators_for(FileType::ClaudeMd).len(), 9);
+
+        // Built-in AS-* rules no longer run on skills
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_path = temp.path().join("SKILL.md");
+        std::fs::write(&skill_path, "---\nname: Bad_Name\n---\nBody").unwrap();
+        let diagnostics =
+            validate_file_with_registry(&skill_path, &LintConfig::default(), &registry).unwrap();
+        let rules: Vec<&str> = diagnostics.iter().map(|d| d.rule.as_str()).collect();
+        assert_eq!(rules, vec!["CUSTOM-001"]);
+
+        // Registering after a replace appends as usual
+        registry.register(FileType::Skill, || Box::new(CustomSkillValidator));
+        assert_eq!(registry.validators_for(FileType::Skill).len(), 2);
+    }
+
+    #[test]
+    fn test_validate_file_unknown_type() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let unknown_path = temp.path().join("test.rs");
+        std::fs::write(&unknown_path, "fn main() {}").unwrap();
+
+        let config = LintConfig::default();
+        let diagnostics = validate_file(&unknown_path, &config).unwrap();
+
+        assert_eq!(diagnostics.len(), 0);
+    }
+
+    #[test]
+    fn test_validate_file_skill() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_path = temp.path().join("SKILL.md");
+        std::fs::write(
+            &skill_path,
+            "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+        )
+        .unwrap();
+
+        let config = LintConfig::default();
+        let diagnostics = validate_file(&skill_path, &config).unwrap();
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_validate_file_invalid_skill() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_path = temp.path().join("SKILL.md");
+        std::fs::write(
+            &skill_path,
+            "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
+        )
+        .unwrap();
+
+        let config = LintConfig::default();
+        let diagnostics = validate_file(&skill_path, &config).unwrap();
+
+        assert!(!diagnostics.is_empty());
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-SK-006"));
+    }
+
+    #[test]
+    fn test_default_registry_is_shared() {
+        assert!(std::ptr::eq(default_registry(), default_registry()));
+        let fresh = ValidatorRegistry::with_defaults();
+        for file_type in [FileType::Skill, FileType::Mcp, FileType::ClaudeMd] {
+            assert_eq!(
+                default_registry().validators_for(file_type).len(),
+                fresh.validators_for(file_type).len()
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_file_many_files_matches_fresh_registry() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let paths: Vec<PathBuf> = (0..200)
+            .map(|i| {
+                let dir = temp.path().join(format!("skill-{}", i));
+                std::fs::create_dir_all(&dir).unwrap();
+                let path = dir.join("SKILL.md");
+                // Alternate valid and invalid skills
+                let name = if i % 2 == 0 {
+                    "deploy-prod"
+                } else {
+                    "test-skill"
+                };
+                std::fs::write(
+                    &path,
+                    format!(
+                        "---\nname: {}\ndescription: Use when testing\n---\nBody",
+                        name
+                    ),
+                )
+                .unwrap();
+                path
+            })
+            .collect();
+
+        let config = LintConfig::default();
+        let fresh = ValidatorRegistry::with_defaults();
+        for (i, path) in paths.iter().enumerate() {
+            let shared: Vec<_> = validate_file(path, &config)
+                .unwrap()
+                .into_iter()
+                .map(|d| (d.rule, d.line, d.column))
+                .collect();
+            let expected: Vec<_> = validate_file_with_registry(path, &config, &fresh)
+                .unwrap()
+                .into_iter()
+                .map(|d| (d.rule, d.line, d.column))
+                .collect();
+            assert_eq!(shared, expected, "{}", path.display());
+            assert_eq!(
+                shared.iter().any(|(rule, _, _)| rule == "CC-SK-006"),
+                i % 2 == 0
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_and_validate_all_returns_type_with_diagnostics() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_path = temp.path().join("SKILL.md");
+        std::fs::write(
+            &skill_path,
+            "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
+        )
+        .unwrap();
+        let other_path = temp.path().join("notes.txt");
+        std::fs::write(&other_path, "not validated").unwrap();
+
+        let config = LintConfig::default();
+        let results = detect_and_validate_all(&[&skill_path, &other_path], &config);
+
+        assert_eq!(results.len(), 2);
+        let (file_type, diagnostics) = &results[0];
+        assert_eq!(*file_type, FileType::Skill);
+        let rules = |diags: &[Diagnostic]| diags.iter().map(|d| d.rule.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            rules(diagnostics),
+            rules(&validate_file(&skill_path, &config).unwrap()),
+            "must match validate_file"
+        );
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-SK-006"));
+        assert_eq!(results[1].0, FileType::Unknown);
+        assert!(results[1].1.is_empty());
+    }
+
+    #[test]
+    fn test_detect_and_validate_all_reports_read_errors_per_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let missing = temp.path().join("SKILL.md");
+        let skill_dir = temp.path().join("deploy");
+        std::fs::create_dir(&skill_dir).unwrap();
+        let skill_path = skill_dir.join("SKILL.md");
+        std::fs::write(
+            &skill_path,
+            "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
+        )
+        .unwrap();
+
+        let results = detect_and_validate_all(&[&missing, &skill_path], &LintConfig::default());
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, FileType::Skill);
+        assert_eq!(results[0].1.len(), 1);
+        assert_eq!(results[0].1[0].rule, "file::read");
+        assert_eq!(results[0].1[0].file, missing);
+        assert_eq!(results[1].0, FileType::Skill);
+        assert!(results[1].1.iter().any(|d| d.rule == "CC-SK-006"));
+    }
+
+    #[test]
+    fn test_validate_files_only_checks_given_paths() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let invalid = "---\nname: deploy-prod\ndescription: Deploys\n---\nBody";
+        for dir in ["staged", "unstaged", "vendor"] {
+            let skill_dir = temp.path().join(dir);
+            std::fs::create_dir_all(&skill_dir).unwrap();
+            std::fs::write(skill_dir.join("SKILL.md"), invalid).unwrap();
+        }
+        std::fs::write(temp.path().join("notes.txt"), "ignored").unwrap();
+
+        let mut config = LintConfig::default();
+        config.exclude = vec!["vendor/**".to_string()];
+        let paths = vec![
+            temp.path().join("staged/SKILL.md"),
+            temp.path().join("vendor/SKILL.md"),
+            temp.path().join("notes.txt"),
+            temp.path().join("staged/SKILL.md"),
+        ];
+        let result = validate_files(temp.path(), &paths, &config).unwrap();
+
+        assert_eq!(result.files_checked, 1);
+        assert!(result.diagnostics.iter().any(|d| d.rule == "CC-SK-006"));
+        assert!(
+            result
+                .diagnostics
+                .iter()
+                .all(|d| d.file.ends_with("staged/SKILL.md"))
+        );
+    }
+
+    #[test]
+    fn test_validate_files_reports_unreadable_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let missing = temp.path().join("SKILL.md");
+        let result = validate_files(temp.path(), &[missing], &LintConfig::default()).unwrap();
+        assert_eq!(result.files_checked, 1);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].rule, "file::read");
+    }
+
+    #[test]
+    fn test_validate_content_does_not_touch_disk() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_path = temp.path().join("SKILL.md");
+        assert!(!skill_path.exists());
+
+        let diagnostics = validate_content(
+            &skill_path,
+            "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
+            &LintConfig::default(),
+        );
+
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-SK-006"));
+        assert!(diagnostics.iter().all(|d| d.file == skill_path));
+    }
+
+    #[test]
+    fn test_validate_content_uses_buffer_over_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_path = temp.path().join("SKILL.md");
+        std::fs::write(
+            &skill_path,
+            "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
+        )
+        .unwrap();
+
+        let diagnostics = validate_content(
+            &skill_path,
+            "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
+            &LintConfig::default(),
+        );
+
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_validate_content_honors_inline_suppression() {
+        let path = Path::new("CLAUDE.md");
+        let flagged = "# Project\n\nBe helpful and accurate when responding.\n";
+        let diagnostics = validate_content(path, flagged, &LintConfig::default());
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-MEM-005"));
+
+        let suppressed = "# Project\n\n<!-- agnix-disable-next-line CC-MEM-005 -->\nBe helpful and accurate when responding.\n";
+        let diagnostics = validate_content(path, suppressed, &LintConfig::default());
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-MEM-005"));
+
+        let same_line =
+            "# Project\n\nBe helpful and accurate when responding. <!-- agnix-disable-line -->\n";
+        let diagnostics = validate_content(path, same_line, &LintConfig::default());
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-MEM-005"));
+    }
+
+    #[test]
+    fn test_validate_content_unknown_type() {
+        let diagnostics =
+            validate_content(Path::new("main.rs"), "fn main() {}", &LintConfig::default());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_validate_content_with_custom_registry() {
+        struct DummyValidator;
+
+        impl Validator for DummyValidator {
+            fn validate(
+                &self,
+                path: &Path,
+                content: &str,
+                _config: &LintConfig,
+            ) -> Vec<Diagnostic> {
+                vec![Diagnostic::info(
+                    path.to_path_buf(),
+                    1,
+                    1,
+                    "TEST-001",
+                    content.to_string(),
+                )]
+            }
+        }
+
+        let mut registry = ValidatorRegistry::new();
+        registry.register(FileType::Skill, || Box::new(DummyValidator));
+
+        let diagnostics = validate_content_with_registry(
+            Path::new("SKILL.md"),
+            "unsaved buffer",
+            &LintConfig::default(),
+            &registry,
+        );
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "unsaved buffer");
+    }
+
+    struct OutOfRangeValidator;
+
+    impl Validator for OutOfRangeValidator {
+        fn validate(&self, path: &Path, _content: &str, _config: &LintConfig) -> Vec<Diagnostic> {
+            vec![Diagnostic::error(
+                path.to_path_buf(),
+                99,
+                1,
+                "TEST-001",
+                "Out of range".to_string(),
+            )]
+        }
+    }
+
+    fn validate_with_out_of_range_validator() -> Vec<Diagnostic> {
+        let mut registry = ValidatorRegistry::new();
+        registry.register(FileType::Skill, || Box::new(OutOfRangeValidator));
+        validate_content_with_registry(
+            Path::new("SKILL.md"),
+            "---\nname: test\n---\nBody",
+            &LintConfig::default(),
+            &registry,
+        )
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "validator bug: TEST-001 reported invalid diagnostic")]
+    fn test_out_of_range_diagnostic_panics_in_debug() {
+        validate_with_out_of_range_validator();
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn test_out_of_range_diagnostic_is_clamped_in_release() {
+        let diagnostics = validate_with_out_of_range_validator();
+        assert_eq!(diagnostics[0].line, 5);
+    }
+
+    #[test]
+    fn test_diagnostic_position_check_accepts_valid_diagnostics() {
+        let content = "line one\nline two\n";
+        let mut diagnostics = vec![
+            Diagnostic::error(PathBuf::from("a.md"), 2, 9, "CC-HK-001", "x".to_string()),
+            // One past the last line is allowed for end-of-file findings
+            Diagnostic::error(
+                PathBuf::from("a.md"),
+                3,
+                0,
+                "mcp::invalid_tool",
+                "x".to_string(),
+            ),
+            // Diagnostics about other files are not checked against this content
+            Diagnostic::error(PathBuf::from("b.md"), 50, 1, "REF-001", "x".to_string()),
+        ];
+        check_diagnostic_positions(Path::new("a.md"), content, &mut diagnostics);
+        assert_eq!(diagnostics[1].line, 3);
+        assert_eq!(diagnostics[2].line, 50);
+    }
+
+    #[test]
+    fn test_internal_rule_id_format() {
+        assert!(is_internal_rule_id("mcp::invalid_tool"));
+        assert!(is_internal_rule_id("file::read"));
+        assert!(!is_internal_rule_id("mcp::"));
+        assert!(!is_internal_rule_id("Bad::Id"));
+        assert!(!diagnostics::is_valid_rule_id("as-001"));
+    }
+
+    #[test]
+    fn test_nested_config_applies_to_subdirectory() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let generic = "# Project\n\nBe helpful and accurate when responding.\n";
+        let nested = temp.path().join("packages").join("web");
+        std::fs::create_dir_all(nested.join("deeper")).unwrap();
+        std::fs::write(temp.path().join("CLAUDE.md"), generic).unwrap();
+        std::fs::write(nested.join("CLAUDE.md"), generic).unwrap();
+        std::fs::write(nested.join("deeper").join("CLAUDE.md"), generic).unwrap();
+        std::fs::write(
+            nested.join(".agnix.toml"),
+            "[rules]\ndisabled_rules = [\"CC-MEM-005\"]\n",
+        )
+        .unwrap();
+
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["CC-MEM-006".to_string()];
+        let result = validate_project(temp.path(), &config).unwrap();
+
+        let mem_005_files: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-MEM-005")
+            .map(|d| d.file.clone())
+            .collect();
+        assert!(!mem_005_files.is_empty(), "root CLAUDE.md keeps CC-MEM-005");
+        assert!(
+            mem_005_files
+                .iter()
+                .all(|f| !f.starts_with(nested.canonicalize().unwrap())),
+            "nested config disables CC-MEM-005 for its directory and below: {:?}",
+            mem_005_files
+        );
+        // The parent's disabled_rules are inherited by the nested directory
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "CC-MEM-006"));
+    }
+
+    #[test]
+    fn test_invalid_nested_config_is_reported() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let nested = temp.path().join("sub");
+        std::fs::create_dir_all(&nested).unwrap();
+        std::fs::write(nested.join("CLAUDE.md"), "# Notes\n").unwrap();
+        std::fs::write(nested.join(".agnix.toml"), "[rules\nbroken").unwrap();
+
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+
+        assert!(
+            result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule == "config::load" && d.file.ends_with(".agnix.toml"))
+        );
+    }
+
+    #[test]
+    fn test_escalate_promotes_warnings_above_threshold() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("CLAUDE.md"),
+            "# Project\n\nBe helpful and accurate when responding.\n\nBe helpful and accurate when responding.\n",
+        )
+        .unwrap();
+
+        let count_levels = |config: &LintConfig| {
+            let result = validate_project(temp.path(), config).unwrap();
+            let mem_005: Vec<_> = result
+                .diagnostics
+                .into_iter()
+                .filter(|d| d.rule == "CC-MEM-005")
+                .collect();
+            let errors = mem_005
+                .iter()
+                .filter(|d| d.level == DiagnosticLevel::Error)
+                .count();
+            (mem_005.len(), errors)
+        };
+
+        let (fired, errors) = count_levels(&LintConfig::default());
+        assert!(fired >= 2, "expected repeated CC-MEM-005 warnings");
+        assert_eq!(errors, 0);
+
+        // At the threshold: stays a warning
+        let mut at_threshold = LintConfig::default();
+        at_threshold
+            .rules
+            .escalate
+            .insert("CC-MEM-005".to_string(), fired);
+        assert_eq!(count_levels(&at_threshold), (fired, 0));
+
+        // Above the threshold: every occurrence becomes an error
+        let mut above_threshold = LintConfig::default();
+        above_threshold
+            .rules
+            .escalate
+            .insert("CC-MEM-005".to_string(), fired - 1);
+        assert_eq!(count_levels(&above_threshold), (fired, fired));
+    }
+
+    #[test]
+    fn test_severity_overrides_remap_and_resort() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("CLAUDE.md"),
+            "# Project\n\nBe helpful and accurate when responding.\n",
+        )
+        .unwrap();
+        let skill_dir = temp.path().join("deploy-prod");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
+        )
+        .unwrap();
+
+        let mut config = LintConfig::default();
+        config
+            .rules
+            .severity_overrides
+            .insert("CC-MEM-005".to_string(), DiagnosticLevel::Error);
+        config
+            .rules
+            .severity_overrides
+            .insert("CC-SK-006".to_string(), DiagnosticLevel::Warning);
+        let result = validate_project(temp.path(), &config).unwrap();
+
+        let level_of = |rule: &str| {
+            result
+                .diagnostics
+                .iter()
+                .find(|d| d.rule == rule)
+                .unwrap_or_else(|| panic!("expected {}", rule))
+                .level
+        };
+        assert_eq!(level_of("CC-MEM-005"), DiagnosticLevel::Error);
+        assert_eq!(level_of("CC-SK-006"), DiagnosticLevel::Warning);
+        // Still sorted severity-first after remapping
+        assert!(
+            result
+                .diagnostics
+                .windows(2)
+                .all(|pair| pair[0].level <= pair[1].level)
+        );
+    }
+
+    #[test]
+    fn test_batched_keeps_order_and_remainder() {
+        let batches: Vec<Vec<usize>> = batched(0..7, 3).collect();
+        assert_eq!(batches, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!(batched(std::iter::empty::<usize>(), 3).count(), 0);
     }
 
     #[test]
-    fn test_detect_gemini_md() {
-        assert_eq!(detect_file_type(Path::new("GEMINI.md")), FileType::GeminiMd);
-        assert_eq!(
-            detect_file_type(Path::new("GEMINI.local.md")),
-            FileType::GeminiMd
-        );
-        assert_eq!(
-            detect_file_type(Path::new("project/GEMINI.md")),
-            FileType::GeminiMd
-        );
-        assert_eq!(
-            detect_file_type(Path::new("subdir/GEMINI.local.md")),
-            FileType::GeminiMd
-        );
-    }
+    fn test_overrides_disable_rules_for_matching_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let content = "# Project\n\nBe helpful and accurate when responding.\n";
+        std::fs::write(temp.path().join("CLAUDE.md"), content).unwrap();
+        std::fs::create_dir_all(temp.path().join("docs")).unwrap();
+        std::fs::write(temp.path().join("docs/CLAUDE.md"), content).unwrap();
 
-    #[test]
-    fn test_validators_for_gemini_md() {
-        let registry = ValidatorRegistry::with_defaults();
-        let validators = registry.validators_for(FileType::GeminiMd);
-        assert_eq!(validators.len(), 5);
-    }
+        let config: LintConfig = toml::from_str(
+            "[[overrides]]\nfiles = \"docs/**\"\ndisabled_rules = [\"CC-MEM-005\"]\n",
+        )
+        .unwrap();
+        let result = validate_project(temp.path(), &config).unwrap();
 
-    #[test]
-    fn test_validators_for_skill() {
-        let registry = ValidatorRegistry::with_defaults();
-        let validators = registry.validators_for(FileType::Skill);
-        assert_eq!(validators.len(), 3);
+        let flagged: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-MEM-005")
+            .map(|d| d.file.clone())
+            .collect();
+        assert_eq!(flagged.len(), 1, "{:?}", result.diagnostics);
+        assert!(!flagged[0].parent().unwrap().ends_with("docs"));
     }
 
     #[test]
-    fn test_validators_for_claude_md() {
-        let registry = ValidatorRegistry::with_defaults();
-        let validators = registry.validators_for(FileType::ClaudeMd);
-        assert_eq!(validators.len(), 7);
-    }
+    fn test_overrides_enabled_rules_narrow_matching_files() {
+        let mut config = LintConfig::default();
+        config.set_root_dir(PathBuf::from("/project"));
+        config.overrides = vec![
+            config::ConfigOverride {
+                files: "docs/**".to_string(),
+                enabled_rules: vec!["XP-003".to_string()],
+                disabled_rules: Vec::new(),
+            },
+            config::ConfigOverride {
+                files: "[".to_string(),
+                enabled_rules: Vec::new(),
+                disabled_rules: vec!["XP-003".to_string()],
+            },
+        ];
+        let mut diagnostics = vec![
+            Diagnostic::warning(
+                PathBuf::from("/project/docs/a.md"),
+                1,
+                0,
+                "XP-003",
+                "a".to_string(),
+            ),
+            Diagnostic::warning(
+                PathBuf::from("/project/docs/a.md"),
+                2,
+                0,
+                "XP-001",
+                "b".to_string(),
+            ),
+            Diagnostic::warning(
+                PathBuf::from("/project/CLAUDE.md"),
+                3,
+                0,
+                "XP-001",
+                "c".to_string(),
+            ),
+        ];
 
-    #[test]
-    fn test_validators_for_mcp() {
-        let registry = ValidatorRegistry::with_defaults();
-        let validators = registry.validators_for(FileType::Mcp);
-        assert_eq!(validators.len(), 1);
-    }
+        apply_path_overrides(&mut diagnostics, &config);
 
-    #[test]
-    fn test_validators_for_unknown() {
-        let registry = ValidatorRegistry::with_defaults();
-        let validators = registry.validators_for(FileType::Unknown);
-        assert_eq!(validators.len(), 0);
+        let lines: Vec<_> = diagnostics.iter().map(|d| d.line).collect();
+        assert_eq!(lines, vec![1, 3]);
     }
 
     #[test]
-    fn test_validate_file_with_custom_registry() {
-        struct DummyValidator;
+    fn test_invalid_skill_name_reported_once() {
+        let diagnostics = validate_content(
+            Path::new("my-skill/SKILL.md"),
+            "---\nname: -my--skill\ndescription: Use when deploying\n---\nBody\n",
+            &LintConfig::default(),
+        );
 
-        impl Validator for DummyValidator {
-            fn validate(
-                &self,
-                path: &Path,
-                _content: &str,
-                _config: &LintConfig,
-            ) -> Vec<Diagnostic> {
-                vec![Diagnostic::error(
-                    path.to_path_buf(),
-                    1,
-                    1,
-                    "TEST-001",
-                    "Registry override".to_string(),
-                )]
-            }
-        }
+        let name_rules: Vec<_> = diagnostics
+            .iter()
+            .map(|d| d.rule.as_str())
+            .filter(|rule| ["AS-004", "AS-005", "AS-006"].contains(rule))
+            .collect();
+        assert_eq!(name_rules, vec!["AS-004"]);
 
-        let temp = tempfile::TempDir::new().unwrap();
-        let skill_path = temp.path().join("SKILL.md");
-        std::fs::write(&skill_path, "---\nname: test\n---\nBody").unwrap();
+        // The default subsumption is config, so clearing it reports all three
+        let mut config = LintConfig::default();
+        config.rules.subsumes.clear();
+        let diagnostics = validate_content(
+            Path::new("my-skill/SKILL.md"),
+            "---\nname: -my--skill\ndescription: Use when deploying\n---\nBody\n",
+            &config,
+        );
+        let mut name_rules: Vec<_> = diagnostics
+            .iter()
+            .map(|d| d.rule.as_str())
+            .filter(|rule| ["AS-004", "AS-005", "AS-006"].contains(rule))
+            .collect();
+        name_rules.sort_unstable();
+        assert_eq!(name_rules, vec!["AS-004", "AS-005", "AS-006"]);
+    }
 
-        let mut registry = ValidatorRegistry::new();
-        registry.register(FileType::Skill, || Box::new(DummyValidator));
+    #[test]
+    fn test_configured_subsumption_only_drops_same_position() {
+        let file = PathBuf::from("CLAUDE.md");
+        let mut diagnostics = vec![
+            Diagnostic::warning(file.clone(), 1, 0, "CC-MEM-005", "a".to_string()),
+            Diagnostic::warning(file.clone(), 1, 0, "CC-MEM-006", "b".to_string()),
+            Diagnostic::warning(file.clone(), 2, 0, "CC-MEM-006", "c".to_string()),
+            Diagnostic::warning(file, 3, 0, "CC-MEM-007", "d".to_string())
+                .with_related_rule("CC-MEM-007"),
+        ];
+        let subsumes = std::collections::BTreeMap::from([(
+            "CC-MEM-005".to_string(),
+            vec!["CC-MEM-006".to_string()],
+        )]);
 
-        let diagnostics =
-            validate_file_with_registry(&skill_path, &LintConfig::default(), &registry).unwrap();
+        drop_subsumed_diagnostics(&mut diagnostics, &subsumes);
 
-        assert_eq!(diagnostics.len(), 1);
-        assert_eq!(diagnostics[0].rule, "TEST-001");
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(messages, vec!["a", "c", "d"]);
     }
 
     #[test]
-    fn test_validate_file_unknown_type() {
-        let temp = tempfile::TempDir::new().unwrap();
-        let unknown_path = temp.path().join("test.rs");
-        std::fs::write(&unknown_path, "fn main() {}").unwrap();
+    fn test_subsumption_needs_a_surviving_subsumer() {
+        let file = PathBuf::from("CLAUDE.md");
+        let mut diagnostics = vec![
+            Diagnostic::warning(file.clone(), 1, 0, "CC-MEM-005", "a".to_string()),
+            Diagnostic::warning(file.clone(), 1, 0, "CC-MEM-006", "b".to_string()),
+            Diagnostic::warning(file.clone(), 2, 0, "CC-MEM-007", "c".to_string()),
+            Diagnostic::warning(file.clone(), 2, 0, "CC-MEM-008", "d".to_string()),
+            Diagnostic::warning(file, 2, 0, "CC-MEM-009", "e".to_string()),
+        ];
+        // Mutual subsumption keeps the first; in a chain the subsumed middle
+        // rule no longer hides the last one
+        let subsumes = std::collections::BTreeMap::from([
+            ("CC-MEM-005".to_string(), vec!["CC-MEM-006".to_string()]),
+            ("CC-MEM-006".to_string(), vec!["CC-MEM-005".to_string()]),
+            ("CC-MEM-007".to_string(), vec!["CC-MEM-008".to_string()]),
+            ("CC-MEM-008".to_string(), vec!["CC-MEM-009".to_string()]),
+        ]);
 
-        let config = LintConfig::default();
-        let diagnostics = validate_file(&unknown_path, &config).unwrap();
+        drop_subsumed_diagnostics(&mut diagnostics, &subsumes);
 
-        assert_eq!(diagnostics.len(), 0);
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(messages, vec!["a", "c", "e"]);
     }
 
     #[test]
-    fn test_validate_file_skill() {
-        let temp = tempfile::TempDir::new().unwrap();
-        let skill_path = temp.path().join("SKILL.md");
-        std::fs::write(
-            &skill_path,
-            "---\nname: test-skill\ndescription: Use when testing\n---\nBody",
-        )
-        .unwrap();
+    fn test_severity_override_runs_before_escalation() {
+        let file = PathBuf::from("CLAUDE.md");
+        let mut diagnostics = vec![
+            Diagnostic::info(file.clone(), 1, 0, "CC-MEM-005", "a".to_string()),
+            Diagnostic::info(file, 2, 0, "CC-MEM-005", "b".to_string()),
+        ];
+        let overrides = std::collections::BTreeMap::from([(
+            "CC-MEM-005".to_string(),
+            DiagnosticLevel::Warning,
+        )]);
+        let escalate = std::collections::BTreeMap::from([("CC-MEM-005".to_string(), 1)]);
 
-        let config = LintConfig::default();
-        let diagnostics = validate_file(&skill_path, &config).unwrap();
+        apply_severity_overrides(&mut diagnostics, &overrides);
+        escalate_repeated_warnings(&mut diagnostics, &escalate);
 
-        assert!(diagnostics.is_empty());
+        assert!(
+            diagnostics
+                .iter()
+                .all(|d| d.level == DiagnosticLevel::Error)
+        );
     }
 
     #[test]
-    fn test_validate_file_invalid_skill() {
-        let temp = tempfile::TempDir::new().unwrap();
-        let skill_path = temp.path().join("SKILL.md");
-        std::fs::write(
-            &skill_path,
-            "---\nname: deploy-prod\ndescription: Deploys\n---\nBody",
-        )
-        .unwrap();
+    fn test_escalate_only_affects_configured_warnings() {
+        let file = PathBuf::from("CLAUDE.md");
+        let mut diagnostics = vec![
+            Diagnostic::warning(file.clone(), 1, 0, "CC-MEM-005", "a".to_string()),
+            Diagnostic::warning(file.clone(), 2, 0, "CC-MEM-005", "b".to_string()),
+            Diagnostic::info(file.clone(), 3, 0, "CC-MEM-005", "c".to_string()),
+            Diagnostic::warning(file.clone(), 4, 0, "CC-MEM-006", "d".to_string()),
+            Diagnostic::warning(file, 5, 0, "CC-MEM-006", "e".to_string()),
+        ];
+        let escalate = std::collections::BTreeMap::from([("CC-MEM-005".to_string(), 1)]);
 
-        let config = LintConfig::default();
-        let diagnostics = validate_file(&skill_path, &config).unwrap();
+        escalate_repeated_warnings(&mut diagnostics, &escalate);
 
-        assert!(!diagnostics.is_empty());
-        assert!(diagnostics.iter().any(|d| d.rule == "CC-SK-006"));
+        let levels: Vec<_> = diagnostics.iter().map(|d| d.level).collect();
+        assert_eq!(
+            levels,
+            vec![
+                DiagnosticLevel::Error,
+                DiagnosticLevel::Error,
+                DiagnosticLevel::Info,
+                DiagnosticLevel::Warning,
+                DiagnosticLevel::Warning,
+            ]
+        );
     }
 
     #[test]
@@ -1572,6 +3858,9 @@ mod tests {
             "files_checked should count only recognized file types, got {}",
             result.files_checked
         );
+        assert_eq!(result.files_by_type.get(&FileType::Skill), Some(&1));
+        assert_eq!(result.files_by_type.get(&FileType::ClaudeMd), Some(&1));
+        assert_eq!(result.files_by_type.values().sum::<usize>(), 2);
     }
 
     #[test]
@@ -1644,6 +3933,25 @@ mod tests {
         assert!(diagnostics.iter().any(|d| d.rule == "MCP-003"));
     }
 
+    #[test]
+    fn test_validate_file_mcp_yaml_and_toml() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let yaml_path = temp.path().join("servers.mcp.yaml");
+        std::fs::write(&yaml_path, "mcpServers:\n  remote:\n    type: http\n").unwrap();
+        let toml_path = temp.path().join("mcp.toml");
+        std::fs::write(&toml_path, "[mcpServers.remote]\ntype = \"http\"\n").unwrap();
+
+        let config = LintConfig::default();
+        let yaml = validate_file(&yaml_path, &config).unwrap();
+        let toml = validate_file(&toml_path, &config).unwrap();
+
+        // http server without url: MCP-010, located at the server key
+        let yaml_diag = yaml.iter().find(|d| d.rule == "MCP-010").unwrap();
+        assert_eq!(yaml_diag.line, 2);
+        let toml_diag = toml.iter().find(|d| d.rule == "MCP-010").unwrap();
+        assert_eq!(toml_diag.line, 1);
+    }
+
     #[test]
     fn test_validate_project_mcp_detection() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -1987,66 +4295,249 @@ allowed-tools: Read Write
 
         // Create nested AGENTS.md files
         std::fs::write(
-            temp.path().join("AGENTS.md"),
-            "# Project\n\nThis project does something.",
+            temp.path().join("AGENTS.md"),
+            "# Project\n\nThis project does something.",
+        )
+        .unwrap();
+
+        let subdir = temp.path().join("subdir");
+        std::fs::create_dir_all(&subdir).unwrap();
+        std::fs::write(
+            subdir.join("AGENTS.md"),
+            "# Subproject\n\nThis is a nested AGENTS.md.",
+        )
+        .unwrap();
+
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["AGM-006".to_string()];
+        let result = validate_project(temp.path(), &config).unwrap();
+
+        // Should not detect AGM-006 when disabled
+        let agm_006: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "AGM-006")
+            .collect();
+        assert!(agm_006.is_empty(), "AGM-006 should not fire when disabled");
+    }
+
+    // ===== XP-004: Conflicting Build Commands =====
+
+    #[test]
+    fn test_xp_004_conflicting_package_managers() {
+        let temp = tempfile::TempDir::new().unwrap();
+
+        // CLAUDE.md uses npm
+        std::fs::write(
+            temp.path().join("CLAUDE.md"),
+            "# Project\n\nUse `npm install` for dependencies.",
+        )
+        .unwrap();
+
+        // AGENTS.md uses pnpm
+        std::fs::write(
+            temp.path().join("AGENTS.md"),
+            "# Project\n\nUse `pnpm install` for dependencies.",
+        )
+        .unwrap();
+
+        let config = LintConfig::default();
+        let result = validate_project(temp.path(), &config).unwrap();
+
+        let xp_004: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "XP-004")
+            .collect();
+        assert!(
+            !xp_004.is_empty(),
+            "Should detect conflicting package managers"
+        );
+        assert!(xp_004.iter().any(|d| d.message.contains("npm")));
+        assert!(xp_004.iter().any(|d| d.message.contains("pnpm")));
+
+        // The other side of the conflict is attached as a related location
+        let (related_file, related_line, _, related_message) = &xp_004[0].related[0];
+        assert_ne!(related_file, &xp_004[0].file);
+        assert_eq!(*related_line, 3);
+        assert!(related_message.contains("install"));
+    }
+
+    #[test]
+    fn test_result_cache_skips_unchanged_files() {
+        static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        struct CountingValidator;
+
+        impl Validator for CountingValidator {
+            fn validate(
+                &self,
+                path: &Path,
+                _content: &str,
+                _config: &LintConfig,
+            ) -> Vec<Diagnostic> {
+                CALLS.fetch_add(1, Ordering::SeqCst);
+                vec![Diagnostic::warning(
+                    path.to_path_buf(),
+                    1,
+                    1,
+                    "TEST-001",
+                    "counted".to_string(),
+                )]
+            }
+        }
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skills").join("counted");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "---\nname: counted\n---\n").unwrap();
+
+        let mut registry = ValidatorRegistry::new();
+        registry.register(FileType::Skill, || Box::new(CountingValidator));
+        let mut config = LintConfig::default();
+        config.set_result_cache_dir(cache_dir.path().to_path_buf());
+
+        let first = validate_project_with_registry(temp.path(), &config, &registry).unwrap();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        assert!(cache_dir.path().join("diagnostics.json").exists());
+
+        // Unchanged file: diagnostics come from the cache
+        let second = validate_project_with_registry(temp.path(), &config, &registry).unwrap();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+        let rules = |result: &ValidationResult| -> Vec<String> {
+            result.diagnostics.iter().map(|d| d.rule.clone()).collect()
+        };
+        assert_eq!(rules(&first), rules(&second));
+        assert_eq!(second.files_checked, 1);
+
+        // Edited content is re-validated
+        std::fs::write(skill_dir.join("SKILL.md"), "---\nname: edited\n---\n").unwrap();
+        validate_project_with_registry(temp.path(), &config, &registry).unwrap();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+        // A config change invalidates the whole cache
+        config.rules.disabled_rules = vec!["AS-001".to_string()];
+        validate_project_with_registry(temp.path(), &config, &registry).unwrap();
+        assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_result_cache_refreshes_when_import_target_appears() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        std::fs::create_dir(&project).unwrap();
+        std::fs::write(project.join("CLAUDE.md"), "# Project\n\nSee @rules.md\n").unwrap();
+
+        let mut config = LintConfig::default();
+        config.set_result_cache_dir(cache_dir.path().to_path_buf());
+        let missing_import =
+            |result: &ValidationResult| result.diagnostics.iter().any(|d| d.rule == "CC-MEM-001");
+
+        let first = validate_project(&project, &config).unwrap();
+        assert!(missing_import(&first));
+        let second = validate_project(&project, &config).unwrap();
+        assert!(missing_import(&second));
+
+        // CLAUDE.md is unchanged, but the import it reported now exists
+        std::fs::write(project.join("rules.md"), "# Rules\n").unwrap();
+        let third = validate_project(&project, &config).unwrap();
+        assert!(!missing_import(&third));
+    }
+
+    #[test]
+    fn test_result_cache_refreshes_cop_007_when_a_match_appears() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let project = temp.path().join("project");
+        let instructions = project.join(".github").join("instructions");
+        std::fs::create_dir_all(&instructions).unwrap();
+        std::fs::write(
+            instructions.join("python.instructions.md"),
+            "---\napplyTo: \"**/*.py\"\n---\n# Python\n\nUse type hints.\n",
+        )
+        .unwrap();
+
+        let mut config = LintConfig::default();
+        config.set_result_cache_dir(cache_dir.path().to_path_buf());
+        let unmatched =
+            |result: &ValidationResult| result.diagnostics.iter().any(|d| d.rule == "COP-007");
+
+        assert!(unmatched(&validate_project(&project, &config).unwrap()));
+        assert!(unmatched(&validate_project(&project, &config).unwrap()));
+
+        std::fs::write(project.join("main.py"), "print('hi')\n").unwrap();
+        assert!(!unmatched(&validate_project(&project, &config).unwrap()));
+    }
+
+    #[test]
+    fn test_xp_cache_dir_reuses_analysis_across_runs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("CLAUDE.md"),
+            "# Project\n\nUse `npm install` for dependencies.",
         )
         .unwrap();
-
-        let subdir = temp.path().join("subdir");
-        std::fs::create_dir_all(&subdir).unwrap();
         std::fs::write(
-            subdir.join("AGENTS.md"),
-            "# Subproject\n\nThis is a nested AGENTS.md.",
+            temp.path().join("AGENTS.md"),
+            "# Project\n\nUse `pnpm install` for dependencies.",
         )
         .unwrap();
 
         let mut config = LintConfig::default();
-        config.rules.disabled_rules = vec!["AGM-006".to_string()];
-        let result = validate_project(temp.path(), &config).unwrap();
+        config.set_cache_dir(cache_dir.path().to_path_buf());
+        let xp_rules = |result: &ValidationResult| -> Vec<(PathBuf, String, usize)> {
+            let mut rules: Vec<_> = result
+                .diagnostics
+                .iter()
+                .filter(|d| d.rule.starts_with("XP-00"))
+                .map(|d| (d.file.clone(), d.rule.clone(), d.line))
+                .collect();
+            rules.sort();
+            rules
+        };
 
-        // Should not detect AGM-006 when disabled
-        let agm_006: Vec<_> = result
-            .diagnostics
-            .iter()
-            .filter(|d| d.rule == "AGM-006")
-            .collect();
-        assert!(agm_006.is_empty(), "AGM-006 should not fire when disabled");
-    }
+        let first = validate_project(temp.path(), &config).unwrap();
+        assert!(cache_dir.path().join("project-analysis.json").exists());
+        let second = validate_project(temp.path(), &config).unwrap();
+        assert!(xp_rules(&first).iter().any(|(_, rule, _)| rule == "XP-004"));
+        assert_eq!(xp_rules(&first), xp_rules(&second));
 
-    // ===== XP-004: Conflicting Build Commands =====
+        // Edited files are re-analyzed rather than served from the cache
+        std::fs::write(
+            temp.path().join("AGENTS.md"),
+            "# Project\n\nUse `npm install` for dependencies.",
+        )
+        .unwrap();
+        let third = validate_project(temp.path(), &config).unwrap();
+        assert!(!xp_rules(&third).iter().any(|(_, rule, _)| rule == "XP-004"));
+    }
 
     #[test]
-    fn test_xp_004_conflicting_package_managers() {
+    fn test_xp_004_reports_low_confidence() {
         let temp = tempfile::TempDir::new().unwrap();
-
-        // CLAUDE.md uses npm
         std::fs::write(
             temp.path().join("CLAUDE.md"),
-            "# Project\n\nUse `npm install` for dependencies.",
+            "# Project\n\nRun `npm install` to set up.",
         )
         .unwrap();
-
-        // AGENTS.md uses pnpm
         std::fs::write(
             temp.path().join("AGENTS.md"),
-            "# Project\n\nUse `pnpm install` for dependencies.",
+            "# Project\n\nRun `pnpm install` to set up.",
         )
         .unwrap();
 
-        let config = LintConfig::default();
-        let result = validate_project(temp.path(), &config).unwrap();
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
 
         let xp_004: Vec<_> = result
             .diagnostics
             .iter()
             .filter(|d| d.rule == "XP-004")
             .collect();
-        assert!(
-            !xp_004.is_empty(),
-            "Should detect conflicting package managers"
-        );
-        assert!(xp_004.iter().any(|d| d.message.contains("npm")));
-        assert!(xp_004.iter().any(|d| d.message.contains("pnpm")));
+        assert!(!xp_004.is_empty());
+        assert!(xp_004.iter().all(|d| d.confidence == Confidence::Low));
     }
 
     #[test]
@@ -2235,6 +4726,344 @@ allowed-tools: Read Write
         );
     }
 
+    // ===== XP-011: Undefined Agent References =====
+
+    #[test]
+    fn test_xp_011_reference_to_missing_agent() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".claude/agents")).unwrap();
+        std::fs::write(
+            temp.path().join(".claude/agents/code-reviewer.md"),
+            "---\nname: code-reviewer\ndescription: Reviews code\n---\nReview code.",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("CLAUDE.md"),
+            "# Project\n\nAsk @agent-code-reviewer and @agent-release-auditor for reviews.",
+        )
+        .unwrap();
+
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+
+        let xp_011: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "XP-011")
+            .collect();
+        assert_eq!(xp_011.len(), 1, "Expected one XP-011, got: {:?}", xp_011);
+        assert!(xp_011[0].message.contains("release-auditor"));
+        assert_eq!(xp_011[0].level, DiagnosticLevel::Warning);
+        assert_eq!(xp_011[0].line, 3);
+    }
+
+    #[test]
+    fn test_xp_011_agent_matched_by_file_stem() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".claude/agents")).unwrap();
+        std::fs::write(
+            temp.path().join(".claude/agents/release-auditor.md"),
+            "---\ndescription: Audits releases\n---\nAudit releases.",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("AGENTS.md"),
+            "# Project\n\nAsk the `release-auditor` agent before tagging.",
+        )
+        .unwrap();
+
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "XP-011"));
+    }
+
+    #[test]
+    fn test_xp_011_disabled_rule() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("CLAUDE.md"),
+            "# Project\n\nAsk @agent-release-auditor for reviews.",
+        )
+        .unwrap();
+
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["XP-011".to_string()];
+        let result = validate_project(temp.path(), &config).unwrap();
+
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "XP-011"));
+    }
+
+    // ===== XP-012: Conflicting Models =====
+
+    #[test]
+    fn test_xp_012_fixture_reports_both_files() {
+        let fixtures_dir = get_fixtures_dir().join("cross_platform/conflicting-models");
+        let result = validate_project(&fixtures_dir, &LintConfig::default()).unwrap();
+
+        let xp_012: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "XP-012")
+            .collect();
+        assert_eq!(xp_012.len(), 2, "got: {:?}", xp_012);
+
+        let agent = xp_012
+            .iter()
+            .find(|d| d.file.ends_with("release-manager.md"))
+            .unwrap();
+        assert_eq!(agent.level, DiagnosticLevel::Warning);
+        assert_eq!(agent.line, 4);
+        assert!(agent.message.contains("deploy"));
+        assert!(agent.related[0].0.ends_with("deploy/SKILL.md"));
+
+        let skill = xp_012
+            .iter()
+            .find(|d| d.file.ends_with("deploy/SKILL.md"))
+            .unwrap();
+        assert_eq!(skill.line, 4);
+        assert!(skill.related[0].0.ends_with("release-manager.md"));
+    }
+
+    #[test]
+    fn test_xp_012_disabled_rule() {
+        let fixtures_dir = get_fixtures_dir().join("cross_platform/conflicting-models");
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["XP-012".to_string()];
+        let result = validate_project(&fixtures_dir, &config).unwrap();
+
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "XP-012"));
+    }
+
+    // ===== CC-AG-014/015: Agent Handoffs =====
+
+    #[test]
+    fn test_agent_handoff_fixtures() {
+        let fixtures_dir = get_fixtures_dir();
+
+        let result =
+            validate_project(&fixtures_dir.join("valid/agents"), &LintConfig::default()).unwrap();
+        assert!(
+            !result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule == "CC-AG-014" || d.rule == "CC-AG-015"),
+            "Valid handoff should not be reported, got: {:?}",
+            result.diagnostics
+        );
+
+        let result =
+            validate_project(&fixtures_dir.join("invalid/agents"), &LintConfig::default()).unwrap();
+        let ag_014: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-AG-014")
+            .collect();
+        assert_eq!(ag_014.len(), 1, "got: {:?}", ag_014);
+        assert!(ag_014[0].file.ends_with("handoff-dangling.md"));
+        assert!(ag_014[0].message.contains("missing-reviewer"));
+        assert_eq!(ag_014[0].line, 5);
+
+        let ag_015: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-AG-015")
+            .collect();
+        assert_eq!(ag_015.len(), 1, "got: {:?}", ag_015);
+        assert!(ag_015[0].file.ends_with("handoff-cycle-a.md"));
+        assert!(
+            ag_015[0]
+                .message
+                .contains("handoff-cycle-a -> handoff-cycle-b -> handoff-cycle-a")
+        );
+    }
+
+    #[test]
+    fn test_agent_handoff_rules_disabled() {
+        let fixtures_dir = get_fixtures_dir();
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["CC-AG-014".to_string(), "CC-AG-015".to_string()];
+
+        let result = validate_project(&fixtures_dir.join("invalid/agents"), &config).unwrap();
+        assert!(
+            !result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule == "CC-AG-014" || d.rule == "CC-AG-015")
+        );
+    }
+
+    #[test]
+    fn test_cross_file_rules_disabled_skips_project_checks() {
+        let fixtures_dir = get_fixtures_dir();
+        let mut config = LintConfig::default();
+        config.cross_file_rules = false;
+
+        let result = validate_project(&fixtures_dir.join("invalid/agents"), &config).unwrap();
+        assert!(
+            !result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule == "CC-AG-014" || d.rule == "CC-AG-015"),
+            "got: {:?}",
+            result.diagnostics
+        );
+        // Per-file agent rules still run
+        assert!(
+            result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule.starts_with("CC-AG-"))
+        );
+
+        let files = ProjectFiles::collect(&fixtures_dir.join("invalid/agents"), &config).unwrap();
+        assert!(
+            validate_project_rules(&fixtures_dir.join("invalid/agents"), &config, &files)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_validate_project_streaming_emits_all_diagnostics() {
+        let fixtures_dir = get_fixtures_dir().join("invalid/agents");
+        let config = LintConfig::default();
+
+        let expected = validate_project(&fixtures_dir, &config).unwrap();
+        let streamed = Mutex::new(Vec::new());
+        let result = validate_project_streaming(
+            &fixtures_dir,
+            &config,
+            default_registry(),
+            &|d: &Diagnostic| streamed.lock().unwrap().push(d.clone()),
+        )
+        .unwrap();
+
+        assert!(result.diagnostics.is_empty());
+        assert_eq!(result.files_checked, expected.files_checked);
+        let mut streamed = streamed.into_inner().unwrap();
+        sort_diagnostics(&mut streamed);
+        let key = |d: &Diagnostic| (d.file.clone(), d.line, d.rule.clone(), d.message.clone());
+        assert_eq!(
+            streamed.iter().map(key).collect::<Vec<_>>(),
+            expected.diagnostics.iter().map(key).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_validate_project_streaming_cancellation() {
+        struct StopAfterFirst(AtomicUsize);
+
+        impl DiagnosticSink for StopAfterFirst {
+            fn emit(&self, _diagnostic: &Diagnostic) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+
+            fn is_cancelled(&self) -> bool {
+                self.0.load(Ordering::SeqCst) > 0
+            }
+        }
+
+        let fixtures_dir = get_fixtures_dir().join("invalid/agents");
+        let config = LintConfig::default();
+        let total = validate_project(&fixtures_dir, &config)
+            .unwrap()
+            .diagnostics
+            .len();
+
+        let sink = StopAfterFirst(AtomicUsize::new(0));
+        validate_project_streaming(&fixtures_dir, &config, default_registry(), &sink).unwrap();
+        let emitted = sink.0.load(Ordering::SeqCst);
+        assert!(emitted > 0);
+        // Files already in flight finish, but project-level checks such as
+        // VER-001 never run
+        assert!(emitted < total, "emitted {emitted} of {total}");
+    }
+
+    #[test]
+    fn test_mcp_duplicate_server_name_fixtures() {
+        let fixtures_dir = get_fixtures_dir();
+
+        let result = validate_project(
+            &fixtures_dir.join("mcp-duplicate-names"),
+            &LintConfig::default(),
+        )
+        .unwrap();
+        let mut mcp_023: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "MCP-023")
+            .collect();
+        mcp_023.sort_by(|a, b| a.file.cmp(&b.file));
+        assert_eq!(mcp_023.len(), 2, "got: {:?}", mcp_023);
+        assert!(mcp_023[0].file.ends_with("team-a/.mcp.json"));
+        assert!(mcp_023[1].file.ends_with("team-b/.mcp.json"));
+        for diag in &mcp_023 {
+            assert_eq!(diag.level, DiagnosticLevel::Warning);
+            assert_eq!((diag.line, diag.column), (3, 5));
+            assert!(diag.message.contains("'github'"));
+        }
+        assert!(mcp_023[0].message.contains("team-b"));
+        assert!(mcp_023[1].message.contains("team-a"));
+
+        // Single-file MCP fixtures never collide
+        let result = validate_project(&fixtures_dir.join("mcp"), &LintConfig::default()).unwrap();
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "MCP-023"));
+
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["MCP-023".to_string()];
+        let result = validate_project(&fixtures_dir.join("mcp-duplicate-names"), &config).unwrap();
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "MCP-023"));
+    }
+
+    #[test]
+    fn test_cc_sk_016_fixture_undefined_mcp_server() {
+        let fixture = get_fixtures_dir()
+            .join("skills")
+            .join("mcp-tool-references");
+        let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+        let cc_sk_016: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-SK-016")
+            .collect();
+        assert_eq!(cc_sk_016.len(), 1, "{:?}", result.diagnostics);
+        assert!(cc_sk_016[0].file.ends_with("triage-issues/SKILL.md"));
+        assert_eq!(cc_sk_016[0].level, DiagnosticLevel::Warning);
+        assert_eq!((cc_sk_016[0].line, cc_sk_016[0].column), (4, 48));
+        assert!(cc_sk_016[0].message.contains("'gihub'"));
+
+        // MCP tools are no longer reported as unknown built-in tools
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "CC-SK-008"));
+    }
+
+    #[test]
+    fn test_cc_sk_016_skipped_without_project_mcp_config() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("skills").join("triage");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: triage\ndescription: Use when triaging issues\nallowed-tools: mcp__github__list_issues\n---\nList the issues.\n",
+        )
+        .unwrap();
+
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "CC-SK-016"));
+
+        // Once the project defines MCP servers, unknown ones are reported
+        std::fs::write(
+            temp.path().join(".mcp.json"),
+            r#"{"mcpServers": {"gitlab": {"command": "gl"}}}"#,
+        )
+        .unwrap();
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+        assert!(result.diagnostics.iter().any(|d| d.rule == "CC-SK-016"));
+
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["CC-SK-016".to_string()];
+        let result = validate_project(temp.path(), &config).unwrap();
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "CC-SK-016"));
+    }
+
     // ===== XP-004/005/006 Edge Case Tests (review findings) =====
 
     #[test]
@@ -2303,12 +5132,92 @@ allowed-tools: Read Write
         config.rules.disabled_rules = vec!["XP-004".to_string()];
         let result = validate_project(temp.path(), &config).unwrap();
 
-        let xp_004: Vec<_> = result
-            .diagnostics
-            .iter()
-            .filter(|d| d.rule == "XP-004")
-            .collect();
-        assert!(xp_004.is_empty(), "XP-004 should not fire when disabled");
+        let xp_004: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "XP-004")
+            .collect();
+        assert!(xp_004.is_empty(), "XP-004 should not fire when disabled");
+    }
+
+    #[test]
+    fn test_project_files_collect_groups_inputs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join("CLAUDE.md"), "# Project\n").unwrap();
+        std::fs::write(temp.path().join("AGENTS.md"), "# Project\n").unwrap();
+        std::fs::write(temp.path().join(".mcp.json"), "{\"mcpServers\": {}}").unwrap();
+        std::fs::write(temp.path().join("README.md"), "# Readme\n").unwrap();
+
+        let files = ProjectFiles::collect(temp.path(), &LintConfig::default()).unwrap();
+        let root = std::fs::canonicalize(temp.path()).unwrap();
+
+        assert_eq!(files.agents_md, vec![root.join("AGENTS.md")]);
+        assert_eq!(
+            files.instruction_files,
+            vec![root.join("AGENTS.md"), root.join("CLAUDE.md")]
+        );
+        assert_eq!(files.mcp_files, vec![root.join(".mcp.json")]);
+        assert!(files.skill_files.is_empty());
+        assert!(!files.contains(&root.join("README.md")));
+
+        assert!(ProjectFiles::is_input(&root.join("CLAUDE.md")));
+        assert!(ProjectFiles::is_input(&root.join(".mcp.json")));
+        assert!(!ProjectFiles::is_input(&root.join("README.md")));
+    }
+
+    #[test]
+    fn test_project_files_remove_drops_every_group() {
+        let mut files = ProjectFiles::default();
+        let agents_md = Path::new("/project/AGENTS.md");
+        files.insert(agents_md);
+        files.insert(Path::new("/project/CLAUDE.md"));
+        assert_eq!(files.agents_md, vec![agents_md.to_path_buf()]);
+
+        files.remove(agents_md);
+        assert!(!files.contains(agents_md));
+        assert!(files.agents_md.is_empty());
+        assert_eq!(
+            files.instruction_files,
+            vec![PathBuf::from("/project/CLAUDE.md")]
+        );
+    }
+
+    #[test]
+    fn test_validate_project_rules_reads_through_config_fs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            temp.path().join("CLAUDE.md"),
+            "# Project\n\nUse `npm install` for dependencies.",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("AGENTS.md"),
+            "# Project\n\nUse `npm install` for dependencies.",
+        )
+        .unwrap();
+
+        let config = LintConfig::default();
+        let files = ProjectFiles::collect(temp.path(), &config).unwrap();
+        let on_disk = validate_project_rules(temp.path(), &config, &files);
+        assert!(!on_disk.iter().any(|d| d.rule == "XP-004"));
+
+        // An unsaved AGENTS.md buffer switches to pnpm
+        let root = std::fs::canonicalize(temp.path()).unwrap();
+        let mock_fs = MockFileSystem::new();
+        mock_fs.add_file(
+            root.join("CLAUDE.md"),
+            "# Project\n\nUse `npm install` for dependencies.",
+        );
+        mock_fs.add_file(
+            root.join("AGENTS.md"),
+            "# Project\n\nUse `pnpm install` for dependencies.",
+        );
+        let mut buffered = config.clone();
+        buffered.set_fs(std::sync::Arc::new(mock_fs));
+
+        let diagnostics = validate_project_rules(temp.path(), &buffered, &files);
+        assert!(diagnostics.iter().any(|d| d.rule == "XP-004"));
+        assert!(!diagnostics.iter().any(|d| d.rule == "VER-001"));
     }
 
     #[test]
@@ -2700,6 +5609,79 @@ Run npm install and npm build.
             "Expected AS-014 from windows-path/SKILL.md fixture"
         );
 
+        // nested-references-tree/SKILL.md should trigger AS-024 (references tree too deep)
+        assert!(
+            skill_diagnostics.iter().any(|d| d.rule == "AS-024"
+                && d.file.to_string_lossy().contains("nested-references-tree")),
+            "Expected AS-024 from nested-references-tree/SKILL.md fixture"
+        );
+        assert!(
+            !skill_diagnostics.iter().any(|d| d.rule == "AS-024"
+                && d.file.to_string_lossy().contains("shallow-references-tree")),
+            "Did not expect AS-024 from shallow-references-tree/SKILL.md fixture"
+        );
+
+        // multi-document-frontmatter/SKILL.md has a second YAML block; the first is
+        // authoritative, so AS-025 flags the extra block and its fields are ignored
+        let as_025: Vec<_> = skill_diagnostics
+            .iter()
+            .filter(|d| {
+                d.rule == "AS-025"
+                    && d.file
+                        .to_string_lossy()
+                        .contains("multi-document-frontmatter")
+            })
+            .collect();
+        assert_eq!(
+            as_025.len(),
+            1,
+            "Expected one AS-025 from multi-document-frontmatter/SKILL.md fixture"
+        );
+        assert_eq!(as_025[0].line, 8);
+        assert!(
+            !result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule.starts_with("CC-SK-")
+                    && d.file
+                        .to_string_lossy()
+                        .contains("multi-document-frontmatter")),
+            "Fields in the second YAML block must not be validated"
+        );
+
+        // Description length is graded: crisp is clean, verbose gets AS-026 (info),
+        // and past 1024 characters only the AS-008 hard error is reported
+        let description_rules = |fixture: &str| -> Vec<&str> {
+            skill_diagnostics
+                .iter()
+                .filter(|d| {
+                    matches!(d.rule.as_str(), "AS-008" | "AS-026")
+                        && d.file.to_string_lossy().contains(fixture)
+                })
+                .map(|d| d.rule.as_str())
+                .collect()
+        };
+        assert!(
+            description_rules("crisp-description").is_empty(),
+            "Did not expect description length rules from crisp-description/SKILL.md fixture"
+        );
+        assert_eq!(description_rules("verbose-description"), vec!["AS-026"]);
+        assert_eq!(description_rules("overlong-description"), vec!["AS-008"]);
+
+        // A metadata key with a space is reported on the metadata block
+        let metadata_diagnostics: Vec<_> = skill_diagnostics
+            .iter()
+            .filter(|d| d.rule == "AS-027")
+            .collect();
+        assert_eq!(metadata_diagnostics.len(), 1, "{:?}", metadata_diagnostics);
+        assert!(
+            metadata_diagnostics[0]
+                .file
+                .to_string_lossy()
+                .contains("oversized-metadata")
+        );
+        assert!(metadata_diagnostics[0].message.contains("Style Guide"));
+
         // Verify MCP fixtures trigger expected MCP-* rules
         let mcp_diagnostics: Vec<_> = result
             .diagnostics
@@ -2758,6 +5740,39 @@ Run npm install and npm build.
             "Expected MCP-006 from untrusted-annotations.mcp.json fixture"
         );
 
+        // duplicate-env-key.mcp.json should trigger MCP-021 (duplicate env key)
+        assert!(
+            mcp_diagnostics
+                .iter()
+                .any(|d| d.rule == "MCP-021"
+                    && d.file.to_string_lossy().contains("duplicate-env-key")),
+            "Expected MCP-021 from duplicate-env-key.mcp.json fixture"
+        );
+
+        // conflicting-env-values.mcp.json should trigger MCP-022 (conflicting env values)
+        assert!(
+            mcp_diagnostics.iter().any(|d| d.rule == "MCP-022"
+                && d.file.to_string_lossy().contains("conflicting-env-values")),
+            "Expected MCP-022 from conflicting-env-values.mcp.json fixture"
+        );
+
+        // hardcoded-env-secret.mcp.json should trigger MCP-024 (hard-coded secret)
+        assert!(
+            mcp_diagnostics.iter().any(|d| d.rule == "MCP-024"
+                && d.file.to_string_lossy().contains("hardcoded-env-secret")),
+            "Expected MCP-024 from hardcoded-env-secret.mcp.json fixture"
+        );
+
+        // missing-url-http.mcp.yaml should trigger MCP-010 at the YAML server key
+        assert!(
+            mcp_diagnostics.iter().any(|d| d.rule == "MCP-010"
+                && d.line == 3
+                && d.file
+                    .to_string_lossy()
+                    .ends_with("missing-url-http.mcp.yaml")),
+            "Expected MCP-010 from missing-url-http.mcp.yaml fixture"
+        );
+
         // Verify AGM, XP, REF, and XML fixtures trigger expected rules
         let expectations = [
             (
@@ -2770,6 +5785,16 @@ Run npm install and npm build.
                 "hard-coded",
                 "Expected XP-003 from cross_platform/hard-coded/AGENTS.md fixture",
             ),
+            (
+                "XP-011",
+                "missing-agent",
+                "Expected XP-011 from cross_platform/missing-agent/CLAUDE.md fixture",
+            ),
+            (
+                "XP-013",
+                "with-imports",
+                "Expected XP-013 from cross_platform/with-imports/AGENTS.md fixture",
+            ),
             (
                 "REF-001",
                 "missing-import",
@@ -2780,6 +5805,11 @@ Run npm install and npm build.
                 "broken-link",
                 "Expected REF-002 from refs/broken-link.md fixture",
             ),
+            (
+                "REF-010",
+                "undefined-reference",
+                "Expected REF-010 from refs/undefined-reference/CLAUDE.md fixture",
+            ),
             (
                 "XML-001",
                 "xml-001-unclosed",
@@ -2809,6 +5839,54 @@ Run npm install and npm build.
         }
     }
 
+    #[test]
+    fn test_ref_008_fixture_import_of_excluded_file() {
+        let fixture = get_fixtures_dir().join("refs").join("excluded-import");
+
+        let mut config = LintConfig::default();
+        config.exclude = vec!["build/**".to_string()];
+        let result = validate_project(&fixture, &config).unwrap();
+        let ref_008: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "REF-008")
+            .collect();
+        assert_eq!(ref_008.len(), 1, "{:?}", result.diagnostics);
+        assert!(ref_008[0].file.ends_with("CLAUDE.md"));
+        assert_eq!(ref_008[0].level, DiagnosticLevel::Info);
+        assert!(
+            !result
+                .diagnostics
+                .iter()
+                .any(|d| d.file.ends_with("generated.md")),
+            "Excluded file should not be validated"
+        );
+
+        // Without the exclude the imported file is linted and REF-008 stays quiet
+        let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+        assert!(!result.diagnostics.iter().any(|d| d.rule == "REF-008"));
+    }
+
+    #[test]
+    fn test_ref_009_fixture_circular_import() {
+        let fixture = get_fixtures_dir().join("refs").join("circular-import");
+        let result = validate_project(&fixture, &LintConfig::default()).unwrap();
+
+        let ref_009: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.rule == "REF-009")
+            .collect();
+        assert_eq!(ref_009.len(), 1, "{:?}", result.diagnostics);
+        assert!(ref_009[0].file.ends_with("docs/conventions.md"));
+        assert_eq!(ref_009[0].line, 3);
+        assert!(
+            ref_009[0]
+                .message
+                .contains("AGENTS.md -> docs/conventions.md -> AGENTS.md")
+        );
+    }
+
     #[test]
     fn test_fixture_positive_cases_by_family() {
         let fixtures_dir = get_fixtures_dir();
@@ -2923,6 +6001,121 @@ Run npm install and npm build.
         );
     }
 
+    #[test]
+    fn test_detect_junie_guidelines() {
+        assert_eq!(
+            detect_file_type(Path::new(".junie/guidelines.md")),
+            FileType::ClaudeMd
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.junie/guidelines.md")),
+            FileType::ClaudeMd
+        );
+        // Only the guidelines file inside .junie/ is an instructions file
+        assert_eq!(
+            detect_file_type(Path::new("notes/guidelines.md")),
+            FileType::GenericMarkdown
+        );
+        assert_eq!(
+            detect_file_type(Path::new(".junie/notes.md")),
+            FileType::GenericMarkdown
+        );
+    }
+
+    #[test]
+    fn test_detect_codex_instructions() {
+        assert_eq!(detect_file_type(Path::new("codex.md")), FileType::ClaudeMd);
+        assert_eq!(
+            detect_file_type(Path::new("project/codex.md")),
+            FileType::ClaudeMd
+        );
+        assert_eq!(
+            detect_file_type(Path::new(".codex/instructions.md")),
+            FileType::ClaudeMd
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.codex/instructions.md")),
+            FileType::ClaudeMd
+        );
+        // Only instructions.md inside .codex/ is an instructions file
+        assert_eq!(
+            detect_file_type(Path::new("notes/instructions.md")),
+            FileType::GenericMarkdown
+        );
+        assert_eq!(
+            detect_file_type(Path::new(".codex/config.toml")),
+            FileType::CodexConfig
+        );
+    }
+
+    #[test]
+    fn test_validate_project_finds_codex_instructions() {
+        // .codex is hidden; the walk must still pick it up
+        let temp = tempfile::TempDir::new().unwrap();
+        let codex = temp.path().join(".codex");
+        std::fs::create_dir(&codex).unwrap();
+        std::fs::write(
+            codex.join("instructions.md"),
+            "# Instructions\n\n<example>\nUse `cargo test`.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            temp.path().join("codex.md"),
+            "# Codex\n\nRun `cargo test`.\n",
+        )
+        .unwrap();
+
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+        assert_eq!(result.files_by_type.get(&FileType::ClaudeMd), Some(&2));
+        assert!(
+            result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule == "XML-001" && d.file.ends_with(".codex/instructions.md")),
+            "Expected XML-001 for unclosed tag, got: {:?}",
+            result.diagnostics
+        );
+    }
+
+    #[test]
+    fn test_validate_project_finds_junie_guidelines() {
+        // .junie is hidden; the walk must still pick it up
+        let junie_dir = get_fixtures_dir().join("junie");
+        let result = validate_project(&junie_dir, &LintConfig::default()).unwrap();
+
+        assert_eq!(result.files_checked, 1);
+        assert_eq!(result.files_by_type.get(&FileType::ClaudeMd), Some(&1));
+        let problems: Vec<_> = result
+            .diagnostics
+            .iter()
+            .filter(|d| d.level != DiagnosticLevel::Info)
+            .collect();
+        assert!(
+            problems.is_empty(),
+            "Valid Junie guidelines should have no errors or warnings, got: {:?}",
+            problems
+        );
+    }
+
+    #[test]
+    fn test_validate_junie_guidelines_runs_memory_rules() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let junie = temp.path().join(".junie");
+        std::fs::create_dir(&junie).unwrap();
+        std::fs::write(
+            junie.join("guidelines.md"),
+            "# Guidelines\n\n<example>\nUse `cargo test`.\n",
+        )
+        .unwrap();
+
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+        assert!(
+            result.diagnostics.iter().any(|d| d.rule == "XML-001"),
+            "Expected XML-001 for unclosed tag, got: {:?}",
+            result.diagnostics
+        );
+    }
+
     #[test]
     fn test_copilot_not_detected_outside_github() {
         // Files outside .github/ should not be detected as Copilot
@@ -2941,10 +6134,10 @@ Run npm install and npm build.
         let registry = ValidatorRegistry::with_defaults();
 
         let copilot_validators = registry.validators_for(FileType::Copilot);
-        assert_eq!(copilot_validators.len(), 2); // copilot + xml
+        assert_eq!(copilot_validators.len(), 3); // conflict markers + copilot + xml
 
         let scoped_validators = registry.validators_for(FileType::CopilotScoped);
-        assert_eq!(scoped_validators.len(), 2); // copilot + xml
+        assert_eq!(scoped_validators.len(), 3); // conflict markers + copilot + xml
     }
 
     #[test]
@@ -3234,6 +6427,68 @@ Use idiomatic Rust patterns.
                 .map(|d| &d.rule)
                 .collect::<Vec<_>>()
         );
+
+        // Should find COP-007 from no-matching-files.instructions.md (no Kotlin files)
+        assert!(
+            result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule == "COP-007"
+                    && d.file.ends_with("no-matching-files.instructions.md")),
+            "validate_project should find COP-007 in copilot-invalid fixtures"
+        );
+    }
+
+    // ===== Cline Rules Validation Integration Tests =====
+
+    #[test]
+    fn test_detect_cline_rules() {
+        assert_eq!(
+            detect_file_type(Path::new("project/.clinerules")),
+            FileType::ClineRules
+        );
+        assert_eq!(
+            detect_file_type(Path::new("project/.clinerules/coding.md")),
+            FileType::ClineRulesFolder
+        );
+        // Non-markdown files in the folder are not rules
+        assert_eq!(
+            detect_file_type(Path::new("project/.clinerules/notes.txt")),
+            FileType::Unknown
+        );
+    }
+
+    #[test]
+    fn test_cline_fixtures_detected() {
+        let fixtures_dir = get_fixtures_dir();
+        assert_eq!(
+            detect_file_type(&fixtures_dir.join("cline-single/.clinerules")),
+            FileType::ClineRules
+        );
+        assert_eq!(
+            detect_file_type(&fixtures_dir.join("cline/.clinerules/coding.md")),
+            FileType::ClineRulesFolder
+        );
+    }
+
+    #[test]
+    fn test_cline_rules_hard_coded_path_portability() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let rules_dir = temp.path().join(".clinerules");
+        std::fs::create_dir_all(&rules_dir).unwrap();
+        let file_path = rules_dir.join("coding.md");
+        std::fs::write(
+            &file_path,
+            "# Coding\n\nCursor settings live in .cursor/rules/ for this repo.",
+        )
+        .unwrap();
+
+        let diagnostics = validate_file(&file_path, &LintConfig::default()).unwrap();
+        assert!(
+            diagnostics.iter().any(|d| d.rule == "XP-003"),
+            "Expected XP-003 for hard-coded path in .clinerules file, got: {:?}",
+            diagnostics
+        );
     }
 
     // ===== Cursor Project Rules Validation Integration Tests =====
@@ -3289,10 +6544,10 @@ Use idiomatic Rust patterns.
         let registry = ValidatorRegistry::with_defaults();
 
         let cursor_validators = registry.validators_for(FileType::CursorRule);
-        assert_eq!(cursor_validators.len(), 3); // cursor + prompt + claude_md
+        assert_eq!(cursor_validators.len(), 4); // conflict markers + cursor + prompt + claude_md
 
         let legacy_validators = registry.validators_for(FileType::CursorRulesLegacy);
-        assert_eq!(legacy_validators.len(), 3); // cursor + prompt + claude_md
+        assert_eq!(legacy_validators.len(), 4); // conflict markers + cursor + prompt + claude_md
     }
 
     #[test]
@@ -3391,6 +6646,126 @@ Use idiomatic Rust patterns.
         );
     }
 
+    #[test]
+    fn test_validate_aider_fixtures() {
+        let fixtures_dir = get_fixtures_dir();
+        let config = LintConfig::default();
+
+        let diagnostics = validate_project(&fixtures_dir.join("aider"), &config)
+            .unwrap()
+            .diagnostics;
+        assert!(
+            !diagnostics.iter().any(|d| d.rule.starts_with("AIDER-")),
+            "Valid CONVENTIONS.md should have no AIDER diagnostics, got: {:?}",
+            diagnostics
+        );
+
+        let diagnostics = validate_project(&fixtures_dir.join("aider-invalid"), &config)
+            .unwrap()
+            .diagnostics;
+        let aider_001: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "AIDER-001")
+            .collect();
+        assert_eq!(aider_001.len(), 1, "got: {:?}", diagnostics);
+        assert_eq!(aider_001[0].line, 3);
+    }
+
+    #[test]
+    fn test_nested_conventions_md_is_not_aider() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let docs = temp.path().join("docs");
+        std::fs::create_dir_all(&docs).unwrap();
+        std::fs::write(
+            docs.join("CONVENTIONS.md"),
+            std::fs::read_to_string(get_fixtures_dir().join("aider-invalid/CONVENTIONS.md"))
+                .unwrap(),
+        )
+        .unwrap();
+
+        let result = validate_project(temp.path(), &LintConfig::default()).unwrap();
+        assert!(!result.files_by_type.contains_key(&FileType::ClaudeMd));
+        assert!(
+            !result
+                .diagnostics
+                .iter()
+                .any(|d| d.rule.starts_with("AIDER-")),
+            "{:?}",
+            result.diagnostics
+        );
+    }
+
+    #[test]
+    fn test_validate_conflict_marker_fixtures() {
+        let fixtures_dir = get_fixtures_dir();
+        let config = LintConfig::default();
+
+        for (path, file_type, lines) in [
+            (
+                "conflict-markers/skills/conflicted/SKILL.md",
+                FileType::Skill,
+                vec![3, 5, 7],
+            ),
+            (
+                "conflict-markers/.claude/settings.json",
+                FileType::Hooks,
+                vec![7, 9, 11],
+            ),
+        ] {
+            let path = fixtures_dir.join(path);
+            assert_eq!(detect_file_type(&path), file_type);
+            let diagnostics = validate_file(&path, &config).unwrap();
+            let gen_002: Vec<usize> = diagnostics
+                .iter()
+                .filter(|d| d.rule == "GEN-002")
+                .map(|d| d.line)
+                .collect();
+            assert_eq!(gen_002, lines, "got: {:?}", diagnostics);
+            // GEN-002 runs ahead of the type-specific parse errors
+            assert_eq!(diagnostics[0].rule, "GEN-002");
+        }
+    }
+
+    #[test]
+    fn test_validate_windsurf_fixtures() {
+        // Use validate_file directly since .windsurf is a hidden directory
+        let fixtures_dir = get_fixtures_dir();
+        let config = LintConfig::default();
+
+        let valid = fixtures_dir.join("windsurf/.windsurf/rules/typescript.md");
+        let diagnostics = validate_file(&valid, &config).unwrap();
+        assert!(
+            !diagnostics.iter().any(|d| d.rule.starts_with("WS-")),
+            "Valid Windsurf rule should have no WS diagnostics, got: {:?}",
+            diagnostics
+        );
+
+        let legacy = fixtures_dir.join("windsurf-legacy/.windsurfrules");
+        let diagnostics = validate_file(&legacy, &config).unwrap();
+        assert!(
+            !diagnostics.iter().any(|d| d.rule.starts_with("WS-")),
+            "Non-empty .windsurfrules should have no WS diagnostics, got: {:?}",
+            diagnostics
+        );
+
+        let invalid_dir = fixtures_dir.join("windsurf-invalid/.windsurf/rules");
+        for (file, rule) in [
+            ("empty.md", "WS-001"),
+            ("no-frontmatter.md", "WS-002"),
+            ("bad-yaml.md", "WS-003"),
+            ("bad-glob.md", "WS-004"),
+        ] {
+            let diagnostics = validate_file(&invalid_dir.join(file), &config).unwrap();
+            assert!(
+                diagnostics.iter().any(|d| d.rule == rule),
+                "Expected {} from {} fixture, got: {:?}",
+                rule,
+                file,
+                diagnostics
+            );
+        }
+    }
+
     #[test]
     fn test_validate_cursor_file_empty() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -3528,6 +6903,7 @@ Use idiomatic Rust patterns.
             ("pe-002-cot-on-simple.md", "PE-002"),
             ("pe-003-weak-language.md", "PE-003"),
             ("pe-004-ambiguous.md", "PE-004"),
+            ("pe-009-mixed-voice.md", "PE-009"),
         ];
 
         for (fixture, expected_rule) in test_cases {
@@ -3667,6 +7043,54 @@ Use idiomatic Rust patterns.
         );
     }
 
+    #[test]
+    fn test_agnixignore_files_are_honored() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = std::fs::canonicalize(temp.path()).unwrap();
+        for file in [
+            "CLAUDE.md",
+            "drafts/CLAUDE.md",
+            "a/AGENTS.md",
+            "keep/AGENTS.md",
+            "sub/CLAUDE.md",
+            "sub/other/SKILL.md",
+        ] {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, "# Project\n").unwrap();
+        }
+        std::fs::write(
+            root.join(".agnixignore"),
+            "drafts/\n**/AGENTS.md\n!keep/AGENTS.md\n",
+        )
+        .unwrap();
+        // Nested ignore files apply to their own directory, like .gitignore
+        std::fs::write(root.join("sub/.agnixignore"), "/CLAUDE.md\n").unwrap();
+
+        let config_excludes = |patterns: &[&str]| {
+            let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+            std::sync::Arc::new(compile_exclude_patterns(&patterns).unwrap())
+        };
+        let walked = |excludes| {
+            let mut files: Vec<String> = walk_project(&root, &root, &excludes)
+                .map(|path| normalize_rel_path(&path, &root))
+                .filter(|path| !path.ends_with(".agnixignore"))
+                .collect();
+            files.sort();
+            files
+        };
+
+        assert_eq!(
+            walked(config_excludes(&[])),
+            vec!["CLAUDE.md", "keep/AGENTS.md", "sub/other/SKILL.md"]
+        );
+        // Config exclude patterns apply on top and win over negations
+        assert_eq!(
+            walked(config_excludes(&["keep/**"])),
+            vec!["CLAUDE.md", "sub/other/SKILL.md"]
+        );
+    }
+
     #[test]
     fn test_should_prune_dir_with_globbed_patterns() {
         let patterns =
@@ -45,6 +45,18 @@ pub fn parse_frontmatter<T: DeserializeOwned>(content: &str) -> LintResult<(T, S
     Ok((parsed, parts.body.trim_start().to_string()))
 }
 
+/// Position of a YAML parse error in the file, when serde_yaml reports one.
+///
+/// `first_line` is the 1-indexed file line the parsed YAML starts on. Returns
+/// a 1-indexed line and 0-indexed column.
+pub fn yaml_error_position(error: &serde_yaml::Error, first_line: usize) -> Option<(usize, usize)> {
+    let location = error.location()?;
+    Some((
+        first_line + location.line().saturating_sub(1),
+        location.column().saturating_sub(1),
+    ))
+}
+
 /// Extract frontmatter and body from content with offsets.
 #[derive(Debug, Clone)]
 pub struct FrontmatterParts {
@@ -101,6 +113,99 @@ pub fn split_frontmatter(content: &str) -> FrontmatterParts {
     }
 }
 
+/// A `---`-delimited YAML section found after the leading frontmatter block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdditionalFrontmatter {
+    /// Byte offset of the opening `---` line in the original content.
+    pub start: usize,
+    /// Raw YAML between the delimiters.
+    pub yaml: String,
+}
+
+/// Find extra frontmatter-like sections in the body after the leading block.
+///
+/// Only the leading block is authoritative: validators read their fields from
+/// it and nothing else. Sections returned here are surfaced so they can be
+/// flagged instead of being silently treated as body text.
+///
+/// A section is a `---` line that opens the body or follows a blank line,
+/// closed by another `---` line, where every top-level line in between is a
+/// `key: value` pair (or list/indented continuation) and the whole section
+/// parses as a YAML mapping. Fenced code blocks are skipped, so examples and
+/// markdown horizontal rules around prose don't match.
+pub fn find_additional_frontmatter(parts: &FrontmatterParts) -> Vec<AdditionalFrontmatter> {
+    if !parts.has_frontmatter || !parts.has_closing {
+        return Vec::new();
+    }
+
+    let mut lines: Vec<(usize, &str)> = Vec::new();
+    let mut offset = parts.body_start;
+    for line in parts.body.split_inclusive('\n') {
+        lines.push((offset, line));
+        offset += line.len();
+    }
+
+    let mut sections = Vec::new();
+    let mut in_fence = false;
+    let mut prev_blank = true;
+    // The first segment is the tail of the closing `---` line
+    let mut idx = 1;
+    while idx < lines.len() {
+        let (line_offset, line) = lines[idx];
+        let trimmed = line.trim_end();
+
+        if trimmed.trim_start().starts_with("```") || trimmed.trim_start().starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if !in_fence && prev_blank && trimmed == "---" {
+            if let Some(close) = (idx + 1..lines.len()).find(|&i| lines[i].1.trim_end() == "---") {
+                let yaml: String = lines[idx + 1..close].iter().map(|(_, l)| *l).collect();
+                if is_yaml_mapping_section(&yaml) {
+                    sections.push(AdditionalFrontmatter {
+                        start: line_offset,
+                        yaml,
+                    });
+                    idx = close + 1;
+                    prev_blank = true;
+                    continue;
+                }
+            }
+        }
+
+        prev_blank = trimmed.is_empty();
+        idx += 1;
+    }
+
+    sections
+}
+
+/// Whether text between two `---` lines looks like a YAML frontmatter mapping.
+fn is_yaml_mapping_section(yaml: &str) -> bool {
+    let mut has_key = false;
+    for line in yaml.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if line.starts_with(char::is_whitespace) || trimmed.starts_with("- ") {
+            continue;
+        }
+        let Some((key, _)) = line.split_once(':') else {
+            return false;
+        };
+        let mut chars = key.chars();
+        let valid_key = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid_key {
+            return false;
+        }
+        has_key = true;
+    }
+
+    has_key && serde_yaml::from_str::<serde_yaml::Mapping>(yaml).is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -133,6 +238,14 @@ Body content here"#;
         assert!(result.is_err()); // Should fail to deserialize empty frontmatter
     }
 
+    #[test]
+    fn test_yaml_error_position_offsets_into_file() {
+        let yaml = "applyTo: '**/*.ts'\n  bad: : x\n";
+        let error = serde_yaml::from_str::<serde_yaml::Value>(yaml).unwrap_err();
+        // YAML starting on file line 2 puts its second line on file line 3
+        assert_eq!(yaml_error_position(&error, 2), Some((3, 2)));
+    }
+
     #[test]
     fn test_split_frontmatter_basic() {
         let content = "---\nname: test\n---\nbody";
@@ -178,6 +291,50 @@ Body content here"#;
         // Should split at first closing ---
         assert!(parts.body.contains("middle"));
     }
+
+    #[test]
+    fn test_find_additional_frontmatter() {
+        let content =
+            "---\nname: first\n---\n# Body\n\n---\nname: second\nmodel: opus\n---\nMore body\n";
+        let parts = split_frontmatter(content);
+        let extra = find_additional_frontmatter(&parts);
+        assert_eq!(extra.len(), 1);
+        assert_eq!(extra[0].yaml, "name: second\nmodel: opus\n");
+        assert_eq!(&content[extra[0].start..extra[0].start + 4], "---\n");
+    }
+
+    #[test]
+    fn test_find_additional_frontmatter_directly_after_first() {
+        let content = "---\nname: first\n---\n---\nextra: true\n---\nBody\n";
+        let extra = find_additional_frontmatter(&split_frontmatter(content));
+        assert_eq!(extra.len(), 1);
+        assert_eq!(extra[0].yaml, "extra: true\n");
+    }
+
+    #[test]
+    fn test_horizontal_rules_are_not_frontmatter() {
+        let content =
+            "---\nname: first\n---\nIntro\n\n---\n\nSome prose, not YAML.\n\n---\n\nEnd\n";
+        assert!(find_additional_frontmatter(&split_frontmatter(content)).is_empty());
+    }
+
+    #[test]
+    fn test_setext_heading_is_not_frontmatter() {
+        let content = "---\nname: first\n---\nTitle\n---\nkey: value\n---\n";
+        assert!(find_additional_frontmatter(&split_frontmatter(content)).is_empty());
+    }
+
+    #[test]
+    fn test_fenced_frontmatter_example_is_ignored() {
+        let content = "---\nname: first\n---\n```markdown\n---\nname: example\n---\n```\n";
+        assert!(find_additional_frontmatter(&split_frontmatter(content)).is_empty());
+    }
+
+    #[test]
+    fn test_no_additional_frontmatter_without_leading_block() {
+        let content = "# Title\n\n---\nname: x\n---\n";
+        assert!(find_additional_frontmatter(&split_frontmatter(content)).is_empty());
+    }
 }
 
 #[cfg(test)]
@@ -194,6 +351,14 @@ mod proptests {
             let _ = split_frontmatter(&content);
         }
 
+        #[test]
+        fn find_additional_frontmatter_never_panics(content in "(---\n|[a-z]+: [a-z]+\n|\n|```\n|text\n){0,12}") {
+            let parts = split_frontmatter(&content);
+            for section in find_additional_frontmatter(&parts) {
+                prop_assert!(section.start < content.len());
+            }
+        }
+
         #[test]
         fn split_frontmatter_valid_offsets(content in ".*") {
             let parts = split_frontmatter(&content);
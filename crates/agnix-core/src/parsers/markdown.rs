@@ -6,7 +6,7 @@
 //! of Service) attacks. The `MAX_REGEX_INPUT_SIZE` constant limits the size of
 //! content that will be processed by regex operations.
 
-use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{BrokenLink, Event, LinkType, Options, Parser, Tag, TagEnd};
 use regex::Regex;
 use std::ops::Range;
 use std::panic::{self, AssertUnwindSafe};
@@ -168,6 +168,50 @@ fn extract_markdown_links_inner(content: &str) -> Vec<MarkdownLink> {
     links
 }
 
+/// Extract reference-style links whose label has no definition
+///
+/// Covers full (`[text][label]`) and collapsed (`[label][]`) references,
+/// including images. Shortcut references (`[label]`) are skipped because bare
+/// brackets are common in prose. Labels are matched the CommonMark way
+/// (case-insensitive, whitespace-collapsed) by pulldown-cmark itself.
+pub fn extract_undefined_references(content: &str) -> Vec<UndefinedReference> {
+    // Catch upstream parser panics (e.g., pulldown-cmark bugs) gracefully
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        extract_undefined_references_inner(content)
+    }))
+    .unwrap_or_default()
+}
+
+fn extract_undefined_references_inner(content: &str) -> Vec<UndefinedReference> {
+    let line_starts = compute_line_starts(content);
+    let mut broken: Vec<(String, Range<usize>)> = Vec::new();
+
+    let callback = |link: BrokenLink<'_>| {
+        if matches!(link.link_type, LinkType::Reference | LinkType::Collapsed)
+            && !broken.iter().any(|(_, span)| *span == link.span)
+        {
+            broken.push((link.reference.to_string(), link.span));
+        }
+        None
+    };
+    // Broken links are only reported while the parser is driven
+    Parser::new_with_broken_link_callback(content, Options::all(), Some(callback)).for_each(drop);
+
+    broken
+        .into_iter()
+        .map(|(label, span)| {
+            let (line, column) = line_col_at(span.start, &line_starts);
+            UndefinedReference {
+                label,
+                line,
+                column,
+                start_byte: span.start,
+                end_byte: span.end,
+            }
+        })
+        .collect()
+}
+
 /// Check if XML tags are balanced
 pub fn check_xml_balance(tags: &[XmlTag]) -> Vec<XmlBalanceError> {
     check_xml_balance_with_content_end(tags, None)
@@ -252,6 +296,21 @@ pub struct MarkdownLink {
     pub end_byte: usize,
 }
 
+/// A reference-style link whose label is never defined
+#[derive(Debug, Clone)]
+pub struct UndefinedReference {
+    /// The label as written, e.g. `Setup Guide` for `[docs][Setup Guide]`
+    pub label: String,
+    /// Line number (1-indexed)
+    pub line: usize,
+    /// Column number (1-indexed)
+    pub column: usize,
+    /// Byte offset of the reference start
+    pub start_byte: usize,
+    /// Byte offset of the reference end
+    pub end_byte: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct XmlTag {
     pub name: String,
@@ -860,6 +919,35 @@ mod tests {
         assert_eq!(links[0].column, 1);
     }
 
+    // ===== Undefined Reference Extraction Tests =====
+
+    #[test]
+    fn test_extract_undefined_references() {
+        let content = "See [the guide][guide] and ![logo][img].\n\n[Guide]: docs/guide.md\n";
+        let refs = extract_undefined_references(content);
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].label, "img");
+        assert_eq!((refs[0].line, refs[0].column), (1, 28));
+        assert_eq!(
+            &content[refs[0].start_byte..refs[0].end_byte],
+            "![logo][img]"
+        );
+    }
+
+    #[test]
+    fn test_extract_undefined_references_collapsed_and_case_folded() {
+        let content = "Read [Setup  Steps][] and [setup][].\n\n[setup steps]: setup.md\n";
+        let refs = extract_undefined_references(content);
+        assert_eq!(refs.len(), 1);
+        assert_eq!(refs[0].label, "setup");
+    }
+
+    #[test]
+    fn test_extract_undefined_references_skips_shortcuts_and_code() {
+        let content = "- [x] done [WIP]\n\n`[a][b]`\n\n```\n[c][d]\n```\n";
+        assert!(extract_undefined_references(content).is_empty());
+    }
+
     // ===== Security: Regex DoS Protection Tests =====
 
     #[test]
@@ -0,0 +1,492 @@
+//! Persistent cache of per-file validation results.
+//!
+//! When enabled with `LintConfig::set_result_cache_dir`, project validation
+//! stores each file's diagnostics in `<dir>/diagnostics.json`, keyed by a hash
+//! of the file's content and its effective config. Unchanged files reuse the
+//! stored diagnostics on the next run instead of being re-validated.
+//!
+//! The whole cache is discarded when the agnix version, the cache format, the
+//! output locale or the root config changes. Project-level passes (AGM-006,
+//! XP-*, MCP-023, ...) always run. Validators read other files through a
+//! [`RecordingFileSystem`] (and `LintConfig::project_paths`), and each entry stores a fingerprint of every path
+//! they touched, so findings such as a missing `@import` target are refreshed
+//! as soon as that path changes.
+//!
+//! Like the analysis cache, this cache is best-effort: a missing, unreadable or
+//! stale-format file is treated as empty, and write failures are logged and
+//! ignored.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::analysis_cache::content_hash;
+use crate::config::LintConfig;
+use crate::diagnostics::{Diagnostic, LintResult};
+use crate::file_utils;
+use crate::fs::{DirEntry, FileMetadata, FileSystem};
+
+const CACHE_FILE_NAME: &str = "diagnostics.json";
+
+/// Bumped whenever the stored layout or `Diagnostic` serialization changes.
+const CACHE_FORMAT: u32 = 2;
+
+#[derive(Clone, Serialize, Deserialize)]
+struct CachedResult {
+    /// Hash of the file content and effective config the diagnostics came from
+    key: String,
+    diagnostics: Vec<Diagnostic>,
+    /// Other paths the validators read, with their state at the time
+    dependencies: Vec<Dependency>,
+}
+
+/// How a validator read a path, which decides what its fingerprint covers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub(crate) enum Access {
+    /// Existence, kind or size checks
+    Metadata,
+    /// The file's content
+    Content,
+    /// The directory's entries
+    Listing,
+    /// Every file below the project root, as listed by `LintConfig::project_paths`
+    Tree,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct Dependency {
+    path: PathBuf,
+    access: Access,
+    fingerprint: String,
+}
+
+impl Dependency {
+    /// Whether `path` still looks the way it did when the entry was stored.
+    fn is_current(&self, config: &LintConfig) -> bool {
+        fingerprint(config, &self.path, self.access) == self.fingerprint
+    }
+}
+
+/// Current state of `path` as far as `access` can observe it.
+fn fingerprint(config: &LintConfig, path: &Path, access: Access) -> String {
+    let fs = config.fs().as_ref();
+    match access {
+        Access::Metadata => format!(
+            "{:?} {:?}",
+            fs.symlink_metadata(path).ok(),
+            fs.metadata(path).ok()
+        ),
+        Access::Content => fs
+            .read_to_string(path)
+            .map(|content| content_hash(&content))
+            .unwrap_or_else(|_| "unreadable".to_string()),
+        Access::Listing => match fs.read_dir(path) {
+            Ok(entries) => {
+                let mut names: Vec<String> = entries
+                    .iter()
+                    .map(|entry| format!("{} {:?}", entry.path.display(), entry.metadata))
+                    .collect();
+                names.sort();
+                content_hash(&names.join("\n"))
+            }
+            Err(_) => "unreadable".to_string(),
+        },
+        Access::Tree => config
+            .project_paths()
+            .map(|paths| content_hash(&paths.join("\n")))
+            .unwrap_or_default(),
+    }
+}
+
+/// Collects the paths read while validating one file.
+#[derive(Debug, Default)]
+pub(crate) struct DependencyRecorder {
+    reads: Mutex<BTreeSet<(PathBuf, Access)>>,
+}
+
+impl DependencyRecorder {
+    /// Note that `path` was read with `access`.
+    pub fn record(&self, path: &Path, access: Access) {
+        self.reads
+            .lock()
+            .unwrap()
+            .insert((path.to_path_buf(), access));
+    }
+
+    /// Fingerprint every recorded read against `config`.
+    fn dependencies(&self, config: &LintConfig) -> Vec<Dependency> {
+        self.reads
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(path, access)| Dependency {
+                path: path.clone(),
+                access: *access,
+                fingerprint: fingerprint(config, path, *access),
+            })
+            .collect()
+    }
+}
+
+/// `FileSystem` wrapper that records every read in a [`DependencyRecorder`].
+#[derive(Debug)]
+pub(crate) struct RecordingFileSystem {
+    inner: Arc<dyn FileSystem>,
+    recorder: Arc<DependencyRecorder>,
+}
+
+impl RecordingFileSystem {
+    pub fn new(inner: Arc<dyn FileSystem>, recorder: Arc<DependencyRecorder>) -> Self {
+        Self { inner, recorder }
+    }
+}
+
+impl FileSystem for RecordingFileSystem {
+    fn exists(&self, path: &Path) -> bool {
+        self.recorder.record(path, Access::Metadata);
+        self.inner.exists(path)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.recorder.record(path, Access::Metadata);
+        self.inner.is_file(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        self.recorder.record(path, Access::Metadata);
+        self.inner.is_dir(path)
+    }
+
+    fn is_symlink(&self, path: &Path) -> bool {
+        self.recorder.record(path, Access::Metadata);
+        self.inner.is_symlink(path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.recorder.record(path, Access::Metadata);
+        self.inner.metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        self.recorder.record(path, Access::Metadata);
+        self.inner.symlink_metadata(path)
+    }
+
+    fn read_to_string(&self, path: &Path) -> LintResult<String> {
+        self.recorder.record(path, Access::Content);
+        self.inner.read_to_string(path)
+    }
+
+    fn write(&self, path: &Path, content: &str) -> LintResult<()> {
+        self.inner.write(path, content)
+    }
+
+    fn create_new(&self, path: &Path, content: &str) -> LintResult<()> {
+        self.inner.create_new(path, content)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        self.recorder.record(path, Access::Metadata);
+        self.inner.canonicalize(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        self.recorder.record(path, Access::Listing);
+        self.inner.read_dir(path)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    format: u32,
+    version: String,
+    /// Hash of the root config and locale; any change invalidates every entry
+    config: String,
+    entries: HashMap<PathBuf, CachedResult>,
+}
+
+#[derive(Default)]
+struct State {
+    entries: HashMap<PathBuf, CachedResult>,
+    /// Paths looked up this run; only these are written back, dropping stale entries.
+    used: HashSet<PathBuf>,
+    dirty: bool,
+}
+
+/// Hash of a config's serialized form, used in cache keys.
+fn config_hash(config: &LintConfig) -> String {
+    content_hash(&serde_json::to_string(config).unwrap_or_default())
+}
+
+/// Cache key for `content` validated with `config`.
+pub(crate) fn result_key(content: &str, config: &LintConfig) -> String {
+    format!("{}-{}", content_hash(content), config_hash(config))
+}
+
+/// Per-file result cache shared by the parallel validation of one project run.
+pub(crate) struct ResultCache {
+    path: PathBuf,
+    config: String,
+    state: Mutex<State>,
+}
+
+impl ResultCache {
+    /// Load the cache from `cache_dir` for a run with the given root config.
+    pub fn load(cache_dir: &Path, config: &LintConfig) -> Self {
+        let path = cache_dir.join(CACHE_FILE_NAME);
+        let config = content_hash(&format!(
+            "{}\n{}",
+            config_hash(config),
+            &*rust_i18n::locale()
+        ));
+        let entries = file_utils::safe_read_file(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<CacheFile>(&content).ok())
+            .filter(|file| {
+                file.format == CACHE_FORMAT
+                    && file.version == env!("CARGO_PKG_VERSION")
+                    && file.config == config
+            })
+            .map(|file| file.entries)
+            .unwrap_or_default();
+
+        Self {
+            path,
+            config,
+            state: Mutex::new(State {
+                entries,
+                ..State::default()
+            }),
+        }
+    }
+
+    /// Stored diagnostics for `file`, if they were produced from the same key
+    /// and every path the validators read is unchanged.
+    pub fn get(&self, file: &Path, key: &str, config: &LintConfig) -> Option<Vec<Diagnostic>> {
+        let entry = {
+            let mut state = self.state.lock().unwrap();
+            state.used.insert(file.to_path_buf());
+            state
+                .entries
+                .get(file)
+                .filter(|entry| entry.key == key)
+                .cloned()?
+        };
+        // Checked outside the lock so parallel lookups don't serialize on I/O
+        entry
+            .dependencies
+            .iter()
+            .all(|dependency| dependency.is_current(config))
+            .then_some(entry.diagnostics)
+    }
+
+    /// Record the diagnostics produced for `file` under `key`, along with the
+    /// current state of every path in `recorder`.
+    pub fn insert(
+        &self,
+        file: &Path,
+        key: String,
+        diagnostics: &[Diagnostic],
+        recorder: &DependencyRecorder,
+        config: &LintConfig,
+    ) {
+        let dependencies = recorder.dependencies(config);
+        let mut state = self.state.lock().unwrap();
+        state.used.insert(file.to_path_buf());
+        state.entries.insert(
+            file.to_path_buf(),
+            CachedResult {
+                key,
+                diagnostics: diagnostics.to_vec(),
+                dependencies,
+            },
+        );
+        state.dirty = true;
+    }
+
+    /// Write entries used this run back to the cache dir.
+    pub fn save(self) {
+        let State {
+            mut entries,
+            used,
+            dirty,
+        } = self.state.into_inner().unwrap();
+        // Nothing new and nothing stale to drop
+        if !dirty && used.len() == entries.len() {
+            return;
+        }
+
+        entries.retain(|file, _| used.contains(file));
+        let file = CacheFile {
+            format: CACHE_FORMAT,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            config: self.config,
+            entries,
+        };
+        let json = match serde_json::to_string(&file) {
+            Ok(json) => json,
+            Err(e) => {
+                tracing::debug!(error = %e, "Failed to serialize result cache");
+                return;
+            }
+        };
+
+        let path = self.path;
+        let result = match path.parent() {
+            Some(dir) => std::fs::create_dir_all(dir).map_err(|e| e.to_string()),
+            None => Ok(()),
+        }
+        .and_then(|_| {
+            if path.exists() {
+                file_utils::safe_write_file(&path, &json)
+            } else {
+                file_utils::safe_create_file(&path, &json)
+            }
+            .map_err(|e| e.to_string())
+        });
+        if let Err(e) = result {
+            tracing::debug!(path = %path.display(), error = %e, "Failed to write result cache");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::RealFileSystem;
+
+    fn diagnostic(file: &Path) -> Diagnostic {
+        Diagnostic::warning(file.to_path_buf(), 2, 1, "AS-004", "cached".to_string())
+    }
+
+    #[test]
+    fn test_hit_after_save_and_reload() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config = LintConfig::default();
+        let file = Path::new("/project/SKILL.md");
+        let key = result_key("content", &config);
+
+        let cache = ResultCache::load(temp.path(), &config);
+        assert!(cache.get(file, &key, &config).is_none());
+        cache.insert(
+            file,
+            key.clone(),
+            &[diagnostic(file)],
+            &DependencyRecorder::default(),
+            &config,
+        );
+        cache.save();
+        assert!(temp.path().join(CACHE_FILE_NAME).exists());
+
+        let cache = ResultCache::load(temp.path(), &config);
+        let cached = cache
+            .get(file, &key, &config)
+            .expect("unchanged file should hit");
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].rule, "AS-004");
+        assert!(
+            cache
+                .get(file, &result_key("changed", &config), &config)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_config_change_invalidates_everything() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config = LintConfig::default();
+        let file = Path::new("/project/SKILL.md");
+        let key = result_key("content", &config);
+
+        let cache = ResultCache::load(temp.path(), &config);
+        cache.insert(
+            file,
+            key.clone(),
+            &[diagnostic(file)],
+            &DependencyRecorder::default(),
+            &config,
+        );
+        cache.save();
+
+        let mut changed = LintConfig::default();
+        changed.rules.disabled_rules = vec!["AS-004".to_string()];
+        let cache = ResultCache::load(temp.path(), &changed);
+        // Even a lookup with the old key misses: the entries were discarded
+        assert!(cache.get(file, &key, &changed).is_none());
+    }
+
+    #[test]
+    fn test_stale_entries_dropped_on_save() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let config = LintConfig::default();
+        let kept = Path::new("/project/kept/SKILL.md");
+        let removed = Path::new("/project/removed/SKILL.md");
+
+        let cache = ResultCache::load(temp.path(), &config);
+        cache.insert(
+            kept,
+            result_key("a", &config),
+            &[],
+            &DependencyRecorder::default(),
+            &config,
+        );
+        cache.insert(
+            removed,
+            result_key("b", &config),
+            &[],
+            &DependencyRecorder::default(),
+            &config,
+        );
+        cache.save();
+
+        let cache = ResultCache::load(temp.path(), &config);
+        assert!(
+            cache
+                .get(kept, &result_key("a", &config), &config)
+                .is_some()
+        );
+        cache.save();
+
+        let cache = ResultCache::load(temp.path(), &config);
+        assert_eq!(cache.state.lock().unwrap().entries.len(), 1);
+    }
+
+    #[test]
+    fn test_changed_dependency_misses() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let project = tempfile::TempDir::new().unwrap();
+        let config = LintConfig::default();
+        let file = project.path().join("CLAUDE.md");
+        let imported = project.path().join("rules.md");
+        let key = result_key("@rules.md", &config);
+
+        let recorder = Arc::new(DependencyRecorder::default());
+        let fs = RecordingFileSystem::new(Arc::new(RealFileSystem), recorder.clone());
+        assert!(!fs.exists(&imported));
+        assert!(fs.read_dir(project.path()).is_ok());
+
+        let cache = ResultCache::load(temp.path(), &config);
+        cache.insert(&file, key.clone(), &[diagnostic(&file)], &recorder, &config);
+        cache.save();
+
+        let cache = ResultCache::load(temp.path(), &config);
+        assert!(cache.get(&file, &key, &config).is_some());
+
+        // Creating the missing file changes both recorded reads
+        std::fs::write(&imported, "# Rules").unwrap();
+        assert!(cache.get(&file, &key, &config).is_none());
+    }
+
+    #[test]
+    fn test_corrupt_cache_file_is_ignored() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::write(temp.path().join(CACHE_FILE_NAME), "not json").unwrap();
+
+        let config = LintConfig::default();
+        let cache = ResultCache::load(temp.path(), &config);
+        assert!(cache.state.lock().unwrap().entries.is_empty());
+    }
+}
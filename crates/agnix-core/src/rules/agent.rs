@@ -1,6 +1,8 @@
-//! Agent file validation (CC-AG-001 to CC-AG-013)
+//! Agent file validation (CC-AG-001 to CC-AG-013, CC-AG-016, CC-AG-017)
 //!
 //! Validates Claude Code subagent definitions in `.claude/agents/*.md`.
+//! Handoff checks across agents (CC-AG-014, CC-AG-015) run as a project-level
+//! pass in `validate_project`, see `schemas::agent::check_handoffs`.
 //! Includes structural validation of hooks, tool names, memory, and permissions.
 
 use crate::{
@@ -9,6 +11,7 @@ use crate::{
     fs::FileSystem,
     parsers::frontmatter::split_frontmatter,
     rules::Validator,
+    rules::skill::{convert_to_kebab_case, is_valid_name_format, mcp_tool_server},
     schemas::agent::AgentSchema,
     schemas::hooks::HooksSchema,
 };
@@ -51,7 +54,7 @@ fn humanize_yaml_error(raw: &str) -> String {
 }
 
 /// Valid model values per CC-AG-003
-const VALID_MODELS: &[&str] = &["sonnet", "opus", "haiku", "inherit"];
+pub(crate) const VALID_MODELS: &[&str] = &["sonnet", "opus", "haiku", "inherit"];
 
 /// Valid permission modes per CC-AG-004
 const VALID_PERMISSION_MODES: &[&str] = &[
@@ -85,6 +88,20 @@ const KNOWN_AGENT_TOOLS: &[&str] = &[
     "ExitPlanMode",
 ];
 
+/// Description phrases that tell Claude when to delegate to an agent (CC-AG-017)
+const TRIGGER_PHRASES: &[&str] = &[
+    "use when",
+    "use proactively",
+    "use this agent",
+    "must be used",
+];
+
+/// Whether a tools entry names a known tool or an MCP tool (CC-AG-009, CC-AG-010)
+fn is_known_agent_tool(tool: &str) -> bool {
+    let base_name = tool.split('(').next().unwrap_or(tool);
+    KNOWN_AGENT_TOOLS.contains(&base_name) || mcp_tool_server(base_name).is_some()
+}
+
 pub struct AgentValidator;
 
 /// Maximum directory traversal depth to prevent unbounded filesystem walking
@@ -308,6 +325,59 @@ impl Validator for AgentValidator {
             );
         }
 
+        // CC-AG-016: Name must be kebab-case
+        if config.is_rule_enabled("CC-AG-016") {
+            let name = schema.name.as_deref().unwrap_or("").trim();
+            if !name.is_empty() && !is_valid_name_format(name) {
+                let mut diagnostic = Diagnostic::error(
+                    path.to_path_buf(),
+                    1,
+                    0,
+                    "CC-AG-016",
+                    t!("rules.cc_ag_016.message", name = name),
+                )
+                .with_suggestion(t!("rules.cc_ag_016.suggestion"));
+
+                // Unsafe auto-fix: renaming changes how the agent is referenced
+                let fixed_name = convert_to_kebab_case(name);
+                if is_valid_name_format(&fixed_name) {
+                    if let Some((start, end)) = frontmatter_value_byte_range(content, "name") {
+                        diagnostic = diagnostic.with_fix(Fix::replace(
+                            start,
+                            end,
+                            fixed_name.as_str(),
+                            t!("rules.cc_ag_016.fix", name = fixed_name.as_str()),
+                            false,
+                        ));
+                    }
+                }
+
+                diagnostics.push(diagnostic);
+            }
+        }
+
+        // CC-AG-017: Description should say when to use the agent
+        if config.is_rule_enabled("CC-AG-017") {
+            let description = schema.description.as_deref().unwrap_or("").trim();
+            let description_lower = description.to_lowercase();
+            if !description.is_empty()
+                && !TRIGGER_PHRASES
+                    .iter()
+                    .any(|phrase| description_lower.contains(phrase))
+            {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path.to_path_buf(),
+                        1,
+                        0,
+                        "CC-AG-017",
+                        t!("rules.cc_ag_017.message"),
+                    )
+                    .with_suggestion(t!("rules.cc_ag_017.suggestion")),
+                );
+            }
+        }
+
         // CC-AG-003: Invalid model value
         if config.is_rule_enabled("CC-AG-003") {
             if let Some(model) = &schema.model {
@@ -459,8 +529,7 @@ impl Validator for AgentValidator {
         if config.is_rule_enabled("CC-AG-009") {
             if let Some(tools) = &schema.tools {
                 for tool in tools {
-                    let base_name = tool.split('(').next().unwrap_or(tool);
-                    if !KNOWN_AGENT_TOOLS.contains(&base_name) {
+                    if !is_known_agent_tool(tool) {
                         diagnostics.push(
                             Diagnostic::error(
                                 path.to_path_buf(),
@@ -483,8 +552,7 @@ impl Validator for AgentValidator {
         if config.is_rule_enabled("CC-AG-010") {
             if let Some(disallowed) = &schema.disallowed_tools {
                 for tool in disallowed {
-                    let base_name = tool.split('(').next().unwrap_or(tool);
-                    if !KNOWN_AGENT_TOOLS.contains(&base_name) {
+                    if !is_known_agent_tool(tool) {
                         diagnostics.push(
                             Diagnostic::error(
                                 path.to_path_buf(),
@@ -2109,8 +2177,8 @@ Body"#;
 
     #[test]
     fn test_cc_ag_007_type_error_reports_error_line() {
-        // tools should be a list, not a string - error should be on the tools line (line 4)
-        let content = "---\nname: test\ndescription: test\ntools: not-a-list\n---\nBody";
+        // memory should be a string, not a list - error should be on the memory line (line 4)
+        let content = "---\nname: test\ndescription: test\nmemory: [user]\n---\nBody";
 
         let diagnostics = validate(content);
         let parse_errors: Vec<_> = diagnostics
@@ -2121,7 +2189,7 @@ Body"#;
         assert_eq!(parse_errors.len(), 1);
         assert_eq!(
             parse_errors[0].line, 4,
-            "Expected error on line 4 (tools field), got {}",
+            "Expected error on line 4 (memory field), got {}",
             parse_errors[0].line
         );
     }
@@ -2147,8 +2215,8 @@ Body"#;
 
     #[test]
     fn test_cc_ag_007_reports_column() {
-        // tools should be a list, not a string
-        let content = "---\nname: test\ndescription: test\ntools: not-a-list\n---\nBody";
+        // memory should be a string, not a list
+        let content = "---\nname: test\ndescription: test\nmemory: [user]\n---\nBody";
 
         let diagnostics = validate(content);
         let parse_errors: Vec<_> = diagnostics
@@ -2374,6 +2442,74 @@ Agent instructions"#;
         assert_eq!(cc_ag_009.len(), 2);
     }
 
+    #[test]
+    fn test_cc_ag_009_mcp_tools_valid() {
+        let content = r#"---
+name: my-agent
+description: A test agent
+tools:
+  - Read
+  - mcp__github__create_issue
+  - mcp__sentry
+---
+Agent instructions"#;
+
+        let diagnostics = validate(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-AG-009"));
+    }
+
+    #[test]
+    fn test_cc_ag_009_malformed_mcp_tool() {
+        let content = r#"---
+name: my-agent
+description: A test agent
+tools:
+  - mcp__
+---
+Agent instructions"#;
+
+        let diagnostics = validate(content);
+        let cc_ag_009: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-AG-009")
+            .collect();
+        assert_eq!(cc_ag_009.len(), 1);
+        assert!(cc_ag_009[0].message.contains("mcp__"));
+    }
+
+    #[test]
+    fn test_cc_ag_009_comma_separated_tools() {
+        let content = r#"---
+name: my-agent
+description: A test agent
+tools: Read, Grep, FakeTool, mcp__github__create_issue
+---
+Agent instructions"#;
+
+        let diagnostics = validate(content);
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-AG-007"));
+        let cc_ag_009: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-AG-009")
+            .collect();
+        assert_eq!(cc_ag_009.len(), 1);
+        assert!(cc_ag_009[0].message.contains("FakeTool"));
+    }
+
+    #[test]
+    fn test_cc_ag_006_comma_separated_conflict() {
+        let content = r#"---
+name: my-agent
+description: A test agent
+tools: Read, Bash
+disallowedTools: Bash
+---
+Agent instructions"#;
+
+        let diagnostics = validate(content);
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-AG-006"));
+    }
+
     // ===== CC-AG-010 Tests: Invalid Tool Name in DisallowedTools =====
 
     #[test]
@@ -2820,8 +2956,122 @@ Agent instructions"#;
         assert_eq!(cc_ag_013.len(), 1);
     }
 
+    // ===== CC-AG-016 Tests: Agent Name Format =====
+
+    #[test]
+    fn test_cc_ag_016_invalid_name_format() {
+        let content = r#"---
+name: Code_Reviewer
+description: Use when reviewing code
+---
+Agent instructions"#;
+
+        let diagnostics = validate(content);
+        let cc_ag_016: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-AG-016")
+            .collect();
+        assert_eq!(cc_ag_016.len(), 1);
+        assert_eq!(cc_ag_016[0].level, DiagnosticLevel::Error);
+        assert!(cc_ag_016[0].message.contains("Code_Reviewer"));
+
+        let fix = &cc_ag_016[0].fixes[0];
+        assert!(!fix.safe);
+        assert_eq!(fix.replacement, "code-reviewer");
+        assert_eq!(&content[fix.start_byte..fix.end_byte], "Code_Reviewer");
+    }
+
+    #[test]
+    fn test_cc_ag_016_valid_names() {
+        for name in ["code-reviewer", "test-runner-2", "a"] {
+            let content = format!("---\nname: {name}\ndescription: Use when needed\n---\nBody");
+            let diagnostics = validate(&content);
+            assert!(
+                !diagnostics.iter().any(|d| d.rule == "CC-AG-016"),
+                "'{name}' should be a valid agent name"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cc_ag_016_missing_name_left_to_cc_ag_001() {
+        let content = "---\ndescription: Use when needed\n---\nBody";
+        let diagnostics = validate(content);
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-AG-001"));
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-AG-016"));
+    }
+
+    // ===== CC-AG-017 Tests: Description Trigger Phrase =====
+
+    #[test]
+    fn test_cc_ag_017_missing_trigger_phrase() {
+        let content = r#"---
+name: test-runner
+description: Runs the test suite
+---
+Agent instructions"#;
+
+        let diagnostics = validate(content);
+        let cc_ag_017: Vec<_> = diagnostics
+            .iter()
+            .filter(|d| d.rule == "CC-AG-017")
+            .collect();
+        assert_eq!(cc_ag_017.len(), 1);
+        assert_eq!(cc_ag_017[0].level, DiagnosticLevel::Warning);
+    }
+
+    #[test]
+    fn test_cc_ag_017_trigger_phrases_accepted() {
+        for description in [
+            "Use when the user asks for a review",
+            "Runs tests. Use proactively after code changes.",
+            "MUST BE USED before every commit",
+            "Use this agent to plan migrations",
+        ] {
+            let content = format!("---\nname: my-agent\ndescription: {description}\n---\nBody");
+            let diagnostics = validate(&content);
+            assert!(
+                !diagnostics.iter().any(|d| d.rule == "CC-AG-017"),
+                "'{description}' should count as a trigger phrase"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cc_ag_017_empty_description_left_to_cc_ag_002() {
+        let content = "---\nname: my-agent\ndescription: \"\"\n---\nBody";
+        let diagnostics = validate(content);
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-AG-002"));
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-AG-017"));
+    }
+
     // ===== Fixture Tests for New Rules =====
 
+    #[test]
+    fn test_fixture_invalid_name_format() {
+        let content =
+            include_str!("../../../../tests/fixtures/invalid/agents/invalid-name-format.md");
+        let diagnostics = validate(content);
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-AG-016"));
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-AG-017"));
+    }
+
+    #[test]
+    fn test_fixture_missing_trigger_phrase() {
+        let content =
+            include_str!("../../../../tests/fixtures/invalid/agents/missing-trigger-phrase.md");
+        let diagnostics = validate(content);
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-AG-017"));
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-AG-009"));
+    }
+
+    #[test]
+    fn test_fixture_mcp_tools_agent() {
+        let content = include_str!("../../../../tests/fixtures/valid/agents/mcp-tools-agent.md");
+        let diagnostics = validate(content);
+        assert!(diagnostics.is_empty(), "{diagnostics:?}");
+    }
+
     #[test]
     fn test_fixture_invalid_memory() {
         let content = include_str!("../../../../tests/fixtures/invalid/agents/invalid-memory.md");
@@ -1,20 +1,23 @@
-//! AGENTS.md validation rules (AGM-001 to AGM-006)
+//! AGENTS.md validation rules (AGM-001 to AGM-007)
 //!
 //! Validates:
 //! - AGM-001: Valid Markdown Structure (HIGH) - unclosed code blocks, malformed links
 //! - AGM-002: Missing Section Headers (MEDIUM) - no # or ## headers
-//! - AGM-003: Character Limit (HIGH) - over 12000 chars (Windsurf compatibility)
+//! - AGM-003: Character Limit (HIGH) - over `rules.memory_max_chars` (default 12000,
+//!   Windsurf compatibility)
 //! - AGM-004: Missing Project Context (MEDIUM) - no project description
 //! - AGM-005: Platform-Specific Features Without Guard (HIGH) - missing guard comments
 //! - AGM-006: Nested AGENTS.md Hierarchy (MEDIUM) - project-level check
+//! - AGM-007: Token Budget (MEDIUM) - estimated tokens over `rules.instruction_token_budget`,
+//!   also checked for CLAUDE.md
 
 use crate::{
     config::LintConfig,
     diagnostics::Diagnostic,
     rules::Validator,
     schemas::agents_md::{
-        MarkdownIssueType, WINDSURF_CHAR_LIMIT, check_character_limit, check_markdown_validity,
-        check_project_context, check_section_headers, find_unguarded_platform_features,
+        MarkdownIssueType, check_character_limit, check_markdown_validity, check_project_context,
+        check_section_headers, check_token_budget, find_unguarded_platform_features,
     },
 };
 use rust_i18n::t;
@@ -22,16 +25,47 @@ use std::path::Path;
 
 pub struct AgentsMdValidator;
 
+fn is_agents_md(filename: &str) -> bool {
+    matches!(
+        filename,
+        "AGENTS.md" | "AGENTS.local.md" | "AGENTS.override.md"
+    )
+}
+
 impl Validator for AgentsMdValidator {
     fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
-        // Only validate AGENTS.md variants (not CLAUDE.md files)
         let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-        if !matches!(
-            filename,
-            "AGENTS.md" | "AGENTS.local.md" | "AGENTS.override.md"
-        ) {
+        let is_claude_md = matches!(filename, "CLAUDE.md" | "CLAUDE.local.md");
+
+        // AGM-007: Token Budget (WARNING)
+        // CLAUDE.md is loaded into every session the same way, so it shares the budget
+        #[allow(clippy::collapsible_if)]
+        if config.is_rule_enabled("AGM-007") && (is_claude_md || is_agents_md(filename)) {
+            if let Some(exceeded) =
+                check_token_budget(content, config.rules.instruction_token_budget)
+            {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path.to_path_buf(),
+                        1,
+                        0,
+                        "AGM-007",
+                        t!(
+                            "rules.agm_007.message",
+                            filename = filename,
+                            tokens = exceeded.estimated_tokens,
+                            budget = exceeded.budget
+                        ),
+                    )
+                    .with_suggestion(t!("rules.agm_007.suggestion")),
+                );
+            }
+        }
+
+        // Only validate AGENTS.md variants (not CLAUDE.md files)
+        if !is_agents_md(filename) {
             return diagnostics;
         }
 
@@ -79,7 +113,7 @@ impl Validator for AgentsMdValidator {
         // AGM-003: Character Limit (WARNING)
         #[allow(clippy::collapsible_if)]
         if config.is_rule_enabled("AGM-003") {
-            if let Some(exceeded) = check_character_limit(content, WINDSURF_CHAR_LIMIT) {
+            if let Some(exceeded) = check_character_limit(content, config.rules.memory_max_chars) {
                 diagnostics.push(
                     Diagnostic::warning(
                         path.to_path_buf(),
@@ -143,6 +177,7 @@ mod tests {
     use super::*;
     use crate::config::LintConfig;
     use crate::diagnostics::DiagnosticLevel;
+    use crate::schemas::agents_md::WINDSURF_CHAR_LIMIT;
 
     fn validate(content: &str) -> Vec<Diagnostic> {
         let validator = AgentsMdValidator;
@@ -330,6 +365,48 @@ More content.
         assert!(agm_003.is_empty());
     }
 
+    // ===== AGM-007: Token Budget =====
+
+    #[test]
+    fn test_agm_007_over_budget() {
+        let mut config = LintConfig::default();
+        config.rules.instruction_token_budget = 100;
+        let content = format!("# Project\n\n{}", "word ".repeat(100));
+
+        let diagnostics = validate_with_config(&content, &config);
+        let agm_007: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AGM-007").collect();
+        assert_eq!(agm_007.len(), 1);
+        assert_eq!(agm_007[0].level, DiagnosticLevel::Warning);
+        assert!(agm_007[0].message.contains("~128 tokens"));
+        assert!(agm_007[0].message.contains("budget of 100"));
+    }
+
+    #[test]
+    fn test_agm_007_default_budget() {
+        let under = format!("# Project\n\n{}", "x".repeat(30_000));
+        assert!(!validate(&under).iter().any(|d| d.rule == "AGM-007"));
+
+        let over = format!("# Project\n\n{}", "x".repeat(40_000));
+        assert!(validate(&over).iter().any(|d| d.rule == "AGM-007"));
+    }
+
+    #[test]
+    fn test_agm_007_checks_claude_md_only_for_budget() {
+        let mut config = LintConfig::default();
+        config.rules.instruction_token_budget = 10;
+        let content = "```unclosed\nSome content that is long enough to exceed the budget";
+
+        let diagnostics =
+            AgentsMdValidator.validate(Path::new("CLAUDE.local.md"), content, &config);
+        assert_eq!(diagnostics.len(), 1, "{:?}", diagnostics);
+        assert_eq!(diagnostics[0].rule, "AGM-007");
+        assert!(diagnostics[0].message.contains("CLAUDE.local.md"));
+
+        // Other instruction files are left to their own validators
+        let diagnostics = AgentsMdValidator.validate(Path::new("GEMINI.md"), content, &config);
+        assert!(diagnostics.is_empty());
+    }
+
     // ===== AGM-004: Missing Project Context =====
 
     #[test]
@@ -595,6 +672,22 @@ Content here."#;
         assert!(!agm_003.is_empty());
     }
 
+    #[test]
+    fn test_agm_003_respects_memory_max_chars_config() {
+        let content = format!("# Project\n\n{}", "a".repeat(WINDSURF_CHAR_LIMIT + 1));
+
+        let mut config = LintConfig::default();
+        config.rules.memory_max_chars = 20000;
+        let diagnostics = AgentsMdValidator.validate(Path::new("AGENTS.md"), &content, &config);
+        assert!(!diagnostics.iter().any(|d| d.rule == "AGM-003"));
+
+        config.rules.memory_max_chars = 5000;
+        let diagnostics = AgentsMdValidator.validate(Path::new("AGENTS.md"), &content, &config);
+        let agm_003: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AGM-003").collect();
+        assert_eq!(agm_003.len(), 1);
+        assert!(agm_003[0].message.contains("max 5000"));
+    }
+
     #[test]
     fn test_agm_004_has_tech_stack_section() {
         let content = r#"# Project
@@ -0,0 +1,138 @@
+//! Aider conventions file validation rules (AIDER-001)
+//!
+//! `CONVENTIONS.md` at the project root is detected as `FileType::ClaudeMd`,
+//! so memory and cross-platform rules already apply. This validator adds
+//! Aider-specific checks and skips every other instruction file.
+//!
+//! Validates:
+//! - AIDER-001: Missing Aider config reference (MEDIUM) - a relative path to
+//!   `.aider.conf.yml`, `.aiderignore` or another Aider config file that does
+//!   not exist
+
+use crate::{config::LintConfig, diagnostics::Diagnostic, rules::Validator};
+use regex::Regex;
+use rust_i18n::t;
+use std::path::Path;
+
+use crate::regex_util::static_regex;
+
+// A relative path ending in an Aider config file name. The path must start at a
+// delimiter so that `~/.aider.conf.yml` and absolute paths are not matched.
+static_regex!(fn aider_config_ref, r#"(?:^|[\s`'"(\[=])((?:[A-Za-z0-9_.-]+/)*(?:\.aider\.conf\.ya?ml|\.aider\.model\.settings\.ya?ml|\.aider\.model\.metadata\.json|\.aiderignore))\b"#);
+
+pub struct AiderValidator;
+
+impl Validator for AiderValidator {
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        // Only validate Aider's conventions file
+        let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+        if filename != "CONVENTIONS.md" {
+            return diagnostics;
+        }
+
+        // AIDER-001: Missing Aider config reference (WARNING)
+        if config.is_rule_enabled("AIDER-001") && content.contains(".aider") {
+            let base_dir = path.parent().unwrap_or_else(|| Path::new("."));
+            let fs = config.fs();
+            for (idx, line) in content.lines().enumerate() {
+                for caps in aider_config_ref().captures_iter(line) {
+                    let reference = caps.get(1).unwrap();
+                    if fs.exists(&base_dir.join(reference.as_str())) {
+                        continue;
+                    }
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path.to_path_buf(),
+                            idx + 1,
+                            reference.start() + 1,
+                            "AIDER-001",
+                            t!("rules.aider_001.message", path = reference.as_str()),
+                        )
+                        .with_span(idx + 1, reference.end() + 1)
+                        .with_suggestion(t!("rules.aider_001.suggestion")),
+                    );
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fs::MockFileSystem;
+    use std::sync::Arc;
+
+    fn validate_with_files(content: &str, files: &[&str]) -> Vec<Diagnostic> {
+        let mock_fs = Arc::new(MockFileSystem::new());
+        for file in files {
+            mock_fs.add_file(file, "");
+        }
+        let mut config = LintConfig::default();
+        config.set_fs(mock_fs);
+        AiderValidator.validate(Path::new("project/CONVENTIONS.md"), content, &config)
+    }
+
+    #[test]
+    fn test_aider_001_missing_config() {
+        let content = "# Conventions\n\nModel settings live in `.aider.conf.yml`.\n";
+        let diagnostics = validate_with_files(content, &[]);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].rule, "AIDER-001");
+        assert_eq!(diagnostics[0].line, 3);
+        assert_eq!(diagnostics[0].column, 25);
+        assert!(diagnostics[0].message.contains(".aider.conf.yml"));
+    }
+
+    #[test]
+    fn test_aider_001_existing_config_ok() {
+        let content = "See `.aider.conf.yml` and `config/.aiderignore`.\n";
+        let diagnostics = validate_with_files(
+            content,
+            &["project/.aider.conf.yml", "project/config/.aiderignore"],
+        );
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_aider_001_nested_path_missing() {
+        let content = "Run `aider --config tools/.aider.conf.yaml`.\n";
+        let diagnostics = validate_with_files(content, &["project/.aider.conf.yaml"]);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("tools/.aider.conf.yaml"));
+    }
+
+    #[test]
+    fn test_aider_001_home_and_absolute_paths_ignored() {
+        let content = "Global settings: `~/.aider.conf.yml` or /etc/aider/.aider.conf.yml\n";
+        assert!(validate_with_files(content, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_aider_001_history_files_ignored() {
+        let content = "Ignore `.aider.chat.history.md` and `.aider.tags.cache.v3`.\n";
+        assert!(validate_with_files(content, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_skip_other_memory_files() {
+        let content = "Settings in `.aider.conf.yml`.\n";
+        let diagnostics =
+            AiderValidator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+        assert!(diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_aider_001_disabled() {
+        let content = "Settings in `.aider.conf.yml`.\n";
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["AIDER-001".to_string()];
+        let diagnostics = AiderValidator.validate(Path::new("CONVENTIONS.md"), content, &config);
+        assert!(diagnostics.is_empty());
+    }
+}
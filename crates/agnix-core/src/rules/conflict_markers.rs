@@ -0,0 +1,134 @@
+//! Merge-conflict marker validation (GEN-002)
+//!
+//! Runs on every recognized file type ahead of the type-specific validators,
+//! so an unresolved merge is reported before the parse errors it causes.
+//!
+//! Validates:
+//! - GEN-002: Unresolved merge-conflict marker (HIGH) - a line starting with
+//!   `<<<<<<<`, `|||||||`, `=======` or `>>>>>>>`
+
+use crate::{config::LintConfig, diagnostics::Diagnostic, rules::Validator};
+use rust_i18n::t;
+use std::path::Path;
+
+/// Returns the marker a line starts with, if any. Markers are exactly seven
+/// characters followed by end of line or whitespace, matching what git writes.
+fn marker_at_line_start(line: &str) -> Option<&'static str> {
+    const MARKERS: [&str; 4] = ["<<<<<<<", "|||||||", "=======", ">>>>>>>"];
+    let line = line.strip_suffix('\r').unwrap_or(line);
+    MARKERS.into_iter().find(|marker| {
+        line.strip_prefix(marker)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with([' ', '\t']))
+    })
+}
+
+pub struct ConflictMarkerValidator;
+
+impl Validator for ConflictMarkerValidator {
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if !config.is_rule_enabled("GEN-002") || !content.contains("<<<<<<<") {
+            return diagnostics;
+        }
+
+        // `=======` and `|||||||` are only markers inside a conflict block; on
+        // their own they may be a setext heading underline or a table rule.
+        let mut in_conflict = false;
+        for (idx, line) in content.lines().enumerate() {
+            let Some(marker) = marker_at_line_start(line) else {
+                continue;
+            };
+            match marker {
+                "<<<<<<<" => in_conflict = true,
+                ">>>>>>>" => {
+                    if !in_conflict {
+                        continue;
+                    }
+                    in_conflict = false;
+                }
+                _ => {
+                    if !in_conflict {
+                        continue;
+                    }
+                }
+            }
+            diagnostics.push(
+                Diagnostic::error(
+                    path.to_path_buf(),
+                    idx + 1,
+                    1,
+                    "GEN-002",
+                    t!("rules.gen_002.message", marker = marker),
+                )
+                .with_span(idx + 1, marker.len() + 1)
+                .with_suggestion(t!("rules.gen_002.suggestion")),
+            );
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate(content: &str) -> Vec<Diagnostic> {
+        ConflictMarkerValidator.validate(Path::new("SKILL.md"), content, &LintConfig::default())
+    }
+
+    #[test]
+    fn test_gen_002_reports_each_marker() {
+        let content = "---\n<<<<<<< HEAD\nname: one\n=======\nname: two\n>>>>>>> feature\n---\n";
+        let diagnostics = validate(content);
+
+        assert_eq!(diagnostics.len(), 3);
+        assert!(diagnostics.iter().all(|d| d.rule == "GEN-002"));
+        assert!(
+            diagnostics
+                .iter()
+                .all(|d| d.level == DiagnosticLevel::Error)
+        );
+        let lines: Vec<usize> = diagnostics.iter().map(|d| d.line).collect();
+        assert_eq!(lines, vec![2, 4, 6]);
+        assert!(diagnostics[0].message.contains("<<<<<<<"));
+    }
+
+    #[test]
+    fn test_gen_002_diff3_base_marker() {
+        let content = "<<<<<<< ours\na\n||||||| base\nb\n=======\nc\n>>>>>>> theirs\n";
+        assert_eq!(validate(content).len(), 4);
+    }
+
+    #[test]
+    fn test_gen_002_crlf_line_endings() {
+        let content = "<<<<<<< HEAD\r\na\r\n=======\r\nb\r\n>>>>>>> other\r\n";
+        assert_eq!(validate(content).len(), 3);
+    }
+
+    #[test]
+    fn test_gen_002_setext_heading_not_flagged() {
+        let content = "Title\n=======\n\nBody text.\n";
+        assert!(validate(content).is_empty());
+    }
+
+    #[test]
+    fn test_gen_002_indented_or_longer_runs_not_flagged() {
+        let content = "  <<<<<<< HEAD\n<<<<<<<<<< not a marker\ntext >>>>>>> here\n";
+        assert!(validate(content).is_empty());
+    }
+
+    #[test]
+    fn test_gen_002_disabled() {
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["GEN-002".to_string()];
+        let diagnostics = ConflictMarkerValidator.validate(
+            Path::new("settings.json"),
+            "<<<<<<< HEAD\n{}\n=======\n{}\n>>>>>>> x\n",
+            &config,
+        );
+        assert!(diagnostics.is_empty());
+    }
+}
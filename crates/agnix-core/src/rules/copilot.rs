@@ -1,4 +1,4 @@
-//! GitHub Copilot instruction file validation rules (COP-001 to COP-006)
+//! GitHub Copilot instruction file validation rules (COP-001 to COP-007)
 //!
 //! Validates:
 //! - COP-001: Empty instruction file (HIGH) - files must have content
@@ -7,6 +7,7 @@
 //! - COP-004: Unknown frontmatter keys (MEDIUM) - warn about unrecognized keys
 //! - COP-005: Invalid excludeAgent value (HIGH) - must be "code-review" or "coding-agent"
 //! - COP-006: File length limit (MEDIUM) - global files should not exceed ~4000 characters
+//! - COP-007: applyTo matches no files (LOW) - scoped globs should match something in the project
 
 use crate::{
     FileType,
@@ -20,6 +21,35 @@ use std::path::Path;
 
 pub struct CopilotValidator;
 
+/// Whether any project file matches one of the comma-separated `applyTo`
+/// globs. Only files the project walk visits count, so paths ignored by
+/// `.gitignore`, `.agnixignore` or the exclude list never match.
+///
+/// Returns `true` (nothing to report) for invalid globs, which COP-003 covers,
+/// and when there is no project root to search.
+fn apply_to_matches_any_file(apply_to: &str, config: &LintConfig) -> bool {
+    let patterns: Vec<glob::Pattern> = match apply_to
+        .split(',')
+        .map(str::trim)
+        .filter(|pattern| !pattern.is_empty())
+        .map(glob::Pattern::new)
+        .collect()
+    {
+        Ok(patterns) => patterns,
+        Err(_) => return true,
+    };
+    if patterns.is_empty() {
+        return true;
+    }
+
+    let Some(paths) = config.project_paths() else {
+        return true;
+    };
+    paths
+        .iter()
+        .any(|path| patterns.iter().any(|pattern| pattern.matches(path)))
+}
+
 fn line_byte_range(content: &str, line_number: usize) -> Option<(usize, usize)> {
     if line_number == 0 {
         return None;
@@ -148,11 +178,14 @@ impl Validator for CopilotValidator {
         // COP-002: Invalid frontmatter (YAML parse error)
         if config.is_rule_enabled("COP-002") {
             if let Some(ref error) = parsed.parse_error {
+                let (line, column) = parsed
+                    .parse_error_position
+                    .unwrap_or((parsed.start_line, 0));
                 diagnostics.push(
                     Diagnostic::error(
                         path.to_path_buf(),
-                        parsed.start_line,
-                        0,
+                        line,
+                        column,
                         "COP-002",
                         t!("rules.cop_002.message_invalid_yaml", error = error.as_str()),
                     )
@@ -204,6 +237,26 @@ impl Validator for CopilotValidator {
             }
         }
 
+        // COP-007: applyTo matches no files (INFO); needs the project root
+        if config.is_rule_enabled("COP-007") {
+            if let Some(schema) = &parsed.schema {
+                if let Some(ref apply_to) = schema.apply_to {
+                    if !apply_to_matches_any_file(apply_to, config) {
+                        diagnostics.push(
+                            Diagnostic::info(
+                                path.to_path_buf(),
+                                parsed.start_line + 1,
+                                0,
+                                "COP-007",
+                                t!("rules.cop_007.message", pattern = apply_to.as_str()),
+                            )
+                            .with_suggestion(t!("rules.cop_007.suggestion")),
+                        );
+                    }
+                }
+            }
+        }
+
         // COP-004: Unknown frontmatter keys (WARNING)
         if config.is_rule_enabled("COP-004") {
             for unknown in &parsed.unknown_keys {
@@ -373,6 +426,17 @@ applyTo: [unclosed
         assert!(cop_002[0].message.contains("Invalid YAML"));
     }
 
+    #[test]
+    fn test_cop_002_points_at_the_bad_yaml_line() {
+        let content = "---\napplyTo: '**/*.ts'\n  description: : x\n---\n# Body\n";
+        let diagnostics = validate_scoped(content);
+        let cop_002 = diagnostics
+            .iter()
+            .find(|d| d.rule == "COP-002")
+            .expect("COP-002 should be reported");
+        assert_eq!((cop_002.line, cop_002.column), (3, 2));
+    }
+
     #[test]
     fn test_cop_002_missing_apply_to() {
         let content = r#"---
@@ -869,4 +933,91 @@ excludeAgent: "invalid-agent"
         let cop_006: Vec<_> = diagnostics.iter().filter(|d| d.rule == "COP-006").collect();
         assert!(cop_006.is_empty());
     }
+
+    // ===== COP-007: applyTo Matches No Files =====
+
+    fn cop_007_project(exclude: &[&str]) -> (tempfile::TempDir, LintConfig) {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join("src")).unwrap();
+        std::fs::write(temp.path().join("src/app.ts"), "export {};").unwrap();
+        std::fs::create_dir_all(temp.path().join("vendor")).unwrap();
+        std::fs::write(temp.path().join("vendor/lib.kt"), "fun main() {}").unwrap();
+
+        let mut config = LintConfig::default();
+        config.set_root_dir(temp.path().to_path_buf());
+        config.exclude = exclude.iter().map(|p| p.to_string()).collect();
+        (temp, config)
+    }
+
+    fn cop_007(apply_to: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let content = format!("---\napplyTo: \"{}\"\n---\n# Instructions\n", apply_to);
+        validate_scoped_with_config(&content, config)
+            .into_iter()
+            .filter(|d| d.rule == "COP-007")
+            .collect()
+    }
+
+    #[test]
+    fn test_cop_007_pattern_matches_nothing() {
+        let (_temp, config) = cop_007_project(&[]);
+        let diagnostics = cop_007("**/*.py", &config);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].level, DiagnosticLevel::Info);
+        assert_eq!(diagnostics[0].line, 2);
+        assert!(diagnostics[0].message.contains("**/*.py"));
+    }
+
+    #[test]
+    fn test_cop_007_pattern_matches_file() {
+        let (_temp, config) = cop_007_project(&[]);
+        assert!(cop_007("**/*.ts", &config).is_empty());
+        assert!(cop_007("src/**", &config).is_empty());
+        // Any entry of a comma-separated list is enough
+        assert!(cop_007("**/*.py, **/*.ts", &config).is_empty());
+    }
+
+    #[test]
+    fn test_cop_007_respects_exclude_patterns() {
+        let (_temp, config) = cop_007_project(&[]);
+        assert!(cop_007("**/*.kt", &config).is_empty());
+
+        let (_temp, config) = cop_007_project(&["vendor/**"]);
+        assert_eq!(cop_007("**/*.kt", &config).len(), 1);
+    }
+
+    #[test]
+    fn test_cop_007_respects_ignore_files() {
+        let (temp, config) = cop_007_project(&[]);
+        std::fs::write(temp.path().join(".agnixignore"), "vendor/\n").unwrap();
+        assert_eq!(cop_007("**/*.kt", &config).len(), 1);
+        assert!(cop_007("**/*.ts", &config).is_empty());
+    }
+
+    #[test]
+    fn test_cop_007_shares_one_walk_per_run() {
+        let (temp, mut config) = cop_007_project(&[]);
+        config.share_project_paths();
+        assert_eq!(cop_007("**/*.py", &config).len(), 1);
+
+        // Files added after the walk are not seen until the next run
+        std::fs::write(temp.path().join("src/tool.py"), "pass").unwrap();
+        assert_eq!(cop_007("**/*.py", &config).len(), 1);
+        config.share_project_paths();
+        assert!(cop_007("**/*.py", &config).is_empty());
+    }
+
+    #[test]
+    fn test_cop_007_needs_project_root() {
+        // Single-file validation has no root to search
+        let diagnostics = validate_scoped("---\napplyTo: \"**/*.py\"\n---\n# Instructions\n");
+        assert!(!diagnostics.iter().any(|d| d.rule == "COP-007"));
+    }
+
+    #[test]
+    fn test_cop_007_disabled() {
+        let (_temp, mut config) = cop_007_project(&[]);
+        config.rules.disabled_rules = vec!["COP-007".to_string()];
+        assert!(cop_007("**/*.py", &config).is_empty());
+    }
 }
@@ -4,13 +4,15 @@
 //! - XP-001: Claude-specific features in AGENTS.md (error)
 //! - XP-002: AGENTS.md markdown structure (warning)
 //! - XP-003: Hard-coded platform paths in configs (warning)
+//! - XP-013: Claude Code `@path` imports in AGENTS.md (warning)
 
 use crate::{
     config::LintConfig,
     diagnostics::Diagnostic,
     rules::Validator,
     schemas::cross_platform::{
-        check_markdown_structure, find_claude_specific_features, find_hard_coded_paths,
+        check_markdown_structure, find_claude_imports, find_claude_specific_features,
+        find_hard_coded_paths,
     },
 };
 use rust_i18n::t;
@@ -95,6 +97,27 @@ impl Validator for CrossPlatformValidator {
             }
         }
 
+        // XP-013: Claude Code imports in AGENTS.md (WARNING)
+        // Other AGENTS.md readers do not expand @path imports
+        if config.is_rule_enabled("XP-013") && is_agents_md {
+            for import in find_claude_imports(content) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        path.to_path_buf(),
+                        import.line,
+                        import.column,
+                        "XP-013",
+                        t!(
+                            "rules.xp_013.message",
+                            import = import.path.as_str(),
+                            filename = filename
+                        ),
+                    )
+                    .with_suggestion(t!("rules.xp_013.suggestion")),
+                );
+            }
+        }
+
         diagnostics
     }
 }
@@ -726,19 +749,42 @@ Description here.
         );
     }
 
+    // ===== XP-013: Claude Code Imports in AGENTS.md =====
+
     #[test]
-    fn test_xp_001_at_import_in_agents_md() {
+    fn test_xp_013_at_import_in_agents_md() {
         let content = "# Project\n\nSee @.config/agents/rules/coding.md for coding guidelines";
         let validator = CrossPlatformValidator;
         let diagnostics =
             validator.validate(Path::new("AGENTS.md"), content, &LintConfig::default());
 
-        let xp_001: Vec<_> = diagnostics.iter().filter(|d| d.rule == "XP-001").collect();
-        assert!(
-            xp_001.iter().any(|d| d.message.contains("@import")),
-            "Should detect @file import as Claude-specific. Got: {:?}",
-            xp_001.iter().map(|d| &d.message).collect::<Vec<_>>()
+        let xp_013: Vec<_> = diagnostics.iter().filter(|d| d.rule == "XP-013").collect();
+        assert_eq!(xp_013.len(), 1, "{:?}", diagnostics);
+        assert_eq!(xp_013[0].level, DiagnosticLevel::Warning);
+        assert_eq!((xp_013[0].line, xp_013[0].column), (3, 5));
+        assert!(xp_013[0].message.contains(".config/agents/rules/coding.md"));
+        assert!(!diagnostics.iter().any(|d| d.rule == "XP-001"));
+    }
+
+    #[test]
+    fn test_xp_013_not_for_claude_md() {
+        let content = "# Project\n\nSee @docs/coding.md\n";
+        let validator = CrossPlatformValidator;
+        for file in ["CLAUDE.md", "CLAUDE.local.md"] {
+            let diagnostics = validator.validate(Path::new(file), content, &LintConfig::default());
+            assert!(
+                !diagnostics.iter().any(|d| d.rule == "XP-013"),
+                "XP-013 should not fire for {}",
+                file
+            );
+        }
+
+        let diagnostics = validator.validate(
+            Path::new("AGENTS.override.md"),
+            content,
+            &LintConfig::default(),
         );
+        assert!(diagnostics.iter().any(|d| d.rule == "XP-013"));
     }
 
     #[test]
@@ -762,7 +808,7 @@ Use context: fork for subagents.
 
     #[test]
     fn test_all_xp_rules_can_be_disabled() {
-        let rules = ["XP-001", "XP-002", "XP-003"];
+        let rules = ["XP-001", "XP-002", "XP-003", "XP-013"];
 
         for rule in rules {
             let mut config = LintConfig::default();
@@ -771,7 +817,8 @@ Use context: fork for subagents.
             // Content that could trigger each rule
             let content = r#"# Project
 context: fork
-/etc/hosts"#;
+/etc/hosts
+@docs/rules.md"#;
 
             let validator = CrossPlatformValidator;
             let diagnostics = validator.validate(Path::new("AGENTS.md"), content, &config);
@@ -173,11 +173,14 @@ impl Validator for CursorValidator {
         // CUR-003: Invalid YAML frontmatter (ERROR)
         if config.is_rule_enabled("CUR-003") {
             if let Some(ref error) = parsed.parse_error {
+                let (line, column) = parsed
+                    .parse_error_position
+                    .unwrap_or((parsed.start_line, 0));
                 diagnostics.push(
                     Diagnostic::error(
                         path.to_path_buf(),
-                        parsed.start_line,
-                        0,
+                        line,
+                        column,
                         "CUR-003",
                         t!("rules.cur_003.message", error = error.as_str()),
                     )
@@ -469,6 +472,17 @@ globs: [unclosed
         assert!(cur_003[0].message.contains("Invalid YAML"));
     }
 
+    #[test]
+    fn test_cur_003_points_at_the_bad_yaml_line() {
+        let content = "---\nglobs: '**/*.ts'\n  description: : x\n---\n# Body\n";
+        let diagnostics = validate_mdc(content);
+        let cur_003 = diagnostics
+            .iter()
+            .find(|d| d.rule == "CUR-003")
+            .expect("CUR-003 should be reported");
+        assert_eq!((cur_003.line, cur_003.column), (3, 2));
+    }
+
     #[test]
     fn test_cur_003_unclosed_frontmatter() {
         let content = r#"---
@@ -995,6 +1009,23 @@ Body.
         );
     }
 
+    #[test]
+    fn test_cur_007_fix_range_is_exactly_globs_line() {
+        // The LSP turns this range into a "remove globs" quick-fix, so it must
+        // cover the globs line and nothing else
+        let content = "---\ndescription: Scoped rule\nalwaysApply: true\nglobs: \"**/*.ts\"\n---\n# Rules\nBody.\n";
+        let diagnostics = validate_mdc(content);
+        let cur_007: Vec<_> = diagnostics.iter().filter(|d| d.rule == "CUR-007").collect();
+        assert_eq!(cur_007.len(), 1);
+        assert_eq!(cur_007[0].line, 4);
+
+        let fix = &cur_007[0].fixes[0];
+        assert_eq!(
+            &content[fix.start_byte..fix.end_byte],
+            "globs: \"**/*.ts\"\n"
+        );
+    }
+
     // ===== CUR-008: Invalid alwaysApply type =====
 
     #[test]
@@ -581,6 +581,114 @@ pub(super) fn validate_cc_hk_016_unknown_type(
     });
 }
 
+/// Whether a matcher is meant as a regex rather than a literal tool name list.
+/// `Bash` and `Edit|Write` compile either way; `*` and empty matchers mean
+/// "every tool" and are not regexes.
+pub(super) fn is_regex_matcher(matcher: &str) -> bool {
+    let matcher = matcher.trim();
+    matcher != "*"
+        && matcher.contains([
+            '\\', '.', '+', '*', '?', '(', ')', '[', ']', '{', '}', '^', '$',
+        ])
+}
+
+/// One-line reason from a regex compile error, e.g. `unclosed group`.
+/// Syntax errors render the pattern with a caret before an `error:` line.
+pub(super) fn regex_error_summary(error: &regex::Error) -> String {
+    let rendered = error.to_string();
+    rendered
+        .lines()
+        .find_map(|line| line.strip_prefix("error: "))
+        .unwrap_or(rendered.lines().next().unwrap_or_default())
+        .trim()
+        .to_string()
+}
+
+static DECISION_PATTERN: OnceLock<Regex> = OnceLock::new();
+static EXIT_BLOCK_PATTERN: OnceLock<Regex> = OnceLock::new();
+
+/// Tools a matcher applies to, when that can be read without running a regex.
+#[derive(Debug, PartialEq)]
+pub(super) enum MatcherScope<'a> {
+    /// Absent, empty or `*`
+    AllTools,
+    /// `Bash` or `Edit|Write`
+    Tools(Vec<&'a str>),
+}
+
+impl MatcherScope<'_> {
+    /// A tool both scopes apply to, or `None` when they are disjoint.
+    /// `*` stands for "every tool" when neither scope names one.
+    pub(super) fn shared_tool(&self, other: &Self) -> Option<&str> {
+        match (self, other) {
+            (Self::AllTools, Self::AllTools) => Some("*"),
+            (Self::AllTools, Self::Tools(tools)) | (Self::Tools(tools), Self::AllTools) => {
+                tools.first().copied()
+            }
+            (Self::Tools(tools), Self::Tools(others)) => {
+                tools.iter().find(|tool| others.contains(tool)).copied()
+            }
+        }
+    }
+}
+
+/// Scope of a literal matcher; regex matchers return `None` and are not compared.
+pub(super) fn matcher_scope(matcher: Option<&str>) -> Option<MatcherScope<'_>> {
+    let matcher = matcher.map(str::trim).unwrap_or_default();
+    if matcher.is_empty() || matcher == "*" {
+        return Some(MatcherScope::AllTools);
+    }
+    if is_regex_matcher(matcher) {
+        return None;
+    }
+    Some(MatcherScope::Tools(
+        matcher
+            .split('|')
+            .map(str::trim)
+            .filter(|tool| !tool.is_empty())
+            .collect(),
+    ))
+}
+
+/// Decision a command hook returns, when the command spells it out inline:
+/// a JSON `decision`/`permissionDecision` value, or `exit 2` (block).
+pub(super) fn command_decision(command: &str) -> Option<String> {
+    let decision = DECISION_PATTERN.get_or_init(|| {
+        Regex::new(r#"\\?"(?:decision|permissionDecision)\\?"\s*:\s*\\?"([A-Za-z]+)"#)
+            .expect("BUG: invalid decision pattern regex")
+    });
+    if let Some(captures) = decision.captures(command) {
+        return Some(captures[1].to_ascii_lowercase());
+    }
+    let exit_block = EXIT_BLOCK_PATTERN
+        .get_or_init(|| Regex::new(r"\bexit\s+2\b").expect("BUG: invalid exit pattern regex"));
+    exit_block.is_match(command).then(|| "block".to_string())
+}
+
+/// Whether a decision stops the tool call or event.
+pub(super) fn is_blocking_decision(decision: &str) -> bool {
+    matches!(decision, "block" | "deny")
+}
+
+/// Span of the first `"matcher": "<value>"` string value, including quotes.
+pub(super) fn find_matcher_value_span(
+    content: &str,
+    matcher_value: &str,
+) -> Option<(usize, usize)> {
+    let serialized = serde_json::to_string(matcher_value).ok()?;
+    let pattern = format!(r#""matcher"\s*:\s*({})"#, regex::escape(&serialized));
+    let re = Regex::new(&pattern).ok()?;
+    let value = re.captures(content)?.get(1)?;
+    Some((value.start(), value.end()))
+}
+
+/// 1-based line and column of a byte offset.
+pub(super) fn offset_line_col(content: &str, offset: usize) -> (usize, usize) {
+    let before = &content[..offset];
+    let line_start = before.rfind('\n').map_or(0, |p| p + 1);
+    (before.matches('\n').count() + 1, offset - line_start + 1)
+}
+
 /// Find a unique matcher line span that can be safely deleted.
 /// Includes trailing newline when present.
 fn find_unique_matcher_line_span(content: &str, matcher_value: &str) -> Option<(usize, usize)> {
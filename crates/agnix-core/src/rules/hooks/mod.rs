@@ -1,19 +1,35 @@
-//! Hooks validation rules (CC-HK-001 to CC-HK-018)
+//! Hooks validation rules (CC-HK-001 to CC-HK-018, CC-HK-023 to CC-HK-025)
 
 use crate::{
     config::LintConfig,
     diagnostics::Diagnostic,
+    fs::FileSystem,
     rules::Validator,
-    schemas::hooks::{Hook, HooksSchema, SettingsSchema},
+    schemas::hooks::{Hook, HookMatcher, HooksSchema, SettingsSchema, normalize_split_hook_file},
 };
+use regex::Regex;
 use rust_i18n::t;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 mod helpers;
 use helpers::*;
 
 pub struct HooksValidator;
 
+/// Event name for a split hook file (`.claude/hooks/<Event>.json`), taken from the file stem
+fn split_hook_event(path: &Path) -> Option<&str> {
+    let parent = path.parent()?;
+    let in_claude_hooks = parent.file_name().is_some_and(|n| n == "hooks")
+        && parent
+            .parent()
+            .and_then(|p| p.file_name())
+            .is_some_and(|n| n == ".claude");
+    if !in_claude_hooks || path.extension().is_none_or(|e| e != "json") {
+        return None;
+    }
+    path.file_stem().and_then(|s| s.to_str())
+}
+
 /// Default timeout thresholds per hook type (from official Claude Code docs)
 const COMMAND_HOOK_DEFAULT_TIMEOUT: u64 = 600; // 10 minutes
 const PROMPT_HOOK_DEFAULT_TIMEOUT: u64 = 30; // 30 seconds
@@ -47,30 +63,42 @@ fn validate_cc_hk_008_script_exists(
     path: &Path,
     diagnostics: &mut Vec<Diagnostic>,
 ) {
-    let fs = config.fs();
-    for script_path in extract_script_paths(command) {
-        if !has_unresolved_env_vars(&script_path) {
-            let resolved = resolve_script_path(&script_path, project_dir);
-            if !fs.exists(&resolved) {
-                diagnostics.push(
-                    Diagnostic::error(
-                        path.to_path_buf(),
-                        1,
-                        0,
-                        "CC-HK-008",
-                        t!(
-                            "rules.cc_hk_008.message",
-                            script = script_path.as_str(),
-                            resolved = resolved.display().to_string()
-                        ),
-                    )
-                    .with_suggestion(t!("rules.cc_hk_008.suggestion")),
-                );
-            }
-        }
+    for (script_path, resolved) in missing_scripts(command, project_dir, config.fs().as_ref()) {
+        diagnostics.push(
+            Diagnostic::error(
+                path.to_path_buf(),
+                1,
+                0,
+                "CC-HK-008",
+                t!(
+                    "rules.cc_hk_008.message",
+                    script = script_path.as_str(),
+                    resolved = resolved.display().to_string()
+                ),
+            )
+            .with_suggestion(t!("rules.cc_hk_008.suggestion")),
+        );
     }
 }
 
+/// Scripts a hook command runs that do not exist under `project_dir`, with
+/// their resolved paths. Paths using environment variables other than
+/// `$CLAUDE_PROJECT_DIR` cannot be resolved and are skipped.
+pub(crate) fn missing_scripts(
+    command: &str,
+    project_dir: &Path,
+    fs: &dyn FileSystem,
+) -> Vec<(String, PathBuf)> {
+    extract_script_paths(command)
+        .into_iter()
+        .filter(|script_path| !has_unresolved_env_vars(script_path))
+        .filter_map(|script_path| {
+            let resolved = resolve_script_path(&script_path, project_dir);
+            (!fs.exists(&resolved)).then_some((script_path, resolved))
+        })
+        .collect()
+}
+
 /// CC-HK-009: Dangerous command patterns
 fn validate_cc_hk_009_dangerous_patterns(
     command: &str,
@@ -213,6 +241,154 @@ fn validate_cc_hk_018_matcher_ignored(
     }
 }
 
+/// CC-HK-023: Command hook on PreToolUse/PostToolUse that runs for every tool
+///
+/// An absent matcher is already an error under CC-HK-003, so it is only
+/// reported here when `report_absent` is set (CC-HK-003 disabled).
+fn validate_cc_hk_023_unscoped_tool_hook(
+    event: &str,
+    matcher: &Option<String>,
+    hooks: &[Hook],
+    matcher_idx: usize,
+    report_absent: bool,
+    path: &Path,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if !matches!(event, "PreToolUse" | "PostToolUse") {
+        return;
+    }
+    let unscoped = match matcher {
+        Some(m) => m.trim().is_empty(),
+        None => report_absent,
+    };
+    let has_command = hooks.iter().any(|h| matches!(h, Hook::Command { .. }));
+    if unscoped && has_command {
+        let hook_location = format!("hooks.{}[{}]", event, matcher_idx);
+        diagnostics.push(
+            Diagnostic::info(
+                path.to_path_buf(),
+                1,
+                0,
+                "CC-HK-023",
+                t!(
+                    "rules.cc_hk_023.message",
+                    location = hook_location.as_str(),
+                    event = event
+                ),
+            )
+            .with_suggestion(t!("rules.cc_hk_023.suggestion")),
+        );
+    }
+}
+
+/// CC-HK-024: Matcher regex that fails to compile
+///
+/// Claude Code matches tool names against `matcher` as a regex, so a pattern
+/// that does not compile matches nothing and the hook never runs.
+fn validate_cc_hk_024_matcher_regex(
+    event: &str,
+    matcher: &Option<String>,
+    matcher_idx: usize,
+    path: &Path,
+    content: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let Some(matcher) = matcher.as_deref().filter(|m| is_regex_matcher(m)) else {
+        return;
+    };
+    let Err(error) = Regex::new(matcher) else {
+        return;
+    };
+
+    let hook_location = format!("hooks.{}[{}]", event, matcher_idx);
+    let span = find_matcher_value_span(content, matcher);
+    let (line, column) = span.map_or((1, 0), |(start, _)| offset_line_col(content, start));
+    let mut diagnostic = Diagnostic::error(
+        path.to_path_buf(),
+        line,
+        column,
+        "CC-HK-024",
+        t!(
+            "rules.cc_hk_024.message",
+            location = hook_location.as_str(),
+            matcher = matcher,
+            error = regex_error_summary(&error)
+        ),
+    )
+    .with_suggestion(t!("rules.cc_hk_024.suggestion"));
+    if let Some((_, end)) = span {
+        let (end_line, end_column) = offset_line_col(content, end);
+        diagnostic = diagnostic.with_span(end_line, end_column);
+    }
+    diagnostics.push(diagnostic);
+}
+
+/// CC-HK-025: Overlapping matchers on one event that return conflicting decisions
+///
+/// Matching hooks run in parallel, so when two matcher entries cover the same
+/// tool and each returns a decision, one decision (and its reason) is lost.
+/// Only literal matchers are compared, and only decisions spelled out in the
+/// command itself are known. Two different decisions conflict, and so do two
+/// blocking ones: only one block reason reaches Claude.
+fn validate_cc_hk_025_conflicting_decisions(
+    event: &str,
+    matchers: &[HookMatcher],
+    path: &Path,
+    content: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let decided: Vec<(usize, &HookMatcher, MatcherScope, String)> = matchers
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, matcher)| {
+            let scope = matcher_scope(matcher.matcher.as_deref())?;
+            let decision = matcher
+                .hooks
+                .iter()
+                .find_map(|hook| hook.command().and_then(command_decision))?;
+            Some((idx, matcher, scope, decision))
+        })
+        .collect();
+
+    for (pos, (idx, matcher, scope, decision)) in decided.iter().enumerate() {
+        let conflict = decided[..pos]
+            .iter()
+            .find_map(|(other_idx, _, other_scope, other)| {
+                let tool = scope.shared_tool(other_scope)?;
+                let conflicting = other != decision
+                    || (is_blocking_decision(other) && is_blocking_decision(decision));
+                conflicting.then_some((other_idx, other, tool))
+            });
+        let Some((other_idx, other_decision, tool)) = conflict else {
+            continue;
+        };
+
+        let (line, column) = matcher
+            .matcher
+            .as_deref()
+            .and_then(|m| find_matcher_value_span(content, m))
+            .map_or((1, 0), |(start, _)| offset_line_col(content, start));
+        diagnostics.push(
+            Diagnostic::warning(
+                path.to_path_buf(),
+                line,
+                column,
+                "CC-HK-025",
+                t!(
+                    "rules.cc_hk_025.message",
+                    location = format!("hooks.{}[{}]", event, idx).as_str(),
+                    other = format!("hooks.{}[{}]", event, other_idx).as_str(),
+                    event = event,
+                    tool = tool,
+                    decision = decision.as_str(),
+                    other_decision = other_decision.as_str()
+                ),
+            )
+            .with_suggestion(t!("rules.cc_hk_025.suggestion")),
+        );
+    }
+}
+
 /// CC-HK-002: Prompt hook on wrong event
 fn validate_cc_hk_002_prompt_event_type(
     event: &str,
@@ -343,7 +519,7 @@ impl Validator for HooksValidator {
     /// 2. **JSON parsing** - Parse raw JSON, report CC-HK-012 on failure
     /// 3. **Pre-parse validation** - Raw JSON checks (CC-HK-005, CC-HK-011, CC-HK-013, CC-HK-014, CC-HK-016)
     /// 4. **Typed parsing** - Parse into SettingsSchema
-    /// 5. **Event iteration** - Validate each event and hook (CC-HK-015, CC-HK-017, CC-HK-018)
+    /// 5. **Event iteration** - Validate each event and hook (CC-HK-015, CC-HK-017, CC-HK-018, CC-HK-023, CC-HK-024, CC-HK-025)
     fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
 
@@ -367,6 +543,13 @@ impl Validator for HooksValidator {
             }
         };
 
+        // Split hook files hold a single event's hooks; validate them as settings
+        let split_event = split_hook_event(path);
+        let raw_value = match split_event {
+            Some(event) => normalize_split_hook_file(event, raw_value),
+            None => raw_value,
+        };
+
         // CC-HK-005: Missing type field (early return on failure)
         if config.is_rule_enabled("CC-HK-005") {
             validate_cc_hk_005_missing_type_field(&raw_value, path, &mut diagnostics);
@@ -418,7 +601,10 @@ impl Validator for HooksValidator {
         let project_dir = path
             .parent()
             .and_then(|p| {
-                if p.ends_with(".claude") {
+                if split_event.is_some() {
+                    // .claude/hooks/<Event>.json -> project root
+                    p.parent().and_then(|claude| claude.parent())
+                } else if p.ends_with(".claude") {
                     p.parent()
                 } else {
                     Some(p)
@@ -437,6 +623,17 @@ impl Validator for HooksValidator {
                 continue; // Skip invalid events even if rule disabled
             }
 
+            // CC-HK-025: Overlapping matchers with conflicting decisions
+            if config.is_rule_enabled("CC-HK-025") {
+                validate_cc_hk_025_conflicting_decisions(
+                    event,
+                    matchers,
+                    path,
+                    content,
+                    &mut diagnostics,
+                );
+            }
+
             for (matcher_idx, matcher) in matchers.iter().enumerate() {
                 // --- Matcher-level validation ---
                 // CC-HK-003: Missing matcher for tool events
@@ -473,6 +670,31 @@ impl Validator for HooksValidator {
                     );
                 }
 
+                // CC-HK-023: Command hook on PreToolUse/PostToolUse without a matcher
+                if config.is_rule_enabled("CC-HK-023") {
+                    validate_cc_hk_023_unscoped_tool_hook(
+                        event,
+                        &matcher.matcher,
+                        &matcher.hooks,
+                        matcher_idx,
+                        !config.is_rule_enabled("CC-HK-003"),
+                        path,
+                        &mut diagnostics,
+                    );
+                }
+
+                // CC-HK-024: Matcher regex that fails to compile
+                if config.is_rule_enabled("CC-HK-024") {
+                    validate_cc_hk_024_matcher_regex(
+                        event,
+                        &matcher.matcher,
+                        matcher_idx,
+                        path,
+                        content,
+                        &mut diagnostics,
+                    );
+                }
+
                 // --- Hook-level validation ---
                 for (hook_idx, hook) in matcher.hooks.iter().enumerate() {
                     let hook_location = format!(
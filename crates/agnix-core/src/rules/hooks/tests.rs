@@ -3090,6 +3090,370 @@ fn test_fixture_matcher_on_ignored_event() {
     assert_eq!(cc_hk_018.len(), 2);
 }
 
+// ===== CC-HK-023 Tests: Unscoped Tool Hook =====
+
+#[test]
+fn test_cc_hk_023_empty_matcher_on_post_tool_use() {
+    let content = r#"{
+            "hooks": {
+                "PostToolUse": [
+                    {
+                        "matcher": "",
+                        "hooks": [
+                            { "type": "command", "command": "echo done" }
+                        ]
+                    }
+                ]
+            }
+        }"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_023: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-023")
+        .collect();
+
+    assert_eq!(cc_hk_023.len(), 1);
+    assert_eq!(cc_hk_023[0].level, DiagnosticLevel::Info);
+    assert!(cc_hk_023[0].message.contains("PostToolUse"));
+}
+
+#[test]
+fn test_cc_hk_023_absent_matcher_left_to_cc_hk_003() {
+    let content = r#"{
+            "hooks": {
+                "PreToolUse": [
+                    {
+                        "hooks": [
+                            { "type": "command", "command": "echo before" }
+                        ]
+                    }
+                ]
+            }
+        }"#;
+
+    let diagnostics = validate(content);
+    assert!(diagnostics.iter().any(|d| d.rule == "CC-HK-003"));
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-023"));
+
+    let mut config = LintConfig::default();
+    config.rules.disabled_rules = vec!["CC-HK-003".to_string()];
+    let diagnostics = HooksValidator.validate(Path::new("settings.json"), content, &config);
+    assert_eq!(
+        diagnostics.iter().filter(|d| d.rule == "CC-HK-023").count(),
+        1
+    );
+}
+
+#[test]
+fn test_cc_hk_023_scoped_matcher_ok() {
+    let content = r#"{
+            "hooks": {
+                "PostToolUse": [
+                    {
+                        "matcher": "Edit|Write",
+                        "hooks": [
+                            { "type": "command", "command": "echo done" }
+                        ]
+                    }
+                ]
+            }
+        }"#;
+
+    let diagnostics = validate(content);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-023"));
+}
+
+#[test]
+fn test_cc_hk_023_prompt_hook_ok() {
+    let content = r#"{
+            "hooks": {
+                "PreToolUse": [
+                    {
+                        "matcher": "",
+                        "hooks": [
+                            { "type": "prompt", "prompt": "Check $ARGUMENTS" }
+                        ]
+                    }
+                ]
+            }
+        }"#;
+
+    let diagnostics = validate(content);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-023"));
+}
+
+#[test]
+fn test_fixture_unscoped_tool_hook() {
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/unscoped-tool-hook/settings.json"
+    );
+    let diagnostics = validate(content);
+    assert_eq!(
+        diagnostics.iter().filter(|d| d.rule == "CC-HK-023").count(),
+        1
+    );
+}
+
+#[test]
+fn test_fixture_scoped_tool_hook() {
+    let content =
+        include_str!("../../../../../tests/fixtures/valid/hooks/scoped-tool-hook/settings.json");
+    let diagnostics = validate(content);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-023"));
+}
+
+// ===== CC-HK-024 Tests: Matcher Regex Compile =====
+
+#[test]
+fn test_cc_hk_024_unclosed_group() {
+    let content = r#"{
+    "hooks": {
+        "PreToolUse": [
+            {
+                "matcher": "mcp__(github__.*",
+                "hooks": [{ "type": "command", "command": "echo hi" }]
+            }
+        ]
+    }
+}"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_024: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-024")
+        .collect();
+
+    assert_eq!(cc_hk_024.len(), 1);
+    assert_eq!(cc_hk_024[0].level, DiagnosticLevel::Error);
+    assert!(cc_hk_024[0].message.contains("mcp__(github__.*"));
+    assert!(cc_hk_024[0].message.contains("unclosed group"));
+    // Points at the matcher's string value
+    assert_eq!((cc_hk_024[0].line, cc_hk_024[0].column), (5, 28));
+    assert_eq!(cc_hk_024[0].end_line, Some(5));
+    assert_eq!(cc_hk_024[0].end_column, Some(46));
+}
+
+#[test]
+fn test_cc_hk_024_valid_and_literal_matchers() {
+    let content = r#"{
+    "hooks": {
+        "PreToolUse": [
+            { "matcher": "Bash", "hooks": [{ "type": "command", "command": "a" }] },
+            { "matcher": "Edit|Write", "hooks": [{ "type": "command", "command": "b" }] },
+            { "matcher": "*", "hooks": [{ "type": "command", "command": "c" }] },
+            { "matcher": "mcp__memory__.*", "hooks": [{ "type": "command", "command": "d" }] },
+            { "matcher": "Notebook\\(Edit\\)", "hooks": [{ "type": "command", "command": "e" }] }
+        ]
+    }
+}"#;
+
+    let diagnostics = validate(content);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-024"));
+}
+
+#[test]
+fn test_cc_hk_024_escaped_matcher_located() {
+    let content = r#"{"hooks": {"PostToolUse": [
+  {"matcher": "Write\\", "hooks": [{"type": "command", "command": "x"}]}
+]}}"#;
+
+    let diagnostics = validate(content);
+    let diagnostic = diagnostics
+        .iter()
+        .find(|d| d.rule == "CC-HK-024")
+        .expect("trailing backslash should not compile");
+    assert_eq!(diagnostic.line, 2);
+    assert_eq!(diagnostic.column, 15);
+}
+
+#[test]
+fn test_cc_hk_024_disabled() {
+    let content = r#"{
+    "hooks": {
+        "PreToolUse": [
+            { "matcher": "Bash(", "hooks": [{ "type": "command", "command": "a" }] }
+        ]
+    }
+}"#;
+
+    let mut config = LintConfig::default();
+    config.rules.disabled_rules = vec!["CC-HK-024".to_string()];
+    let diagnostics = HooksValidator.validate(Path::new("settings.json"), content, &config);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-024"));
+}
+
+#[test]
+fn test_fixture_invalid_matcher_regex() {
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/invalid-matcher-regex/settings.json"
+    );
+    let diagnostics = validate(content);
+    assert_eq!(
+        diagnostics.iter().filter(|d| d.rule == "CC-HK-024").count(),
+        1
+    );
+}
+
+#[test]
+fn test_fixture_regex_matcher() {
+    let content =
+        include_str!("../../../../../tests/fixtures/valid/hooks/regex-matcher/settings.json");
+    let diagnostics = validate(content);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-024"));
+}
+
+#[test]
+fn test_is_regex_matcher() {
+    assert!(!is_regex_matcher("Bash"));
+    assert!(!is_regex_matcher("Edit|Write"));
+    assert!(!is_regex_matcher(" * "));
+    assert!(is_regex_matcher("mcp__.*"));
+    assert!(is_regex_matcher("Bash("));
+}
+
+// ===== CC-HK-025 Tests: Conflicting Decisions =====
+
+#[test]
+fn test_cc_hk_025_allow_and_deny_for_same_tool() {
+    let content = r#"{
+    "hooks": {
+        "PreToolUse": [
+            {
+                "matcher": "Bash",
+                "hooks": [{ "type": "command", "command": "echo '{\"decision\": \"approve\"}'" }]
+            },
+            {
+                "matcher": "Edit|Bash",
+                "hooks": [{ "type": "command", "command": "echo '{\"decision\": \"block\"}'" }]
+            }
+        ]
+    }
+}"#;
+
+    let diagnostics = validate(content);
+    let cc_hk_025: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.rule == "CC-HK-025")
+        .collect();
+
+    assert_eq!(cc_hk_025.len(), 1);
+    assert_eq!(cc_hk_025[0].level, DiagnosticLevel::Warning);
+    assert!(cc_hk_025[0].message.contains("hooks.PreToolUse[0]"));
+    assert!(cc_hk_025[0].message.contains("hooks.PreToolUse[1]"));
+    assert!(cc_hk_025[0].message.contains("'Bash'"));
+    assert!(cc_hk_025[0].message.contains("'approve' and 'block'"));
+    // Points at the later matcher
+    assert_eq!((cc_hk_025[0].line, cc_hk_025[0].column), (9, 28));
+}
+
+#[test]
+fn test_cc_hk_025_double_block() {
+    let content = r#"{
+    "hooks": {
+        "PreToolUse": [
+            { "matcher": "Bash", "hooks": [{ "type": "command", "command": "./a.sh || exit 2" }] },
+            { "matcher": "*", "hooks": [{ "type": "command", "command": "echo '{\"permissionDecision\": \"deny\"}'" }] }
+        ]
+    }
+}"#;
+
+    let diagnostics = validate(content);
+    let diagnostic = diagnostics
+        .iter()
+        .find(|d| d.rule == "CC-HK-025")
+        .expect("two blocking hooks on Bash should conflict");
+    assert!(diagnostic.message.contains("'block' and 'deny'"));
+}
+
+#[test]
+fn test_cc_hk_025_no_conflict() {
+    let content = r#"{
+    "hooks": {
+        "PreToolUse": [
+            { "matcher": "Bash", "hooks": [{ "type": "command", "command": "exit 2" }] },
+            { "matcher": "Edit|Write", "hooks": [{ "type": "command", "command": "echo '{\"decision\": \"approve\"}'" }] },
+            { "matcher": "Write", "hooks": [{ "type": "command", "command": "echo '{\"decision\": \"approve\"}'" }] },
+            { "matcher": "Bash", "hooks": [{ "type": "command", "command": "./log.sh" }] },
+            { "matcher": "mcp__.*", "hooks": [{ "type": "command", "command": "exit 2" }] }
+        ],
+        "PostToolUse": [
+            { "matcher": "Bash", "hooks": [{ "type": "command", "command": "echo '{\"decision\": \"block\"}'" }] }
+        ]
+    }
+}"#;
+
+    let diagnostics = validate(content);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-025"));
+}
+
+#[test]
+fn test_cc_hk_025_disabled() {
+    let content = r#"{
+    "hooks": {
+        "PreToolUse": [
+            { "matcher": "Bash", "hooks": [{ "type": "command", "command": "exit 2" }] },
+            { "matcher": "Bash", "hooks": [{ "type": "command", "command": "exit 2" }] }
+        ]
+    }
+}"#;
+
+    let mut config = LintConfig::default();
+    config.rules.disabled_rules = vec!["CC-HK-025".to_string()];
+    let diagnostics = HooksValidator.validate(Path::new("settings.json"), content, &config);
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-HK-025"));
+}
+
+#[test]
+fn test_fixture_conflicting_decisions() {
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/conflicting-decisions/settings.json"
+    );
+    let diagnostics = validate(content);
+    assert_eq!(
+        diagnostics.iter().filter(|d| d.rule == "CC-HK-025").count(),
+        1
+    );
+}
+
+#[test]
+fn test_command_decision() {
+    assert_eq!(
+        command_decision(r#"echo '{"decision": "block", "reason": "no"}'"#),
+        Some("block".to_string())
+    );
+    assert_eq!(
+        command_decision(r#"printf "{\"permissionDecision\":\"Ask\"}""#),
+        Some("ask".to_string())
+    );
+    assert_eq!(
+        command_decision("./check.sh || exit 2"),
+        Some("block".to_string())
+    );
+    assert_eq!(command_decision("./check.sh; exit 20"), None);
+    assert_eq!(command_decision("./check.sh"), None);
+}
+
+#[test]
+fn test_matcher_scope() {
+    assert_eq!(matcher_scope(None), Some(MatcherScope::AllTools));
+    assert_eq!(matcher_scope(Some(" * ")), Some(MatcherScope::AllTools));
+    assert_eq!(
+        matcher_scope(Some("Edit | Write")),
+        Some(MatcherScope::Tools(vec!["Edit", "Write"]))
+    );
+    assert_eq!(matcher_scope(Some("mcp__.*")), None);
+
+    let bash = MatcherScope::Tools(vec!["Bash"]);
+    let edits = MatcherScope::Tools(vec!["Edit", "Write"]);
+    assert_eq!(bash.shared_tool(&edits), None);
+    assert_eq!(bash.shared_tool(&MatcherScope::AllTools), Some("Bash"));
+    assert_eq!(
+        MatcherScope::AllTools.shared_tool(&MatcherScope::AllTools),
+        Some("*")
+    );
+}
+
 // ===== CC-HK-016: Agent Hook Integration Tests =====
 
 #[test]
@@ -3181,3 +3545,70 @@ fn test_agent_hook_timeout_policy() {
     // Agent hooks should get timeout warnings like prompt hooks
     assert_eq!(cc_hk_010.len(), 1);
 }
+
+// ===== Split hook files (.claude/hooks/<Event>.json) =====
+
+fn validate_split(file_name: &str, content: &str) -> Vec<Diagnostic> {
+    let path = Path::new(".claude/hooks").join(file_name);
+    HooksValidator.validate(&path, content, &LintConfig::default())
+}
+
+#[test]
+fn test_split_hook_file_matcher_array() {
+    let content = include_str!(
+        "../../../../../tests/fixtures/valid/hooks/split/.claude/hooks/PreToolUse.json"
+    );
+    let diagnostics = validate_split("PreToolUse.json", content);
+    let errors: Vec<_> = diagnostics
+        .iter()
+        .filter(|d| d.level == DiagnosticLevel::Error)
+        .collect();
+    assert!(errors.is_empty(), "unexpected errors: {:?}", errors);
+}
+
+#[test]
+fn test_split_hook_file_bare_hook() {
+    let content =
+        include_str!("../../../../../tests/fixtures/valid/hooks/split/.claude/hooks/Stop.json");
+    let diagnostics = validate_split("Stop.json", content);
+    assert!(
+        !diagnostics.iter().any(|d| d.rule == "CC-HK-012"),
+        "bare hook should parse as a split hook file: {:?}",
+        diagnostics
+    );
+}
+
+#[test]
+fn test_split_hook_file_single_matcher_validated() {
+    let content = include_str!(
+        "../../../../../tests/fixtures/invalid/hooks/split-missing-command/.claude/hooks/PreToolUse.json"
+    );
+    let diagnostics = validate_split("PreToolUse.json", content);
+    assert!(diagnostics.iter().any(|d| d.rule == "CC-HK-006"));
+}
+
+#[test]
+fn test_split_hook_file_event_from_file_stem() {
+    let content = r#"{ "type": "command", "command": "echo hi" }"#;
+    let diagnostics = validate_split("NotAnEvent.json", content);
+    assert!(diagnostics.iter().any(|d| d.rule == "CC-HK-001"));
+}
+
+#[test]
+fn test_split_hook_file_full_settings_shape() {
+    let content = r#"{
+        "hooks": {
+            "Stop": [{ "hooks": [{ "type": "command" }] }]
+        }
+    }"#;
+    let diagnostics = validate_split("Stop.json", content);
+    assert!(diagnostics.iter().any(|d| d.rule == "CC-HK-006"));
+}
+
+#[test]
+fn test_settings_json_not_treated_as_split_file() {
+    // A bare hook is not a valid settings.json; it must still fail to parse there
+    let content = r#"[{ "hooks": [{ "type": "command", "command": "echo hi" }] }]"#;
+    let diagnostics = validate(content);
+    assert!(diagnostics.iter().any(|d| d.rule == "CC-HK-012"));
+}
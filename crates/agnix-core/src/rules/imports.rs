@@ -6,12 +6,17 @@
 //! - CC-MEM-003: @import depth exceeded
 //! - REF-001: @import file not found (universal)
 //! - REF-002: Broken markdown links (universal)
+//! - REF-008: @import target excluded from linting (universal)
+//! - REF-010: Reference-style links to undefined labels (universal)
+//!
+//! REF-009 (circular @import chains across the project) runs in the
+//! project-level pass via [`find_import_cycles`].
 
 use crate::{
     config::LintConfig,
     diagnostics::Diagnostic,
     fs::FileSystem,
-    parsers::markdown::{extract_imports, extract_markdown_links},
+    parsers::markdown::{extract_imports, extract_markdown_links, extract_undefined_references},
     parsers::{Import, ImportCache},
     rules::Validator,
 };
@@ -116,7 +121,7 @@ impl Validator for ImportsValidator {
             fs.as_ref(),
         );
 
-        // Validate markdown links (REF-002)
+        // Validate markdown links (REF-002) and link references (REF-010)
         // Only check agent config files, not generic markdown. Generic markdown
         // files (plans, research notes, etc.) commonly have broken relative links
         // that are project documentation issues, not agent configuration problems.
@@ -134,6 +139,7 @@ impl Validator for ImportsValidator {
             || filename == "copilot-instructions.md";
         if is_agent_config {
             validate_markdown_links(path, content, config, &mut diagnostics, fs.as_ref());
+            validate_link_references(path, content, config, &mut diagnostics);
         }
 
         diagnostics
@@ -307,6 +313,27 @@ fn visit_imports(
             continue;
         }
 
+        // REF-008: the linted file imports something the exclude list skips
+        let excluded_rel = (depth == 0 && config.is_rule_enabled("REF-008"))
+            .then(|| normalized.strip_prefix(project_root).ok())
+            .flatten()
+            .map(|rel| rel.to_string_lossy().replace('\\', "/"))
+            .filter(|rel| crate::is_excluded_path(rel, &config.exclude_patterns()));
+        if let Some(rel) = excluded_rel {
+            push_unique_diagnostic(
+                diagnostics,
+                seen_diagnostics,
+                Diagnostic::info(
+                    file_path.clone(),
+                    import.line,
+                    import.column,
+                    "REF-008",
+                    t!("rules.ref_008.message", path = import.path.as_str()),
+                )
+                .with_suggestion(t!("rules.ref_008.suggestion", path = rel.as_str())),
+            );
+        }
+
         // Always check for cycles/depth to prevent infinite recursion
         let has_cycle = stack.contains(&normalized);
         let exceeds_depth = depth + 1 > MAX_IMPORT_DEPTH;
@@ -501,6 +528,177 @@ fn find_repo_root(path: &Path, fs: &dyn FileSystem) -> Option<PathBuf> {
     None
 }
 
+/// Resolve an `@import` to an existing file inside the project, the way the
+/// per-file pass does: relative to the importing file, then to the project
+/// root. Absolute, home-relative and escaping imports resolve to `None`.
+fn resolve_import_target(
+    import_path: &str,
+    file_path: &Path,
+    project_root: &Path,
+    fs: &dyn FileSystem,
+) -> Option<PathBuf> {
+    if Path::new(import_path).is_absolute()
+        || import_path.starts_with('/')
+        || import_path.starts_with('\\')
+        || import_path.starts_with('~')
+    {
+        return None;
+    }
+
+    let base_dir = file_path.parent().unwrap_or(Path::new("."));
+    let normalized_base = normalize_existing_path(base_dir, fs);
+    if !normalize_join(&normalized_base, import_path).starts_with(project_root) {
+        return None;
+    }
+
+    let resolved = base_dir.join(import_path);
+    let target = if fs.exists(&resolved) {
+        resolved
+    } else {
+        project_root.join(import_path)
+    };
+    if !fs.is_file(&target) {
+        return None;
+    }
+    let target = normalize_existing_path(&target, fs);
+    target.starts_with(project_root).then_some(target)
+}
+
+/// A circular `@import` chain found by the project-level REF-009 pass
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ImportCycle {
+    /// File containing the import that closes the cycle
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    /// Files in the cycle relative to the project root, e.g. `A.md -> B.md -> A.md`
+    pub chain: String,
+}
+
+/// Find `@import` cycles among the files reachable from `seeds` (REF-009).
+///
+/// Imports are resolved like the per-file pass and read through the shared
+/// import cache when one is set. Each cycle is reported once, starting at its
+/// file with the lowest path, on the import that leads back to that file.
+/// When CC-MEM-002 is active, cycles reachable from a `CLAUDE.md` seed are
+/// skipped because the per-file pass already reports them.
+pub(crate) fn find_import_cycles(
+    seeds: &[PathBuf],
+    project_root: &Path,
+    config: &LintConfig,
+) -> Vec<ImportCycle> {
+    let fs = config.fs();
+    let fs = fs.as_ref();
+    let shared_cache = config.get_import_cache();
+    let mut local_cache: HashMap<PathBuf, Vec<Import>> = HashMap::new();
+    let project_root = normalize_existing_path(project_root, fs);
+
+    // Breadth-first walk of the import graph: nodes are visited in discovery
+    // order, and edges[i] holds (target node, import) for each import of node i
+    let mut index: HashMap<PathBuf, usize> = HashMap::new();
+    let mut nodes: Vec<PathBuf> = Vec::new();
+    let mut edges: Vec<Vec<(usize, Import)>> = Vec::new();
+    let mut intern = |path: PathBuf, nodes: &mut Vec<PathBuf>, edges: &mut Vec<Vec<_>>| {
+        *index.entry(path.clone()).or_insert_with(|| {
+            nodes.push(path);
+            edges.push(Vec::new());
+            nodes.len() - 1
+        })
+    };
+    for seed in seeds {
+        intern(normalize_existing_path(seed, fs), &mut nodes, &mut edges);
+    }
+    let seed_count = nodes.len();
+
+    let mut next = 0;
+    while next < nodes.len() {
+        let file = nodes[next].clone();
+        let imports = get_imports_for_file(&file, None, shared_cache, &mut local_cache, fs);
+        for import in imports.unwrap_or_default() {
+            if let Some(target) = resolve_import_target(&import.path, &file, &project_root, fs) {
+                let to = intern(target, &mut nodes, &mut edges);
+                edges[next].push((to, import));
+            }
+        }
+        next += 1;
+    }
+
+    let skip = if config.is_rule_enabled("CC-MEM-002") {
+        let claude_seeds: Vec<usize> = (0..seed_count)
+            .filter(|&i| {
+                matches!(
+                    nodes[i].file_name().and_then(|n| n.to_str()),
+                    Some("CLAUDE.md" | "CLAUDE.local.md")
+                )
+            })
+            .collect();
+        reachable_from(&claude_seeds, &edges)
+    } else {
+        HashSet::new()
+    };
+
+    let display = |node: usize| -> String {
+        let path = &nodes[node];
+        path.strip_prefix(&project_root)
+            .unwrap_or(path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    };
+
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+    let mut cycles = Vec::new();
+    for cycle in crate::schemas::agent::find_cycles(&edges) {
+        if cycle.iter().any(|node| skip.contains(node)) {
+            continue;
+        }
+        // Rotate so the cycle starts at its lowest path; dedupes rotations
+        let start = (0..cycle.len())
+            .min_by_key(|&i| &nodes[cycle[i]])
+            .unwrap_or(0);
+        let cycle: Vec<usize> = cycle[start..]
+            .iter()
+            .chain(&cycle[..start])
+            .copied()
+            .collect();
+        if !seen.insert(cycle.clone()) {
+            continue;
+        }
+
+        let first = cycle[0];
+        let last = cycle[cycle.len() - 1];
+        let Some((_, import)) = edges[last].iter().find(|(to, _)| *to == first) else {
+            continue;
+        };
+        let chain = cycle
+            .iter()
+            .chain(std::iter::once(&first))
+            .map(|&node| display(node))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        cycles.push(ImportCycle {
+            path: nodes[last].clone(),
+            line: import.line,
+            column: import.column,
+            chain,
+        });
+    }
+    cycles
+}
+
+/// Nodes reachable from `starts` (inclusive) along `edges`
+fn reachable_from<T>(starts: &[usize], edges: &[Vec<(usize, T)>]) -> HashSet<usize> {
+    let mut reached: HashSet<usize> = starts.iter().copied().collect();
+    let mut stack = starts.to_vec();
+    while let Some(node) = stack.pop() {
+        for (to, _) in &edges[node] {
+            if reached.insert(*to) {
+                stack.push(*to);
+            }
+        }
+    }
+    reached
+}
+
 fn format_cycle(stack: &[PathBuf], target: &Path) -> String {
     let mut cycle = Vec::new();
     let mut in_cycle = false;
@@ -589,6 +787,34 @@ fn validate_markdown_links(
     }
 }
 
+/// Validate reference-style links against link definitions (REF-010)
+fn validate_link_references(
+    path: &Path,
+    content: &str,
+    config: &LintConfig,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if !config.is_rule_enabled("REF-010") {
+        return;
+    }
+
+    for reference in extract_undefined_references(content) {
+        diagnostics.push(
+            Diagnostic::error(
+                path.to_path_buf(),
+                reference.line,
+                reference.column,
+                "REF-010",
+                t!("rules.ref_010.message", label = reference.label.as_str()),
+            )
+            .with_suggestion(t!(
+                "rules.ref_010.suggestion",
+                label = reference.label.as_str()
+            )),
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -973,6 +1199,55 @@ mod tests {
         assert!(diagnostics.is_empty());
     }
 
+    // ===== REF-008 Tests =====
+
+    fn ref_008_project(exclude: &[&str]) -> (TempDir, PathBuf, LintConfig) {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join("build")).unwrap();
+        fs::write(temp.path().join("build/generated.md"), "Generated").unwrap();
+        let file_path = temp.path().join("CLAUDE.md");
+        fs::write(&file_path, "@build/generated.md").unwrap();
+
+        let mut config = LintConfig::default();
+        config.set_root_dir(temp.path().to_path_buf());
+        config.exclude = exclude.iter().map(|p| p.to_string()).collect();
+        (temp, file_path, config)
+    }
+
+    #[test]
+    fn test_ref_008_import_of_excluded_file() {
+        let (_temp, file_path, config) = ref_008_project(&["build/**"]);
+        let diagnostics = ImportsValidator.validate(&file_path, "@build/generated.md", &config);
+
+        let ref_008 = diagnostics.iter().find(|d| d.rule == "REF-008").unwrap();
+        assert_eq!(ref_008.level, crate::diagnostics::DiagnosticLevel::Info);
+        assert_eq!(ref_008.line, 1);
+        assert!(ref_008.message.contains("build/generated.md"));
+    }
+
+    #[test]
+    fn test_ref_008_excluded_parent_directory() {
+        // A bare directory name prunes the whole directory during the walk
+        let (_temp, file_path, config) = ref_008_project(&["build"]);
+        let diagnostics = ImportsValidator.validate(&file_path, "@build/generated.md", &config);
+        assert!(diagnostics.iter().any(|d| d.rule == "REF-008"));
+    }
+
+    #[test]
+    fn test_ref_008_not_excluded() {
+        let (_temp, file_path, config) = ref_008_project(&["node_modules/**"]);
+        let diagnostics = ImportsValidator.validate(&file_path, "@build/generated.md", &config);
+        assert!(!diagnostics.iter().any(|d| d.rule == "REF-008"));
+    }
+
+    #[test]
+    fn test_ref_008_disabled() {
+        let (_temp, file_path, mut config) = ref_008_project(&["build/**"]);
+        config.rules.disabled_rules = vec!["REF-008".to_string()];
+        let diagnostics = ImportsValidator.validate(&file_path, "@build/generated.md", &config);
+        assert!(!diagnostics.iter().any(|d| d.rule == "REF-008"));
+    }
+
     // ===== REF-002 Tests =====
 
     #[test]
@@ -1150,6 +1425,39 @@ mod tests {
         assert!(!diagnostics.iter().any(|d| d.rule == "REF-002"));
     }
 
+    // ===== REF-010 Tests =====
+
+    #[test]
+    fn test_ref_010_undefined_label() {
+        let temp = TempDir::new().unwrap();
+        let file_path = temp.path().join("CLAUDE.md");
+        let content = "# Rules\n\nFollow the [style guide][style] and [tests][Testing].\n\n[testing]: https://example.com/testing\n";
+
+        let diagnostics = ImportsValidator.validate(&file_path, content, &LintConfig::default());
+
+        let ref_010: Vec<_> = diagnostics.iter().filter(|d| d.rule == "REF-010").collect();
+        assert_eq!(ref_010.len(), 1, "got: {:?}", ref_010);
+        assert_eq!(ref_010[0].level, crate::diagnostics::DiagnosticLevel::Error);
+        assert_eq!((ref_010[0].line, ref_010[0].column), (3, 12));
+        assert!(ref_010[0].message.contains("[style]"));
+    }
+
+    #[test]
+    fn test_ref_010_skips_generic_markdown_and_disabled() {
+        let temp = TempDir::new().unwrap();
+        let content = "See [guide][missing].";
+
+        let notes = temp.path().join("notes.md");
+        let diagnostics = ImportsValidator.validate(&notes, content, &LintConfig::default());
+        assert!(!diagnostics.iter().any(|d| d.rule == "REF-010"));
+
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["REF-010".to_string()];
+        let claude_md = temp.path().join("CLAUDE.md");
+        let diagnostics = ImportsValidator.validate(&claude_md, content, &config);
+        assert!(!diagnostics.iter().any(|d| d.rule == "REF-010"));
+    }
+
     // ===== Shared Import Cache Tests =====
 
     #[test]
@@ -1436,4 +1744,82 @@ mod tests {
             missing.len()
         );
     }
+
+    // ===== REF-009: Circular @import chains (project-level) =====
+
+    fn write_project(files: &[(&str, &str)]) -> TempDir {
+        let temp = TempDir::new().unwrap();
+        for (path, content) in files {
+            let path = temp.path().join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        temp
+    }
+
+    fn cycles_for(temp: &TempDir, seeds: &[&str], config: &LintConfig) -> Vec<ImportCycle> {
+        let seeds: Vec<PathBuf> = seeds.iter().map(|s| temp.path().join(s)).collect();
+        find_import_cycles(&seeds, temp.path(), config)
+    }
+
+    #[test]
+    fn test_ref_009_two_file_cycle() {
+        let temp = write_project(&[
+            ("AGENTS.md", "# Agents\n\nSee @docs/rules.md\n"),
+            ("docs/rules.md", "# Rules\n\nBack to @../AGENTS.md\n"),
+        ]);
+        let cycles = cycles_for(&temp, &["AGENTS.md"], &LintConfig::default());
+
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].chain, "AGENTS.md -> docs/rules.md -> AGENTS.md");
+        // Reported on the import that closes the cycle
+        assert!(cycles[0].path.ends_with("docs/rules.md"));
+        assert_eq!(cycles[0].line, 3);
+    }
+
+    #[test]
+    fn test_ref_009_longer_cycle_reported_once() {
+        let temp = write_project(&[
+            ("AGENTS.md", "@a.md\n"),
+            ("a.md", "@b.md\n"),
+            ("b.md", "@AGENTS.md\n"),
+        ]);
+        // Seeding from several files in the cycle still reports it once
+        let cycles = cycles_for(&temp, &["AGENTS.md", "a.md"], &LintConfig::default());
+
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].chain, "AGENTS.md -> a.md -> b.md -> AGENTS.md");
+        assert!(cycles[0].path.ends_with("b.md"));
+    }
+
+    #[test]
+    fn test_ref_009_shared_import_is_not_a_cycle() {
+        let temp = write_project(&[
+            ("AGENTS.md", "@a.md\n@b.md\n"),
+            ("a.md", "@shared.md\n"),
+            ("b.md", "@shared.md\n"),
+            ("shared.md", "# Shared\n"),
+        ]);
+        assert!(cycles_for(&temp, &["AGENTS.md"], &LintConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_ref_009_self_import() {
+        let temp = write_project(&[("AGENTS.md", "# Agents\n@AGENTS.md\n")]);
+        let cycles = cycles_for(&temp, &["AGENTS.md"], &LintConfig::default());
+
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].chain, "AGENTS.md -> AGENTS.md");
+        assert_eq!(cycles[0].line, 2);
+    }
+
+    #[test]
+    fn test_ref_009_leaves_claude_md_cycles_to_cc_mem_002() {
+        let temp = write_project(&[("CLAUDE.md", "@notes.md\n"), ("notes.md", "@CLAUDE.md\n")]);
+        assert!(cycles_for(&temp, &["CLAUDE.md"], &LintConfig::default()).is_empty());
+
+        let mut config = LintConfig::default();
+        config.rules.disabled_rules = vec!["CC-MEM-002".to_string()];
+        assert_eq!(cycles_for(&temp, &["CLAUDE.md"], &config).len(), 1);
+    }
 }
@@ -2,10 +2,12 @@
 
 pub mod agent;
 pub mod agents_md;
+pub mod aider;
 pub mod claude_md;
 pub mod claude_rules;
 pub mod cline;
 pub mod codex;
+pub mod conflict_markers;
 pub mod copilot;
 pub mod cross_platform;
 pub mod cursor;
@@ -17,6 +19,7 @@ pub mod opencode;
 pub mod plugin;
 pub mod prompt;
 pub mod skill;
+pub mod windsurf;
 pub mod xml;
 
 use crate::{config::LintConfig, diagnostics::Diagnostic};
@@ -25,4 +28,9 @@ use std::path::Path;
 /// Trait for file validators
 pub trait Validator {
     fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic>;
+
+    /// Name shown in validation timings; defaults to the implementing type's name.
+    fn name(&self) -> &'static str {
+        crate::timing::short_type_name(std::any::type_name::<Self>())
+    }
 }
@@ -1,4 +1,4 @@
-//! Plugin manifest validation (CC-PL-001 to CC-PL-010).
+//! Plugin manifest validation (CC-PL-001 to CC-PL-014).
 //!
 //! Validates `.claude-plugin/plugin.json` manifests.
 
@@ -14,6 +14,9 @@ use std::path::Path;
 
 pub struct PluginValidator;
 
+/// Optional manifest fields shown in marketplace listings (CC-PL-014).
+const MARKETPLACE_FIELDS: [&str; 5] = ["author", "homepage", "repository", "license", "keywords"];
+
 impl Validator for PluginValidator {
     fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
@@ -204,6 +207,107 @@ impl Validator for PluginValidator {
             }
         }
 
+        // CC-PL-011: Invalid repository URL
+        if config.is_rule_enabled("CC-PL-011") {
+            if let Some(repository_val) = raw_value.get("repository") {
+                let invalid = match repository_val.as_str() {
+                    Some(repository) => !repository.is_empty() && !is_valid_url(repository),
+                    None => true,
+                };
+                if invalid {
+                    let url = repository_val
+                        .as_str()
+                        .map(String::from)
+                        .unwrap_or_else(|| repository_val.to_string());
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path.to_path_buf(),
+                            1,
+                            0,
+                            "CC-PL-011",
+                            t!("rules.cc_pl_011.message", url = url.as_str()),
+                        )
+                        .with_suggestion(t!("rules.cc_pl_011.suggestion")),
+                    );
+                }
+            }
+        }
+
+        // CC-PL-012: License is not an SPDX identifier
+        if config.is_rule_enabled("CC-PL-012") {
+            if let Some(license_val) = raw_value.get("license") {
+                let invalid = match license_val.as_str() {
+                    Some(license) => !license.is_empty() && !is_valid_license(license),
+                    None => true,
+                };
+                if invalid {
+                    let license = license_val
+                        .as_str()
+                        .map(String::from)
+                        .unwrap_or_else(|| license_val.to_string());
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path.to_path_buf(),
+                            1,
+                            0,
+                            "CC-PL-012",
+                            t!("rules.cc_pl_012.message", license = license.as_str()),
+                        )
+                        .with_suggestion(t!("rules.cc_pl_012.suggestion")),
+                    );
+                }
+            }
+        }
+
+        // CC-PL-013: Invalid keywords
+        if config.is_rule_enabled("CC-PL-013") {
+            if let Some(keywords) = raw_value.get("keywords") {
+                let valid = keywords.as_array().is_some_and(|items| {
+                    !items.is_empty()
+                        && items
+                            .iter()
+                            .all(|k| k.as_str().is_some_and(|k| !k.trim().is_empty()))
+                });
+                if !valid {
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path.to_path_buf(),
+                            1,
+                            0,
+                            "CC-PL-013",
+                            t!("rules.cc_pl_013.message"),
+                        )
+                        .with_suggestion(t!("rules.cc_pl_013.suggestion")),
+                    );
+                }
+            }
+        }
+
+        // CC-PL-014: Marketplace metadata missing on a plugin that looks published
+        if config.is_rule_enabled("CC-PL-014") {
+            let has_marketplace = plugin_dir
+                .map(|dir| config.fs().exists(&dir.join("marketplace.json")))
+                .unwrap_or(false);
+            let missing: Vec<&str> = MARKETPLACE_FIELDS
+                .iter()
+                .copied()
+                .filter(|field| raw_value.get(*field).is_none())
+                .collect();
+            let intends_publishing = has_marketplace || missing.len() < MARKETPLACE_FIELDS.len();
+            if intends_publishing && !missing.is_empty() {
+                diagnostics.push(
+                    Diagnostic::info(
+                        path.to_path_buf(),
+                        1,
+                        0,
+                        "CC-PL-014",
+                        t!("rules.cc_pl_014.message", fields = missing.join(", ")),
+                    )
+                    .with_suggestion(t!("rules.cc_pl_014.suggestion")),
+                );
+            }
+        }
+
         let schema: PluginSchema = match serde_json::from_value(raw_value.clone()) {
             Ok(schema) => schema,
             Err(_) => {
@@ -418,6 +522,15 @@ fn is_valid_url(url: &str) -> bool {
     url.starts_with("http://") || url.starts_with("https://")
 }
 
+/// Check if a license is an SPDX expression (`MIT`, `Apache-2.0 OR MIT`,
+/// `LicenseRef-...`) or one of npm's `UNLICENSED` / `SEE LICENSE IN <file>` forms.
+fn is_valid_license(license: &str) -> bool {
+    let license = license.trim();
+    license == "UNLICENSED"
+        || license.starts_with("SEE LICENSE IN ")
+        || spdx::Expression::parse(license).is_ok()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1519,4 +1632,275 @@ mod tests {
             "Non-string homepage should trigger CC-PL-010"
         );
     }
+
+    // ===== CC-PL-011 to CC-PL-014: Marketplace metadata =====
+
+    #[test]
+    fn test_cc_pl_011_invalid_repository_url() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","repository":"github.com/owner/plugin"}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        let cc_pl_011 = diagnostics
+            .iter()
+            .find(|d| d.rule == "CC-PL-011")
+            .expect("CC-PL-011 should be reported");
+        assert!(cc_pl_011.message.contains("github.com/owner/plugin"));
+    }
+
+    #[test]
+    fn test_cc_pl_011_non_string_repository() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","repository":{"type":"git","url":"https://github.com/owner/plugin"}}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-PL-011"));
+    }
+
+    #[test]
+    fn test_cc_pl_011_valid_repository_no_error() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","repository":"https://github.com/owner/plugin"}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-PL-011"));
+    }
+
+    #[test]
+    fn test_cc_pl_012_invalid_license() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","license":"MIT License"}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        let cc_pl_012 = diagnostics
+            .iter()
+            .find(|d| d.rule == "CC-PL-012")
+            .expect("CC-PL-012 should be reported");
+        assert!(cc_pl_012.message.contains("MIT License"));
+    }
+
+    #[test]
+    fn test_cc_pl_012_valid_licenses_no_error() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        let validator = PluginValidator;
+
+        for license in [
+            "MIT",
+            "Apache-2.0",
+            "MIT OR Apache-2.0",
+            "GPL-3.0-only WITH Classpath-exception-2.0",
+            "LicenseRef-Proprietary",
+            "UNLICENSED",
+            "SEE LICENSE IN LICENSE.txt",
+        ] {
+            let content = format!(
+                r#"{{"name":"test","description":"desc","version":"1.0.0","license":"{}"}}"#,
+                license
+            );
+            write_plugin(&plugin_path, &content);
+            let diagnostics = validator.validate(&plugin_path, &content, &LintConfig::default());
+            assert!(
+                !diagnostics.iter().any(|d| d.rule == "CC-PL-012"),
+                "'{}' should be accepted",
+                license
+            );
+        }
+    }
+
+    #[test]
+    fn test_cc_pl_013_empty_keywords() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","keywords":[]}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-PL-013"));
+    }
+
+    #[test]
+    fn test_cc_pl_013_keywords_not_strings() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","keywords":["git", "", 3]}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-PL-013"));
+    }
+
+    #[test]
+    fn test_cc_pl_013_keywords_string_instead_of_array() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","keywords":"git"}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        assert!(diagnostics.iter().any(|d| d.rule == "CC-PL-013"));
+    }
+
+    #[test]
+    fn test_cc_pl_014_partial_metadata_reports_missing_fields() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","repository":"https://github.com/owner/plugin","license":"MIT"}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        let cc_pl_014 = diagnostics
+            .iter()
+            .find(|d| d.rule == "CC-PL-014")
+            .expect("CC-PL-014 should be reported");
+        assert_eq!(cc_pl_014.level, crate::diagnostics::DiagnosticLevel::Info);
+        assert!(cc_pl_014.message.contains("author, homepage, keywords"));
+    }
+
+    #[test]
+    fn test_cc_pl_014_marketplace_json_signals_publishing() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0"}"#,
+        );
+        fs::write(
+            temp.path().join(".claude-plugin").join("marketplace.json"),
+            r#"{"name":"market","owner":{"name":"me"},"plugins":[]}"#,
+        )
+        .unwrap();
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        let cc_pl_014 = diagnostics
+            .iter()
+            .find(|d| d.rule == "CC-PL-014")
+            .expect("CC-PL-014 should be reported");
+        assert!(
+            cc_pl_014
+                .message
+                .contains("author, homepage, repository, license, keywords")
+        );
+    }
+
+    #[test]
+    fn test_cc_pl_014_unpublished_plugin_no_info() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0"}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        assert!(!diagnostics.iter().any(|d| d.rule == "CC-PL-014"));
+    }
+
+    #[test]
+    fn test_cc_pl_014_complete_metadata_no_info() {
+        let temp = TempDir::new().unwrap();
+        let plugin_path = temp.path().join(".claude-plugin").join("plugin.json");
+        write_plugin(
+            &plugin_path,
+            r#"{"name":"test","description":"desc","version":"1.0.0","author":{"name":"Me"},"homepage":"https://example.com","repository":"https://github.com/owner/plugin","license":"MIT","keywords":["git"]}"#,
+        );
+
+        let validator = PluginValidator;
+        let diagnostics = validator.validate(
+            &plugin_path,
+            &fs::read_to_string(&plugin_path).unwrap(),
+            &LintConfig::default(),
+        );
+
+        assert!(!diagnostics.iter().any(|d| {
+            matches!(
+                d.rule.as_str(),
+                "CC-PL-009" | "CC-PL-010" | "CC-PL-011" | "CC-PL-012" | "CC-PL-013" | "CC-PL-014"
+            )
+        }));
+    }
 }
@@ -5,14 +5,18 @@
 //! - PE-002: Chain-of-thought phrases in simple tasks
 //! - PE-003: Weak language (should/try/consider) in critical sections
 //! - PE-004: Ambiguous terms (usually/sometimes/if possible)
+//! - PE-009: Mixed addressing modes (imperative/you vs "I" vs "the assistant")
+//!
+//! These are heuristics: PE-001/PE-002/PE-009 report `Confidence::Low`,
+//! PE-003/PE-004 report `Confidence::Medium`.
 
 use crate::{
     config::LintConfig,
-    diagnostics::Diagnostic,
+    diagnostics::{Confidence, Diagnostic},
     rules::Validator,
     schemas::prompt::{
         find_ambiguous_instructions, find_cot_on_simple_tasks, find_critical_in_middle_pe,
-        find_weak_imperative_language,
+        find_mixed_addressing, find_weak_imperative_language,
     },
 };
 use rust_i18n::t;
@@ -40,7 +44,8 @@ impl Validator for PromptValidator {
                             percent = format!("{:.0}", issue.position_percent)
                         ),
                     )
-                    .with_suggestion(t!("rules.pe_001.suggestion")),
+                    .with_suggestion(t!("rules.pe_001.suggestion"))
+                    .with_confidence(Confidence::Low),
                 );
             }
         }
@@ -61,7 +66,8 @@ impl Validator for PromptValidator {
                             task = issue.task_indicator.as_str()
                         ),
                     )
-                    .with_suggestion(t!("rules.pe_002.suggestion")),
+                    .with_suggestion(t!("rules.pe_002.suggestion"))
+                    .with_confidence(Confidence::Low),
                 );
             }
         }
@@ -82,7 +88,8 @@ impl Validator for PromptValidator {
                             section = issue.section_name.as_str()
                         ),
                     )
-                    .with_suggestion(t!("rules.pe_003.suggestion")),
+                    .with_suggestion(t!("rules.pe_003.suggestion"))
+                    .with_confidence(Confidence::Medium),
                 );
             }
         }
@@ -99,15 +106,61 @@ impl Validator for PromptValidator {
                         "PE-004",
                         t!("rules.pe_004.message", term = issue.term.as_str()),
                     )
-                    .with_suggestion(t!("rules.pe_004.suggestion")),
+                    .with_suggestion(t!("rules.pe_004.suggestion"))
+                    .with_confidence(Confidence::Medium),
                 );
             }
         }
 
+        // PE-009: Inconsistent addressing mode
+        if config.is_rule_enabled("PE-009") {
+            if let Some(mixed) = find_mixed_addressing(content) {
+                let counts = mixed
+                    .counts
+                    .iter()
+                    .map(|(mode, count)| format!("{} {}", count, mode.as_str()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let examples = mixed
+                    .examples
+                    .iter()
+                    .map(|e| format!("line {}: \"{}\"", e.line, truncate_example(&e.text)))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                if let Some(first) = mixed.examples.first() {
+                    diagnostics.push(
+                        Diagnostic::info(
+                            path.to_path_buf(),
+                            first.line,
+                            first.column,
+                            "PE-009",
+                            t!(
+                                "rules.pe_009.message",
+                                counts = counts.as_str(),
+                                examples = examples.as_str()
+                            ),
+                        )
+                        .with_suggestion(t!("rules.pe_009.suggestion"))
+                        .with_confidence(Confidence::Low),
+                    );
+                }
+            }
+        }
+
         diagnostics
     }
 }
 
+/// Shorten an example sentence for the PE-009 message
+fn truncate_example(text: &str) -> String {
+    const MAX_CHARS: usize = 60;
+    if text.chars().count() <= MAX_CHARS {
+        return text.to_string();
+    }
+    let truncated: String = text.chars().take(MAX_CHARS).collect();
+    format!("{}...", truncated.trim_end())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,6 +169,21 @@ mod tests {
 
     // ===== PE-001: Critical Content in Middle =====
 
+    #[test]
+    fn test_pe_diagnostics_report_heuristic_confidence() {
+        let mut lines: Vec<String> = (0..20).map(|i| format!("Line {}", i)).collect();
+        lines[10] = "This is critical information.".to_string();
+        let content = lines.join("\n");
+
+        let validator = PromptValidator;
+        let diagnostics =
+            validator.validate(Path::new("SKILL.md"), &content, &LintConfig::default());
+
+        let pe_001: Vec<_> = diagnostics.iter().filter(|d| d.rule == "PE-001").collect();
+        assert_eq!(pe_001.len(), 1);
+        assert_eq!(pe_001[0].confidence, Confidence::Low);
+    }
+
     #[test]
     fn test_pe_001_critical_in_middle() {
         // Create 20 lines with "critical" at line 10 (50%)
@@ -651,7 +719,7 @@ This is not a critical section.
 
     #[test]
     fn test_all_pe_rules_can_be_disabled() {
-        let rules = ["PE-001", "PE-002", "PE-003", "PE-004"];
+        let rules = ["PE-001", "PE-002", "PE-003", "PE-004", "PE-009"];
 
         for rule in rules {
             let mut config = LintConfig::default();
@@ -662,6 +730,7 @@ This is not a critical section.
             lines[10] = "This is critical information.".to_string();
             lines[1] = "# Critical Rules".to_string();
             lines[2] = "You should step by step read the file. Usually do it.".to_string();
+            lines[3] = "The assistant must never push to main.".to_string();
             let content = lines.join("\n");
 
             let validator = PromptValidator;
@@ -674,4 +743,45 @@ This is not a critical section.
             );
         }
     }
+
+    // ===== PE-009: Inconsistent Addressing Mode =====
+
+    #[test]
+    fn test_pe_009_mixed_voice_flagged() {
+        let content = "# Rules\n\nRun tests before committing.\nYou must keep diffs small.\nThe assistant should never push to main.\nI will explain every change.\n";
+        let diagnostics =
+            PromptValidator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+
+        let pe_009: Vec<_> = diagnostics.iter().filter(|d| d.rule == "PE-009").collect();
+        assert_eq!(pe_009.len(), 1);
+        assert_eq!(pe_009[0].level, DiagnosticLevel::Info);
+        assert_eq!(pe_009[0].confidence, Confidence::Low);
+        assert_eq!((pe_009[0].line, pe_009[0].column), (5, 1));
+        assert!(pe_009[0].message.contains("1 imperative"));
+        assert!(pe_009[0].message.contains("1 third-person"));
+        assert!(
+            pe_009[0]
+                .message
+                .contains("line 6: \"I will explain every change.\"")
+        );
+    }
+
+    #[test]
+    fn test_pe_009_consistent_voice_ok() {
+        let content = "# Rules\n\nRun tests before committing.\nYou must keep diffs small.\nNever push to main.\n";
+        let diagnostics =
+            PromptValidator.validate(Path::new("CLAUDE.md"), content, &LintConfig::default());
+        assert!(!diagnostics.iter().any(|d| d.rule == "PE-009"));
+    }
+
+    #[test]
+    fn test_pe_009_long_example_truncated() {
+        let long = format!("The assistant must {}.", "always double check ".repeat(10));
+        let content = format!("Run tests.\nYou must lint.\n{}\n", long);
+        let diagnostics =
+            PromptValidator.validate(Path::new("CLAUDE.md"), &content, &LintConfig::default());
+        let pe_009 = diagnostics.iter().find(|d| d.rule == "PE-009").unwrap();
+        assert!(pe_009.message.contains("...\""));
+        assert!(!pe_009.message.contains(&long));
+    }
 }
@@ -1,7 +1,7 @@
 use crate::fs::FileSystem;
 use crate::parsers::frontmatter::FrontmatterParts;
 use std::collections::HashSet;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use super::{
     PathMatch, SkillFrontmatter, reference_path_regex, windows_path_regex, windows_path_token_regex,
@@ -151,6 +151,23 @@ pub(super) fn trim_path_token_with_offset(token: &str) -> Option<(String, usize)
     Some((trimmed.to_string(), offset))
 }
 
+/// Split an `allowed-tools` value into tool entries.
+///
+/// Supports both formats:
+/// - Comma-separated: "Bash(git:*), Read, Grep" (preferred)
+/// - Space-separated: "Read Write Grep" (legacy)
+pub(crate) fn split_allowed_tools(tools: &str) -> Vec<String> {
+    if tools.contains(',') {
+        tools
+            .split(',')
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect()
+    } else {
+        tools.split_whitespace().map(|t| t.to_string()).collect()
+    }
+}
+
 pub(super) fn compute_line_starts(content: &str) -> Vec<usize> {
     let mut starts = vec![0];
     for (idx, ch) in content.char_indices() {
@@ -394,3 +411,25 @@ pub(super) fn directory_size_until(path: &Path, max_bytes: u64, fs: &dyn FileSys
     }
     total
 }
+
+/// Returns the deepest directory nesting under `root` along with the path of
+/// that directory. `root` itself is depth 0. Symlinks are not followed.
+pub(super) fn max_directory_depth(root: &Path, fs: &dyn FileSystem) -> (usize, PathBuf) {
+    let mut deepest = (0, root.to_path_buf());
+    let mut stack = vec![(root.to_path_buf(), 0usize)];
+    while let Some((current, depth)) = stack.pop() {
+        if depth > deepest.0 {
+            deepest = (depth, current.clone());
+        }
+        let entries = match fs.read_dir(&current) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+        for entry in entries {
+            if entry.metadata.is_dir && !entry.metadata.is_symlink {
+                stack.push((entry.path, depth + 1));
+            }
+        }
+    }
+    deepest
+}
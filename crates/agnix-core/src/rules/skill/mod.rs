@@ -2,8 +2,8 @@
 
 use crate::{
     config::LintConfig,
-    diagnostics::{Diagnostic, Fix},
-    parsers::frontmatter::{FrontmatterParts, split_frontmatter},
+    diagnostics::{Confidence, Diagnostic, Fix},
+    parsers::frontmatter::{FrontmatterParts, find_additional_frontmatter, split_frontmatter},
     regex_util::static_regex,
     rules::Validator,
     schemas::hooks::HooksSchema,
@@ -17,6 +17,7 @@ use std::path::Path;
 use std::sync::OnceLock;
 
 mod helpers;
+pub(crate) use helpers::split_allowed_tools;
 use helpers::*;
 
 #[derive(Debug, Default, Deserialize)]
@@ -47,6 +48,7 @@ struct PathMatch {
 
 static_regex!(fn name_format_regex, r"^[a-z0-9]+(-[a-z0-9]+)*$");
 static_regex!(fn consecutive_hyphen_regex, r"-{2,}");
+static_regex!(fn metadata_key_regex, r"^[a-z0-9]+([-_][a-z0-9]+)*$");
 static_regex!(fn description_xml_regex, r"<[^>]+>");
 static_regex!(fn reference_path_regex, "(?i)\\b(?:references?|refs)[/\\\\][^\\s)\\]}>\"']+");
 static_regex!(fn windows_path_regex, r"(?i)\b(?:[a-z]:)?[a-z0-9._-]+(?:\\[a-z0-9._-]+)+\b");
@@ -55,7 +57,7 @@ static_regex!(fn plain_bash_regex, r"\bBash\b");
 static_regex!(fn imperative_verb_regex, r"(?i)\b(run|execute|create|build|deploy|install|configure|update|delete|remove|add|write|read|check|test|validate|ensure|make|use|call|invoke|start|stop|send|fetch|generate|implement|fix|analyze|review|search|find|move|copy|replace|push|pull|commit|clean|format|lint|parse|process|handle|prepare|download|upload|export|import|open|save|load|connect|verify|apply|enable|disable)\b");
 
 /// Valid model values for CC-SK-001
-const VALID_MODELS: &[&str] = &["sonnet", "opus", "haiku", "inherit"];
+pub(crate) const VALID_MODELS: &[&str] = &["sonnet", "opus", "haiku", "inherit"];
 
 /// Built-in agent types for CC-SK-005
 const BUILTIN_AGENTS: &[&str] = &["Explore", "Plan", "general-purpose"];
@@ -76,9 +78,18 @@ const KNOWN_TOOLS: &[&str] = &[
     "MultiTool",
 ];
 
+/// Prefix of MCP tool names in `allowed-tools` (`mcp__<server>__<tool>`)
+const MCP_TOOL_PREFIX: &str = "mcp__";
+
 /// Maximum dynamic injections for CC-SK-009
 const MAX_INJECTIONS: usize = 3;
 
+/// Maximum characters in a single metadata value for AS-027
+const MAX_METADATA_VALUE_LEN: usize = 1024;
+
+/// Maximum characters across all metadata keys and values for AS-027
+const MAX_METADATA_TOTAL_LEN: usize = 4096;
+
 /// Convert a name to kebab-case format.
 /// - Lowercase the name
 /// - Replace underscores with hyphens
@@ -86,7 +97,7 @@ const MAX_INJECTIONS: usize = 3;
 /// - Collapse consecutive hyphens
 /// - Trim leading/trailing hyphens
 /// - Truncate to 64 characters
-fn convert_to_kebab_case(name: &str) -> String {
+pub(crate) fn convert_to_kebab_case(name: &str) -> String {
     let mut kebab = String::with_capacity(name.len());
     let mut last_was_hyphen = true; // Use to trim leading hyphens and collapse consecutive ones
 
@@ -117,6 +128,20 @@ fn convert_to_kebab_case(name: &str) -> String {
     kebab
 }
 
+/// Whether `name` is 1-64 characters of lowercase letters, digits, and
+/// single hyphens (AS-004, CC-AG-016).
+pub(crate) fn is_valid_name_format(name: &str) -> bool {
+    name.len() <= 64 && name_format_regex().is_match(name)
+}
+
+/// Server segment of an MCP tool entry (`mcp__<server>__<tool>` or
+/// `mcp__<server>`), or `None` when `tool` is not one.
+pub(crate) fn mcp_tool_server(tool: &str) -> Option<&str> {
+    let rest = tool.strip_prefix(MCP_TOOL_PREFIX)?;
+    let server = rest.split("__").next().unwrap_or(rest);
+    (!server.is_empty()).then_some(server)
+}
+
 /// Find byte positions of plain "Bash" (not scoped like "Bash(...)") in content
 /// Returns Vec of (start_byte, end_byte) for each occurrence
 fn find_plain_bash_positions(content: &str, search_start: usize) -> Vec<(usize, usize)> {
@@ -233,10 +258,19 @@ impl<'a> ValidationContext<'a> {
                 }
                 Err(e) => {
                     if self.config.is_rule_enabled("AS-016") {
+                        // Point at the offending spot; serde_yaml's index is
+                        // relative to the frontmatter block
+                        let (line, col) = match e.location() {
+                            Some(location) => self.line_col_at(
+                                (self.parts.frontmatter_start + location.index())
+                                    .min(self.parts.body_start),
+                            ),
+                            None => (frontmatter_line, frontmatter_col),
+                        };
                         self.diagnostics.push(Diagnostic::error(
                             self.path.to_path_buf(),
-                            frontmatter_line,
-                            frontmatter_col,
+                            line,
+                            col,
                             "AS-016",
                             t!("rules.as_016.message", error = e.to_string()),
                         ));
@@ -287,8 +321,7 @@ impl<'a> ValidationContext<'a> {
 
         // AS-004: Invalid name format
         if self.config.is_rule_enabled("AS-004") {
-            let name_re = name_format_regex();
-            if name_trimmed.len() > 64 || !name_re.is_match(name_trimmed) {
+            if !is_valid_name_format(name_trimmed) {
                 let fixed_name = convert_to_kebab_case(name_trimmed);
                 let mut diagnostic = Diagnostic::error(
                     self.path.to_path_buf(),
@@ -298,10 +331,15 @@ impl<'a> ValidationContext<'a> {
                     t!("rules.as_004.message", name = name_trimmed),
                 )
                 .with_suggestion(t!("rules.as_004.suggestion"));
+                let value_range = self.frontmatter_value_byte_range("name");
+                if let Some((_, end)) = value_range {
+                    let (end_line, end_col) = self.line_col_at(end);
+                    diagnostic = diagnostic.with_span(end_line, end_col);
+                }
 
                 // Add auto-fix if we can find the byte range and the fixed name is valid
-                if !fixed_name.is_empty() && name_re.is_match(&fixed_name) {
-                    if let Some((start, end)) = self.frontmatter_value_byte_range("name") {
+                if is_valid_name_format(&fixed_name) {
+                    if let Some((start, end)) = value_range {
                         // Determine if fix is safe: only case changes are safe
                         let has_structural_changes = name_trimmed.contains('_')
                             || name_trimmed.contains(' ')
@@ -431,6 +469,24 @@ impl<'a> ValidationContext<'a> {
             }
         }
 
+        // AS-026: Description valid but verbose (AS-008 owns the hard limit)
+        if self.config.is_rule_enabled("AS-026") {
+            let len = description_trimmed.len();
+            let max = self.config.rules.description_soft_limit;
+            if len > max && len <= 1024 {
+                self.diagnostics.push(
+                    Diagnostic::info(
+                        self.path.to_path_buf(),
+                        description_line,
+                        description_col,
+                        "AS-026",
+                        t!("rules.as_026.message", len = len, max = max),
+                    )
+                    .with_suggestion(t!("rules.as_026.suggestion")),
+                );
+            }
+        }
+
         // AS-009: Description contains XML tags
         if self.config.is_rule_enabled("AS-009") && description_xml_regex().is_match(description) {
             self.diagnostics.push(
@@ -459,14 +515,13 @@ impl<'a> ValidationContext<'a> {
                 .with_suggestion(t!("rules.as_010.suggestion"));
 
                 // Add auto-fix: prepend "Use when user wants to " to description
-                if let Some((start, end)) = self.frontmatter_value_byte_range("description") {
-                    let new_description = format!("Use when user wants to {}", description_trimmed);
+                if let Some((start, _)) = self.frontmatter_value_byte_range("description") {
+                    const TRIGGER_PREFIX: &str = "Use when user wants to ";
                     // Check if the new description would exceed length limit
-                    if new_description.len() <= 1024 {
-                        let fix = Fix::replace(
+                    if TRIGGER_PREFIX.len() + description_trimmed.len() <= 1024 {
+                        let fix = Fix::insert(
                             start,
-                            end,
-                            &new_description,
+                            TRIGGER_PREFIX,
                             t!("rules.as_010.fix"),
                             false, // Not safe - changes semantics
                         );
@@ -501,6 +556,61 @@ impl<'a> ValidationContext<'a> {
         }
     }
 
+    /// AS-027: Validate metadata key format and value sizes
+    fn validate_metadata(&mut self, frontmatter: &SkillFrontmatter) {
+        if !self.config.is_rule_enabled("AS-027") {
+            return;
+        }
+        let Some(metadata) = frontmatter.metadata.as_ref() else {
+            return;
+        };
+        let (metadata_line, metadata_col) = self.frontmatter_key_line_col("metadata");
+
+        // HashMap order is random; sort so diagnostics are stable
+        let mut entries: Vec<_> = metadata.iter().collect();
+        entries.sort();
+
+        let mut messages = Vec::new();
+        for (key, value) in &entries {
+            if !metadata_key_regex().is_match(key) {
+                messages.push(t!("rules.as_027.message", key = key.as_str()));
+            }
+            let len = value.chars().count();
+            if len > MAX_METADATA_VALUE_LEN {
+                messages.push(t!(
+                    "rules.as_027.message_value",
+                    key = key.as_str(),
+                    len = len,
+                    max = MAX_METADATA_VALUE_LEN
+                ));
+            }
+        }
+        let total: usize = entries
+            .iter()
+            .map(|(key, value)| key.chars().count() + value.chars().count())
+            .sum();
+        if total > MAX_METADATA_TOTAL_LEN {
+            messages.push(t!(
+                "rules.as_027.message_total",
+                len = total,
+                max = MAX_METADATA_TOTAL_LEN
+            ));
+        }
+
+        for message in messages {
+            self.diagnostics.push(
+                Diagnostic::warning(
+                    self.path.to_path_buf(),
+                    metadata_line,
+                    metadata_col,
+                    "AS-027",
+                    message,
+                )
+                .with_suggestion(t!("rules.as_027.suggestion")),
+            );
+        }
+    }
+
     /// CC-SK-001, CC-SK-002, CC-SK-003, CC-SK-004: Model and context validation
     fn validate_cc_model_context(&mut self, schema: &SkillSchema) {
         let (model_line, model_col) = self.frontmatter_key_line_col("model");
@@ -666,22 +776,8 @@ impl<'a> ValidationContext<'a> {
             self.frontmatter_key_line_col("allowed-tools");
 
         // Parse allowed_tools once for CC-SK-007 and CC-SK-008
-        // Supports both formats:
-        // - Comma-separated: "Bash(git:*), Read, Grep" (preferred)
-        // - Space-separated: "Read Write Grep" (legacy)
-        let tool_list: Option<Vec<String>> = schema.allowed_tools.as_ref().map(|tools| {
-            if tools.contains(',') {
-                // Comma-separated format
-                tools
-                    .split(',')
-                    .map(|t| t.trim().to_string())
-                    .filter(|t| !t.is_empty())
-                    .collect()
-            } else {
-                // Space-separated format (legacy)
-                tools.split_whitespace().map(|t| t.to_string()).collect()
-            }
-        });
+        let tool_list: Option<Vec<String>> =
+            schema.allowed_tools.as_deref().map(split_allowed_tools);
 
         // CC-SK-007: Unrestricted Bash warning
         if self.config.is_rule_enabled("CC-SK-007") {
@@ -735,7 +831,9 @@ impl<'a> ValidationContext<'a> {
 
                 for tool in tools {
                     let base_name = tool.split('(').next().unwrap_or(tool);
-                    if !KNOWN_TOOLS.contains(&base_name) {
+                    // MCP tools are checked against the project's MCP servers (CC-SK-016)
+                    if !KNOWN_TOOLS.contains(&base_name) && !base_name.starts_with(MCP_TOOL_PREFIX)
+                    {
                         self.diagnostics.push(
                             Diagnostic::error(
                                 self.path.to_path_buf(),
@@ -1091,6 +1189,30 @@ impl<'a> ValidationContext<'a> {
         }
     }
 
+    /// AS-025: Flag extra `---` YAML sections after the leading frontmatter.
+    ///
+    /// Only the leading block is read, so fields in later sections are
+    /// silently ignored by both agnix and the tools loading the skill.
+    fn validate_additional_frontmatter(&mut self) {
+        if !self.config.is_rule_enabled("AS-025") {
+            return;
+        }
+        for section in find_additional_frontmatter(&self.parts) {
+            let (line, col) = self.line_col_at(section.start);
+            self.diagnostics.push(
+                Diagnostic::warning(
+                    self.path.to_path_buf(),
+                    line,
+                    col,
+                    "AS-025",
+                    t!("rules.as_025.message", line = line),
+                )
+                .with_suggestion(t!("rules.as_025.suggestion"))
+                .with_confidence(Confidence::Medium),
+            );
+        }
+    }
+
     /// AS-012, AS-013, AS-014: Validate body content
     fn validate_body_rules(&mut self) {
         let body_raw = if self.parts.body_start <= self.content.len() {
@@ -1100,17 +1222,18 @@ impl<'a> ValidationContext<'a> {
         };
         let (body_line, body_col) = self.line_col_at(self.parts.body_start);
 
-        // AS-012: Content exceeds 500 lines
+        // AS-012: Content exceeds rules.skill_max_lines (default 500)
         if self.config.is_rule_enabled("AS-012") {
             let line_count = body_raw.lines().count();
-            if line_count > 500 {
+            let max = self.config.rules.skill_max_lines;
+            if line_count > max {
                 self.diagnostics.push(
                     Diagnostic::warning(
                         self.path.to_path_buf(),
                         body_line,
                         body_col,
                         "AS-012",
-                        t!("rules.as_012.message", count = line_count),
+                        t!("rules.as_012.message", count = line_count, max = max),
                     )
                     .with_suggestion(t!("rules.as_012.suggestion")),
                 );
@@ -1122,7 +1245,9 @@ impl<'a> ValidationContext<'a> {
             let paths = extract_reference_paths(body_raw);
             for ref_path in paths {
                 if reference_path_too_deep(&ref_path.path) {
-                    let (line, col) = self.line_col_at(self.parts.body_start + ref_path.start);
+                    let abs_start = self.parts.body_start + ref_path.start;
+                    let (line, col) = self.line_col_at(abs_start);
+                    let (end_line, end_col) = self.line_col_at(abs_start + ref_path.path.len());
                     self.diagnostics.push(
                         Diagnostic::error(
                             self.path.to_path_buf(),
@@ -1131,7 +1256,8 @@ impl<'a> ValidationContext<'a> {
                             "AS-013",
                             t!("rules.as_013.message", path = ref_path.path.as_str()),
                         )
-                        .with_suggestion(t!("rules.as_013.suggestion")),
+                        .with_suggestion(t!("rules.as_013.suggestion"))
+                        .with_span(end_line, end_col),
                     );
                 }
             }
@@ -1141,7 +1267,10 @@ impl<'a> ValidationContext<'a> {
         if self.config.is_rule_enabled("AS-014") {
             let paths = extract_windows_paths(body_raw);
             for win_path in paths {
-                let (line, col) = self.line_col_at(self.parts.body_start + win_path.start);
+                let abs_start = self.parts.body_start + win_path.start;
+                let abs_end = abs_start + win_path.path.len();
+                let (line, col) = self.line_col_at(abs_start);
+                let (end_line, end_col) = self.line_col_at(abs_end);
                 let mut diagnostic = Diagnostic::error(
                     self.path.to_path_buf(),
                     line,
@@ -1149,12 +1278,11 @@ impl<'a> ValidationContext<'a> {
                     "AS-014",
                     t!("rules.as_014.message", path = win_path.path.as_str()),
                 )
-                .with_suggestion(t!("rules.as_014.suggestion"));
+                .with_suggestion(t!("rules.as_014.suggestion"))
+                .with_span(end_line, end_col);
 
                 // Safe auto-fix: normalize path separators in-place.
                 let replacement = win_path.path.replace('\\', "/");
-                let abs_start = self.parts.body_start + win_path.start;
-                let abs_end = abs_start + win_path.path.len();
                 if replacement != win_path.path
                     && abs_end <= self.content.len()
                     && self.content.is_char_boundary(abs_start)
@@ -1197,6 +1325,107 @@ impl<'a> ValidationContext<'a> {
             }
         }
     }
+
+    /// AS-024: Validate nesting depth of the on-disk references/ tree
+    fn validate_reference_tree(&mut self) {
+        if !self.config.is_rule_enabled("AS-024") {
+            return;
+        }
+        let Some(dir) = self.path.parent() else {
+            return;
+        };
+        let fs = self.config.fs();
+        let references_dir = dir.join("references");
+        if !fs.is_dir(&references_dir) {
+            return;
+        }
+
+        let max_depth = self.config.rules.max_reference_tree_depth;
+        let (depth, deepest) = max_directory_depth(&references_dir, fs.as_ref());
+        if depth > max_depth {
+            let (frontmatter_line, frontmatter_col) =
+                self.line_col_at(self.parts.frontmatter_start);
+            let relative = deepest
+                .strip_prefix(dir)
+                .unwrap_or(&deepest)
+                .to_string_lossy()
+                .replace('\\', "/");
+            self.diagnostics.push(
+                Diagnostic::info(
+                    self.path.to_path_buf(),
+                    frontmatter_line,
+                    frontmatter_col,
+                    "AS-024",
+                    t!(
+                        "rules.as_024.message",
+                        depth = depth,
+                        max = max_depth,
+                        path = relative
+                    ),
+                )
+                .with_suggestion(t!("rules.as_024.suggestion", max = max_depth)),
+            );
+        }
+    }
+}
+
+/// An MCP tool entry in a skill's `allowed-tools`, checked by the project-level
+/// CC-SK-016 pass
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct McpToolReference {
+    /// Tool entry as written, e.g. `mcp__github__create_issue`
+    pub tool: String,
+    /// Server segment of the entry, e.g. `github`
+    pub server: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// MCP tool entries (`mcp__<server>__<tool>` or `mcp__<server>`) in the
+/// `allowed-tools` frontmatter of a SKILL.md (CC-SK-016).
+pub(crate) fn extract_mcp_tool_references(content: &str) -> Vec<McpToolReference> {
+    let parts = split_frontmatter(content);
+    if !parts.has_frontmatter || !parts.has_closing {
+        return Vec::new();
+    }
+    let Some(allowed_tools) = parse_frontmatter_fields(&parts.frontmatter)
+        .ok()
+        .and_then(|frontmatter| frontmatter.allowed_tools)
+    else {
+        return Vec::new();
+    };
+
+    let line_starts = compute_line_starts(content);
+    let key_offset = frontmatter_key_offset(&parts.frontmatter, "allowed-tools")
+        .map(|offset| parts.frontmatter_start + offset)
+        .unwrap_or(parts.frontmatter_start);
+    let frontmatter_end = parts.frontmatter_start + parts.frontmatter.len();
+
+    let mut references = Vec::new();
+    let mut search_from = key_offset;
+    for tool in split_allowed_tools(&allowed_tools) {
+        let Some(server) = mcp_tool_server(&tool).map(str::to_string) else {
+            continue;
+        };
+
+        // Point at the entry itself, falling back to the allowed-tools key
+        let offset = content
+            .get(search_from..frontmatter_end)
+            .and_then(|frontmatter| frontmatter.find(tool.as_str()))
+            .map(|local| search_from + local);
+        if let Some(offset) = offset {
+            search_from = offset + tool.len();
+        }
+        let (line, column) = line_col_at(offset.unwrap_or(key_offset), &line_starts);
+
+        references.push(McpToolReference {
+            server,
+            tool,
+            line,
+            column,
+        });
+    }
+    references
 }
 
 pub struct SkillValidator;
@@ -1236,8 +1465,9 @@ impl Validator for SkillValidator {
             ctx.validate_description_rules(description);
         }
 
-        // Phase 5: Compatibility validation (AS-011)
+        // Phase 5: Compatibility and metadata validation (AS-011, AS-027)
         ctx.validate_compatibility(&frontmatter);
+        ctx.validate_metadata(&frontmatter);
 
         // Phase 6: CC-SK-010 (hooks in frontmatter)
         ctx.validate_cc_hooks();
@@ -1289,11 +1519,13 @@ impl Validator for SkillValidator {
             }
         }
 
-        // Phase 14: Body validation (AS-012, AS-013, AS-014)
+        // Phase 14: Body validation (AS-012, AS-013, AS-014, AS-025)
         ctx.validate_body_rules();
+        ctx.validate_additional_frontmatter();
 
-        // Phase 15: Directory validation (AS-015)
+        // Phase 15: Directory validation (AS-015, AS-024)
         ctx.validate_directory();
+        ctx.validate_reference_tree();
 
         ctx.diagnostics
     }
@@ -116,6 +116,52 @@ fn test_as_008_description_too_long() {
     assert_eq!(as_008_errors.len(), 1);
 }
 
+#[test]
+fn test_as_026_verbose_description_is_info() {
+    let description = format!("Use when {}", "reviewing pull requests ".repeat(20));
+    let content = format!(
+        "---\nname: test-skill\ndescription: {}\n---\nBody",
+        description
+    );
+
+    let diagnostics =
+        SkillValidator.validate(Path::new("test.md"), &content, &LintConfig::default());
+
+    let as_026: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-026").collect();
+    assert_eq!(as_026.len(), 1);
+    assert_eq!(as_026[0].level, crate::diagnostics::DiagnosticLevel::Info);
+    assert_eq!(as_026[0].line, 3);
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-008"));
+}
+
+#[test]
+fn test_as_026_not_reported_past_hard_limit() {
+    let content = format!(
+        "---\nname: test-skill\ndescription: {}\n---\nBody",
+        "a".repeat(1025)
+    );
+
+    let diagnostics =
+        SkillValidator.validate(Path::new("test.md"), &content, &LintConfig::default());
+
+    assert!(diagnostics.iter().any(|d| d.rule == "AS-008"));
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-026"));
+}
+
+#[test]
+fn test_as_026_respects_soft_limit_config() {
+    let content = "---\nname: test-skill\ndescription: Use when reviewing pull requests for style issues\n---\nBody";
+
+    let diagnostics =
+        SkillValidator.validate(Path::new("test.md"), content, &LintConfig::default());
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-026"));
+
+    let mut config = LintConfig::default();
+    config.rules.description_soft_limit = 20;
+    let diagnostics = SkillValidator.validate(Path::new("test.md"), content, &config);
+    assert!(diagnostics.iter().any(|d| d.rule == "AS-026"));
+}
+
 #[test]
 fn test_as_008_description_empty_string() {
     let content = r#"---
@@ -164,6 +210,55 @@ fn test_as_011_compatibility_too_long() {
     assert_eq!(as_011_errors.len(), 1);
 }
 
+#[test]
+fn test_as_027_valid_metadata() {
+    let content = "---\nname: test-skill\ndescription: Use when validating metadata\nmetadata:\n  author: jane\n  source_repo: github.com/acme/skills\n  min-version: \"2\"\n---\nBody";
+
+    let diagnostics =
+        SkillValidator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-027"));
+}
+
+#[test]
+fn test_as_027_bad_key_and_long_value() {
+    let content = format!(
+        "---\nname: test-skill\ndescription: Use when validating metadata\nmetadata:\n  Author Name: jane\n  notes: {}\n---\nBody",
+        "x".repeat(1025)
+    );
+
+    let diagnostics =
+        SkillValidator.validate(Path::new("test.md"), &content, &LintConfig::default());
+
+    let as_027: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-027").collect();
+    assert_eq!(as_027.len(), 2, "{:?}", as_027);
+    assert!(as_027[0].message.contains("Author Name"));
+    assert!(as_027[1].message.contains("notes"));
+    assert!(
+        as_027
+            .iter()
+            .all(|d| d.line == 4 && d.level == crate::diagnostics::DiagnosticLevel::Warning)
+    );
+}
+
+#[test]
+fn test_as_027_total_size() {
+    let values: String = (0..5)
+        .map(|i| format!("  field-{}: {}\n", i, "y".repeat(900)))
+        .collect();
+    let content = format!(
+        "---\nname: test-skill\ndescription: Use when validating metadata\nmetadata:\n{}---\nBody",
+        values
+    );
+
+    let diagnostics =
+        SkillValidator.validate(Path::new("test.md"), &content, &LintConfig::default());
+
+    let as_027: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-027").collect();
+    assert_eq!(as_027.len(), 1, "{:?}", as_027);
+    assert!(as_027[0].message.contains("4096"));
+}
+
 #[test]
 fn test_as_012_content_too_long() {
     let body = (0..501).map(|_| "line").collect::<Vec<_>>().join("\n");
@@ -206,6 +301,27 @@ See reference/deep/guide.md for details."#;
     assert_eq!(as_013_errors.len(), 1);
 }
 
+#[test]
+fn test_as_013_span_covers_reference_path() {
+    let content = r#"---
+name: deep-reference
+description: Use when validating deep references
+---
+
+See reference/deep/guide.md for details."#;
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("SKILL.md"), content, &LintConfig::default());
+
+    let as_013 = diagnostics.iter().find(|d| d.rule == "AS-013").unwrap();
+    assert_eq!((as_013.line, as_013.column), (6, 5));
+    assert_eq!(as_013.end_line, Some(6));
+    assert_eq!(
+        as_013.end_column,
+        Some(as_013.column + "reference/deep/guide.md".len())
+    );
+}
+
 #[test]
 fn test_as_014_windows_path_separator() {
     let content = include_str!("../../../../../tests/fixtures/skills/windows-path/SKILL.md");
@@ -245,6 +361,156 @@ fn test_as_015_directory_size_exceeds() {
     assert_eq!(as_015_errors.len(), 1);
 }
 
+fn reference_tree_config(dirs: &[&str]) -> LintConfig {
+    use crate::fs::MockFileSystem;
+    use std::sync::Arc;
+
+    let mock_fs = Arc::new(MockFileSystem::new());
+    mock_fs.add_dir("/project/skills/docs-skill");
+    mock_fs.add_file("/project/skills/docs-skill/SKILL.md", "");
+    for dir in dirs {
+        mock_fs.add_dir(format!("/project/skills/docs-skill/{}", dir));
+    }
+
+    let mut config = LintConfig::default();
+    config.set_fs(mock_fs);
+    config
+}
+
+#[test]
+fn test_as_025_additional_frontmatter_section() {
+    let content = "---\nname: test-skill\ndescription: Use when testing\n---\n\nBody\n\n---\nallowed-tools: Bash\n---\n\nMore body\n";
+
+    let diagnostics =
+        SkillValidator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    let as_025: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-025").collect();
+    assert_eq!(as_025.len(), 1);
+    assert_eq!(as_025[0].line, 8);
+    assert_eq!(
+        as_025[0].level,
+        crate::diagnostics::DiagnosticLevel::Warning
+    );
+    // The leading block is authoritative; the second block's fields are not validated
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-SK-007"));
+}
+
+#[test]
+fn test_as_025_horizontal_rules_not_flagged() {
+    let content = "---\nname: test-skill\ndescription: Use when testing\n---\n\nIntro\n\n---\n\nSection two\n\n---\n\nEnd\n";
+
+    let diagnostics =
+        SkillValidator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-025"));
+}
+
+#[test]
+fn test_as_025_disabled() {
+    let content =
+        "---\nname: test-skill\ndescription: Use when testing\n---\n\n---\nmodel: opus\n---\n";
+    let mut config = LintConfig::default();
+    config.rules.disabled_rules = vec!["AS-025".to_string()];
+
+    let diagnostics = SkillValidator.validate(Path::new("test.md"), content, &config);
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-025"));
+}
+
+const REFERENCE_TREE_SKILL: &str =
+    "---\nname: docs-skill\ndescription: Use when reading docs\n---\nBody";
+
+#[test]
+fn test_as_024_deeply_nested_references_tree() {
+    let config = reference_tree_config(&[
+        "references",
+        "references/a",
+        "references/a/b",
+        "references/a/b/c",
+        "references/a/b/c/d",
+    ]);
+
+    let diagnostics = SkillValidator.validate(
+        Path::new("/project/skills/docs-skill/SKILL.md"),
+        REFERENCE_TREE_SKILL,
+        &config,
+    );
+
+    let as_024: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-024").collect();
+    assert_eq!(as_024.len(), 1);
+    assert_eq!(as_024[0].level, crate::diagnostics::DiagnosticLevel::Info);
+    assert!(as_024[0].message.contains("references/a/b/c/d"));
+}
+
+#[test]
+fn test_as_024_shallow_references_tree() {
+    let config = reference_tree_config(&[
+        "references",
+        "references/a",
+        "references/a/b",
+        "references/a/b/c",
+    ]);
+
+    let diagnostics = SkillValidator.validate(
+        Path::new("/project/skills/docs-skill/SKILL.md"),
+        REFERENCE_TREE_SKILL,
+        &config,
+    );
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-024"));
+}
+
+#[test]
+fn test_as_024_no_references_dir() {
+    let config = reference_tree_config(&["assets", "assets/a/b/c/d/e"]);
+
+    let diagnostics = SkillValidator.validate(
+        Path::new("/project/skills/docs-skill/SKILL.md"),
+        REFERENCE_TREE_SKILL,
+        &config,
+    );
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-024"));
+}
+
+#[test]
+fn test_as_024_configurable_depth() {
+    let mut config = reference_tree_config(&["references", "references/a", "references/a/b"]);
+    config.rules.max_reference_tree_depth = 1;
+
+    let diagnostics = SkillValidator.validate(
+        Path::new("/project/skills/docs-skill/SKILL.md"),
+        REFERENCE_TREE_SKILL,
+        &config,
+    );
+
+    let as_024: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-024").collect();
+    assert_eq!(as_024.len(), 1);
+    assert!(as_024[0].message.contains("2 levels"));
+}
+
+#[test]
+fn test_as_024_nested_references_fixture() {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../tests/fixtures/skills/nested-references-tree/SKILL.md");
+    let content = fs::read_to_string(&fixture).unwrap();
+
+    let diagnostics = SkillValidator.validate(&fixture, &content, &LintConfig::default());
+
+    assert_eq!(diagnostics.iter().filter(|d| d.rule == "AS-024").count(), 1);
+}
+
+#[test]
+fn test_as_024_shallow_references_fixture() {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("../../tests/fixtures/skills/shallow-references-tree/SKILL.md");
+    let content = fs::read_to_string(&fixture).unwrap();
+
+    let diagnostics = SkillValidator.validate(&fixture, &content, &LintConfig::default());
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-024"));
+}
+
 #[test]
 fn test_cc_sk_006_dangerous_name_without_safety() {
     let content = r#"---
@@ -1173,6 +1439,45 @@ Body"#;
     assert_eq!(cc_sk_008.len(), 0);
 }
 
+#[test]
+fn test_cc_sk_008_mcp_tools_not_reported() {
+    let content = r#"---
+name: test-skill
+description: Use when testing
+allowed-tools: Read, mcp__github__create_issue, mcp__postgres
+---
+Body"#;
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    assert!(!diagnostics.iter().any(|d| d.rule == "CC-SK-008"));
+}
+
+// ===== CC-SK-016: MCP tool references (extraction; project pass lives in lib.rs) =====
+
+#[test]
+fn test_extract_mcp_tool_references() {
+    let content = "---\nname: test-skill\ndescription: Use when testing\nallowed-tools: Read, mcp__github__create_issue, mcp__postgres, mcp____query\n---\nBody mentions mcp__slack__post\n";
+
+    let references = extract_mcp_tool_references(content);
+    assert_eq!(references.len(), 2);
+
+    assert_eq!(references[0].tool, "mcp__github__create_issue");
+    assert_eq!(references[0].server, "github");
+    assert_eq!((references[0].line, references[0].column), (4, 22));
+
+    assert_eq!(references[1].tool, "mcp__postgres");
+    assert_eq!(references[1].server, "postgres");
+    assert_eq!((references[1].line, references[1].column), (4, 49));
+}
+
+#[test]
+fn test_extract_mcp_tool_references_without_frontmatter() {
+    assert!(extract_mcp_tool_references("allowed-tools: mcp__github__list\n").is_empty());
+    assert!(extract_mcp_tool_references("---\nname: x\ndescription: y\n---\nBody\n").is_empty());
+}
+
 #[test]
 fn test_cc_sk_008_multiple_unknown_tools() {
     let content = r#"---
@@ -1592,6 +1897,22 @@ Body"#;
     assert_eq!(as_004[0].fixes[0].replacement, "test-skill");
 }
 
+#[test]
+fn test_as_004_span_ends_after_name_value() {
+    let content = r#"---
+name: Test_Skill
+description: Use when testing
+---
+Body"#;
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("test.md"), content, &LintConfig::default());
+
+    let as_004 = diagnostics.iter().find(|d| d.rule == "AS-004").unwrap();
+    assert_eq!((as_004.line, as_004.column), (2, 1));
+    assert_eq!((as_004.end_line, as_004.end_column), (Some(2), Some(17)));
+}
+
 #[test]
 fn test_as_004_fix_case_only_is_safe() {
     let content = r#"---
@@ -1726,10 +2047,8 @@ Body"#;
     let as_010: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-010").collect();
     assert_eq!(as_010.len(), 1);
     assert!(as_010[0].has_fixes());
-    assert_eq!(
-        as_010[0].fixes[0].replacement,
-        "Use when user wants to Reviews code for quality"
-    );
+    assert!(as_010[0].fixes[0].is_insertion());
+    assert_eq!(as_010[0].fixes[0].replacement, "Use when user wants to ");
 }
 
 #[test]
@@ -2025,6 +2344,21 @@ Body content"#;
     assert!(as_016.is_empty());
 }
 
+#[test]
+fn test_as_016_points_at_the_bad_yaml_line() {
+    let content =
+        "---\nname: test-skill\ndescription: Use when testing\n  model: : sonnet\n---\nBody";
+
+    let validator = SkillValidator;
+    let diagnostics = validator.validate(Path::new("SKILL.md"), content, &LintConfig::default());
+
+    let as_016 = diagnostics
+        .iter()
+        .find(|d| d.rule == "AS-016")
+        .expect("AS-016 should be reported");
+    assert_eq!((as_016.line, as_016.column), (4, 8));
+}
+
 #[test]
 fn test_as_016_invalid_yaml_colon_in_value() {
     let content = r#"---
@@ -2275,6 +2609,30 @@ fn test_as_012_exactly_500_lines_ok() {
     assert!(!diagnostics.iter().any(|d| d.rule == "AS-012"));
 }
 
+#[test]
+fn test_as_012_respects_skill_max_lines_config() {
+    let body_lines = (0..120)
+        .map(|i| format!("Line {}", i))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let content = format!(
+        "---\nname: test\ndescription: Use when testing line limits\n---\n{}",
+        body_lines
+    );
+
+    let mut config = LintConfig::default();
+    config.rules.skill_max_lines = 100;
+    let diagnostics = SkillValidator.validate(Path::new("test.md"), &content, &config);
+
+    let as_012: Vec<_> = diagnostics.iter().filter(|d| d.rule == "AS-012").collect();
+    assert_eq!(as_012.len(), 1);
+    assert!(as_012[0].message.contains("exceeds 100 lines"));
+
+    config.rules.skill_max_lines = 1000;
+    let diagnostics = SkillValidator.validate(Path::new("test.md"), &content, &config);
+    assert!(!diagnostics.iter().any(|d| d.rule == "AS-012"));
+}
+
 #[test]
 fn test_cc_sk_001_all_valid_models() {
     // Must match VALID_MODELS constant in skill/mod.rs
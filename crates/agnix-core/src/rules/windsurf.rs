@@ -0,0 +1,260 @@
+//! Windsurf rules validation rules (WS-001 to WS-004)
+//!
+//! Validates:
+//! - WS-001: Empty Windsurf rule file (HIGH) - files must have content
+//! - WS-002: Missing frontmatter (MEDIUM) - .windsurf/rules/*.md should declare activation
+//! - WS-003: Invalid YAML frontmatter (HIGH) - frontmatter must be valid YAML
+//! - WS-004: Invalid glob pattern (HIGH) - globs field must contain valid patterns
+//!
+//! Windsurf rule frontmatter has the same layout as Cursor `.mdc` files, so
+//! parsing is shared with the Cursor schema helpers.
+
+use crate::{
+    FileType,
+    config::LintConfig,
+    diagnostics::Diagnostic,
+    rules::Validator,
+    schemas::cursor::{
+        ParsedMdcFrontmatter, is_body_empty, is_content_empty, parse_mdc_frontmatter,
+        validate_glob_pattern,
+    },
+};
+use rust_i18n::t;
+use std::path::Path;
+
+pub struct WindsurfValidator;
+
+/// Find the 1-indexed line number of a YAML field in parsed frontmatter.
+fn find_field_line(parsed: &ParsedMdcFrontmatter, field_prefix: &str) -> usize {
+    parsed
+        .raw
+        .lines()
+        .enumerate()
+        .find(|(_, line)| line.trim_start().starts_with(field_prefix))
+        .map(|(idx, _)| parsed.start_line + 1 + idx)
+        .unwrap_or(parsed.start_line)
+}
+
+impl Validator for WindsurfValidator {
+    fn validate(&self, path: &Path, content: &str, config: &LintConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        let is_legacy = crate::detect_file_type(path) == FileType::WindsurfLegacy;
+
+        // .windsurfrules is plain markdown; only the empty check applies
+        if is_legacy {
+            if config.is_rule_enabled("WS-001") && is_content_empty(content) {
+                diagnostics.push(
+                    Diagnostic::error(
+                        path.to_path_buf(),
+                        1,
+                        0,
+                        "WS-001",
+                        t!("rules.ws_001.message_empty"),
+                    )
+                    .with_suggestion(t!("rules.ws_001.suggestion_empty")),
+                );
+            }
+            return diagnostics;
+        }
+
+        let parsed = match parse_mdc_frontmatter(content) {
+            Some(parsed) => parsed,
+            None => {
+                if is_content_empty(content) {
+                    // WS-001: Empty rule file (ERROR)
+                    if config.is_rule_enabled("WS-001") {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path.to_path_buf(),
+                                1,
+                                0,
+                                "WS-001",
+                                t!("rules.ws_001.message_empty"),
+                            )
+                            .with_suggestion(t!("rules.ws_001.suggestion_empty")),
+                        );
+                    }
+                } else if config.is_rule_enabled("WS-002") {
+                    // WS-002: Missing frontmatter (WARNING)
+                    diagnostics.push(
+                        Diagnostic::warning(
+                            path.to_path_buf(),
+                            1,
+                            0,
+                            "WS-002",
+                            t!("rules.ws_002.message"),
+                        )
+                        .with_suggestion(t!("rules.ws_002.suggestion")),
+                    );
+                }
+                return diagnostics;
+            }
+        };
+
+        // WS-003: Invalid YAML frontmatter (ERROR)
+        if let Some(ref error) = parsed.parse_error {
+            if config.is_rule_enabled("WS-003") {
+                diagnostics.push(
+                    Diagnostic::error(
+                        path.to_path_buf(),
+                        parsed.start_line,
+                        0,
+                        "WS-003",
+                        t!("rules.ws_003.message", error = error.as_str()),
+                    )
+                    .with_suggestion(t!("rules.ws_003.suggestion")),
+                );
+            }
+            // Can't continue validating if YAML is broken
+            return diagnostics;
+        }
+
+        // WS-001: No content after frontmatter (ERROR)
+        if config.is_rule_enabled("WS-001") && is_body_empty(&parsed.body) {
+            diagnostics.push(
+                Diagnostic::error(
+                    path.to_path_buf(),
+                    parsed.end_line + 1,
+                    0,
+                    "WS-001",
+                    t!("rules.ws_001.message_no_content"),
+                )
+                .with_suggestion(t!("rules.ws_001.suggestion_no_content")),
+            );
+        }
+
+        // WS-004: Invalid glob pattern (ERROR)
+        if config.is_rule_enabled("WS-004") {
+            if let Some(globs) = parsed.schema.as_ref().and_then(|s| s.globs.as_ref()) {
+                let globs_line = find_field_line(&parsed, "globs:");
+                for pattern in globs.patterns() {
+                    let validation = validate_glob_pattern(pattern);
+                    if !validation.valid {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                path.to_path_buf(),
+                                globs_line,
+                                0,
+                                "WS-004",
+                                t!(
+                                    "rules.ws_004.message",
+                                    pattern = pattern,
+                                    error = validation.error.unwrap_or_default()
+                                ),
+                            )
+                            .with_suggestion(t!("rules.ws_004.suggestion")),
+                        );
+                    }
+                }
+            }
+        }
+
+        diagnostics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LintConfig;
+    use crate::diagnostics::DiagnosticLevel;
+
+    fn validate_rule(content: &str) -> Vec<Diagnostic> {
+        WindsurfValidator.validate(
+            Path::new(".windsurf/rules/typescript.md"),
+            content,
+            &LintConfig::default(),
+        )
+    }
+
+    fn validate_legacy(content: &str) -> Vec<Diagnostic> {
+        WindsurfValidator.validate(Path::new(".windsurfrules"), content, &LintConfig::default())
+    }
+
+    fn rules_of<'a>(diagnostics: &'a [Diagnostic], rule: &str) -> Vec<&'a Diagnostic> {
+        diagnostics.iter().filter(|d| d.rule == rule).collect()
+    }
+
+    // ===== WS-001: Empty rule file =====
+
+    #[test]
+    fn test_ws_001_empty_rule_file() {
+        let diagnostics = validate_rule("");
+        let ws_001 = rules_of(&diagnostics, "WS-001");
+        assert_eq!(ws_001.len(), 1);
+        assert_eq!(ws_001[0].level, DiagnosticLevel::Error);
+    }
+
+    #[test]
+    fn test_ws_001_frontmatter_without_body() {
+        let diagnostics = validate_rule("---\ntrigger: always_on\n---\n\n");
+        let ws_001 = rules_of(&diagnostics, "WS-001");
+        assert_eq!(ws_001.len(), 1);
+        assert_eq!(ws_001[0].line, 4);
+    }
+
+    #[test]
+    fn test_ws_001_empty_legacy_file() {
+        let diagnostics = validate_legacy("   \n\n");
+        assert_eq!(rules_of(&diagnostics, "WS-001").len(), 1);
+    }
+
+    #[test]
+    fn test_legacy_file_without_frontmatter_is_valid() {
+        let diagnostics = validate_legacy("# Project rules\n\nUse tabs.\n");
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    }
+
+    // ===== WS-002: Missing frontmatter =====
+
+    #[test]
+    fn test_ws_002_missing_frontmatter() {
+        let diagnostics = validate_rule("# TypeScript\n\nPrefer strict mode.\n");
+        let ws_002 = rules_of(&diagnostics, "WS-002");
+        assert_eq!(ws_002.len(), 1);
+        assert_eq!(ws_002[0].level, DiagnosticLevel::Warning);
+    }
+
+    // ===== WS-003: Invalid YAML =====
+
+    #[test]
+    fn test_ws_003_invalid_yaml() {
+        let diagnostics = validate_rule("---\ntrigger: [unclosed\n---\n# Rule\n");
+        assert_eq!(rules_of(&diagnostics, "WS-003").len(), 1);
+    }
+
+    #[test]
+    fn test_ws_003_missing_closing_marker() {
+        let diagnostics = validate_rule("---\ntrigger: glob\n# Rule\n");
+        assert_eq!(rules_of(&diagnostics, "WS-003").len(), 1);
+        assert!(rules_of(&diagnostics, "WS-001").is_empty());
+    }
+
+    // ===== WS-004: Invalid glob =====
+
+    #[test]
+    fn test_ws_004_invalid_glob() {
+        let content = "---\ntrigger: glob\nglobs: \"src/[unclosed\"\n---\n# Rule\n";
+        let diagnostics = validate_rule(content);
+        let ws_004 = rules_of(&diagnostics, "WS-004");
+        assert_eq!(ws_004.len(), 1);
+        assert_eq!(ws_004[0].line, 3);
+    }
+
+    #[test]
+    fn test_valid_rule_file() {
+        let content = "---\ntrigger: glob\ndescription: TypeScript conventions\nglobs: \"**/*.ts\"\n---\n\n# TypeScript\n\nPrefer strict mode.\n";
+        let diagnostics = validate_rule(content);
+        assert!(diagnostics.is_empty(), "{:?}", diagnostics);
+    }
+
+    #[test]
+    fn test_ws_rules_disabled_by_category() {
+        let mut config = LintConfig::default();
+        config.rules.windsurf = false;
+        let diagnostics =
+            WindsurfValidator.validate(Path::new(".windsurf/rules/typescript.md"), "", &config);
+        assert!(diagnostics.is_empty());
+    }
+}
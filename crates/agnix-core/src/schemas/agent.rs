@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Agent .md file frontmatter schema
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -15,11 +17,20 @@ pub struct AgentSchema {
     pub description: Option<String>,
 
     /// Optional: tools list
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_tool_list"
+    )]
     pub tools: Option<Vec<String>>,
 
     /// Optional: disallowed tools
-    #[serde(skip_serializing_if = "Option::is_none", rename = "disallowedTools")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        rename = "disallowedTools",
+        deserialize_with = "deserialize_tool_list"
+    )]
     pub disallowed_tools: Option<Vec<String>>,
 
     /// Optional: model (CC-AG-003)
@@ -43,4 +54,332 @@ pub struct AgentSchema {
     pub hooks: Option<Value>,
 }
 
+/// Read a tool list written either as a YAML sequence or as the
+/// comma-separated string Claude Code documents (`tools: Read, Grep, Glob`).
+fn deserialize_tool_list<'de, D>(deserializer: D) -> Result<Option<Vec<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum ToolList {
+        List(Vec<String>),
+        Csv(String),
+    }
+
+    Ok(match Option::<ToolList>::deserialize(deserializer)? {
+        Some(ToolList::List(tools)) => Some(tools),
+        Some(ToolList::Csv(tools)) => Some(crate::rules::skill::split_allowed_tools(&tools)),
+        None => None,
+    })
+}
+
 // Validation is performed in rules/agent.rs (AgentValidator)
+
+/// Frontmatter keys listing the agents an agent delegates to (CC-AG-014, CC-AG-015)
+pub const HANDOFF_KEYS: &[&str] = &["handoffs", "delegates_to"];
+
+/// One entry of an agent's `handoffs` / `delegates_to` list
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandoffTarget {
+    /// Target agent name as written
+    pub name: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Read the handoff targets from an agent file's frontmatter.
+///
+/// Accepts a YAML list or a single string under any of `HANDOFF_KEYS`.
+/// Lines point at the list item when it is on its own line, otherwise at the key.
+pub fn parse_handoffs(content: &str) -> Vec<HandoffTarget> {
+    let parts = crate::parsers::frontmatter::split_frontmatter(content);
+    if !parts.has_frontmatter || !parts.has_closing {
+        return Vec::new();
+    }
+    let Ok(serde_yaml::Value::Mapping(map)) =
+        serde_yaml::from_str::<serde_yaml::Value>(&parts.frontmatter)
+    else {
+        return Vec::new();
+    };
+
+    // The frontmatter text begins right after the opening `---`, so its
+    // first (empty) line is the `---` line itself
+    let first_line = content[..parts.frontmatter_start].lines().count();
+    let lines: Vec<&str> = parts.frontmatter.lines().collect();
+
+    let mut targets = Vec::new();
+    for key in HANDOFF_KEYS {
+        let names: Vec<String> = match map.get(*key) {
+            Some(serde_yaml::Value::String(name)) => vec![name.clone()],
+            Some(serde_yaml::Value::Sequence(items)) => items
+                .iter()
+                .filter_map(|item| item.as_str().map(str::to_string))
+                .collect(),
+            _ => continue,
+        };
+
+        let key_idx = lines
+            .iter()
+            .position(|line| line.strip_prefix(*key).is_some_and(|r| r.starts_with(':')))
+            .unwrap_or(0);
+        for name in names {
+            let name = name.trim().to_string();
+            if name.is_empty() {
+                continue;
+            }
+            let (idx, column) = lines
+                .iter()
+                .enumerate()
+                .skip(key_idx + 1)
+                .take_while(|(_, line)| line.starts_with(' ') || line.starts_with('-'))
+                .find_map(|(idx, line)| {
+                    let item = line.trim_start().strip_prefix('-')?.trim();
+                    let unquoted = item.trim_matches(|c: char| c == '"' || c == '\'');
+                    (unquoted == name).then(|| (idx, line.find(&name).unwrap_or(0) + 1))
+                })
+                .unwrap_or((key_idx, 1));
+            targets.push(HandoffTarget {
+                name,
+                line: first_line + idx,
+                column,
+            });
+        }
+    }
+
+    targets
+}
+
+/// A handoff problem found across the project's agent definitions
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HandoffIssue {
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    pub kind: HandoffIssueKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandoffIssueKind {
+    /// CC-AG-014: target does not match any agent in the project
+    UnknownTarget { target: String },
+    /// CC-AG-015: agent hands off to itself
+    SelfHandoff { agent: String },
+    /// CC-AG-015: agents hand off to each other in a loop, e.g. `a -> b -> a`
+    Cycle { chain: String },
+}
+
+/// Check handoff targets and the delegation graph across agent definitions.
+///
+/// `agents` holds each agent file with its content. Agents are matched by
+/// frontmatter `name` or file stem, case-insensitively. Each cycle is
+/// reported once, on the handoff that starts it from its first agent in
+/// `agents` order.
+pub fn check_handoffs(agents: &[(PathBuf, String)]) -> Vec<HandoffIssue> {
+    let mut by_name: HashMap<String, usize> = HashMap::new();
+    for (idx, (path, content)) in agents.iter().enumerate() {
+        for name in crate::schemas::cross_platform::agent_definition_names(path, content) {
+            by_name.entry(name).or_insert(idx);
+        }
+    }
+
+    let mut issues = Vec::new();
+    // edges[i] = (target index, handoff entry) for each resolved handoff of agent i
+    let mut edges: Vec<Vec<(usize, HandoffTarget)>> = vec![Vec::new(); agents.len()];
+    for (idx, (path, content)) in agents.iter().enumerate() {
+        for target in parse_handoffs(content) {
+            match by_name.get(&target.name.to_lowercase()) {
+                Some(&to) if to == idx => issues.push(HandoffIssue {
+                    path: path.clone(),
+                    line: target.line,
+                    column: target.column,
+                    kind: HandoffIssueKind::SelfHandoff {
+                        agent: target.name.clone(),
+                    },
+                }),
+                Some(&to) => edges[idx].push((to, target)),
+                None => issues.push(HandoffIssue {
+                    path: path.clone(),
+                    line: target.line,
+                    column: target.column,
+                    kind: HandoffIssueKind::UnknownTarget {
+                        target: target.name.clone(),
+                    },
+                }),
+            }
+        }
+    }
+
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+    for cycle in find_cycles(&edges) {
+        // Rotate so the cycle starts at its lowest index; dedupes rotations
+        let start = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap_or(0);
+        let cycle: Vec<usize> = cycle[start..]
+            .iter()
+            .chain(&cycle[..start])
+            .copied()
+            .collect();
+        if !seen.insert(cycle.clone()) {
+            continue;
+        }
+
+        let first = cycle[0];
+        let next = cycle.get(1).copied().unwrap_or(first);
+        let Some((_, handoff)) = edges[first].iter().find(|(to, _)| *to == next) else {
+            continue;
+        };
+        let chain = cycle
+            .iter()
+            .chain(std::iter::once(&first))
+            .map(|&i| display_name(&agents[i].0, &agents[i].1))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        issues.push(HandoffIssue {
+            path: agents[first].0.clone(),
+            line: handoff.line,
+            column: handoff.column,
+            kind: HandoffIssueKind::Cycle { chain },
+        });
+    }
+
+    issues
+}
+
+/// Name used for an agent in cycle messages: frontmatter `name`, else file stem
+fn display_name(path: &Path, content: &str) -> String {
+    let names = crate::schemas::cross_platform::agent_definition_names(path, content);
+    names.last().cloned().unwrap_or_default()
+}
+
+/// Cycles in a directed graph, found by depth-first search.
+///
+/// `edges[i]` lists the target node of each edge out of node `i` along with
+/// any per-edge payload. Finds at least one cycle per strongly connected
+/// component; not every elementary cycle is enumerated.
+pub(crate) fn find_cycles<T>(edges: &[Vec<(usize, T)>]) -> Vec<Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        New,
+        OnStack,
+        Done,
+    }
+
+    fn visit<T>(
+        node: usize,
+        edges: &[Vec<(usize, T)>],
+        state: &mut [State],
+        stack: &mut Vec<usize>,
+        cycles: &mut Vec<Vec<usize>>,
+    ) {
+        state[node] = State::OnStack;
+        stack.push(node);
+        for (next, _) in &edges[node] {
+            match state[*next] {
+                State::New => visit(*next, edges, state, stack, cycles),
+                State::OnStack => {
+                    if let Some(pos) = stack.iter().position(|n| n == next) {
+                        cycles.push(stack[pos..].to_vec());
+                    }
+                }
+                State::Done => {}
+            }
+        }
+        stack.pop();
+        state[node] = State::Done;
+    }
+
+    let mut state = vec![State::New; edges.len()];
+    let mut cycles = Vec::new();
+    for node in 0..edges.len() {
+        if state[node] == State::New {
+            visit(node, edges, &mut state, &mut Vec::new(), &mut cycles);
+        }
+    }
+    cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn agent(name: &str, handoffs: &str) -> (PathBuf, String) {
+        (
+            PathBuf::from(format!(".claude/agents/{}.md", name)),
+            format!(
+                "---\nname: {}\ndescription: test agent\n{}---\nBody\n",
+                name, handoffs
+            ),
+        )
+    }
+
+    #[test]
+    fn test_parse_handoffs_list_and_alias() {
+        let content = "---\nname: planner\nhandoffs:\n  - coder\n  - \"reviewer\"\ndelegates_to: tester\n---\n";
+        let targets = parse_handoffs(content);
+        let found: Vec<_> = targets
+            .iter()
+            .map(|t| (t.name.as_str(), t.line, t.column))
+            .collect();
+        assert_eq!(
+            found,
+            vec![("coder", 4, 5), ("reviewer", 5, 6), ("tester", 6, 1)]
+        );
+    }
+
+    #[test]
+    fn test_parse_handoffs_without_frontmatter() {
+        assert!(parse_handoffs("handoffs: [coder]\n").is_empty());
+    }
+
+    #[test]
+    fn test_check_handoffs_valid() {
+        let agents = vec![
+            agent("planner", "handoffs:\n  - coder\n"),
+            agent("coder", ""),
+        ];
+        assert!(check_handoffs(&agents).is_empty());
+    }
+
+    #[test]
+    fn test_check_handoffs_unknown_target() {
+        let agents = vec![agent("planner", "handoffs:\n  - Ghost\n")];
+        let issues = check_handoffs(&agents);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].kind,
+            HandoffIssueKind::UnknownTarget {
+                target: "Ghost".to_string()
+            }
+        );
+        assert_eq!(issues[0].line, 5);
+    }
+
+    #[test]
+    fn test_check_handoffs_self() {
+        let agents = vec![agent("planner", "delegates_to: [planner]\n")];
+        let issues = check_handoffs(&agents);
+        assert_eq!(issues.len(), 1);
+        assert!(matches!(
+            issues[0].kind,
+            HandoffIssueKind::SelfHandoff { .. }
+        ));
+    }
+
+    #[test]
+    fn test_check_handoffs_cycle_reported_once() {
+        let agents = vec![
+            agent("a", "handoffs:\n  - b\n"),
+            agent("b", "handoffs:\n  - c\n"),
+            agent("c", "handoffs:\n  - a\n"),
+        ];
+        let issues = check_handoffs(&agents);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].kind,
+            HandoffIssueKind::Cycle {
+                chain: "a -> b -> c -> a".to_string()
+            }
+        );
+        assert_eq!(issues[0].path, agents[0].0);
+    }
+}
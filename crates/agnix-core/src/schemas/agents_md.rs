@@ -7,6 +7,7 @@
 //! - AGM-004: Missing Project Context
 //! - AGM-005: Platform-Specific Features Without Guard
 //! - AGM-006: Nested AGENTS.md Hierarchy
+//! - AGM-007: Token Budget (estimated tokens over `rules.instruction_token_budget`)
 
 use regex::Regex;
 use std::collections::HashSet;
@@ -191,6 +192,36 @@ pub fn check_character_limit(content: &str, limit: usize) -> Option<CharacterLim
     }
 }
 
+// ============================================================================
+// AGM-007: Token Budget
+// ============================================================================
+
+/// Token budget exceeded result
+#[derive(Debug, Clone)]
+pub struct TokenBudgetExceeded {
+    pub estimated_tokens: usize,
+    pub budget: usize,
+}
+
+/// Estimate the token count of instruction text (~4 characters per token)
+pub fn estimate_tokens(content: &str) -> usize {
+    content.chars().count().div_ceil(4)
+}
+
+/// Check if the estimated token count exceeds `budget` (for AGM-007)
+pub fn check_token_budget(content: &str, budget: usize) -> Option<TokenBudgetExceeded> {
+    let estimated_tokens = estimate_tokens(content);
+
+    if estimated_tokens > budget {
+        Some(TokenBudgetExceeded {
+            estimated_tokens,
+            budget,
+        })
+    } else {
+        None
+    }
+}
+
 // ============================================================================
 // AGM-004: Missing Project Context
 // ============================================================================
@@ -557,6 +588,23 @@ print("hello")
         assert!(result.is_none());
     }
 
+    // ===== AGM-007: Token Budget =====
+
+    #[test]
+    fn test_estimate_tokens_counts_chars_not_bytes() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcde"), 2);
+        assert_eq!(estimate_tokens("ééé"), 1);
+    }
+
+    #[test]
+    fn test_token_budget() {
+        assert!(check_token_budget(&"x".repeat(400), 100).is_none());
+        let exceeded = check_token_budget(&"x".repeat(401), 100).unwrap();
+        assert_eq!(exceeded.estimated_tokens, 101);
+        assert_eq!(exceeded.budget, 100);
+    }
+
     // ===== AGM-004: Missing Project Context =====
 
     #[test]
@@ -7,6 +7,7 @@
 //! Scoped instructions require YAML frontmatter with an `applyTo` field
 //! containing valid glob patterns.
 
+use crate::parsers::frontmatter::yaml_error_position;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -43,6 +44,8 @@ pub struct ParsedFrontmatter {
     pub unknown_keys: Vec<UnknownKey>,
     /// Parse error if YAML is invalid
     pub parse_error: Option<String>,
+    /// File line (1-indexed) and column (0-indexed) of the YAML parse error, when known
+    pub parse_error_position: Option<(usize, usize)>,
 }
 
 /// An unknown key found in frontmatter
@@ -100,6 +103,7 @@ pub fn parse_frontmatter(content: &str) -> Option<ParsedFrontmatter> {
             body: String::new(),
             unknown_keys: Vec::new(),
             parse_error: Some("missing closing ---".to_string()),
+            parse_error_position: None,
         });
     }
 
@@ -114,10 +118,12 @@ pub fn parse_frontmatter(content: &str) -> Option<ParsedFrontmatter> {
     let body = body_lines.join("\n");
 
     // Try to parse as YAML
-    let (schema, parse_error) = match serde_yaml::from_str::<CopilotScopedSchema>(&raw) {
-        Ok(s) => (Some(s), None),
-        Err(e) => (None, Some(e.to_string())),
-    };
+    // The YAML starts on the line after the opening ---
+    let (schema, parse_error, parse_error_position) =
+        match serde_yaml::from_str::<CopilotScopedSchema>(&raw) {
+            Ok(s) => (Some(s), None, None),
+            Err(e) => (None, Some(e.to_string()), yaml_error_position(&e, 2)),
+        };
 
     // Find unknown keys
     let unknown_keys = find_unknown_keys(&raw, 2); // Start at line 2 (after first ---)
@@ -130,6 +136,7 @@ pub fn parse_frontmatter(content: &str) -> Option<ParsedFrontmatter> {
         body,
         unknown_keys,
         parse_error,
+        parse_error_position,
     })
 }
 
@@ -4,6 +4,9 @@
 //! - XP-001: Claude-specific features in AGENTS.md
 //! - XP-002: AGENTS.md markdown structure validation
 //! - XP-003: Hard-coded platform paths in configs
+//! - XP-011: References to undefined agents
+//! - XP-012: Agents and the skills they preload pinning different models
+//! - XP-013: Claude Code `@path` imports in AGENTS.md
 //!
 //! ## Security
 //!
@@ -12,9 +15,11 @@
 //! input.
 
 use regex::Regex;
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
-use crate::parsers::markdown::MAX_REGEX_INPUT_SIZE;
+use crate::parsers::Import;
+use crate::parsers::markdown::{MAX_REGEX_INPUT_SIZE, extract_imports};
 use crate::regex_util::static_regex;
 
 // XP-001: Claude-specific feature patterns
@@ -22,7 +27,6 @@ static_regex!(fn claude_hooks_pattern, r"(?im)^\s*-?\s*(?:type|event):\s*(?:PreT
 static_regex!(fn context_fork_pattern, r"(?im)^\s*context:\s*fork\b");
 static_regex!(fn agent_field_pattern, r"(?im)^\s*agent:\s*\S+");
 static_regex!(fn allowed_tools_pattern, r"(?im)^\s*allowed-tools:\s*.+");
-static_regex!(fn at_import_pattern, r"(?m)(?:^|\s)@[\w./*-]+\.\w+");
 static_regex!(fn claude_section_guard_pattern, r"(?im)^(?:#+\s*|<!--\s*)claude(?:\s+code)?(?:\s+specific|\s+only)?(?:\s*-->)?");
 
 // XP-002/003: Markdown structure and path patterns
@@ -38,6 +42,10 @@ static_regex!(fn tool_disallow_pattern, r"(?im)(?:disallowed[-_]?tools\s*:|\bnev
 // XP-006: Layer type patterns
 static_regex!(fn layer_precedence_pattern, r"(?im)(?:precedence|priority|override|hierarchy|takes?\s+precedence|supersede|primary\s+source|authoritative)");
 
+// XP-011: Agent reference patterns
+// Matches `@agent-name`, `@name agent` and the `name` agent (kebab-case names only)
+static_regex!(fn agent_reference_pattern, r"(?i)(?:^|[\s(\[])(?:@agent-([a-z0-9]+(?:-[a-z0-9]+)*)\b|@([a-z0-9]+(?:-[a-z0-9]+)*)\s+(?:sub-?)?agent\b|\bthe\s+`([a-z0-9]+(?:-[a-z0-9]+)*)`\s+(?:sub-?)?agent\b)");
+
 // ============================================================================
 // XP-001: Claude-Specific Features Detection
 // ============================================================================
@@ -70,47 +78,10 @@ pub fn find_claude_specific_features(content: &str) -> Vec<ClaudeSpecificFeature
     }
 
     let mut results = Vec::new();
-    let guard_pattern = claude_section_guard_pattern();
-
-    let mut in_claude_section = false;
-    let mut claude_section_level = 0; // Track the level of the Claude guard header
+    let guarded = claude_guarded_lines(content);
 
     for (line_num, line) in content.lines().enumerate() {
-        let is_claude_guard = guard_pattern.is_match(line);
-        if is_claude_guard {
-            in_claude_section = true;
-            // Extract header level for Claude section
-            let trimmed = line.trim_start();
-            if trimmed.starts_with('#') {
-                claude_section_level = trimmed.chars().take_while(|c| *c == '#').count();
-            } else if trimmed.starts_with("<!--") {
-                claude_section_level = 2; // Default to level 2 for HTML comments
-            }
-            continue;
-        }
-
-        // Only reset guard if we encounter a header that is:
-        // 1. At the same or higher level (lower number) as the Claude guard
-        // 2. Not a Claude-specific guard itself
-        if in_claude_section
-            && (line.trim_start().starts_with('#') || line.trim_start().starts_with("<!--"))
-        {
-            let trimmed = line.trim_start();
-            let current_level = if trimmed.starts_with('#') {
-                trimmed.chars().take_while(|c| *c == '#').count()
-            } else if trimmed.starts_with("<!--") {
-                2 // Default to level 2 for HTML comments
-            } else {
-                usize::MAX
-            };
-
-            // Reset guard only if new header is at same or higher level (lower number)
-            if current_level <= claude_section_level {
-                in_claude_section = false;
-            }
-        }
-
-        if in_claude_section {
+        if guarded[line_num] {
             continue;
         }
 
@@ -153,23 +124,81 @@ pub fn find_claude_specific_features(content: &str) -> Vec<ClaudeSpecificFeature
                 description: "Tool restrictions are Claude Code specific".to_string(),
             });
         }
+    }
 
-        // Check for @file import syntax (Claude Code specific)
-        if let Some(mat) = at_import_pattern().find(line) {
-            // Avoid matching email addresses (user@domain.com)
-            let matched = mat.as_str().trim_start();
-            if matched.starts_with('@') && matched.matches('@').count() == 1 {
-                results.push(ClaudeSpecificFeature {
-                    line: line_num + 1,
-                    column: mat.start() + 1,
-                    feature: "@import".to_string(),
-                    description: "The @file import syntax is Claude Code specific".to_string(),
-                });
+    results
+}
+
+/// For each line of `content`, whether it is a Claude-specific section guard
+/// (`## Claude Code Specific`, `<!-- Claude Specific -->`, ...) or lies in the
+/// section it opens.
+///
+/// A section ends at the next header of the same or a higher level.
+fn claude_guarded_lines(content: &str) -> Vec<bool> {
+    let guard_pattern = claude_section_guard_pattern();
+
+    let mut guarded = Vec::new();
+    let mut in_claude_section = false;
+    let mut claude_section_level = 0; // Track the level of the Claude guard header
+
+    for line in content.lines() {
+        let is_claude_guard = guard_pattern.is_match(line);
+        if is_claude_guard {
+            in_claude_section = true;
+            // Extract header level for Claude section
+            let trimmed = line.trim_start();
+            if trimmed.starts_with('#') {
+                claude_section_level = trimmed.chars().take_while(|c| *c == '#').count();
+            } else if trimmed.starts_with("<!--") {
+                claude_section_level = 2; // Default to level 2 for HTML comments
+            }
+            guarded.push(true);
+            continue;
+        }
+
+        // Only reset guard if we encounter a header that is:
+        // 1. At the same or higher level (lower number) as the Claude guard
+        // 2. Not a Claude-specific guard itself
+        if in_claude_section
+            && (line.trim_start().starts_with('#') || line.trim_start().starts_with("<!--"))
+        {
+            let trimmed = line.trim_start();
+            let current_level = if trimmed.starts_with('#') {
+                trimmed.chars().take_while(|c| *c == '#').count()
+            } else if trimmed.starts_with("<!--") {
+                2 // Default to level 2 for HTML comments
+            } else {
+                usize::MAX
+            };
+
+            // Reset guard only if new header is at same or higher level (lower number)
+            if current_level <= claude_section_level {
+                in_claude_section = false;
             }
         }
+
+        guarded.push(in_claude_section);
     }
 
-    results
+    guarded
+}
+
+// ============================================================================
+// XP-013: Claude Code Imports in AGENTS.md
+// ============================================================================
+
+/// Find `@path` imports outside Claude-specific sections (for XP-013)
+///
+/// Only Claude Code expands `@path/to/file` imports; other tools reading
+/// AGENTS.md see the reference as plain text. Imports are found with the same
+/// extraction as REF-001, so code blocks, code spans and email addresses are
+/// skipped. Imports inside a Claude-specific section are not reported.
+pub fn find_claude_imports(content: &str) -> Vec<Import> {
+    let guarded = claude_guarded_lines(content);
+    extract_imports(content)
+        .into_iter()
+        .filter(|import| !guarded.get(import.line - 1).copied().unwrap_or(false))
+        .collect()
 }
 
 // ============================================================================
@@ -310,7 +339,7 @@ pub fn find_hard_coded_paths(content: &str) -> Vec<HardCodedPath> {
 // ============================================================================
 
 /// Package manager type for build commands
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum PackageManager {
     Npm,
     Pnpm,
@@ -331,7 +360,7 @@ impl PackageManager {
 }
 
 /// Command type (build, test, install, etc.)
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum CommandType {
     Install,
     Build,
@@ -341,7 +370,7 @@ pub enum CommandType {
 }
 
 /// A build command extracted from content
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BuildCommand {
     pub line: usize,
     pub column: usize,
@@ -439,14 +468,15 @@ pub struct BuildConflict {
 ///
 /// Returns conflicts when different package managers are used for the same command type.
 /// Uses O(n*m) algorithm by grouping commands by type first, then checking for conflicts.
+/// Groups are ordered, so the same input always yields the same `file1`/`file2` sides.
 pub fn detect_build_conflicts(
     files: &[(std::path::PathBuf, Vec<BuildCommand>)],
 ) -> Vec<BuildConflict> {
-    use std::collections::HashMap;
+    use std::collections::BTreeMap;
 
-    // Group commands by CommandType: HashMap<CommandType, Vec<(PathBuf, BuildCommand)>>
-    let mut by_type: HashMap<CommandType, Vec<(std::path::PathBuf, &BuildCommand)>> =
-        HashMap::new();
+    // Group commands by CommandType: BTreeMap<CommandType, Vec<(PathBuf, BuildCommand)>>
+    let mut by_type: BTreeMap<CommandType, Vec<(std::path::PathBuf, &BuildCommand)>> =
+        BTreeMap::new();
 
     for (path, commands) in files {
         for cmd in commands {
@@ -462,8 +492,8 @@ pub fn detect_build_conflicts(
     // For each command type, check if different package managers are used
     for (cmd_type, entries) in by_type {
         // Group by package manager within this command type
-        let mut by_manager: HashMap<PackageManager, Vec<(std::path::PathBuf, &BuildCommand)>> =
-            HashMap::new();
+        let mut by_manager: BTreeMap<PackageManager, Vec<(std::path::PathBuf, &BuildCommand)>> =
+            BTreeMap::new();
 
         for (path, cmd) in entries {
             by_manager
@@ -517,14 +547,14 @@ pub fn detect_build_conflicts(
 // ============================================================================
 
 /// Type of tool constraint
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConstraintType {
     Allow,
     Disallow,
 }
 
 /// A tool constraint extracted from content
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolConstraint {
     pub line: usize,
     pub column: usize,
@@ -831,14 +861,8 @@ pub struct InstructionLayer {
     pub has_precedence_doc: bool,
 }
 
-/// Categorize a file path as an instruction layer (for XP-006)
-///
-/// # Security
-///
-/// For content exceeding `MAX_REGEX_INPUT_SIZE`, `has_precedence_doc` will be
-/// set to false to avoid ReDoS. This is a safe default as it may trigger
-/// additional warnings but won't miss security issues.
-pub fn categorize_layer(path: &Path, content: &str) -> InstructionLayer {
+/// Layer type of an instruction file, decided by its path alone (for XP-006)
+pub fn layer_type_for_path(path: &Path) -> LayerType {
     let path_str = path.to_string_lossy().to_lowercase();
     let file_name = path
         .file_name()
@@ -846,7 +870,7 @@ pub fn categorize_layer(path: &Path, content: &str) -> InstructionLayer {
         .unwrap_or("")
         .to_lowercase();
 
-    let layer_type = if file_name == "claude.md" {
+    if file_name == "claude.md" {
         LayerType::ClaudeMd
     } else if file_name == "agents.md" {
         LayerType::AgentsMd
@@ -862,18 +886,20 @@ pub fn categorize_layer(path: &Path, content: &str) -> InstructionLayer {
         LayerType::OpenCodeRules
     } else {
         LayerType::Other
-    };
+    }
+}
 
+/// Whether content documents which instruction layer takes precedence (for XP-006)
+///
+/// # Security
+///
+/// For content exceeding `MAX_REGEX_INPUT_SIZE` this returns false to avoid
+/// ReDoS. This is a safe default as it may trigger additional warnings but
+/// won't miss security issues.
+pub fn has_precedence_doc(content: &str) -> bool {
     // Security: Skip regex for oversized input to prevent ReDoS
     // Default to false (safer - may trigger warnings but won't miss issues)
-    let has_precedence_doc =
-        content.len() <= MAX_REGEX_INPUT_SIZE && layer_precedence_pattern().is_match(content);
-
-    InstructionLayer {
-        path: path.to_path_buf(),
-        layer_type,
-        has_precedence_doc,
-    }
+    content.len() <= MAX_REGEX_INPUT_SIZE && layer_precedence_pattern().is_match(content)
 }
 
 /// Issue when multiple instruction layers exist without documented precedence
@@ -887,10 +913,18 @@ pub struct LayerPrecedenceIssue {
 ///
 /// Returns an issue if multiple layers exist and none document precedence
 pub fn detect_precedence_issues(layers: &[InstructionLayer]) -> Option<LayerPrecedenceIssue> {
-    // Filter to only include meaningful layers (not Other)
+    // Filter to only include meaningful layers (not Other).
+    // Files in a .clinerules/ folder together form a single Cline layer.
+    let mut seen_cline = false;
     let meaningful_layers: Vec<_> = layers
         .iter()
         .filter(|l| l.layer_type != LayerType::Other)
+        .filter(|l| {
+            if l.layer_type != LayerType::ClineRules {
+                return true;
+            }
+            !std::mem::replace(&mut seen_cline, true)
+        })
         .collect();
 
     // If there's only one or zero layers, no issue
@@ -919,6 +953,221 @@ pub fn detect_precedence_issues(layers: &[InstructionLayer]) -> Option<LayerPrec
     }
 }
 
+// ============================================================================
+// XP-011: References to Undefined Agents
+// ============================================================================
+
+/// Agents built into Claude Code that need no project definition
+const BUILTIN_AGENTS: &[&str] = &[
+    "general-purpose",
+    "explore",
+    "plan",
+    "statusline-setup",
+    "output-style-setup",
+];
+
+/// An agent referenced from an instruction file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AgentReference {
+    pub name: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Extract agent references from instruction file content (for XP-011)
+///
+/// The heuristic is deliberately conservative: only `@agent-name`,
+/// `@name agent` and ``the `name` agent`` (name in backticks) are recognized,
+/// references inside fenced code blocks are skipped, and Claude Code's
+/// built-in agents are never reported.
+///
+/// # Security
+///
+/// Returns early for content exceeding `MAX_REGEX_INPUT_SIZE` to prevent ReDoS.
+pub fn extract_agent_references(content: &str) -> Vec<AgentReference> {
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return Vec::new();
+    }
+
+    let pattern = agent_reference_pattern();
+    let mut results = Vec::new();
+    let mut in_code_block = false;
+
+    for (line_num, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        for caps in pattern.captures_iter(line) {
+            let Some(name) = caps.get(1).or_else(|| caps.get(2)).or_else(|| caps.get(3)) else {
+                continue;
+            };
+            let normalized = name.as_str().to_lowercase();
+            if BUILTIN_AGENTS.contains(&normalized.as_str()) {
+                continue;
+            }
+            results.push(AgentReference {
+                name: normalized,
+                line: line_num + 1,
+                column: name.start(),
+            });
+        }
+    }
+
+    results
+}
+
+/// Names an agent definition file can be referenced by (for XP-011)
+///
+/// Includes the frontmatter `name` field and the file stem, both lowercased.
+pub fn agent_definition_names(path: &Path, content: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+        names.push(stem.to_lowercase());
+    }
+
+    let parts = crate::parsers::frontmatter::split_frontmatter(content);
+    if parts.has_frontmatter && parts.has_closing {
+        if let Ok(serde_yaml::Value::Mapping(map)) =
+            serde_yaml::from_str::<serde_yaml::Value>(&parts.frontmatter)
+        {
+            if let Some(name) = map.get("name").and_then(|v| v.as_str()) {
+                let name = name.trim().to_lowercase();
+                if !name.is_empty() && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+    }
+
+    names
+}
+
+/// A top-level frontmatter string value with the 1-based line of its key
+fn frontmatter_string(content: &str, key: &str) -> Option<(String, usize)> {
+    let parts = crate::parsers::frontmatter::split_frontmatter(content);
+    if !parts.has_frontmatter || !parts.has_closing {
+        return None;
+    }
+    let serde_yaml::Value::Mapping(map) = serde_yaml::from_str(&parts.frontmatter).ok()? else {
+        return None;
+    };
+    let value = map.get(key)?.as_str()?.trim().to_string();
+
+    // The frontmatter text begins right after the opening `---`, so its
+    // first (empty) line is the `---` line itself
+    let first_line = content[..parts.frontmatter_start].lines().count();
+    let idx = parts
+        .frontmatter
+        .lines()
+        .position(|line| line.strip_prefix(key).is_some_and(|r| r.starts_with(':')))
+        .unwrap_or(0);
+    Some((value, first_line + idx))
+}
+
+/// Names listed under an agent's `skills` frontmatter key, lowercased
+fn preloaded_skills(content: &str) -> Vec<String> {
+    let parts = crate::parsers::frontmatter::split_frontmatter(content);
+    if !parts.has_frontmatter || !parts.has_closing {
+        return Vec::new();
+    }
+    let Ok(serde_yaml::Value::Mapping(map)) =
+        serde_yaml::from_str::<serde_yaml::Value>(&parts.frontmatter)
+    else {
+        return Vec::new();
+    };
+    match map.get("skills") {
+        Some(serde_yaml::Value::String(name)) => vec![name.trim().to_lowercase()],
+        Some(serde_yaml::Value::Sequence(items)) => items
+            .iter()
+            .filter_map(|item| item.as_str())
+            .map(|name| name.trim().to_lowercase())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Name a skill is preloaded by: its frontmatter `name`, else its directory name
+fn skill_name(path: &Path, content: &str) -> Option<String> {
+    frontmatter_string(content, "name")
+        .map(|(name, _)| name)
+        .filter(|name| !name.is_empty())
+        .or_else(|| {
+            path.parent()
+                .and_then(|dir| dir.file_name())
+                .and_then(|name| name.to_str())
+                .map(str::to_string)
+        })
+        .map(|name| name.to_lowercase())
+}
+
+/// Where a `model` is pinned in an agent or skill file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelPin {
+    pub path: PathBuf,
+    pub model: String,
+    /// Line of the `model` key
+    pub line: usize,
+}
+
+/// An agent and a skill it preloads that pin different models (for XP-012)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelConflict {
+    pub skill_name: String,
+    pub agent: ModelPin,
+    pub skill: ModelPin,
+}
+
+/// Find agents whose `model` differs from that of a skill listed in their
+/// `skills` frontmatter (for XP-012)
+///
+/// `agents` and `skills` hold each file with its content. Models compare
+/// case-insensitively, and `inherit` never conflicts. Skills are matched by
+/// frontmatter `name` or directory name.
+pub fn detect_model_conflicts(
+    agents: &[(PathBuf, String)],
+    skills: &[(PathBuf, String)],
+) -> Vec<ModelConflict> {
+    let pin = |path: &Path, content: &str| {
+        frontmatter_string(content, "model")
+            .filter(|(model, _)| !model.is_empty() && !model.eq_ignore_ascii_case("inherit"))
+            .map(|(model, line)| ModelPin {
+                path: path.to_path_buf(),
+                model,
+                line,
+            })
+    };
+
+    let skill_pins: Vec<(String, ModelPin)> = skills
+        .iter()
+        .filter_map(|(path, content)| Some((skill_name(path, content)?, pin(path, content)?)))
+        .collect();
+
+    let mut conflicts = Vec::new();
+    for (path, content) in agents {
+        let Some(agent_pin) = pin(path, content) else {
+            continue;
+        };
+        for name in preloaded_skills(content) {
+            for (_, skill_pin) in skill_pins.iter().filter(|(skill, _)| *skill == name) {
+                if !skill_pin.model.eq_ignore_ascii_case(&agent_pin.model) {
+                    conflicts.push(ModelConflict {
+                        skill_name: name.clone(),
+                        agent: agent_pin.clone(),
+                        skill: skill_pin.clone(),
+                    });
+                }
+            }
+        }
+    }
+    conflicts
+}
+
 /// Check if a file is an instruction file (for cross-layer detection)
 pub fn is_instruction_file(path: &Path) -> bool {
     let path_str = path.to_string_lossy().to_lowercase();
@@ -942,7 +1191,18 @@ pub fn is_instruction_file(path: &Path) -> bool {
         || file_name == "agents.md"
         || file_name == "gemini.md"
         || file_name == "gemini.local.md"
+        || file_name == "codex.md"
         || file_name == ".clinerules"
+        || (file_name == "instructions.md"
+            && path
+                .parent()
+                .and_then(|p| p.file_name())
+                .is_some_and(|n| n == ".codex"))
+        || (file_name.ends_with(".md")
+            && path
+                .parent()
+                .and_then(|p| p.file_name())
+                .is_some_and(|n| n == ".clinerules"))
         || (path_str.contains(".cursor")
             && (path_str.ends_with(".mdc") || path_str.contains("rules")))
         || (path_str.contains(".github") && path_str.contains("copilot"))
@@ -953,6 +1213,15 @@ pub fn is_instruction_file(path: &Path) -> bool {
 mod tests {
     use super::*;
 
+    /// Full XP-006 layer for a file, as the project pass builds it
+    fn categorize_layer(path: &Path, content: &str) -> InstructionLayer {
+        InstructionLayer {
+            path: path.to_path_buf(),
+            layer_type: layer_type_for_path(path),
+            has_precedence_doc: has_precedence_doc(content),
+        }
+    }
+
     #[test]
     fn test_regex_patterns_compile() {
         let _ = claude_hooks_pattern();
@@ -966,6 +1235,7 @@ mod tests {
         let _ = tool_allow_pattern();
         let _ = tool_disallow_pattern();
         let _ = layer_precedence_pattern();
+        let _ = agent_reference_pattern();
     }
 
     // ===== XP-001: Claude-Specific Features =====
@@ -1016,43 +1286,34 @@ Body"#;
     }
 
     #[test]
-    fn test_detect_at_import_syntax() {
+    fn test_at_import_is_not_a_claude_feature() {
+        // Imports are reported by XP-013, not XP-001
         let content = "Include rules from @path/to/rules.md in your config.";
-        let results = find_claude_specific_features(content);
-        assert!(
-            results.iter().any(|r| r.feature == "@import"),
-            "Should detect @path/to/rules.md as @import syntax"
-        );
+        assert!(find_claude_specific_features(content).is_empty());
     }
 
     #[test]
-    fn test_detect_at_import_with_wildcard() {
-        let content = "Load all rules with @.config/rules/*.md";
-        let results = find_claude_specific_features(content);
-        assert!(
-            results.iter().any(|r| r.feature == "@import"),
-            "Should detect @.config/rules/*.md (wildcard @import)"
-        );
+    fn test_find_claude_imports() {
+        let content = "Include rules from @path/to/rules.md in your config.\nAlso @README.md\n";
+        let imports = find_claude_imports(content);
+        let paths: Vec<_> = imports.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, vec!["path/to/rules.md", "README.md"]);
+        assert_eq!((imports[0].line, imports[0].column), (1, 20));
     }
 
     #[test]
-    fn test_no_false_positive_email_in_at_import() {
-        let content = "Contact user@example.com for questions.";
-        let results = find_claude_specific_features(content);
-        assert!(
-            !results.iter().any(|r| r.feature == "@import"),
-            "Email addresses should not be flagged as @imports"
-        );
+    fn test_find_claude_imports_skips_emails_and_code() {
+        let content = "Contact user@example.com or admin@domain.org.\n\n`@docs/a.md`\n\n```\n@docs/b.md\n```\n";
+        assert!(find_claude_imports(content).is_empty());
     }
 
     #[test]
-    fn test_no_false_positive_email_standalone() {
-        let content = "Email admin@domain.org for access.";
-        let results = find_claude_specific_features(content);
-        assert!(
-            !results.iter().any(|r| r.feature == "@import"),
-            "Standalone email should not trigger @import detection"
-        );
+    fn test_find_claude_imports_skips_claude_section() {
+        let content =
+            "# Project\n\n## Claude Code\n\n@docs/claude.md\n\n## Build\n\n@docs/build.md\n";
+        let imports = find_claude_imports(content);
+        assert_eq!(imports.len(), 1);
+        assert_eq!(imports[0].path, "docs/build.md");
     }
 
     #[test]
@@ -1806,6 +2067,20 @@ Use pnpm install for dependencies.
         assert!(issue.is_none());
     }
 
+    #[test]
+    fn test_cline_folder_files_form_single_layer() {
+        use std::path::PathBuf;
+        let layers = vec![
+            categorize_layer(&PathBuf::from(".clinerules/01-coding.md"), "# Coding"),
+            categorize_layer(&PathBuf::from(".clinerules/02-testing.md"), "# Testing"),
+        ];
+        assert!(detect_precedence_issues(&layers).is_none());
+
+        let mut with_claude = layers.clone();
+        with_claude.push(categorize_layer(&PathBuf::from("CLAUDE.md"), "# Project"));
+        assert!(detect_precedence_issues(&with_claude).is_some());
+    }
+
     #[test]
     fn test_is_instruction_file() {
         use std::path::PathBuf;
@@ -1819,6 +2094,20 @@ Use pnpm install for dependencies.
             ".github/copilot-instructions.md"
         )));
         assert!(is_instruction_file(&PathBuf::from(".clinerules")));
+        assert!(is_instruction_file(&PathBuf::from(".clinerules/coding.md")));
+        assert!(is_instruction_file(&PathBuf::from(
+            "project/.clinerules/01-general.md"
+        )));
+        assert!(!is_instruction_file(&PathBuf::from(
+            ".clinerules/notes.txt"
+        )));
+        assert!(is_instruction_file(&PathBuf::from("codex.md")));
+        assert!(is_instruction_file(&PathBuf::from(
+            "project/.codex/instructions.md"
+        )));
+        assert!(!is_instruction_file(&PathBuf::from(
+            "notes/instructions.md"
+        )));
 
         assert!(!is_instruction_file(&PathBuf::from("README.md")));
         assert!(!is_instruction_file(&PathBuf::from("src/main.rs")));
@@ -2217,6 +2506,85 @@ Use pnpm install for dependencies.
             "Oversized content should not detect precedence for ReDoS protection"
         );
     }
+    // ===== XP-011: Agent References =====
+
+    #[test]
+    fn test_extract_agent_references_forms() {
+        let content = "Use @agent-code-reviewer for reviews.\nAsk the @test-runner agent to run tests.\nThen the `doc-writer` subagent updates docs.\n";
+        let refs = extract_agent_references(content);
+        let names: Vec<_> = refs.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["code-reviewer", "test-runner", "doc-writer"]);
+        assert_eq!(refs[0].line, 1);
+        assert_eq!(refs[1].line, 2);
+        assert_eq!(refs[1].column, 9);
+    }
+
+    #[test]
+    fn test_extract_agent_references_is_conservative() {
+        let content = "Run the long-running agent loop.\nEmail admin@agent-host.example.\nSee @docs/guide.md for details.\nThe main agent coordinates work.\n";
+        assert!(extract_agent_references(content).is_empty());
+    }
+
+    #[test]
+    fn test_extract_agent_references_skips_code_blocks_and_builtins() {
+        let content =
+            "```\n@agent-example\n```\nUse the @Explore agent or @agent-general-purpose.\n";
+        assert!(extract_agent_references(content).is_empty());
+    }
+
+    #[test]
+    fn test_agent_definition_names() {
+        use std::path::PathBuf;
+        let content = "---\nname: Security-Auditor\ndescription: Audits code\n---\nBody";
+        let names = agent_definition_names(&PathBuf::from(".claude/agents/audit.md"), content);
+        assert_eq!(names, vec!["audit", "security-auditor"]);
+    }
+
+    // ===== XP-012: Model Conflicts =====
+
+    fn agent(model: &str, skills: &str) -> (PathBuf, String) {
+        (
+            PathBuf::from(".claude/agents/reviewer.md"),
+            format!("---\nname: reviewer\nmodel: {model}\nskills: {skills}\n---\nReview."),
+        )
+    }
+
+    fn skill(dir: &str, frontmatter: &str) -> (PathBuf, String) {
+        (
+            PathBuf::from(format!(".claude/skills/{dir}/SKILL.md")),
+            format!("---\ndescription: Helps\n{frontmatter}\n---\nBody"),
+        )
+    }
+
+    #[test]
+    fn test_detect_model_conflicts() {
+        let agents = vec![agent("haiku", "[deploy, lint]")];
+        let skills = vec![
+            skill("deploy", "name: deploy\nmodel: opus"),
+            skill("lint", "model: Haiku"),
+        ];
+
+        let conflicts = detect_model_conflicts(&agents, &skills);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].skill_name, "deploy");
+        assert_eq!(conflicts[0].agent.model, "haiku");
+        assert_eq!(conflicts[0].agent.line, 3);
+        assert_eq!(conflicts[0].skill.model, "opus");
+        assert_eq!(conflicts[0].skill.line, 4);
+    }
+
+    #[test]
+    fn test_detect_model_conflicts_skips_unpinned_and_unlisted() {
+        let skills = vec![skill("deploy", "model: opus")];
+        // Skill not preloaded by the agent
+        assert!(detect_model_conflicts(&[agent("haiku", "lint")], &skills).is_empty());
+        // `inherit` defers to the caller's model
+        assert!(detect_model_conflicts(&[agent("inherit", "deploy")], &skills).is_empty());
+        // Skill without a model
+        let skills = vec![skill("deploy", "name: deploy")];
+        assert!(detect_model_conflicts(&[agent("haiku", "deploy")], &skills).is_empty());
+    }
+
     #[test]
     fn test_categorize_gemini_md_variants() {
         use std::path::PathBuf;
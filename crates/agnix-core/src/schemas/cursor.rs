@@ -9,6 +9,7 @@
 //! - globs: File patterns to apply the rule to
 //! - alwaysApply: Whether to always apply the rule
 
+use crate::parsers::frontmatter::yaml_error_position;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
@@ -90,6 +91,8 @@ pub struct ParsedMdcFrontmatter {
     pub unknown_keys: Vec<UnknownKey>,
     /// Parse error if YAML is invalid
     pub parse_error: Option<String>,
+    /// File line (1-indexed) and column (0-indexed) of the YAML parse error, when known
+    pub parse_error_position: Option<(usize, usize)>,
 }
 
 /// An unknown key found in frontmatter
@@ -144,6 +147,7 @@ pub fn parse_mdc_frontmatter(content: &str) -> Option<ParsedMdcFrontmatter> {
             body: String::new(),
             unknown_keys: Vec::new(),
             parse_error: Some("missing closing ---".to_string()),
+            parse_error_position: None,
         });
     }
 
@@ -158,10 +162,12 @@ pub fn parse_mdc_frontmatter(content: &str) -> Option<ParsedMdcFrontmatter> {
     let body = body_lines.join("\n");
 
     // Try to parse as YAML
-    let (schema, parse_error) = match serde_yaml::from_str::<CursorRuleSchema>(&raw) {
-        Ok(s) => (Some(s), None),
-        Err(e) => (None, Some(e.to_string())),
-    };
+    // The YAML starts on the line after the opening ---
+    let (schema, parse_error, parse_error_position) =
+        match serde_yaml::from_str::<CursorRuleSchema>(&raw) {
+            Ok(s) => (Some(s), None, None),
+            Err(e) => (None, Some(e.to_string()), yaml_error_position(&e, 2)),
+        };
 
     // Find unknown keys
     let unknown_keys = find_unknown_keys(&raw, 2); // Start at line 2 (after first ---)
@@ -174,6 +180,7 @@ pub fn parse_mdc_frontmatter(content: &str) -> Option<ParsedMdcFrontmatter> {
         body,
         unknown_keys,
         parse_error,
+        parse_error_position,
     })
 }
 
@@ -70,6 +70,31 @@ impl SettingsSchema {
     }
 }
 
+/// Normalize the contents of a split hook file (`.claude/hooks/<Event>.json`)
+/// into the settings shape `{"hooks": {"<Event>": [...]}}`.
+///
+/// Split files may contain a full settings object, an array of matchers, a
+/// single matcher, or a single bare hook. The event name comes from the file
+/// stem. Values of any other shape are returned unchanged.
+pub fn normalize_split_hook_file(event: &str, value: Value) -> Value {
+    let matchers = match value {
+        Value::Object(ref obj) if obj.get("hooks").is_some_and(Value::is_object) => {
+            return value;
+        }
+        Value::Array(_) => value,
+        Value::Object(ref obj) if obj.get("hooks").is_some_and(Value::is_array) => {
+            Value::Array(vec![value])
+        }
+        Value::Object(ref obj) if obj.contains_key("type") => {
+            Value::Array(vec![serde_json::json!({ "hooks": [value] })])
+        }
+        other => return other,
+    };
+    let mut events = serde_json::Map::new();
+    events.insert(event.to_string(), matchers);
+    serde_json::json!({ "hooks": Value::Object(events) })
+}
+
 impl Hook {
     pub fn command(&self) -> Option<&str> {
         match self {
@@ -1,7 +1,8 @@
 //! MCP (Model Context Protocol) schema definitions
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 
 /// MCP tool definition schema
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -89,6 +90,75 @@ pub struct McpConfigSchema {
     pub jsonrpc: Option<String>,
 }
 
+/// Format an MCP config file is written in
+///
+/// YAML and TOML configs are parsed into the same JSON value model as
+/// `.mcp.json`, so every MCP rule runs on the parsed schema regardless of format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McpSourceFormat {
+    Json,
+    Yaml,
+    Toml,
+}
+
+impl McpSourceFormat {
+    /// Format implied by the file extension (`.yaml`/`.yml`, `.toml`, else JSON)
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml" | "yml") => Self::Yaml,
+            Some("toml") => Self::Toml,
+            _ => Self::Json,
+        }
+    }
+}
+
+/// Error from parsing an MCP config, with its 1-based position when known
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpParseError {
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Parse MCP config `content` written in `format` into a JSON value.
+pub fn parse_mcp_source(
+    content: &str,
+    format: McpSourceFormat,
+) -> Result<serde_json::Value, McpParseError> {
+    match format {
+        McpSourceFormat::Json => serde_json::from_str(content).map_err(|e| McpParseError {
+            message: e.to_string(),
+            line: e.line().max(1),
+            column: e.column(),
+        }),
+        McpSourceFormat::Yaml => serde_yaml::from_str(content).map_err(|e| {
+            let (line, column) = e
+                .location()
+                .map_or((1, 0), |loc| (loc.line(), loc.column()));
+            McpParseError {
+                message: e.to_string(),
+                line,
+                column,
+            }
+        }),
+        McpSourceFormat::Toml => toml::from_str(content).map_err(|e: toml::de::Error| {
+            let (line, column) = e.span().map_or((1, 0), |span| {
+                let before = &content[..span.start.min(content.len())];
+                let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+                (
+                    before.matches('\n').count() + 1,
+                    before[line_start..].chars().count() + 1,
+                )
+            });
+            McpParseError {
+                message: e.message().to_string(),
+                line,
+                column,
+            }
+        }),
+    }
+}
+
 /// Valid JSON Schema types
 pub const VALID_JSON_SCHEMA_TYPES: &[&str] = &[
     "string", "number", "integer", "boolean", "object", "array", "null",
@@ -195,6 +265,51 @@ impl McpToolSchema {
     }
 }
 
+/// What is wrong with a present, non-empty server `command`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum McpCommandProblem {
+    /// Neither a string nor an array of strings
+    WrongType,
+    /// Array element at this index is blank or not a string
+    BadElement(usize),
+}
+
+impl McpServerConfig {
+    /// Why `command` cannot be run, if it is set but malformed.
+    ///
+    /// Absent, null, blank and empty-array commands are "missing" (MCP-009),
+    /// not malformed, and return `None`.
+    pub fn command_problem(&self) -> Option<McpCommandProblem> {
+        match self.command.as_ref()? {
+            serde_json::Value::Null | serde_json::Value::String(_) => None,
+            serde_json::Value::Array(parts) => parts
+                .iter()
+                .position(|part| part.as_str().is_none_or(|s| s.trim().is_empty()))
+                .map(McpCommandProblem::BadElement),
+            _ => Some(McpCommandProblem::WrongType),
+        }
+    }
+}
+
+/// Whether a server `url` is an absolute http(s) URL with a host.
+///
+/// Values that interpolate a variable (`${MCP_URL}`) are resolved by the
+/// client at startup and are accepted as-is.
+pub fn is_well_formed_server_url(url: &str) -> bool {
+    let url = url.trim();
+    if url.contains("${") {
+        return true;
+    }
+    let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    !host.is_empty() && !url.contains(char::is_whitespace)
+}
+
 impl McpJsonRpcMessage {
     /// Check if JSON-RPC version is valid (must be "2.0")
     pub fn has_valid_jsonrpc_version(&self) -> bool {
@@ -272,6 +387,137 @@ pub fn validate_json_schema_structure(schema: &serde_json::Value) -> Vec<String>
     errors
 }
 
+/// An MCP server name that is also defined in another MCP config file (MCP-023)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateServerName {
+    pub name: String,
+    /// File this occurrence is reported on
+    pub path: PathBuf,
+    pub line: usize,
+    pub column: usize,
+    /// Other files defining the same server name, in path order
+    pub others: Vec<PathBuf>,
+}
+
+/// Find `mcpServers` names defined in more than one file.
+///
+/// Each file is parsed as an [`McpConfigSchema`] in the format implied by its
+/// extension; files that fail to parse are skipped (MCP-007 already reports
+/// them). One entry is returned per file that defines a shared name, located at
+/// the server's key.
+pub fn find_duplicate_server_names(files: &[(PathBuf, String)]) -> Vec<DuplicateServerName> {
+    let mut definitions: BTreeMap<String, Vec<(&PathBuf, &str)>> = BTreeMap::new();
+    for (path, content) in files {
+        let Some(schema) = parse_config_schema(path, content) else {
+            continue;
+        };
+        for name in schema.mcp_servers.unwrap_or_default().into_keys() {
+            definitions
+                .entry(name)
+                .or_default()
+                .push((path, content.as_str()));
+        }
+    }
+
+    let mut duplicates = Vec::new();
+    for (name, mut files) in definitions {
+        if files.len() < 2 {
+            continue;
+        }
+        files.sort_by(|a, b| a.0.cmp(b.0));
+        for (path, content) in &files {
+            let (line, column) = match McpSourceFormat::from_path(path) {
+                McpSourceFormat::Json => find_server_key_location(content, &name),
+                format => find_key_location(content, &name, format)
+                    .map_or((1, 0), |(line, column)| (line, column + 1)),
+            };
+            duplicates.push(DuplicateServerName {
+                name: name.clone(),
+                path: (*path).clone(),
+                line,
+                column,
+                others: files
+                    .iter()
+                    .map(|(other, _)| *other)
+                    .filter(|other| other != path)
+                    .cloned()
+                    .collect(),
+            });
+        }
+    }
+    duplicates
+}
+
+/// All `mcpServers` names defined across `files` (CC-SK-016).
+///
+/// Files are parsed like [`find_duplicate_server_names`]; unparseable files
+/// contribute no names.
+pub fn defined_server_names(files: &[(PathBuf, String)]) -> BTreeSet<String> {
+    files
+        .iter()
+        .filter_map(|(path, content)| parse_config_schema(path, content))
+        .flat_map(|schema| schema.mcp_servers.unwrap_or_default().into_keys())
+        .collect()
+}
+
+/// Parse an MCP config file in the format implied by its extension
+fn parse_config_schema(path: &Path, content: &str) -> Option<McpConfigSchema> {
+    parse_mcp_source(content, McpSourceFormat::from_path(path))
+        .ok()
+        .and_then(|value| serde_json::from_value(value).ok())
+}
+
+/// Line (1-based) and column (0-based) of the first `key` in YAML or TOML
+/// source
+///
+/// Matches a `key:` mapping entry in YAML, and a `key =` assignment or a table
+/// header ending in `key` (e.g. `[mcpServers.key]`) in TOML. Keys may be quoted.
+/// JSON callers search for `"key"` directly.
+pub(crate) fn find_key_location(
+    content: &str,
+    key: &str,
+    format: McpSourceFormat,
+) -> Option<(usize, usize)> {
+    let key = regex::escape(key);
+    let pattern = match format {
+        McpSourceFormat::Yaml => format!(r#"(?m)^[ \t]*(?:-[ \t]+)?(["']?{key}["']?)[ \t]*:"#),
+        McpSourceFormat::Toml => format!(
+            r#"(?m)^[ \t]*(?:(["']?{key}["']?)[ \t]*=|\[\[?(?:[^\]\n]*\.)?(["']?{key}["']?)\]\]?)"#
+        ),
+        McpSourceFormat::Json => format!(r#"("{key}")"#),
+    };
+    let re = regex::Regex::new(&pattern).ok()?;
+    let caps = re.captures(content)?;
+    let found = caps.get(1).or_else(|| caps.get(2))?;
+    let before = &content[..found.start()];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    Some((before.matches('\n').count() + 1, found.start() - line_start))
+}
+
+/// 1-based line and column of the `"<name>":` key under `mcpServers`, or (1, 0)
+fn find_server_key_location(content: &str, name: &str) -> (usize, usize) {
+    let Some(servers_start) = content.find("\"mcpServers\"") else {
+        return (1, 0);
+    };
+    let Ok(quoted) = serde_json::to_string(name) else {
+        return (1, 0);
+    };
+
+    let mut search_from = servers_start;
+    while let Some(found) = content[search_from..].find(&quoted) {
+        let start = search_from + found;
+        let after = &content[start + quoted.len()..];
+        if after.trim_start().starts_with(':') {
+            let before = &content[..start];
+            let line = before.matches('\n').count() + 1;
+            let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+            return (line, before[line_start..].chars().count() + 1);
+        }
+        search_from = start + quoted.len();
+    }
+    (1, 0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -571,4 +817,119 @@ mod tests {
     fn test_default_mcp_protocol_version_constant() {
         assert_eq!(super::DEFAULT_MCP_PROTOCOL_VERSION, "2025-06-18");
     }
+
+    fn mcp_file(path: &str, content: &str) -> (PathBuf, String) {
+        (PathBuf::from(path), content.to_string())
+    }
+
+    #[test]
+    fn test_duplicate_server_names_across_files() {
+        let files = vec![
+            mcp_file(
+                "team-a/.mcp.json",
+                "{\n  \"mcpServers\": {\n    \"github\": {\"command\": \"gh-mcp\"},\n    \"db\": {\"command\": \"db-mcp\"}\n  }\n}\n",
+            ),
+            mcp_file(
+                "team-b/.mcp.json",
+                "{\"mcpServers\": {\"github\": {\"url\": \"https://example.com/mcp\", \"type\": \"http\"}}}",
+            ),
+        ];
+
+        let duplicates = find_duplicate_server_names(&files);
+        assert_eq!(duplicates.len(), 2);
+        assert!(duplicates.iter().all(|d| d.name == "github"));
+
+        assert_eq!(duplicates[0].path, PathBuf::from("team-a/.mcp.json"));
+        assert_eq!((duplicates[0].line, duplicates[0].column), (3, 5));
+        assert_eq!(
+            duplicates[0].others,
+            vec![PathBuf::from("team-b/.mcp.json")]
+        );
+
+        assert_eq!(duplicates[1].path, PathBuf::from("team-b/.mcp.json"));
+        assert_eq!((duplicates[1].line, duplicates[1].column), (1, 17));
+        assert_eq!(
+            duplicates[1].others,
+            vec![PathBuf::from("team-a/.mcp.json")]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_server_names_unique_and_unparseable_ignored() {
+        let files = vec![
+            mcp_file(
+                "a.mcp.json",
+                r#"{"mcpServers": {"github": {"command": "gh"}}}"#,
+            ),
+            mcp_file(
+                "b.mcp.json",
+                r#"{"mcpServers": {"gitlab": {"command": "gl"}}}"#,
+            ),
+            mcp_file("c.mcp.json", r#"{"mcpServers": {"github": "#),
+            mcp_file("d.mcp.json", r#"{"tools": []}"#),
+        ];
+        assert!(find_duplicate_server_names(&files).is_empty());
+    }
+
+    #[test]
+    fn test_defined_server_names_across_formats() {
+        let files = vec![
+            mcp_file(
+                ".mcp.json",
+                r#"{"mcpServers": {"github": {"command": "gh"}}}"#,
+            ),
+            mcp_file(
+                "tools/mcp.yaml",
+                "mcpServers:\n  postgres:\n    command: pg\n",
+            ),
+            mcp_file("broken.mcp.json", r#"{"mcpServers": {"sentry": "#),
+        ];
+        let names: Vec<_> = defined_server_names(&files).into_iter().collect();
+        assert_eq!(names, vec!["github", "postgres"]);
+    }
+
+    #[test]
+    fn test_server_key_location_skips_non_key_matches() {
+        let content = r#"{"mcpServers": {"db": {"args": ["github"]}, "github": {}}}"#;
+        assert_eq!(find_server_key_location(content, "github"), (1, 45));
+        assert_eq!(find_server_key_location(content, "missing"), (1, 0));
+    }
+
+    #[test]
+    fn test_is_well_formed_server_url() {
+        assert!(is_well_formed_server_url("https://example.com/mcp"));
+        assert!(is_well_formed_server_url("http://localhost:3000"));
+        assert!(is_well_formed_server_url("${MCP_URL}/mcp"));
+        assert!(!is_well_formed_server_url("example.com/mcp"));
+        assert!(!is_well_formed_server_url("ws://example.com/mcp"));
+        assert!(!is_well_formed_server_url("https:///mcp"));
+        assert!(!is_well_formed_server_url("https://example.com/my server"));
+    }
+
+    #[test]
+    fn test_command_problem() {
+        let server = |command: serde_json::Value| McpServerConfig {
+            server_type: None,
+            command: Some(command),
+            args: None,
+            env: None,
+            url: None,
+        };
+        assert_eq!(server(json!("npx")).command_problem(), None);
+        assert_eq!(server(json!("")).command_problem(), None);
+        assert_eq!(server(json!(["npx", "-y", "pkg"])).command_problem(), None);
+        assert_eq!(server(json!([])).command_problem(), None);
+        assert_eq!(
+            server(json!(["npx", ""])).command_problem(),
+            Some(McpCommandProblem::BadElement(1))
+        );
+        assert_eq!(
+            server(json!([1])).command_problem(),
+            Some(McpCommandProblem::BadElement(0))
+        );
+        assert_eq!(
+            server(json!({"bin": "npx"})).command_problem(),
+            Some(McpCommandProblem::WrongType)
+        );
+    }
 }
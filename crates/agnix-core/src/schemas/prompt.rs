@@ -5,6 +5,7 @@
 //! - PE-002: Chain-of-thought phrases on simple tasks
 //! - PE-003: Weak imperative language in critical sections
 //! - PE-004: Ambiguous instructions
+//! - PE-009: Inconsistent addressing mode (imperative/you vs "I" vs "the assistant")
 //!
 //! ## Security
 //!
@@ -22,6 +23,11 @@ static_regex!(fn cot_phrase_pattern, r"(?i)\b(think\s+step\s+by\s+step|let'?s\s+
 static_regex!(fn simple_task_indicator_pattern, r"(?i)\b(read\s+(?:the\s+)?file|write\s+(?:the\s+)?file|copy\s+(?:the\s+)?file|move\s+(?:the\s+)?file|delete\s+(?:the\s+)?file|list\s+files|run\s+(?:the\s+)?(?:command|script)|execute\s+(?:the\s+)?(?:command|script)|format\s+(?:the\s+)?(?:code|output)|rename\s+(?:the\s+)?file|create\s+(?:a\s+)?(?:file|directory|folder)|check\s+(?:if|whether)\s+(?:file|directory)\s+exists)\b");
 static_regex!(fn weak_language_pattern, r"(?i)\b(should|try\s+to|consider|maybe|might|could|possibly|preferably|ideally|optionally)\b");
 static_regex!(fn critical_section_pattern, r"(?i)^#+\s*.*\b(critical|important|required|mandatory|rules|must|essential|security|danger)\b");
+static_regex!(fn first_person_pattern, r"^I(?:'ll|'m| will| must| should| am| need to| can| always| never| won't| do not)\b");
+static_regex!(fn second_person_pattern, r"^(?:You(?:'ll|'re)?|Your)\b");
+static_regex!(fn third_person_pattern, r"^(?:(?i:the|this)\s+)?(?i:assistant|model|agent|ai|claude|llm)\s+(?:must|should|will|shall|may|can|needs?\s+to|is\s+(?:expected|required|not\s+allowed)\s+to|always|never)\b");
+static_regex!(fn imperative_pattern, r"^(?:Always|Never|Do|Don't|Use|Run|Write|Add|Avoid|Prefer|Keep|Follow|Ensure|Check|Make|Read|Create|Update|Remove|Include|Return|Respond|Answer|Ask|Explain|Provide|Verify|Test|Document|Format|Limit)\b");
+static_regex!(fn list_marker_pattern, r"^(?:[-*+]\s+|\d+[.)]\s+)");
 static_regex!(fn ambiguous_term_pattern, r"(?i)\b(usually|sometimes|if\s+possible|when\s+appropriate|as\s+needed|often|occasionally|generally|typically|normally|frequently|regularly|commonly)\b");
 
 // ============================================================================
@@ -312,6 +318,210 @@ pub fn find_ambiguous_instructions(content: &str) -> Vec<AmbiguousInstruction> {
     results
 }
 
+// ============================================================================
+// PE-009: Inconsistent Addressing Mode
+// ============================================================================
+
+/// How an instruction sentence addresses the assistant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AddressingMode {
+    /// "Run the tests."
+    Imperative,
+    /// "You must run the tests."
+    SecondPerson,
+    /// "I will run the tests."
+    FirstPerson,
+    /// "The assistant must run the tests."
+    ThirdPerson,
+}
+
+impl AddressingMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AddressingMode::Imperative => "imperative",
+            AddressingMode::SecondPerson => "second-person",
+            AddressingMode::FirstPerson => "first-person",
+            AddressingMode::ThirdPerson => "third-person",
+        }
+    }
+
+    /// Imperative sentences implicitly address "you", so they share a voice
+    /// with second-person ones; mixing those two is not reported.
+    fn voice(&self) -> AddressingMode {
+        match self {
+            AddressingMode::Imperative => AddressingMode::SecondPerson,
+            other => *other,
+        }
+    }
+}
+
+/// A classified instruction sentence
+#[derive(Debug, Clone)]
+pub struct AddressedSentence {
+    pub line: usize,
+    pub column: usize,
+    pub mode: AddressingMode,
+    pub text: String,
+}
+
+/// A file mixing more than one voice when addressing the assistant
+#[derive(Debug, Clone)]
+pub struct MixedAddressing {
+    /// Sentences per mode, for every mode present
+    pub counts: Vec<(AddressingMode, usize)>,
+    /// First sentence of each minority voice (at most two)
+    pub examples: Vec<AddressedSentence>,
+}
+
+/// Classify the start of a sentence, or `None` when it is not clearly an instruction
+fn classify_sentence(sentence: &str) -> Option<AddressingMode> {
+    if first_person_pattern().is_match(sentence) {
+        Some(AddressingMode::FirstPerson)
+    } else if third_person_pattern().is_match(sentence) {
+        Some(AddressingMode::ThirdPerson)
+    } else if second_person_pattern().is_match(sentence) {
+        Some(AddressingMode::SecondPerson)
+    } else if imperative_pattern().is_match(sentence) {
+        Some(AddressingMode::Imperative)
+    } else {
+        None
+    }
+}
+
+/// Split a line into sentences at `.`, `!` or `?` followed by whitespace,
+/// returning each sentence with its byte offset in the line.
+fn split_sentences(line: &str) -> Vec<(usize, &str)> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = line.char_indices().peekable();
+    while let Some((idx, c)) = chars.next() {
+        if matches!(c, '.' | '!' | '?')
+            && chars.peek().is_some_and(|(_, next)| next.is_whitespace())
+        {
+            sentences.push((start, &line[start..=idx]));
+            start = idx + 1;
+        }
+    }
+    sentences.push((start, &line[start..]));
+
+    sentences
+        .into_iter()
+        .map(|(offset, sentence)| {
+            let trimmed = sentence.trim_start();
+            (offset + sentence.len() - trimmed.len(), trimmed.trim_end())
+        })
+        .filter(|(_, sentence)| !sentence.is_empty())
+        .collect()
+}
+
+/// Classify every instruction sentence in the file body
+///
+/// Conservative: only sentences whose opening words clearly mark a voice are
+/// classified. Frontmatter, headings, code blocks, blockquotes, tables and
+/// HTML comments are skipped, since they often quote example dialogue.
+pub fn classify_addressing(content: &str) -> Vec<AddressedSentence> {
+    let mut results = Vec::new();
+    let lines: Vec<&str> = content.lines().collect();
+
+    // Skip YAML frontmatter
+    let body_start = match lines.first() {
+        Some(first) if first.trim() == "---" => lines
+            .iter()
+            .skip(1)
+            .position(|l| l.trim() == "---")
+            .map_or(0, |end| end + 2),
+        _ => 0,
+    };
+
+    let mut in_code_block = false;
+    for (line_num, line) in lines.iter().enumerate().skip(body_start) {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block
+            || trimmed.is_empty()
+            || trimmed.starts_with('#')
+            || trimmed.starts_with('>')
+            || trimmed.starts_with('|')
+            || trimmed.starts_with("<!--")
+        {
+            continue;
+        }
+
+        let indent = line.len() - trimmed.len();
+        let marker_len = list_marker_pattern().find(trimmed).map_or(0, |m| m.end());
+        let text_start = indent + marker_len;
+        for (offset, sentence) in split_sentences(&line[text_start..]) {
+            if let Some(mode) = classify_sentence(sentence) {
+                results.push(AddressedSentence {
+                    line: line_num + 1,
+                    column: text_start + offset + 1,
+                    mode,
+                    text: sentence.to_string(),
+                });
+            }
+        }
+    }
+
+    results
+}
+
+/// Find files that address the assistant in more than one voice (PE-009)
+///
+/// Imperative and second-person sentences count as one voice; the file is
+/// reported when that voice is mixed with first-person ("I will") or
+/// third-person ("the assistant must") instructions.
+///
+/// # Security
+///
+/// Returns early for content exceeding `MAX_REGEX_INPUT_SIZE` to prevent ReDoS.
+pub fn find_mixed_addressing(content: &str) -> Option<MixedAddressing> {
+    // Security: Skip regex processing for oversized input to prevent ReDoS
+    if content.len() > MAX_REGEX_INPUT_SIZE {
+        return None;
+    }
+
+    let sentences = classify_addressing(content);
+
+    let mut counts: Vec<(AddressingMode, usize)> = Vec::new();
+    for sentence in &sentences {
+        match counts.iter_mut().find(|(mode, _)| *mode == sentence.mode) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((sentence.mode, 1)),
+        }
+    }
+    counts.sort();
+
+    let mut voices: Vec<(AddressingMode, usize)> = Vec::new();
+    for (mode, count) in &counts {
+        match voices.iter_mut().find(|(voice, _)| *voice == mode.voice()) {
+            Some((_, total)) => *total += count,
+            None => voices.push((mode.voice(), *count)),
+        }
+    }
+    if voices.len() < 2 {
+        return None;
+    }
+
+    // The most used voice is the one to keep; ties prefer the direct voice
+    let dominant = voices
+        .iter()
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))
+        .map(|(voice, _)| *voice)?;
+    let mut examples: Vec<AddressedSentence> = Vec::new();
+    for sentence in sentences {
+        let voice = sentence.mode.voice();
+        if voice != dominant && !examples.iter().any(|e| e.mode.voice() == voice) {
+            examples.push(sentence);
+        }
+    }
+    examples.truncate(2);
+
+    Some(MixedAddressing { counts, examples })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,6 +534,11 @@ mod tests {
         let _ = weak_language_pattern();
         let _ = critical_section_pattern();
         let _ = ambiguous_term_pattern();
+        let _ = first_person_pattern();
+        let _ = second_person_pattern();
+        let _ = third_person_pattern();
+        let _ = imperative_pattern();
+        let _ = list_marker_pattern();
     }
 
     // ===== PE-001: Critical Content in Middle =====
@@ -783,4 +998,73 @@ Code could be cleaner.
             "Oversized content should be skipped for ReDoS protection"
         );
     }
+
+    // ===== PE-009: Inconsistent Addressing Mode =====
+
+    #[test]
+    fn test_classify_addressing_modes() {
+        let content = "# Rules\n\nRun tests first. You must keep diffs small.\n- The assistant should never push.\n1. I will explain changes.\n";
+        let modes: Vec<_> = classify_addressing(content)
+            .into_iter()
+            .map(|s| (s.line, s.column, s.mode))
+            .collect();
+        assert_eq!(
+            modes,
+            vec![
+                (3, 1, AddressingMode::Imperative),
+                (3, 18, AddressingMode::SecondPerson),
+                (4, 3, AddressingMode::ThirdPerson),
+                (5, 4, AddressingMode::FirstPerson),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_classify_addressing_skips_quoted_and_code() {
+        let content = "---\ndescription: You are helpful\n---\n> I will do it.\n```\nI will run it.\n```\n| I will | cell |\n<!-- The assistant must -->\nPlain prose with no voice.\n";
+        assert!(classify_addressing(content).is_empty());
+    }
+
+    #[test]
+    fn test_imperative_and_second_person_are_one_voice() {
+        let content = "Use pnpm. You must run lint. Always add tests.\nYour changes need docs.\n";
+        assert!(find_mixed_addressing(content).is_none());
+    }
+
+    #[test]
+    fn test_mixed_addressing_reports_counts_and_examples() {
+        let content = "Run tests.\nYou must format code.\nAlways add docs.\nThe assistant must not push.\nI will summarize changes.\n";
+        let mixed = find_mixed_addressing(content).unwrap();
+        assert_eq!(
+            mixed.counts,
+            vec![
+                (AddressingMode::Imperative, 2),
+                (AddressingMode::SecondPerson, 1),
+                (AddressingMode::FirstPerson, 1),
+                (AddressingMode::ThirdPerson, 1),
+            ]
+        );
+        let examples: Vec<_> = mixed.examples.iter().map(|e| (e.line, e.mode)).collect();
+        assert_eq!(
+            examples,
+            vec![
+                (4, AddressingMode::ThirdPerson),
+                (5, AddressingMode::FirstPerson)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mixed_addressing_ignores_non_subject_mentions() {
+        // "the model" as an object, and "I" inside a sentence, are not voices
+        let content =
+            "Use the model registry.\nAsk before I/O heavy work.\nKeep the agent config small.\n";
+        assert!(find_mixed_addressing(content).is_none());
+    }
+
+    #[test]
+    fn test_mixed_addressing_oversized_input() {
+        let content = "I will run. You must run.\n".repeat(MAX_REGEX_INPUT_SIZE / 20 + 1);
+        assert!(find_mixed_addressing(&content).is_none());
+    }
 }
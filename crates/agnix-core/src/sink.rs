@@ -0,0 +1,54 @@
+//! Receivers for diagnostics streamed out of project validation.
+//!
+//! [`validate_project_streaming`](crate::validate_project_streaming) hands each
+//! diagnostic to a [`DiagnosticSink`] as soon as the file that produced it has
+//! been validated, instead of returning one sorted `Vec` at the end. Embedders
+//! use this for progress reporting and to stop a long run early.
+
+use std::sync::Mutex;
+
+use crate::diagnostics::Diagnostic;
+
+/// Receives diagnostics while a project is being validated.
+///
+/// `emit` is called from the parallel validation threads, so implementations
+/// synchronize their own state. Diagnostics arrive in no particular order.
+pub trait DiagnosticSink: Sync {
+    /// Receive one diagnostic.
+    fn emit(&self, diagnostic: &Diagnostic);
+
+    /// Return `true` to stop validating further files. Files already being
+    /// validated still emit their diagnostics, and project-level checks are
+    /// skipped.
+    fn is_cancelled(&self) -> bool {
+        false
+    }
+}
+
+impl<F> DiagnosticSink for F
+where
+    F: Fn(&Diagnostic) + Sync,
+{
+    fn emit(&self, diagnostic: &Diagnostic) {
+        self(diagnostic)
+    }
+}
+
+/// Sink that keeps every diagnostic, used by [`validate_project`](crate::validate_project).
+#[derive(Debug, Default)]
+pub struct CollectingSink {
+    diagnostics: Mutex<Vec<Diagnostic>>,
+}
+
+impl CollectingSink {
+    /// The collected diagnostics, in arrival order.
+    pub fn into_diagnostics(self) -> Vec<Diagnostic> {
+        self.diagnostics.into_inner().unwrap()
+    }
+}
+
+impl DiagnosticSink for CollectingSink {
+    fn emit(&self, diagnostic: &Diagnostic) {
+        self.diagnostics.lock().unwrap().push(diagnostic.clone());
+    }
+}
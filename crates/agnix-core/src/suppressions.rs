@@ -0,0 +1,181 @@
+//! Inline suppression comments.
+//!
+//! A Markdown/HTML comment drops diagnostics on a single line:
+//!
+//! ```markdown
+//! <!-- agnix-disable-next-line CC-SK-007 -->
+//! A line that would trigger CC-SK-007
+//!
+//! Some text <!-- agnix-disable-line PE-003, XP-003 -->
+//!
+//! <!-- agnix-disable-next-line -->
+//! A line where every rule is silenced
+//! ```
+//!
+//! Rule IDs are separated by whitespace or commas; with none listed, all
+//! rules are disabled for that line.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use regex::Regex;
+
+use crate::diagnostics::Diagnostic;
+use crate::regex_util::static_regex;
+
+static_regex!(fn suppression_comment, r"<!--\s*agnix-disable-(next-line|line)\b(.*?)-->");
+
+/// Rules silenced on one line.
+#[derive(Debug, PartialEq, Eq)]
+enum Scope {
+    All,
+    Rules(HashSet<String>),
+}
+
+impl Scope {
+    fn merge(&mut self, other: Scope) {
+        match (&mut *self, other) {
+            (Scope::All, _) => {}
+            (_, Scope::All) => *self = Scope::All,
+            (Scope::Rules(rules), Scope::Rules(more)) => rules.extend(more),
+        }
+    }
+
+    fn covers(&self, rule: &str) -> bool {
+        match self {
+            Scope::All => true,
+            Scope::Rules(rules) => rules.contains(rule),
+        }
+    }
+}
+
+/// Map of 1-based line number to the rules suppressed on it.
+fn parse_suppressions(content: &str) -> HashMap<usize, Scope> {
+    let mut suppressed: HashMap<usize, Scope> = HashMap::new();
+    if !content.contains("agnix-disable") {
+        return suppressed;
+    }
+
+    // Matched per line, so the pattern never sees more than one line of input
+    for (idx, line) in content.lines().enumerate() {
+        for caps in suppression_comment().captures_iter(line) {
+            let target = if &caps[1] == "next-line" {
+                idx + 2
+            } else {
+                idx + 1
+            };
+            let rules: HashSet<String> = caps[2]
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|rule| !rule.is_empty())
+                .map(str::to_string)
+                .collect();
+            let scope = if rules.is_empty() {
+                Scope::All
+            } else {
+                Scope::Rules(rules)
+            };
+            match suppressed.get_mut(&target) {
+                Some(existing) => existing.merge(scope),
+                None => {
+                    suppressed.insert(target, scope);
+                }
+            }
+        }
+    }
+
+    suppressed
+}
+
+/// Drop diagnostics for `path` silenced by an inline comment in `content`.
+pub(crate) fn apply_suppressions(path: &Path, content: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let suppressed = parse_suppressions(content);
+    if suppressed.is_empty() {
+        return;
+    }
+
+    diagnostics.retain(|diag| {
+        diag.file != path
+            || !suppressed
+                .get(&diag.line)
+                .is_some_and(|scope| scope.covers(&diag.rule))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn diag(line: usize, rule: &str) -> Diagnostic {
+        Diagnostic::warning(PathBuf::from("CLAUDE.md"), line, 1, rule, "test")
+    }
+
+    fn remaining(content: &str, mut diagnostics: Vec<Diagnostic>) -> Vec<(usize, String)> {
+        apply_suppressions(Path::new("CLAUDE.md"), content, &mut diagnostics);
+        diagnostics.into_iter().map(|d| (d.line, d.rule)).collect()
+    }
+
+    #[test]
+    fn test_disable_next_line_for_one_rule() {
+        let content = "# Title\n<!-- agnix-disable-next-line CC-SK-007 -->\nflagged line\n";
+        let left = remaining(
+            content,
+            vec![
+                diag(3, "CC-SK-007"),
+                diag(3, "PE-003"),
+                diag(1, "CC-SK-007"),
+            ],
+        );
+        assert_eq!(
+            left,
+            vec![(3, "PE-003".to_string()), (1, "CC-SK-007".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_disable_line_with_several_rules() {
+        let content = "text <!-- agnix-disable-line PE-003, XP-003 -->\n";
+        let left = remaining(
+            content,
+            vec![diag(1, "PE-003"), diag(1, "XP-003"), diag(1, "AS-004")],
+        );
+        assert_eq!(left, vec![(1, "AS-004".to_string())]);
+    }
+
+    #[test]
+    fn test_bare_comment_disables_all_rules() {
+        let content = "<!-- agnix-disable-next-line -->\nflagged\nnot suppressed\n";
+        let left = remaining(
+            content,
+            vec![diag(2, "PE-003"), diag(2, "XP-003"), diag(3, "PE-003")],
+        );
+        assert_eq!(left, vec![(3, "PE-003".to_string())]);
+    }
+
+    #[test]
+    fn test_bare_comment_wins_over_rule_list() {
+        let content =
+            "<!-- agnix-disable-next-line PE-003 --><!-- agnix-disable-next-line -->\nx\n";
+        let left = remaining(content, vec![diag(2, "XP-003")]);
+        assert!(left.is_empty());
+    }
+
+    #[test]
+    fn test_other_files_are_untouched() {
+        let content = "<!-- agnix-disable-next-line -->\nflagged\n";
+        let mut diagnostics = vec![Diagnostic::warning(
+            PathBuf::from("AGENTS.md"),
+            2,
+            1,
+            "XP-003",
+            "test",
+        )];
+        apply_suppressions(Path::new("CLAUDE.md"), content, &mut diagnostics);
+        assert_eq!(diagnostics.len(), 1);
+    }
+
+    #[test]
+    fn test_unrelated_comments_ignored() {
+        assert!(parse_suppressions("<!-- agnix-enable PE-003 -->\n<!-- note -->\n").is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+//! Per-file and per-validator wall time collected during project validation.
+//!
+//! Timings are always recorded (one `Instant` per validator call) and
+//! returned on [`ValidationResult`](crate::ValidationResult), slowest first,
+//! so callers can show where a run spends its time. Files served from the
+//! result cache report their read and lookup time and no validator timings.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::FileType;
+
+/// Wall time spent validating one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileTiming {
+    pub path: PathBuf,
+    pub file_type: FileType,
+    pub duration: Duration,
+}
+
+/// Wall time one validator spent across all files, summed over threads.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorTiming {
+    /// Validator type name without its module path, e.g. `SkillValidator`.
+    pub validator: &'static str,
+    /// Number of files the validator ran on.
+    pub files: usize,
+    pub duration: Duration,
+}
+
+/// Wall time of each validator that ran on one file, in run order.
+pub(crate) type ValidatorDurations = Vec<(&'static str, Duration)>;
+
+/// Thread-safe accumulator shared by the parallel validation loop.
+#[derive(Default)]
+pub(crate) struct TimingCollector {
+    files: Mutex<Vec<FileTiming>>,
+    validators: Mutex<HashMap<&'static str, (usize, Duration)>>,
+}
+
+impl TimingCollector {
+    pub(crate) fn record(
+        &self,
+        path: PathBuf,
+        file_type: FileType,
+        duration: Duration,
+        validators: &[(&'static str, Duration)],
+    ) {
+        self.files.lock().unwrap().push(FileTiming {
+            path,
+            file_type,
+            duration,
+        });
+        let mut totals = self.validators.lock().unwrap();
+        for &(validator, duration) in validators {
+            let entry = totals.entry(validator).or_default();
+            entry.0 += 1;
+            entry.1 += duration;
+        }
+    }
+
+    /// File and validator timings, each sorted slowest first (ties by name).
+    pub(crate) fn finish(self) -> (Vec<FileTiming>, Vec<ValidatorTiming>) {
+        let mut files = self.files.into_inner().unwrap();
+        files.sort_by(|a, b| {
+            b.duration
+                .cmp(&a.duration)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+
+        let mut validators: Vec<ValidatorTiming> = self
+            .validators
+            .into_inner()
+            .unwrap()
+            .into_iter()
+            .map(|(validator, (files, duration))| ValidatorTiming {
+                validator,
+                files,
+                duration,
+            })
+            .collect();
+        validators.sort_by(|a, b| {
+            b.duration
+                .cmp(&a.duration)
+                .then_with(|| a.validator.cmp(b.validator))
+        });
+
+        (files, validators)
+    }
+}
+
+/// Last path segment of a type name: `agnix_core::rules::skill::SkillValidator`
+/// becomes `SkillValidator`.
+pub(crate) fn short_type_name(name: &'static str) -> &'static str {
+    // Generic parameters may contain `::` themselves; only look before them
+    let end = name.find('<').unwrap_or(name.len());
+    name[..end].rsplit("::").next().map_or(name, |short| {
+        let start = end - short.len();
+        &name[start..]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_type_name() {
+        assert_eq!(
+            short_type_name("agnix_core::rules::skill::SkillValidator"),
+            "SkillValidator"
+        );
+        assert_eq!(short_type_name("Plain"), "Plain");
+        assert_eq!(
+            short_type_name("my_crate::Wrapper<other::Inner>"),
+            "Wrapper<other::Inner>"
+        );
+    }
+
+    #[test]
+    fn test_collector_sorts_slowest_first_and_sums_validators() {
+        let collector = TimingCollector::default();
+        let ms = Duration::from_millis;
+        collector.record(
+            PathBuf::from("a/SKILL.md"),
+            FileType::Skill,
+            ms(2),
+            &vec![("SkillValidator", ms(1)), ("XmlValidator", ms(1))],
+        );
+        collector.record(
+            PathBuf::from("b/SKILL.md"),
+            FileType::Skill,
+            ms(5),
+            &vec![("SkillValidator", ms(4)), ("XmlValidator", ms(1))],
+        );
+
+        let (files, validators) = collector.finish();
+        assert_eq!(files[0].path, PathBuf::from("b/SKILL.md"));
+        assert_eq!(files[1].duration, ms(2));
+        assert_eq!(
+            validators[0],
+            ValidatorTiming {
+                validator: "SkillValidator",
+                files: 2,
+                duration: ms(5),
+            }
+        );
+        assert_eq!(validators[1].validator, "XmlValidator");
+    }
+}
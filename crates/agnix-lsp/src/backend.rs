@@ -3,11 +3,12 @@
 //! Implements the Language Server Protocol using tower-lsp, providing
 //! real-time validation of agent configuration files.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::future::Future;
 use std::path::{Component, Path, PathBuf};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
 
 use tokio::sync::RwLock;
 use tower_lsp::jsonrpc::Result;
@@ -17,7 +18,8 @@ use tower_lsp::{Client, LanguageServer};
 use crate::code_actions::fixes_to_code_actions_with_diagnostic;
 use crate::completion_provider::completion_items_for_document;
 use crate::diagnostic_mapper::{deserialize_fixes, to_lsp_diagnostics};
-use crate::hover_provider::hover_at_position;
+use crate::hover_provider::{diagnostic_hover_at_position, hover_at_position};
+use crate::project::{OverlayFileSystem, SiblingCache};
 use crate::vscode_config::VsCodeConfig;
 
 fn create_error_diagnostic(code: &str, message: String) -> Diagnostic {
@@ -43,6 +45,20 @@ fn create_error_diagnostic(code: &str, message: String) -> Diagnostic {
     }
 }
 
+/// Text of a hover's contents (providers always produce Markdown markup).
+fn markup_value(contents: HoverContents) -> String {
+    match contents {
+        HoverContents::Markup(markup) => markup.value,
+        HoverContents::Scalar(MarkedString::String(text)) => text,
+        HoverContents::Scalar(MarkedString::LanguageString(code)) => code.value,
+        HoverContents::Array(items) => items
+            .into_iter()
+            .map(|item| markup_value(HoverContents::Scalar(item)))
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+    }
+}
+
 /// Normalize path components without filesystem access.
 /// Resolves `.` and `..` logically -- used when `canonicalize()` fails.
 /// Expects absolute paths (LSP URIs always produce absolute paths).
@@ -69,6 +85,40 @@ fn normalize_path(path: &Path) -> PathBuf {
 
 const MAX_CONFIG_REVALIDATION_CONCURRENCY: usize = 8;
 
+/// How long edits must pause before `did_change` starts a project-level run.
+const PROJECT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Registration ID of the watcher for project-level inputs.
+const WATCHED_FILES_REGISTRATION_ID: &str = "agnix-project-files";
+
+/// Globs covering every file `ProjectFiles::is_input` accepts.
+const WATCHED_FILE_GLOBS: [&str; 3] =
+    ["**/*.md", "**/.clinerules", "**/*mcp*.{json,yaml,yml,toml}"];
+
+/// When `validate_from_content_and_publish` runs the project-level rules for
+/// a project input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ProjectPass {
+    /// Right away, e.g. on open, save or close
+    Immediate,
+    /// Once edits pause for `PROJECT_DEBOUNCE`, so typing does not start a
+    /// run per keystroke
+    Debounced,
+}
+
+/// Canonical form of `path` as reported by the project walk. Files that do
+/// not exist on disk (e.g. just deleted) keep their name under the canonical
+/// parent, falling back to lexical normalization.
+fn canonical_or_normalized(path: &Path) -> PathBuf {
+    path.canonicalize()
+        .ok()
+        .or_else(|| {
+            let parent = path.parent()?.canonicalize().ok()?;
+            Some(parent.join(path.file_name()?))
+        })
+        .unwrap_or_else(|| normalize_path(path))
+}
+
 fn config_revalidation_concurrency(document_count: usize) -> usize {
     if document_count == 0 {
         return 0;
@@ -129,7 +179,13 @@ where
 ///
 /// The backend maintains a connection to the LSP client and validates
 /// files on open, change, and save events. It also provides code actions
-/// for quick fixes and hover documentation for configuration fields.
+/// for quick fixes and hover documentation for configuration fields and
+/// the diagnostics under the cursor.
+///
+/// When an open document feeds the project-level cross-file rules (AGENTS.md,
+/// instruction files, agents, skills, MCP configs), those rules are re-run
+/// against the open buffers and the diagnostics of every affected file are
+/// republished, including files that are not open.
 ///
 /// # Performance Notes
 ///
@@ -147,12 +203,32 @@ pub struct Backend {
     /// Canonicalized workspace root cached at initialize() to avoid blocking I/O on hot paths.
     workspace_root_canonical: Arc<RwLock<Option<PathBuf>>>,
     documents: Arc<RwLock<HashMap<Url, Arc<String>>>>,
+    /// Diagnostics from the last published content validation of each document,
+    /// used to explain the diagnostic under the cursor on hover.
+    diagnostics: Arc<RwLock<HashMap<Url, Arc<Vec<agnix_core::Diagnostic>>>>>,
     /// Monotonic generation incremented on each config change.
     /// Used to drop stale diagnostics from older revalidation batches.
     config_generation: Arc<AtomicU64>,
     /// Cached validator registry reused across validations.
     /// Immutable after construction; Arc enables sharing across spawn_blocking tasks.
     registry: Arc<agnix_core::ValidatorRegistry>,
+    /// Inputs of the project-level rules, collected by the first project run
+    /// and reset when the configuration changes.
+    project_files: Arc<RwLock<Option<agnix_core::ProjectFiles>>>,
+    /// Diagnostics from the last project-level run, keyed by canonical path.
+    project_diagnostics: Arc<RwLock<HashMap<PathBuf, Vec<agnix_core::Diagnostic>>>>,
+    /// Monotonic generation incremented on each project-level run.
+    /// Used to drop results that a newer run has superseded.
+    project_generation: Arc<AtomicU64>,
+    /// Disk contents of sibling files read by the project-level rules.
+    sibling_cache: Arc<SiblingCache>,
+    /// Monotonic generation incremented on each debounced project run request.
+    /// Only the latest request fires once the delay has passed.
+    project_debounce: Arc<AtomicU64>,
+    /// Delay before a debounced project run starts.
+    project_debounce_delay: Duration,
+    /// Whether the client can watch files for us, set during initialize().
+    watch_files: Arc<AtomicBool>,
 }
 
 impl Backend {
@@ -164,8 +240,16 @@ impl Backend {
             workspace_root: Arc::new(RwLock::new(None)),
             workspace_root_canonical: Arc::new(RwLock::new(None)),
             documents: Arc::new(RwLock::new(HashMap::new())),
+            diagnostics: Arc::new(RwLock::new(HashMap::new())),
             config_generation: Arc::new(AtomicU64::new(0)),
             registry: Arc::new(agnix_core::ValidatorRegistry::with_defaults()),
+            project_files: Arc::new(RwLock::new(None)),
+            project_diagnostics: Arc::new(RwLock::new(HashMap::new())),
+            project_generation: Arc::new(AtomicU64::new(0)),
+            sibling_cache: Arc::new(SiblingCache::default()),
+            project_debounce: Arc::new(AtomicU64::new(0)),
+            project_debounce_delay: PROJECT_DEBOUNCE,
+            watch_files: Arc::new(AtomicBool::new(false)),
         }
     }
 
@@ -201,10 +285,12 @@ impl Backend {
     ///
     /// Used for did_change events where we have the content in memory.
     /// This avoids reading from disk and provides real-time feedback.
+    /// `project_pass` says when to re-run the project-level rules afterwards.
     async fn validate_from_content_and_publish(
         &self,
         uri: Url,
         expected_config_generation: Option<u64>,
+        project_pass: ProjectPass,
     ) {
         let file_path = match uri.to_file_path() {
             Ok(p) => p,
@@ -268,35 +354,30 @@ impl Backend {
             }
         };
 
+        let is_project_input = agnix_core::ProjectFiles::is_input(&file_path);
+        let project_path = canonical_or_normalized(&file_path);
+
         let config = Arc::clone(&*self.config.read().await);
         let registry = Arc::clone(&self.registry);
         let result = tokio::task::spawn_blocking(move || {
-            let file_type = agnix_core::detect_file_type(&file_path);
-            if file_type == agnix_core::FileType::Unknown {
-                return Ok(vec![]);
-            }
-
-            let validators = registry.validators_for(file_type);
-            let mut diagnostics = Vec::new();
-
-            for validator in validators {
-                diagnostics.extend(validator.validate(&file_path, content.as_str(), &config));
-            }
-
-            Ok::<_, agnix_core::LintError>(diagnostics)
+            agnix_core::validate_content_with_registry(
+                &file_path,
+                content.as_str(),
+                &config,
+                &registry,
+            )
         })
         .await;
 
-        let diagnostics = match result {
-            Ok(Ok(diagnostics)) => to_lsp_diagnostics(diagnostics),
-            Ok(Err(e)) => vec![create_error_diagnostic(
-                "agnix::validation-error",
-                format!("Validation error: {}", e),
-            )],
-            Err(e) => vec![create_error_diagnostic(
-                "agnix::internal-error",
-                format!("Internal error: {}", e),
-            )],
+        let (mut diagnostics, core_diagnostics) = match result {
+            Ok(diagnostics) => (to_lsp_diagnostics(diagnostics.clone()), diagnostics),
+            Err(e) => (
+                vec![create_error_diagnostic(
+                    "agnix::internal-error",
+                    format!("Internal error: {}", e),
+                )],
+                Vec::new(),
+            ),
         };
 
         if !self
@@ -306,9 +387,165 @@ impl Backend {
             return;
         }
 
+        self.diagnostics
+            .write()
+            .await
+            .insert(uri.clone(), Arc::new(core_diagnostics));
+
+        if let Some(project) = self.project_diagnostics.read().await.get(&project_path) {
+            diagnostics.extend(to_lsp_diagnostics(project.clone()));
+        }
+
         self.client
             .publish_diagnostics(uri, diagnostics, None)
             .await;
+
+        // Config-change batches run the project-level rules once afterwards
+        if is_project_input && expected_config_generation.is_none() {
+            match project_pass {
+                ProjectPass::Immediate => self.revalidate_project(Some(project_path)).await,
+                ProjectPass::Debounced => self.schedule_project_revalidation(project_path),
+            }
+        }
+    }
+
+    /// Run `revalidate_project` for `changed` once no newer request has
+    /// arrived for `project_debounce_delay`.
+    fn schedule_project_revalidation(&self, changed: PathBuf) {
+        let generation = self.project_debounce.fetch_add(1, Ordering::SeqCst) + 1;
+        let backend = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(backend.project_debounce_delay).await;
+            if backend.project_debounce.load(Ordering::SeqCst) == generation {
+                backend.revalidate_project(Some(changed)).await;
+            }
+        });
+    }
+
+    /// Re-run the project-level cross-file rules and republish every file
+    /// whose project diagnostics changed.
+    ///
+    /// Open documents are read from their buffers and other files through the
+    /// sibling cache, so only files not read before hit the disk. `changed`
+    /// is added to the project inputs when it is new since the last walk.
+    async fn revalidate_project(&self, changed: Option<PathBuf>) {
+        let Some(root) = self.workspace_root_canonical.read().await.clone() else {
+            return;
+        };
+        let generation = self.project_generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+        let config = Arc::clone(&*self.config.read().await);
+        let documents: Vec<(Url, Arc<String>)> = self
+            .documents
+            .read()
+            .await
+            .iter()
+            .map(|(uri, content)| (uri.clone(), Arc::clone(content)))
+            .collect();
+        let project_files = self.project_files.read().await.clone();
+        let sibling_cache = Arc::clone(&self.sibling_cache);
+
+        let result = tokio::task::spawn_blocking(move || {
+            let mut files = match project_files {
+                Some(files) => files,
+                None => agnix_core::ProjectFiles::collect(&root, &config)?,
+            };
+            if let Some(changed) = changed.filter(|path| path.starts_with(&root)) {
+                files.insert(&changed);
+            }
+
+            let mut open_documents = HashMap::new();
+            let mut open_uris = HashMap::new();
+            for (uri, content) in documents {
+                if let Ok(path) = uri.to_file_path() {
+                    let path = canonical_or_normalized(&path);
+                    open_documents.insert(path.clone(), content);
+                    open_uris.insert(path, uri);
+                }
+            }
+
+            let mut config = (*config).clone();
+            config.set_fs(Arc::new(OverlayFileSystem::new(
+                open_documents,
+                sibling_cache,
+            )));
+            let diagnostics = agnix_core::validate_project_rules(&root, &config, &files);
+            Ok::<_, agnix_core::LintError>((files, open_uris, diagnostics))
+        })
+        .await;
+
+        let (files, open_uris, diagnostics) = match result {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => {
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!("Project validation error: {}", e),
+                    )
+                    .await;
+                return;
+            }
+            Err(e) => {
+                self.client
+                    .log_message(
+                        MessageType::ERROR,
+                        format!("Project validation task failed: {}", e),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        if self.project_generation.load(Ordering::SeqCst) != generation {
+            return;
+        }
+
+        let mut by_path: HashMap<PathBuf, Vec<agnix_core::Diagnostic>> = HashMap::new();
+        for diagnostic in diagnostics {
+            by_path
+                .entry(diagnostic.file.clone())
+                .or_default()
+                .push(diagnostic);
+        }
+
+        *self.project_files.write().await = Some(files);
+        let previous = std::mem::replace(
+            &mut *self.project_diagnostics.write().await,
+            by_path.clone(),
+        );
+
+        let affected: BTreeSet<&PathBuf> = previous.keys().chain(by_path.keys()).collect();
+        for path in affected {
+            let unchanged = match (previous.get(path), by_path.get(path)) {
+                (Some(old), Some(new)) => {
+                    to_lsp_diagnostics(old.clone()) == to_lsp_diagnostics(new.clone())
+                }
+                _ => false,
+            };
+            if unchanged {
+                continue;
+            }
+            let uri = match open_uris.get(path) {
+                Some(uri) => uri.clone(),
+                None => match Url::from_file_path(path) {
+                    Ok(uri) => uri,
+                    Err(()) => continue,
+                },
+            };
+
+            let mut merged = self
+                .diagnostics
+                .read()
+                .await
+                .get(&uri)
+                .map(|diagnostics| diagnostics.as_ref().clone())
+                .unwrap_or_default();
+            merged.extend(by_path.get(path).cloned().unwrap_or_default());
+
+            self.client
+                .publish_diagnostics(uri, to_lsp_diagnostics(merged), None)
+                .await;
+        }
     }
 
     /// In config-change batch revalidation mode, only publish if the batch generation is current
@@ -353,6 +590,15 @@ impl Backend {
 #[tower_lsp::async_trait]
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        let can_watch_files = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.did_change_watched_files)
+            .and_then(|watched| watched.dynamic_registration)
+            .unwrap_or(false);
+        self.watch_files.store(can_watch_files, Ordering::SeqCst);
+
         // Capture workspace root for path boundary validation
         if let Some(root_uri) = params.root_uri {
             if let Ok(root_path) = root_uri.to_file_path() {
@@ -413,6 +659,34 @@ impl LanguageServer for Backend {
         self.client
             .log_message(MessageType::INFO, "agnix-lsp initialized")
             .await;
+
+        // Hear about project inputs changed outside the editor (git checkout,
+        // new files) so the sibling cache and project file list stay current
+        if self.watch_files.load(Ordering::SeqCst) {
+            let watchers = WATCHED_FILE_GLOBS
+                .iter()
+                .map(|glob| FileSystemWatcher {
+                    glob_pattern: GlobPattern::String(glob.to_string()),
+                    kind: None,
+                })
+                .collect();
+            let registration = Registration {
+                id: WATCHED_FILES_REGISTRATION_ID.to_string(),
+                method: "workspace/didChangeWatchedFiles".to_string(),
+                register_options: serde_json::to_value(DidChangeWatchedFilesRegistrationOptions {
+                    watchers,
+                })
+                .ok(),
+            };
+            if let Err(e) = self.client.register_capability(vec![registration]).await {
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!("Failed to register file watcher: {}", e),
+                    )
+                    .await;
+            }
+        }
     }
 
     async fn shutdown(&self) -> Result<()> {
@@ -426,7 +700,8 @@ impl LanguageServer for Backend {
             let mut docs = self.documents.write().await;
             docs.insert(uri.clone(), Arc::new(text));
         }
-        self.validate_from_content_and_publish(uri, None).await;
+        self.validate_from_content_and_publish(uri, None, ProjectPass::Immediate)
+            .await;
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
@@ -436,13 +711,22 @@ impl LanguageServer for Backend {
                 let mut docs = self.documents.write().await;
                 docs.insert(uri.clone(), Arc::new(change.text));
             }
-            self.validate_from_content_and_publish(uri, None).await;
+            self.validate_from_content_and_publish(uri, None, ProjectPass::Debounced)
+                .await;
         }
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
-        self.validate_from_content_and_publish(params.text_document.uri, None)
-            .await;
+        if let Ok(path) = params.text_document.uri.to_file_path() {
+            self.sibling_cache
+                .invalidate(&canonical_or_normalized(&path));
+        }
+        self.validate_from_content_and_publish(
+            params.text_document.uri,
+            None,
+            ProjectPass::Immediate,
+        )
+        .await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
@@ -450,9 +734,71 @@ impl LanguageServer for Backend {
             let mut docs = self.documents.write().await;
             docs.remove(&params.text_document.uri);
         }
+        self.diagnostics
+            .write()
+            .await
+            .remove(&params.text_document.uri);
+
+        // The file keeps its project diagnostics; those come from disk again
+        // now that the buffer is gone
+        let path = params
+            .text_document
+            .uri
+            .to_file_path()
+            .ok()
+            .map(|path| canonical_or_normalized(&path));
+        let project = match &path {
+            Some(path) => {
+                self.sibling_cache.invalidate(path);
+                self.project_diagnostics
+                    .read()
+                    .await
+                    .get(path)
+                    .cloned()
+                    .unwrap_or_default()
+            }
+            None => Vec::new(),
+        };
         self.client
-            .publish_diagnostics(params.text_document.uri, vec![], None)
+            .publish_diagnostics(params.text_document.uri, to_lsp_diagnostics(project), None)
             .await;
+
+        if let Some(path) = path.filter(|path| agnix_core::ProjectFiles::is_input(path)) {
+            self.revalidate_project(Some(path)).await;
+        }
+    }
+
+    async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        let root = self.workspace_root_canonical.read().await.clone();
+        let mut project_changed = false;
+        {
+            let mut project_files = self.project_files.write().await;
+            for change in params.changes {
+                let Ok(path) = change.uri.to_file_path() else {
+                    continue;
+                };
+                let path = canonical_or_normalized(&path);
+                self.sibling_cache.invalidate(&path);
+                if !agnix_core::ProjectFiles::is_input(&path)
+                    || !root.as_ref().is_some_and(|root| path.starts_with(root))
+                {
+                    continue;
+                }
+                project_changed = true;
+                if let Some(files) = project_files.as_mut() {
+                    match change.typ {
+                        FileChangeType::CREATED => files.insert(&path),
+                        FileChangeType::DELETED => files.remove(&path),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // Nothing to refresh until the first project run collects the inputs
+        if project_changed && self.project_files.read().await.is_some() {
+            self.revalidate_project(None).await;
+        }
     }
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
@@ -522,8 +868,41 @@ impl LanguageServer for Backend {
             return Ok(None);
         }
 
-        // Get hover info for the position
-        Ok(hover_at_position(file_type, content.as_str(), position))
+        // Diagnostics under the cursor come first, then field documentation
+        let mut diagnostics = self
+            .diagnostics
+            .read()
+            .await
+            .get(uri)
+            .map(|diagnostics| diagnostics.as_ref().clone())
+            .unwrap_or_default();
+        if let Ok(path) = uri.to_file_path() {
+            if let Some(project) = self
+                .project_diagnostics
+                .read()
+                .await
+                .get(&canonical_or_normalized(&path))
+            {
+                diagnostics.extend(project.iter().cloned());
+            }
+        }
+        let diagnostic_hover = diagnostic_hover_at_position(&diagnostics, position);
+        let field_hover = hover_at_position(file_type, content.as_str(), position);
+
+        Ok(match (diagnostic_hover, field_hover) {
+            (Some(diagnostic), Some(field)) => Some(Hover {
+                contents: HoverContents::Markup(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: format!(
+                        "{}\n\n---\n\n{}",
+                        markup_value(diagnostic.contents),
+                        markup_value(field.contents)
+                    ),
+                }),
+                range: None,
+            }),
+            (diagnostic, field) => diagnostic.or(field),
+        })
     }
 
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
@@ -582,6 +961,9 @@ impl LanguageServer for Backend {
             *config_guard = Arc::new(new_config);
         }
 
+        // Exclude patterns may have changed, so re-walk on the next project run
+        let had_project_files = self.project_files.write().await.take().is_some();
+
         // Re-validate all open documents with new config
         let documents: Vec<Url> = {
             let docs = self.documents.read().await;
@@ -598,7 +980,11 @@ impl LanguageServer for Backend {
             let backend = backend.clone();
             async move {
                 backend
-                    .validate_from_content_and_publish(uri, Some(revalidation_generation))
+                    .validate_from_content_and_publish(
+                        uri,
+                        Some(revalidation_generation),
+                        ProjectPass::Immediate,
+                    )
                     .await;
             }
         })
@@ -612,6 +998,12 @@ impl LanguageServer for Backend {
                 )
                 .await;
         }
+
+        if had_project_files
+            && self.config_generation.load(Ordering::SeqCst) == revalidation_generation
+        {
+            self.revalidate_project(None).await;
+        }
     }
 }
 
@@ -955,6 +1347,306 @@ model: sonnet
         // Should complete without error
     }
 
+    async fn project_rule_ids(backend: &Backend) -> Vec<String> {
+        let mut rules: Vec<String> = backend
+            .project_diagnostics
+            .read()
+            .await
+            .values()
+            .flatten()
+            .map(|diagnostic| diagnostic.rule.clone())
+            .collect();
+        rules.sort();
+        rules
+    }
+
+    /// Backend whose debounced project runs start without delay.
+    fn undebounced_backend(client: Client) -> Backend {
+        Backend {
+            project_debounce_delay: Duration::ZERO,
+            ..Backend::new(client)
+        }
+    }
+
+    /// Wait for a (debounced) project run to satisfy `done`.
+    async fn wait_for_project_rules(backend: &Backend, done: impl Fn(&[String]) -> bool) {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while !done(&project_rule_ids(backend).await) {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("timed out waiting for the project run");
+    }
+
+    async fn change_document(backend: &Backend, uri: &Url, version: i32, text: &str) {
+        backend
+            .did_change(DidChangeTextDocumentParams {
+                text_document: VersionedTextDocumentIdentifier {
+                    uri: uri.clone(),
+                    version,
+                },
+                content_changes: vec![TextDocumentContentChangeEvent {
+                    range: None,
+                    range_length: None,
+                    text: text.to_string(),
+                }],
+            })
+            .await;
+    }
+
+    /// Editing an instruction file re-runs the cross-file rules against the
+    /// unsaved buffer, and closing it falls back to the contents on disk.
+    #[tokio::test]
+    async fn test_project_rules_follow_unsaved_instruction_edits() {
+        let (service, _socket) = LspService::new(undebounced_backend);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let npm = "# Project\n\nUse `npm install` for dependencies.\n";
+        std::fs::write(temp_dir.path().join("CLAUDE.md"), npm).unwrap();
+        let agents_path = temp_dir.path().join("AGENTS.md");
+        std::fs::write(&agents_path, npm).unwrap();
+
+        let root_uri = Url::from_file_path(temp_dir.path()).unwrap();
+        service
+            .inner()
+            .initialize(InitializeParams {
+                root_uri: Some(root_uri),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let uri = Url::from_file_path(&agents_path).unwrap();
+        service
+            .inner()
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "markdown".to_string(),
+                    version: 1,
+                    text: npm.to_string(),
+                },
+            })
+            .await;
+        assert!(service.inner().project_files.read().await.is_some());
+        assert!(
+            !project_rule_ids(service.inner())
+                .await
+                .contains(&"XP-004".to_string())
+        );
+
+        let pnpm = "# Project\n\nUse `pnpm install` for dependencies.\n";
+        change_document(service.inner(), &uri, 2, pnpm).await;
+        wait_for_project_rules(service.inner(), |rules| {
+            rules.contains(&"XP-004".to_string())
+        })
+        .await;
+
+        // The sibling CLAUDE.md gets the conflict too, without being open
+        let claude_md = temp_dir.path().canonicalize().unwrap().join("CLAUDE.md");
+        assert!(
+            service
+                .inner()
+                .project_diagnostics
+                .read()
+                .await
+                .get(&claude_md)
+                .is_some_and(|diagnostics| diagnostics.iter().any(|d| d.rule == "XP-004"))
+        );
+
+        service
+            .inner()
+            .did_close(DidCloseTextDocumentParams {
+                text_document: TextDocumentIdentifier { uri },
+            })
+            .await;
+        assert!(
+            !project_rule_ids(service.inner())
+                .await
+                .contains(&"XP-004".to_string())
+        );
+    }
+
+    /// A burst of edits starts a single project run once typing pauses.
+    #[tokio::test]
+    async fn test_did_change_debounces_project_runs() {
+        let (service, _socket) = LspService::new(|client| Backend {
+            project_debounce_delay: Duration::from_millis(500),
+            ..Backend::new(client)
+        });
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let npm = "# Project\n\nUse `npm install` for dependencies.\n";
+        let pnpm = "# Project\n\nUse `pnpm install` for dependencies.\n";
+        std::fs::write(temp_dir.path().join("CLAUDE.md"), npm).unwrap();
+        let agents_path = temp_dir.path().join("AGENTS.md");
+        std::fs::write(&agents_path, npm).unwrap();
+
+        let root_uri = Url::from_file_path(temp_dir.path()).unwrap();
+        service
+            .inner()
+            .initialize(InitializeParams {
+                root_uri: Some(root_uri),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let uri = Url::from_file_path(&agents_path).unwrap();
+        service
+            .inner()
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "markdown".to_string(),
+                    version: 1,
+                    text: npm.to_string(),
+                },
+            })
+            .await;
+        let after_open = service.inner().project_generation.load(Ordering::SeqCst);
+
+        for version in 2..=6 {
+            let text = if version % 2 == 0 { pnpm } else { npm };
+            change_document(service.inner(), &uri, version, text).await;
+        }
+        wait_for_project_rules(service.inner(), |rules| {
+            rules.contains(&"XP-004".to_string())
+        })
+        .await;
+        assert_eq!(
+            service.inner().project_generation.load(Ordering::SeqCst),
+            after_open + 1
+        );
+    }
+
+    /// Files changed outside the editor refresh the sibling cache and the
+    /// project inputs.
+    #[tokio::test]
+    async fn test_watched_file_changes_refresh_project_rules() {
+        let (service, _socket) = LspService::new(Backend::new);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let npm = "# Project\n\nUse `npm install` for dependencies.\n";
+        let claude_path = temp_dir.path().join("CLAUDE.md");
+        std::fs::write(&claude_path, npm).unwrap();
+        let agents_path = temp_dir.path().join("AGENTS.md");
+        std::fs::write(&agents_path, npm).unwrap();
+
+        let root_uri = Url::from_file_path(temp_dir.path()).unwrap();
+        service
+            .inner()
+            .initialize(InitializeParams {
+                root_uri: Some(root_uri),
+                capabilities: ClientCapabilities {
+                    workspace: Some(WorkspaceClientCapabilities {
+                        did_change_watched_files: Some(DidChangeWatchedFilesClientCapabilities {
+                            dynamic_registration: Some(true),
+                            relative_pattern_support: None,
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(service.inner().watch_files.load(Ordering::SeqCst));
+
+        service
+            .inner()
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: Url::from_file_path(&agents_path).unwrap(),
+                    language_id: "markdown".to_string(),
+                    version: 1,
+                    text: npm.to_string(),
+                },
+            })
+            .await;
+        let has_xp_004 = |rules: Vec<String>| rules.contains(&"XP-004".to_string());
+        assert!(!has_xp_004(project_rule_ids(service.inner()).await));
+
+        let watched = |path: &Path, typ: FileChangeType| DidChangeWatchedFilesParams {
+            changes: vec![FileEvent {
+                uri: Url::from_file_path(path).unwrap(),
+                typ,
+            }],
+        };
+
+        // CLAUDE.md was read into the sibling cache; an edit on disk replaces it
+        std::fs::write(
+            &claude_path,
+            "# Project\n\nUse `pnpm install` for dependencies.\n",
+        )
+        .unwrap();
+        service
+            .inner()
+            .did_change_watched_files(watched(&claude_path, FileChangeType::CHANGED))
+            .await;
+        assert!(has_xp_004(project_rule_ids(service.inner()).await));
+
+        // New files join the project inputs and deleted ones leave them
+        let root = temp_dir.path().canonicalize().unwrap();
+        let gemini_path = temp_dir.path().join("GEMINI.md");
+        std::fs::write(&gemini_path, npm).unwrap();
+        service
+            .inner()
+            .did_change_watched_files(watched(&gemini_path, FileChangeType::CREATED))
+            .await;
+        std::fs::remove_file(&claude_path).unwrap();
+        service
+            .inner()
+            .did_change_watched_files(watched(&claude_path, FileChangeType::DELETED))
+            .await;
+
+        let project_files = service.inner().project_files.read().await.clone().unwrap();
+        assert!(project_files.contains(&root.join("GEMINI.md")));
+        assert!(!project_files.contains(&root.join("CLAUDE.md")));
+        assert!(!has_xp_004(project_rule_ids(service.inner()).await));
+    }
+
+    /// Files the cross-file rules do not read never trigger a project run.
+    #[tokio::test]
+    async fn test_project_rules_skipped_for_unrelated_file() {
+        let (service, _socket) = LspService::new(Backend::new);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let claude_dir = temp_dir.path().join(".claude");
+        std::fs::create_dir_all(&claude_dir).unwrap();
+        let settings_path = claude_dir.join("settings.json");
+        std::fs::write(&settings_path, "{}").unwrap();
+
+        let root_uri = Url::from_file_path(temp_dir.path()).unwrap();
+        service
+            .inner()
+            .initialize(InitializeParams {
+                root_uri: Some(root_uri),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let uri = Url::from_file_path(&settings_path).unwrap();
+        service
+            .inner()
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "json".to_string(),
+                    version: 1,
+                    text: "{}".to_string(),
+                },
+            })
+            .await;
+        change_document(service.inner(), &uri, 2, "{\"hooks\": {}}").await;
+
+        assert!(service.inner().project_files.read().await.is_none());
+        assert_eq!(service.inner().project_generation.load(Ordering::SeqCst), 0);
+    }
+
     /// Test initialized() completes without error.
     #[tokio::test]
     async fn test_initialized_completes() {
@@ -1433,6 +2125,65 @@ This is a test project.
         assert!(hover_after.unwrap().is_none());
     }
 
+    /// Test that hovering a diagnostic explains it alongside field docs.
+    #[tokio::test]
+    async fn test_hover_explains_diagnostic_at_position() {
+        let (service, _socket) = LspService::new(Backend::new);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let skill_path = temp_dir.path().join("SKILL.md");
+        let content = "---\nname: Bad-Skill\ndescription: Use when testing\n---\nBody\n";
+        std::fs::write(&skill_path, content).unwrap();
+        let uri = Url::from_file_path(&skill_path).unwrap();
+
+        service
+            .inner()
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "markdown".to_string(),
+                    version: 1,
+                    text: content.to_string(),
+                },
+            })
+            .await;
+
+        let hover_at = |line: u32| HoverParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+                position: Position { line, character: 0 },
+            },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+        };
+
+        let hover = service.inner().hover(hover_at(1)).await.unwrap().unwrap();
+        let HoverContents::Markup(markup) = hover.contents else {
+            panic!("Expected Markup content");
+        };
+        let name = agnix_rules::get_rule_name("AS-004").unwrap();
+        assert!(
+            markup.value.contains(&format!("**AS-004**: {}", name)),
+            "{}",
+            markup.value
+        );
+        assert!(
+            markup
+                .value
+                .contains(&agnix_rules::docs_url("AS-004").unwrap())
+        );
+        // Field documentation for `name` follows the diagnostic sections
+        assert!(markup.value.contains("\n\n---\n\n"));
+
+        // Closing the document forgets its diagnostics
+        service
+            .inner()
+            .did_close(DidCloseTextDocumentParams {
+                text_document: TextDocumentIdentifier { uri: uri.clone() },
+            })
+            .await;
+        assert!(service.inner().diagnostics.read().await.get(&uri).is_none());
+    }
+
     /// Test that document cache is updated on change.
     #[tokio::test]
     async fn test_document_cache_updated_on_change() {
@@ -66,10 +66,12 @@ pub fn fix_to_code_action_with_diagnostic(
 
 /// Convert multiple fixes to code actions.
 ///
-/// Convenience function for converting a slice of fixes.
+/// Convenience function for converting a slice of fixes. File-creation fixes
+/// are skipped since they do not edit the open document.
 pub fn fixes_to_code_actions(uri: &Url, fixes: &[Fix], content: &str) -> Vec<CodeAction> {
     fixes
         .iter()
+        .filter(|fix| !fix.is_file_creation())
         .map(|fix| fix_to_code_action_with_diagnostic(uri, fix, content, None))
         .collect()
 }
@@ -83,6 +85,7 @@ pub fn fixes_to_code_actions_with_diagnostic(
 ) -> Vec<CodeAction> {
     fixes
         .iter()
+        .filter(|fix| !fix.is_file_creation())
         .map(|fix| fix_to_code_action_with_diagnostic(uri, fix, content, Some(diagnostic)))
         .collect()
 }
@@ -98,6 +101,7 @@ mod tests {
             replacement: replacement.to_string(),
             description: description.to_string(),
             safe,
+            create_file: None,
         }
     }
 
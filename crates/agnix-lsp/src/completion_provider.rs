@@ -84,6 +84,48 @@ mod tests {
         assert!(items.iter().any(|item| item.label == "name"));
     }
 
+    #[test]
+    fn test_agent_model_value_completions() {
+        let content = "---\nname: reviewer\nmodel: \n---\n";
+        let items = completion_items_for_document(
+            Path::new(".claude/agents/reviewer.md"),
+            content,
+            Position {
+                line: 2,
+                character: 7,
+            },
+        );
+        let values: Vec<&str> = items
+            .iter()
+            .filter(|item| item.kind == Some(CompletionItemKind::VALUE))
+            .map(|item| item.label.as_str())
+            .collect();
+        assert_eq!(values, ["sonnet", "opus", "haiku", "inherit"]);
+    }
+
+    #[test]
+    fn test_key_completion_detail_includes_value_type() {
+        let content = "---\n\n---\n";
+        let items = completion_items_for_document(
+            Path::new(".claude/agents/reviewer.md"),
+            content,
+            Position {
+                line: 1,
+                character: 0,
+            },
+        );
+        let tools = items
+            .iter()
+            .find(|item| item.label == "tools")
+            .expect("tools key completion");
+        let detail = tools.detail.as_deref().unwrap();
+        assert!(
+            detail.starts_with("Field: string | array of strings"),
+            "{}",
+            detail
+        );
+    }
+
     #[test]
     fn test_unknown_file_type_has_no_completions() {
         let items = completion_items_for_document(
@@ -29,7 +29,8 @@ pub fn deserialize_fixes(data: Option<&JsonValue>) -> Vec<Fix> {
 ///
 /// Handles the mapping of:
 /// - Severity levels (Error, Warning, Info)
-/// - Line/column positions (1-indexed to 0-indexed)
+/// - Line/column positions (1-indexed to 0-indexed), including the end of
+///   the span when the validator reported one
 /// - Rule codes
 /// - Suggestions (appended to message)
 /// - Fixes (serialized to diagnostic.data for code actions)
@@ -42,6 +43,14 @@ pub fn to_lsp_diagnostic(diag: &Diagnostic) -> LspDiagnostic {
 
     let line = diag.line.saturating_sub(1) as u32;
     let column = diag.column.saturating_sub(1) as u32;
+    // Highlight the validator's span when it reported one, otherwise a point
+    let (end_line, end_column) = match (diag.end_line, diag.end_column) {
+        (Some(end_line), Some(end_column)) => (
+            end_line.saturating_sub(1) as u32,
+            end_column.saturating_sub(1) as u32,
+        ),
+        _ => (line, column),
+    };
 
     let message = if let Some(ref suggestion) = diag.suggestion {
         format!(
@@ -63,8 +72,8 @@ pub fn to_lsp_diagnostic(diag: &Diagnostic) -> LspDiagnostic {
                 character: column,
             },
             end: Position {
-                line,
-                character: column,
+                line: end_line,
+                character: end_column,
             },
         },
         severity: Some(severity),
@@ -86,6 +95,7 @@ pub fn to_lsp_diagnostics(diagnostics: Vec<Diagnostic>) -> Vec<LspDiagnostic> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use agnix_core::Confidence;
     use std::path::PathBuf;
 
     fn make_diagnostic(
@@ -106,6 +116,11 @@ mod tests {
             suggestion: suggestion.map(String::from),
             fixes: vec![],
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }
     }
 
@@ -127,6 +142,11 @@ mod tests {
             suggestion: None,
             fixes,
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }
     }
 
@@ -137,6 +157,7 @@ mod tests {
             replacement: replacement.to_string(),
             description: description.to_string(),
             safe,
+            create_file: None,
         }
     }
 
@@ -184,6 +205,24 @@ mod tests {
         assert_eq!(lsp_diag.range.start.character, 4);
     }
 
+    #[test]
+    fn test_range_without_span_is_a_point() {
+        let diag = make_diagnostic(DiagnosticLevel::Error, "Test", 10, 5, "AS-001", None);
+        let lsp_diag = to_lsp_diagnostic(&diag);
+        assert_eq!(lsp_diag.range.end, lsp_diag.range.start);
+    }
+
+    #[test]
+    fn test_range_uses_span_end() {
+        let diag =
+            make_diagnostic(DiagnosticLevel::Error, "Test", 2, 1, "AS-004", None).with_span(2, 16);
+        let lsp_diag = to_lsp_diagnostic(&diag);
+        assert_eq!(lsp_diag.range.start.line, 1);
+        assert_eq!(lsp_diag.range.start.character, 0);
+        assert_eq!(lsp_diag.range.end.line, 1);
+        assert_eq!(lsp_diag.range.end.character, 15);
+    }
+
     #[test]
     fn test_line_zero_saturates() {
         // Line 0 should saturate to 0, not underflow
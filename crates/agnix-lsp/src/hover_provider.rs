@@ -1,9 +1,11 @@
 //! Hover documentation provider for LSP.
 //!
 //! Provides contextual documentation when hovering over fields
-//! in agent configuration files, backed by agnix-core authoring metadata.
+//! in agent configuration files, backed by agnix-core authoring metadata,
+//! and explains the diagnostics reported at the hovered position.
 
-use agnix_core::FileType;
+use agnix_core::{Diagnostic, FileType};
+use rust_i18n::t;
 use tower_lsp::lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position};
 
 /// Get the field name at a position in YAML/JSON-like content.
@@ -59,6 +61,68 @@ pub fn hover_at_position(file_type: FileType, content: &str, position: Position)
     get_hover_info(file_type, &field)
 }
 
+/// Whether `diag` covers `position` (0-based).
+///
+/// Diagnostics with a reported span cover that span; point diagnostics cover
+/// their whole line, since the squiggle is rendered from the start column.
+fn diagnostic_covers(diag: &Diagnostic, position: Position) -> bool {
+    let line = position.line as usize + 1;
+    let column = position.character as usize + 1;
+    match (diag.end_line, diag.end_column) {
+        (Some(end_line), Some(end_column)) => {
+            (line, column) >= (diag.line, diag.column.max(1))
+                && (line, column) <= (end_line, end_column)
+        }
+        _ => line == diag.line,
+    }
+}
+
+/// Markdown section explaining one diagnostic: rule name, suggestion, docs link.
+fn diagnostic_section(diag: &Diagnostic) -> String {
+    let mut section = match agnix_rules::get_rule_name(&diag.rule) {
+        Some(name) => format!("**{}**: {}", diag.rule, name),
+        None => format!("**{}**", diag.rule),
+    };
+    section.push_str(&format!("\n\n{}", diag.message));
+    if let Some(ref suggestion) = diag.suggestion {
+        section.push_str(&format!(
+            "\n\n{} {}",
+            t!("lsp.suggestion_label"),
+            suggestion
+        ));
+    }
+    if let Some(url) = agnix_rules::docs_url(&diag.rule) {
+        section.push_str(&format!("\n\n[{}]({})", t!("lsp.docs_link"), url));
+    }
+    section
+}
+
+/// Get hover information for the diagnostics at a position.
+///
+/// Overlapping diagnostics are rendered as consecutive sections separated
+/// by horizontal rules.
+pub fn diagnostic_hover_at_position(
+    diagnostics: &[Diagnostic],
+    position: Position,
+) -> Option<Hover> {
+    let sections: Vec<String> = diagnostics
+        .iter()
+        .filter(|diag| diagnostic_covers(diag, position))
+        .map(diagnostic_section)
+        .collect();
+    if sections.is_empty() {
+        return None;
+    }
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: sections.join("\n\n---\n\n"),
+        }),
+        range: None,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +221,90 @@ mod tests {
 
         assert!(hover.is_none());
     }
+
+    fn diag(rule: &str, line: usize, column: usize) -> Diagnostic {
+        Diagnostic::warning(
+            std::path::PathBuf::from("SKILL.md"),
+            line,
+            column,
+            rule,
+            "msg",
+        )
+    }
+
+    fn markdown(hover: Hover) -> String {
+        match hover.contents {
+            HoverContents::Markup(markup) => markup.value,
+            _ => panic!("Expected Markup content"),
+        }
+    }
+
+    #[test]
+    fn test_diagnostic_hover_shows_name_suggestion_and_docs() {
+        let diagnostics =
+            vec![diag("CC-SK-006", 2, 1).with_suggestion("Add disable-model-invocation: true")];
+        let hover = diagnostic_hover_at_position(
+            &diagnostics,
+            Position {
+                line: 1,
+                character: 4,
+            },
+        )
+        .unwrap();
+
+        let value = markdown(hover);
+        let name = agnix_rules::get_rule_name("CC-SK-006").unwrap();
+        assert!(value.starts_with(&format!("**CC-SK-006**: {}", name)));
+        assert!(value.contains("Add disable-model-invocation: true"));
+        assert!(value.contains(&agnix_rules::docs_url("CC-SK-006").unwrap()));
+    }
+
+    #[test]
+    fn test_diagnostic_hover_only_on_diagnostic_line() {
+        let diagnostics = vec![diag("AS-004", 2, 1)];
+        let pos = Position {
+            line: 2,
+            character: 0,
+        };
+        assert!(diagnostic_hover_at_position(&diagnostics, pos).is_none());
+    }
+
+    #[test]
+    fn test_diagnostic_hover_respects_span() {
+        let mut spanned = diag("AS-004", 2, 7);
+        spanned.end_line = Some(2);
+        spanned.end_column = Some(12);
+        let diagnostics = vec![spanned];
+
+        let inside = Position {
+            line: 1,
+            character: 8,
+        };
+        let before = Position {
+            line: 1,
+            character: 2,
+        };
+        assert!(diagnostic_hover_at_position(&diagnostics, inside).is_some());
+        assert!(diagnostic_hover_at_position(&diagnostics, before).is_none());
+    }
+
+    #[test]
+    fn test_diagnostic_hover_concatenates_overlapping() {
+        let diagnostics = vec![diag("AS-004", 2, 1), diag("INTERNAL-1", 2, 3)];
+        let hover = diagnostic_hover_at_position(
+            &diagnostics,
+            Position {
+                line: 1,
+                character: 0,
+            },
+        )
+        .unwrap();
+
+        let value = markdown(hover);
+        let sections: Vec<&str> = value.split("\n\n---\n\n").collect();
+        assert_eq!(sections.len(), 2);
+        assert!(sections[0].starts_with("**AS-004**: "));
+        // Unknown rules still get a section, without a name or docs link
+        assert_eq!(sections[1], "**INTERNAL-1**\n\nmsg");
+    }
 }
@@ -10,6 +10,7 @@
 //! ## Features
 //!
 //! - Real-time diagnostics on file open, change, and save
+//! - Cross-file rules re-run when an instruction, agent, skill or MCP file changes
 //! - Quick-fix code actions for automatic repairs
 //! - Hover documentation for configuration fields
 //! - Supports all agnix validation rules
@@ -24,6 +25,8 @@
 //! ```
 //!
 //! The server communicates over stdin/stdout using the LSP protocol.
+//! Pass `--port <n>` to listen on `127.0.0.1:<n>` instead; every TCP
+//! connection gets its own server instance, so several clients can attach.
 
 rust_i18n::i18n!("../../locales", fallback = "en");
 
@@ -34,13 +37,25 @@ mod diagnostic_mapper;
 mod hover_provider;
 mod locale;
 mod position;
+mod project;
 mod vscode_config;
 
 pub use backend::Backend;
 pub use vscode_config::{VsCodeConfig, VsCodeRules, VsCodeSpecs, VsCodeVersions};
 
+use tokio::net::TcpListener;
 use tower_lsp::{LspService, Server};
 
+/// How the LSP server talks to its client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Transport {
+    /// stdin/stdout (the default).
+    #[default]
+    Stdio,
+    /// A TCP socket on `127.0.0.1:<port>`.
+    Tcp(u16),
+}
+
 /// Start the LSP server.
 ///
 /// This function sets up stdin/stdout communication and runs the server
@@ -51,13 +66,53 @@ use tower_lsp::{LspService, Server};
 ///
 /// Returns an error if the server fails to start or encounters a fatal error.
 pub async fn start_server() -> anyhow::Result<()> {
+    start_server_with(Transport::Stdio).await
+}
+
+/// Start the LSP server on the given transport.
+///
+/// With [`Transport::Tcp`] this accepts connections until the process is
+/// stopped; see [`serve_tcp`].
+///
+/// # Errors
+///
+/// Returns an error if the TCP port cannot be bound.
+pub async fn start_server_with(transport: Transport) -> anyhow::Result<()> {
     // Initialize locale from environment variables (AGNIX_LOCALE > LANG/LC_ALL > system > "en")
     locale::init_from_env();
 
-    let stdin = tokio::io::stdin();
-    let stdout = tokio::io::stdout();
+    match transport {
+        Transport::Stdio => {
+            let stdin = tokio::io::stdin();
+            let stdout = tokio::io::stdout();
+
+            let (service, socket) = LspService::new(Backend::new);
+            Server::new(stdin, stdout, socket).serve(service).await;
+            Ok(())
+        }
+        Transport::Tcp(port) => {
+            let listener = TcpListener::bind(("127.0.0.1", port)).await?;
+            eprintln!("agnix-lsp listening on {}", listener.local_addr()?);
+            serve_tcp(listener).await
+        }
+    }
+}
 
-    let (service, socket) = LspService::new(Backend::new);
-    Server::new(stdin, stdout, socket).serve(service).await;
-    Ok(())
+/// Serve LSP clients connecting to `listener`.
+///
+/// Each connection runs its own [`Backend`], so clients don't share
+/// documents or configuration.
+///
+/// # Errors
+///
+/// Returns an error if accepting a connection fails.
+pub async fn serve_tcp(listener: TcpListener) -> anyhow::Result<()> {
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::spawn(async move {
+            let (read, write) = tokio::io::split(stream);
+            let (service, socket) = LspService::new(Backend::new);
+            Server::new(read, write, socket).serve(service).await;
+        });
+    }
 }
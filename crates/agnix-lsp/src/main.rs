@@ -1,3 +1,5 @@
+use agnix_lsp::Transport;
+
 #[tokio::main]
 async fn main() {
     let args: Vec<String> = std::env::args().collect();
@@ -6,8 +8,62 @@ async fn main() {
         return;
     }
 
-    if let Err(e) = agnix_lsp::start_server().await {
+    let transport = match parse_transport(&args[1..]) {
+        Ok(transport) => transport,
+        Err(e) => {
+            eprintln!("agnix-lsp: {e}");
+            eprintln!("usage: agnix-lsp [--port <n>]");
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(e) = agnix_lsp::start_server_with(transport).await {
         eprintln!("LSP server error: {e}");
         std::process::exit(1);
     }
 }
+
+/// Parse `--port <n>` / `--port=<n>`; stdio when absent.
+fn parse_transport(args: &[String]) -> Result<Transport, String> {
+    let mut transport = Transport::Stdio;
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let value = match arg.strip_prefix("--port=") {
+            Some(value) => value,
+            None if arg == "--port" => iter.next().ok_or("--port requires a value")?,
+            // Other arguments (e.g. --stdio from editor clients) were always ignored
+            None => continue,
+        };
+        let port = value
+            .parse::<u16>()
+            .map_err(|_| format!("invalid port '{value}'"))?;
+        transport = Transport::Tcp(port);
+    }
+    Ok(transport)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_transport() {
+        assert_eq!(parse_transport(&args(&[])), Ok(Transport::Stdio));
+        assert_eq!(parse_transport(&args(&["--stdio"])), Ok(Transport::Stdio));
+        assert_eq!(
+            parse_transport(&args(&["--port", "9257"])),
+            Ok(Transport::Tcp(9257))
+        );
+        assert_eq!(
+            parse_transport(&args(&["--port=9257"])),
+            Ok(Transport::Tcp(9257))
+        );
+        assert!(parse_transport(&args(&["--port"])).is_err());
+        assert!(parse_transport(&args(&["--port", "http"])).is_err());
+        assert_eq!(parse_transport(&args(&["--bogus"])), Ok(Transport::Stdio));
+    }
+}
@@ -0,0 +1,167 @@
+//! File system used by the project-level cross-file checks.
+//!
+//! The cross-file rules read every sibling instruction, agent, skill and MCP
+//! file on each run. Open documents are served from their editor buffers so
+//! unsaved edits are checked, and other files are read from disk once and kept
+//! in a [`SiblingCache`] until the editor saves or closes them, or the client
+//! reports them changed on disk.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+
+use agnix_core::fs::{DirEntry, FileMetadata};
+use agnix_core::{FileSystem, LintResult, RealFileSystem};
+
+/// Disk contents of project files, shared across project revalidations.
+#[derive(Debug, Default)]
+pub(crate) struct SiblingCache {
+    contents: RwLock<HashMap<PathBuf, Arc<String>>>,
+}
+
+impl SiblingCache {
+    /// Drop the cached contents of `path` so the next run re-reads it.
+    pub(crate) fn invalidate(&self, path: &Path) {
+        self.contents
+            .write()
+            .expect("SiblingCache lock poisoned")
+            .remove(path);
+    }
+
+    fn get(&self, path: &Path) -> Option<Arc<String>> {
+        self.contents
+            .read()
+            .expect("SiblingCache lock poisoned")
+            .get(path)
+            .cloned()
+    }
+
+    fn insert(&self, path: PathBuf, content: Arc<String>) {
+        self.contents
+            .write()
+            .expect("SiblingCache lock poisoned")
+            .insert(path, content);
+    }
+}
+
+/// [`FileSystem`] that reads open documents from their buffers and other
+/// files through the [`SiblingCache`]. Everything else goes to disk.
+#[derive(Debug)]
+pub(crate) struct OverlayFileSystem {
+    open_documents: HashMap<PathBuf, Arc<String>>,
+    cache: Arc<SiblingCache>,
+}
+
+impl OverlayFileSystem {
+    pub(crate) fn new(
+        open_documents: HashMap<PathBuf, Arc<String>>,
+        cache: Arc<SiblingCache>,
+    ) -> Self {
+        Self {
+            open_documents,
+            cache,
+        }
+    }
+}
+
+impl FileSystem for OverlayFileSystem {
+    fn exists(&self, path: &Path) -> bool {
+        self.open_documents.contains_key(path) || RealFileSystem.exists(path)
+    }
+
+    fn is_file(&self, path: &Path) -> bool {
+        self.open_documents.contains_key(path) || RealFileSystem.is_file(path)
+    }
+
+    fn is_dir(&self, path: &Path) -> bool {
+        RealFileSystem.is_dir(path)
+    }
+
+    fn is_symlink(&self, path: &Path) -> bool {
+        RealFileSystem.is_symlink(path)
+    }
+
+    fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        RealFileSystem.metadata(path)
+    }
+
+    fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+        RealFileSystem.symlink_metadata(path)
+    }
+
+    fn read_to_string(&self, path: &Path) -> LintResult<String> {
+        if let Some(content) = self.open_documents.get(path) {
+            return Ok(content.as_str().to_string());
+        }
+        if let Some(content) = self.cache.get(path) {
+            return Ok(content.as_str().to_string());
+        }
+        let content = RealFileSystem.read_to_string(path)?;
+        self.cache
+            .insert(path.to_path_buf(), Arc::new(content.clone()));
+        Ok(content)
+    }
+
+    fn write(&self, path: &Path, content: &str) -> LintResult<()> {
+        RealFileSystem.write(path, content)
+    }
+
+    fn create_new(&self, path: &Path, content: &str) -> LintResult<()> {
+        RealFileSystem.create_new(path, content)
+    }
+
+    fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+        RealFileSystem.canonicalize(path)
+    }
+
+    fn read_dir(&self, path: &Path) -> io::Result<Vec<DirEntry>> {
+        RealFileSystem.read_dir(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_document_shadows_disk() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("CLAUDE.md");
+        std::fs::write(&path, "on disk").unwrap();
+
+        let open = HashMap::from([(path.clone(), Arc::new("in buffer".to_string()))]);
+        let fs = OverlayFileSystem::new(open, Arc::new(SiblingCache::default()));
+
+        assert_eq!(fs.read_to_string(&path).unwrap(), "in buffer");
+    }
+
+    #[test]
+    fn test_disk_reads_are_cached_until_invalidated() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("AGENTS.md");
+        std::fs::write(&path, "first").unwrap();
+
+        let cache = Arc::new(SiblingCache::default());
+        let fs = OverlayFileSystem::new(HashMap::new(), Arc::clone(&cache));
+        assert_eq!(fs.read_to_string(&path).unwrap(), "first");
+
+        std::fs::write(&path, "second").unwrap();
+        assert_eq!(fs.read_to_string(&path).unwrap(), "first");
+
+        cache.invalidate(&path);
+        assert_eq!(fs.read_to_string(&path).unwrap(), "second");
+    }
+
+    #[test]
+    fn test_missing_file_is_not_cached() {
+        let temp = tempfile::tempdir().unwrap();
+        let path = temp.path().join("missing.md");
+
+        let fs = OverlayFileSystem::new(HashMap::new(), Arc::new(SiblingCache::default()));
+        assert!(fs.read_to_string(&path).is_err());
+
+        std::fs::write(&path, "created").unwrap();
+        assert_eq!(fs.read_to_string(&path).unwrap(), "created");
+    }
+}
@@ -340,6 +340,8 @@ fn parse_target(s: &str) -> Option<TargetTool> {
         "ClaudeCode" => Some(TargetTool::ClaudeCode),
         "Cursor" => Some(TargetTool::Cursor),
         "Codex" => Some(TargetTool::Codex),
+        "Windsurf" => Some(TargetTool::Windsurf),
+        "Gemini" => Some(TargetTool::Gemini),
         _ => None,
     }
 }
@@ -555,6 +557,8 @@ mod tests {
         assert_eq!(parse_target("ClaudeCode"), Some(TargetTool::ClaudeCode));
         assert_eq!(parse_target("Cursor"), Some(TargetTool::Cursor));
         assert_eq!(parse_target("Codex"), Some(TargetTool::Codex));
+        assert_eq!(parse_target("Windsurf"), Some(TargetTool::Windsurf));
+        assert_eq!(parse_target("Gemini"), Some(TargetTool::Gemini));
         assert_eq!(parse_target("invalid"), None);
     }
 
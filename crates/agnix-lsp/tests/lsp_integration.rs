@@ -3,7 +3,7 @@
 //! These tests verify that the LSP server correctly processes
 //! requests and returns appropriate responses.
 
-use agnix_core::{Diagnostic, DiagnosticLevel};
+use agnix_core::{Confidence, Diagnostic, DiagnosticLevel};
 use std::path::PathBuf;
 
 // Re-export the diagnostic mapper for testing
@@ -27,6 +27,11 @@ mod diagnostic_mapper_tests {
             suggestion: None,
             fixes: vec![],
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         }
     }
 
@@ -179,6 +184,7 @@ mod code_action_tests {
             replacement: "hello".to_string(),
             description: "Test fix".to_string(),
             safe: true,
+            create_file: None,
         };
 
         assert!(fix.safe);
@@ -194,6 +200,7 @@ mod code_action_tests {
             replacement: "world".to_string(),
             description: "Unsafe fix".to_string(),
             safe: false,
+            create_file: None,
         };
 
         assert!(!fix.safe);
@@ -208,6 +215,7 @@ mod code_action_tests {
             replacement: "inserted text".to_string(),
             description: "Insert text".to_string(),
             safe: true,
+            create_file: None,
         };
 
         assert_eq!(fix.start_byte, fix.end_byte);
@@ -223,6 +231,7 @@ mod code_action_tests {
             replacement: String::new(),
             description: "Delete text".to_string(),
             safe: true,
+            create_file: None,
         };
 
         assert!(fix.replacement.is_empty());
@@ -231,7 +240,7 @@ mod code_action_tests {
 }
 
 mod did_change_tests {
-    use agnix_core::{Diagnostic, DiagnosticLevel, Fix};
+    use agnix_core::{Confidence, Diagnostic, DiagnosticLevel, Fix};
     use std::path::PathBuf;
 
     #[test]
@@ -243,6 +252,7 @@ mod did_change_tests {
                 replacement: "fix1".to_string(),
                 description: "First fix".to_string(),
                 safe: true,
+                create_file: None,
             },
             Fix {
                 start_byte: 10,
@@ -250,6 +260,7 @@ mod did_change_tests {
                 replacement: "fix2".to_string(),
                 description: "Second fix".to_string(),
                 safe: false,
+                create_file: None,
             },
         ];
 
@@ -263,6 +274,11 @@ mod did_change_tests {
             suggestion: None,
             fixes,
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         };
 
         assert_eq!(diag.fixes.len(), 2);
@@ -286,8 +302,14 @@ mod did_change_tests {
                 replacement: "x".to_string(),
                 description: "Fix".to_string(),
                 safe: true,
+                create_file: None,
             }],
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         };
 
         let diag_without_fixes = Diagnostic {
@@ -300,6 +322,11 @@ mod did_change_tests {
             suggestion: None,
             fixes: vec![],
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         };
 
         assert!(diag_with_fixes.has_fixes());
@@ -1030,3 +1057,67 @@ unknownfield: value
         // May or may not have actions depending on validation results
     }
 }
+
+mod tcp_transport_tests {
+    use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    /// Read one `Content-Length` framed LSP message.
+    async fn read_message(stream: &mut TcpStream) -> serde_json::Value {
+        let mut header = Vec::new();
+        while !header.ends_with(b"\r\n\r\n") {
+            header.push(stream.read_u8().await.unwrap());
+        }
+        let header = String::from_utf8(header).unwrap();
+        let length: usize = header
+            .lines()
+            .find_map(|line| line.strip_prefix("Content-Length: "))
+            .unwrap()
+            .trim()
+            .parse()
+            .unwrap();
+        let mut body = vec![0; length];
+        stream.read_exact(&mut body).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_tcp_transport_responds_to_initialize() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(agnix_lsp::serve_tcp(listener));
+
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": { "capabilities": {} }
+        })
+        .to_string();
+
+        // Two clients can attach to the same listener
+        for _ in 0..2 {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream
+                .write_all(
+                    format!("Content-Length: {}\r\n\r\n{}", request.len(), request).as_bytes(),
+                )
+                .await
+                .unwrap();
+
+            let response = tokio::time::timeout(Duration::from_secs(10), async {
+                loop {
+                    let message = read_message(&mut stream).await;
+                    if message["id"] == 1 {
+                        return message;
+                    }
+                }
+            })
+            .await
+            .expect("initialize response over TCP");
+
+            assert!(response["result"]["capabilities"].is_object(), "{response}");
+        }
+    }
+}
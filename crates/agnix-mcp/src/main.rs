@@ -12,7 +12,9 @@
 //! - **Server metadata**: Provides name, version, and usage instructions
 
 use agnix_core::{
+    FileType, FixResult, LintError, ValidatorRegistry, apply_fixes as core_apply_fixes,
     config::LintConfig,
+    default_registry, detect_file_type as core_detect_file_type,
     diagnostics::{Diagnostic, DiagnosticLevel},
     validate_file as core_validate_file, validate_project as core_validate_project,
 };
@@ -28,11 +30,15 @@ use rmcp::{
 };
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use similar::TextDiff;
 use std::collections::HashSet;
 use std::path::Path;
 
-const TOOL_ALIASES: &[(&str, &str)] =
-    &[("copilot", "github-copilot"), ("claudecode", "claude-code")];
+const TOOL_ALIASES: &[(&str, &str)] = &[
+    ("copilot", "github-copilot"),
+    ("claudecode", "claude-code"),
+    ("gemini", "gemini-cli"),
+];
 
 const COMPAT_TOOL_NAMES: &[&str] = &["generic", "codex"];
 
@@ -52,7 +58,7 @@ pub struct ValidateFileInput {
     pub tools: Option<ToolsInput>,
     /// Target tool for validation rules
     #[schemars(
-        description = "Legacy single target for validation rules (deprecated). Options: 'generic' (default), 'claude-code', 'cursor', 'codex'. Used only when 'tools' is missing or empty."
+        description = "Legacy single target for validation rules (deprecated). Options: 'generic' (default), 'claude-code', 'cursor', 'codex', 'windsurf', 'gemini'. Used only when 'tools' is missing or empty."
     )]
     pub target: Option<String>,
 }
@@ -73,7 +79,45 @@ pub struct ValidateProjectInput {
     pub tools: Option<ToolsInput>,
     /// Target tool for validation rules
     #[schemars(
-        description = "Legacy single target for validation rules (deprecated). Options: 'generic' (default), 'claude-code', 'cursor', 'codex'. Used only when 'tools' is missing or empty."
+        description = "Legacy single target for validation rules (deprecated). Options: 'generic' (default), 'claude-code', 'cursor', 'codex', 'windsurf', 'gemini'. Used only when 'tools' is missing or empty."
+    )]
+    pub target: Option<String>,
+    /// Maximum number of files to validate
+    #[schemars(
+        description = "Maximum number of recognized config files to validate before giving up with an error. Defaults to 10000, the same limit as the CLI. Use 0 to disable the limit (not recommended for large directories)."
+    )]
+    pub max_files: Option<usize>,
+}
+
+/// Input for apply_fixes tool
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[schemars(description = "Input for applying agnix auto-fixes to a file or project directory")]
+pub struct ApplyFixesInput {
+    /// Path to the file or project directory to fix
+    #[schemars(
+        description = "Path to a single agent configuration file (e.g., 'SKILL.md') or a project directory (e.g., '.') whose files should be fixed"
+    )]
+    pub path: String,
+    /// Preview fixes without writing files
+    #[schemars(
+        description = "When true, compute fixes and return their diffs without modifying any files. Defaults to false."
+    )]
+    #[serde(default)]
+    pub dry_run: bool,
+    /// Only apply fixes marked safe
+    #[schemars(
+        description = "When true, only apply fixes marked safe (high-certainty edits). Defaults to false, which applies all available fixes."
+    )]
+    #[serde(default)]
+    pub safe_only: bool,
+    /// Tools to validate for (preferred over legacy target)
+    #[schemars(
+        description = "Tools to validate for. Accepts comma-separated string (e.g., 'claude-code,cursor,windsurf') or array (e.g., ['claude-code','cursor']). Uses canonical agnix tool names (case-insensitive), plus compatibility aliases (e.g., 'copilot', 'claudecode'). When non-empty, this overrides legacy target."
+    )]
+    pub tools: Option<ToolsInput>,
+    /// Target tool for validation rules
+    #[schemars(
+        description = "Legacy single target for validation rules (deprecated). Options: 'generic' (default), 'claude-code', 'cursor', 'codex', 'windsurf', 'gemini'. Used only when 'tools' is missing or empty."
     )]
     pub target: Option<String>,
 }
@@ -99,6 +143,17 @@ pub struct GetRuleDocsInput {
     pub rule_id: String,
 }
 
+/// Input for detect_file_type tool
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+#[schemars(description = "Input for checking how agnix classifies a path")]
+pub struct DetectFileTypeInput {
+    /// Path to classify
+    #[schemars(
+        description = "Path to classify (e.g., '.claude/skills/deploy/SKILL.md', '.cursor/rules/style.mdc'). Only the path is inspected; the file does not need to exist."
+    )]
+    pub path: String,
+}
+
 /// Diagnostic output for JSON serialization
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct DiagnosticOutput {
@@ -157,6 +212,67 @@ struct ValidationResult {
     diagnostics: Vec<DiagnosticOutput>,
 }
 
+/// Fixes applied to a single file
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct FileFixOutput {
+    /// File that was (or would be) changed
+    path: String,
+    /// Descriptions of the fixes applied to the file
+    applied: Vec<String>,
+    /// Unified diff from the original to the fixed content
+    diff: String,
+}
+
+impl From<&FixResult> for FileFixOutput {
+    fn from(result: &FixResult) -> Self {
+        let path = result.path.display().to_string();
+        let diff = TextDiff::from_lines(&result.original, &result.fixed)
+            .unified_diff()
+            .header(&path, &path)
+            .to_string();
+        Self {
+            path,
+            applied: result.applied.clone(),
+            diff,
+        }
+    }
+}
+
+/// apply_fixes result output
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct ApplyFixesOutput {
+    /// Path that was fixed
+    path: String,
+    /// Whether files were left untouched
+    dry_run: bool,
+    /// Whether only safe fixes were considered
+    safe_only: bool,
+    /// Number of files changed (or that would change in dry-run mode)
+    files_fixed: usize,
+    /// Per-file fix results
+    fixes: Vec<FileFixOutput>,
+}
+
+fn fix_results_to_output(
+    path: &str,
+    dry_run: bool,
+    safe_only: bool,
+    results: &[FixResult],
+) -> ApplyFixesOutput {
+    let fixes: Vec<FileFixOutput> = results
+        .iter()
+        .filter(|r| r.has_changes())
+        .map(FileFixOutput::from)
+        .collect();
+    ApplyFixesOutput {
+        path: path.to_string(),
+        dry_run,
+        safe_only,
+        files_fixed: fixes.len(),
+        fixes,
+    }
+}
+
 /// Rule info for listing
 #[derive(Debug, Serialize, schemars::JsonSchema)]
 struct RuleInfo {
@@ -175,6 +291,61 @@ struct RulesListOutput {
     rules: Vec<RuleInfo>,
 }
 
+/// File type and the validators that run for it
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct FileTypeInfo {
+    /// File type name (e.g., skill, claude-md, unknown)
+    file_type: String,
+    /// Names of the validators that run on files of this type
+    validators: Vec<String>,
+}
+
+impl FileTypeInfo {
+    fn new(file_type: FileType, registry: &ValidatorRegistry) -> Self {
+        Self {
+            file_type: file_type.to_string(),
+            validators: registry
+                .validators_for(file_type)
+                .iter()
+                .map(|validator| validator.name().to_string())
+                .collect(),
+        }
+    }
+}
+
+/// detect_file_type result output
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct DetectFileTypeOutput {
+    /// Path that was classified
+    path: String,
+    /// Whether agnix validates files at this path
+    recognized: bool,
+    /// Detected file type name (e.g., skill, claude-md, unknown)
+    file_type: String,
+    /// Names of the validators that would run on this file
+    validators: Vec<String>,
+}
+
+/// File types list output
+#[derive(Debug, Serialize, schemars::JsonSchema)]
+struct FileTypesListOutput {
+    /// Total number of file types
+    count: usize,
+    /// List of file types
+    file_types: Vec<FileTypeInfo>,
+}
+
+fn detect_file_type_output(path: &str, registry: &ValidatorRegistry) -> DetectFileTypeOutput {
+    let file_type = core_detect_file_type(Path::new(path));
+    let info = FileTypeInfo::new(file_type, registry);
+    DetectFileTypeOutput {
+        path: path.to_string(),
+        recognized: file_type != FileType::Unknown,
+        file_type: info.file_type,
+        validators: info.validators,
+    }
+}
+
 fn parse_target(target: Option<String>) -> agnix_core::config::TargetTool {
     use agnix_core::config::TargetTool;
 
@@ -182,6 +353,8 @@ fn parse_target(target: Option<String>) -> agnix_core::config::TargetTool {
         Some("claude-code") | Some("claudecode") => TargetTool::ClaudeCode,
         Some("cursor") => TargetTool::Cursor,
         Some("codex") => TargetTool::Codex,
+        Some("windsurf") => TargetTool::Windsurf,
+        Some("gemini") | Some("gemini-cli") => TargetTool::Gemini,
         _ => TargetTool::Generic,
     }
 }
@@ -301,6 +474,13 @@ fn diagnostics_to_result(
     }
 }
 
+/// Apply the `max_files` override; `None` keeps the config default, `0` disables the limit
+fn apply_max_files(config: &mut LintConfig, max_files: Option<usize>) {
+    if let Some(max_files) = max_files {
+        config.max_files_to_validate = (max_files > 0).then_some(max_files);
+    }
+}
+
 fn make_error(msg: String) -> McpError {
     McpError::internal_error(msg, None::<Value>)
 }
@@ -312,7 +492,7 @@ fn make_invalid_params(msg: String) -> McpError {
 /// Agnix MCP Server - validates AI agent configurations
 ///
 /// Provides tools to validate SKILL.md, CLAUDE.md, AGENTS.md, hooks,
-/// MCP configs, and more against 145 rules.
+/// MCP configs, and more against 181 rules.
 
 #[derive(Debug, Clone)]
 pub struct AgnixServer {
@@ -366,9 +546,19 @@ impl AgnixServer {
     ) -> Result<CallToolResult, McpError> {
         let mut config = LintConfig::default();
         apply_tool_selection(&mut config, input.tools, input.target)?;
-
-        let validation_result = core_validate_project(Path::new(&input.path), &config)
-            .map_err(|e| make_error(format!("Failed to validate project: {}", e)))?;
+        apply_max_files(&mut config, input.max_files);
+
+        let validation_result = match core_validate_project(Path::new(&input.path), &config) {
+            Ok(result) => result,
+            // Reported as a tool error so the caller can narrow the path or raise max_files
+            Err(e @ LintError::TooManyFiles { .. }) => {
+                return Ok(CallToolResult::error(vec![Content::text(format!(
+                    "{}. Validate a smaller directory or pass a higher max_files.",
+                    e
+                ))]));
+            }
+            Err(e) => return Err(make_error(format!("Failed to validate project: {}", e))),
+        };
 
         let result = diagnostics_to_result(
             &input.path,
@@ -381,9 +571,40 @@ impl AgnixServer {
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 
+    /// Apply auto-fixes to a file or project directory
+    #[tool(
+        description = "Apply agnix auto-fixes to a single agent configuration file or every file in a project directory. Set dry_run to preview changes without writing, and safe_only to restrict to high-certainty fixes. Returns each changed file with the applied fix descriptions and a unified diff."
+    )]
+    async fn apply_fixes(
+        &self,
+        Parameters(input): Parameters<ApplyFixesInput>,
+    ) -> Result<CallToolResult, McpError> {
+        let mut config = LintConfig::default();
+        apply_tool_selection(&mut config, input.tools, input.target)?;
+
+        let path = Path::new(&input.path);
+        let diagnostics = if path.is_dir() {
+            core_validate_project(path, &config)
+                .map_err(|e| make_error(format!("Failed to validate project: {}", e)))?
+                .diagnostics
+        } else {
+            core_validate_file(path, &config)
+                .map_err(|e| make_error(format!("Failed to validate file: {}", e)))?
+        };
+
+        let results = core_apply_fixes(&diagnostics, input.dry_run, input.safe_only)
+            .map_err(|e| make_error(format!("Failed to apply fixes: {}", e)))?;
+
+        let output = fix_results_to_output(&input.path, input.dry_run, input.safe_only, &results);
+        let json = serde_json::to_string_pretty(&output)
+            .map_err(|e| make_error(format!("Failed to serialize result: {}", e)))?;
+
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
     /// Get all available validation rules
     #[tool(
-        description = "List all 145 validation rules available in agnix. Returns rule IDs and names organized by category (AS-* Agent Skills, CC-* Claude Code, MCP-* Model Context Protocol, COP-* Copilot, CUR-* Cursor, etc.)."
+        description = "List all 181 validation rules available in agnix. Returns rule IDs and names organized by category (AS-* Agent Skills, CC-* Claude Code, MCP-* Model Context Protocol, COP-* Copilot, CUR-* Cursor, etc.)."
     )]
     async fn get_rules(&self) -> Result<CallToolResult, McpError> {
         let rules: Vec<RuleInfo> = agnix_rules::RULES_DATA
@@ -405,6 +626,43 @@ impl AgnixServer {
         Ok(CallToolResult::success(vec![Content::text(json)]))
     }
 
+    /// Detect how agnix classifies a path
+    #[tool(
+        description = "Check whether agnix recognizes a path and which validators would run on it, without reading the file. Returns the detected file type (e.g., skill, claude-md, mcp, unknown) and validator names. Use before validate_file when unsure whether a file is checked."
+    )]
+    async fn detect_file_type(
+        &self,
+        Parameters(input): Parameters<DetectFileTypeInput>,
+    ) -> Result<CallToolResult, McpError> {
+        let output = detect_file_type_output(&input.path, default_registry());
+
+        let json = serde_json::to_string_pretty(&output)
+            .map_err(|e| make_error(format!("Failed to serialize result: {}", e)))?;
+
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
+    /// List all file types and their validators
+    #[tool(
+        description = "List every file type agnix distinguishes (skill, claude-md, hooks, mcp, cursor-rule, ...) with the validators that run on each. Files detected as unknown are not validated."
+    )]
+    async fn list_file_types(&self) -> Result<CallToolResult, McpError> {
+        let file_types: Vec<FileTypeInfo> = FileType::ALL
+            .iter()
+            .map(|&file_type| FileTypeInfo::new(file_type, default_registry()))
+            .collect();
+
+        let output = FileTypesListOutput {
+            count: file_types.len(),
+            file_types,
+        };
+
+        let json = serde_json::to_string_pretty(&output)
+            .map_err(|e| make_error(format!("Failed to serialize file types: {}", e)))?;
+
+        Ok(CallToolResult::success(vec![Content::text(json)]))
+    }
+
     /// Get documentation for a specific rule
     #[tool(
         description = "Get the name of a specific validation rule by ID. Rule IDs follow patterns like AS-004 (Agent Skills), CC-SK-001 (Claude Code Skills), PE-003 (Prompt Engineering), MCP-001 (Model Context Protocol)."
@@ -446,12 +704,15 @@ impl ServerHandler for AgnixServer {
             instructions: Some(
                 "Agnix - AI agent configuration linter.\n\n\
                  Validates SKILL.md, CLAUDE.md, AGENTS.md, hooks, MCP configs, \
-                 Cursor rules, and more against 145 rules.\n\n\
+                 Cursor rules, and more against 181 rules.\n\n\
 
                  Tools:\n\
                  - validate_project: Validate all agent configs in a directory\n\
                  - validate_file: Validate a single config file\n\
-                 - get_rules: List all 145 validation rules\n\
+                 - apply_fixes: Apply auto-fixes to a file or directory (supports dry_run)\n\
+                 - get_rules: List all 181 validation rules\n\
+                 - detect_file_type: Check whether a path is recognized and which validators run\n\
+                 - list_file_types: List all file types and their validators\n\
 
                  - get_rule_docs: Get details about a specific rule\n\n\
                  Preferred input: tools (CSV string or array)\n\
@@ -487,11 +748,17 @@ async fn main() -> anyhow::Result<()> {
 #[cfg(test)]
 mod tests {
     use super::{
-        ToolsInput, ValidateFileInput, ValidateProjectInput, apply_tool_selection, parse_tools,
+        AgnixServer, ApplyFixesInput, ToolsInput, ValidateFileInput, ValidateProjectInput,
+        apply_max_files, apply_tool_selection, detect_file_type_output, fix_results_to_output,
+        parse_tools,
     };
+    use agnix_core::FixResult;
     use agnix_core::LintConfig;
     use agnix_core::config::TargetTool;
+    use agnix_core::default_registry;
+    use rmcp::handler::server::wrapper::Parameters;
     use serde_json::json;
+    use std::path::PathBuf;
 
     #[test]
     fn test_parse_tools_csv_trims_and_discards_empty_entries() {
@@ -579,6 +846,25 @@ mod tests {
         assert_eq!(config.target, TargetTool::Codex);
     }
 
+    #[test]
+    fn test_apply_tool_selection_accepts_windsurf_and_gemini_targets() {
+        let mut config = LintConfig::default();
+        apply_tool_selection(&mut config, None, Some("windsurf".to_string()))
+            .expect("windsurf target should be accepted");
+        assert_eq!(config.target, TargetTool::Windsurf);
+
+        apply_tool_selection(&mut config, None, Some("gemini".to_string()))
+            .expect("gemini target should be accepted");
+        assert_eq!(config.target, TargetTool::Gemini);
+    }
+
+    #[test]
+    fn test_parse_tools_maps_gemini_alias() {
+        let tools = parse_tools(Some(ToolsInput::Csv("gemini,windsurf".to_string())))
+            .expect("gemini alias should parse");
+        assert_eq!(tools, vec!["gemini-cli", "windsurf"]);
+    }
+
     #[test]
     fn test_apply_tool_selection_clears_existing_tools_on_fallback() {
         let mut config = LintConfig::default();
@@ -686,4 +972,183 @@ mod tests {
             _ => panic!("expected array tools variant"),
         }
     }
+
+    #[test]
+    fn test_apply_max_files() {
+        let mut config = LintConfig::default();
+        let default_limit = config.max_files_to_validate;
+        assert_eq!(default_limit, Some(agnix_core::config::DEFAULT_MAX_FILES));
+
+        apply_max_files(&mut config, None);
+        assert_eq!(config.max_files_to_validate, default_limit);
+
+        apply_max_files(&mut config, Some(50));
+        assert_eq!(config.max_files_to_validate, Some(50));
+
+        apply_max_files(&mut config, Some(0));
+        assert_eq!(config.max_files_to_validate, None);
+    }
+
+    #[tokio::test]
+    async fn test_validate_project_over_max_files_returns_error_result() {
+        let temp = tempfile::TempDir::new().unwrap();
+        for name in ["CLAUDE.md", "AGENTS.md", "GEMINI.md"] {
+            std::fs::write(temp.path().join(name), "# Project\n\nUse cargo test.\n").unwrap();
+        }
+
+        let server = AgnixServer::new();
+        let input: ValidateProjectInput = serde_json::from_value(json!({
+            "path": temp.path().display().to_string(),
+            "max_files": 2
+        }))
+        .unwrap();
+        let result = server
+            .validate_project(Parameters(input))
+            .await
+            .expect("limit overrun is a tool error, not a protocol error");
+
+        assert_eq!(result.is_error, Some(true));
+        let text = &result.content[0].as_text().expect("text content").text;
+        assert!(text.contains("limit is 2"), "got: {}", text);
+
+        let input: ValidateProjectInput = serde_json::from_value(json!({
+            "path": temp.path().display().to_string(),
+            "max_files": 3
+        }))
+        .unwrap();
+        let result = server.validate_project(Parameters(input)).await.unwrap();
+        assert_ne!(result.is_error, Some(true));
+    }
+
+    #[test]
+    fn test_apply_fixes_input_defaults_flags() {
+        let input: ApplyFixesInput = serde_json::from_value(json!({ "path": "SKILL.md" }))
+            .expect("path-only payload should deserialize");
+        assert!(!input.dry_run);
+        assert!(!input.safe_only);
+        assert!(input.tools.is_none());
+    }
+
+    #[test]
+    fn test_fix_results_to_output_skips_unchanged_files() {
+        let results = vec![
+            FixResult {
+                path: PathBuf::from("a/SKILL.md"),
+                original: "name: Bad\n".to_string(),
+                fixed: "name: bad\n".to_string(),
+                applied: vec!["Rename to 'bad'".to_string()],
+            },
+            FixResult {
+                path: PathBuf::from("b/SKILL.md"),
+                original: "same\n".to_string(),
+                fixed: "same\n".to_string(),
+                applied: vec![],
+            },
+        ];
+
+        let output = fix_results_to_output(".", true, false, &results);
+
+        assert_eq!(output.files_fixed, 1);
+        assert_eq!(output.fixes[0].path, "a/SKILL.md");
+        assert_eq!(output.fixes[0].applied, vec!["Rename to 'bad'"]);
+        assert!(output.fixes[0].diff.contains("-name: Bad"));
+        assert!(output.fixes[0].diff.contains("+name: bad"));
+    }
+
+    #[tokio::test]
+    async fn test_apply_fixes_dry_run_leaves_file_untouched() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let skill_dir = temp.path().join("bad-skill");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        let skill = skill_dir.join("SKILL.md");
+        let content = "---\nname: Bad-Skill\ndescription: Use when testing fixes\n---\nBody\n";
+        std::fs::write(&skill, content).unwrap();
+
+        let server = AgnixServer::new();
+        let input = ApplyFixesInput {
+            path: temp.path().display().to_string(),
+            dry_run: true,
+            safe_only: true,
+            tools: None,
+            target: None,
+        };
+        let result = server
+            .apply_fixes(Parameters(input))
+            .await
+            .expect("apply_fixes should succeed");
+
+        let text = result.content[0]
+            .as_text()
+            .expect("text content")
+            .text
+            .clone();
+        let output: serde_json::Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(output["dry_run"], true);
+        assert_eq!(output["files_fixed"], 1);
+        assert!(
+            output["fixes"][0]["diff"]
+                .as_str()
+                .unwrap()
+                .contains("+name: bad-skill")
+        );
+        assert_eq!(std::fs::read_to_string(&skill).unwrap(), content);
+
+        let input = ApplyFixesInput {
+            path: skill.display().to_string(),
+            dry_run: false,
+            safe_only: true,
+            tools: None,
+            target: None,
+        };
+        server
+            .apply_fixes(Parameters(input))
+            .await
+            .expect("apply_fixes should succeed");
+        assert!(
+            std::fs::read_to_string(&skill)
+                .unwrap()
+                .contains("name: bad-skill")
+        );
+    }
+
+    #[test]
+    fn test_detect_file_type_output_lists_validators() {
+        let registry = default_registry();
+
+        let output = detect_file_type_output(".claude/skills/deploy/SKILL.md", registry);
+        assert!(output.recognized);
+        assert_eq!(output.file_type, "skill");
+        assert!(output.validators.contains(&"SkillValidator".to_string()));
+
+        let output = detect_file_type_output("src/main.rs", registry);
+        assert!(!output.recognized);
+        assert_eq!(output.file_type, "unknown");
+        assert!(output.validators.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_file_types_covers_every_type() {
+        let server = AgnixServer::new();
+        let result = server
+            .list_file_types()
+            .await
+            .expect("list_file_types should succeed");
+
+        let text = &result.content[0].as_text().expect("text content").text;
+        let output: serde_json::Value = serde_json::from_str(text).unwrap();
+        assert_eq!(output["count"], agnix_core::FileType::ALL.len());
+        let mcp = output["file_types"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|t| t["file_type"] == "mcp")
+            .expect("mcp file type listed");
+        assert!(
+            mcp["validators"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|v| v == "McpValidator")
+        );
+    }
 }
@@ -58,6 +58,8 @@ mod parse_target_tests {
             Some("claude-code") | Some("claudecode") => TargetTool::ClaudeCode,
             Some("cursor") => TargetTool::Cursor,
             Some("codex") => TargetTool::Codex,
+            Some("windsurf") => TargetTool::Windsurf,
+            Some("gemini") | Some("gemini-cli") => TargetTool::Gemini,
             _ => TargetTool::Generic,
         }
     }
@@ -101,6 +103,23 @@ mod parse_target_tests {
         assert_eq!(parse_target(Some("codex".to_string())), TargetTool::Codex);
     }
 
+    #[test]
+    fn test_parse_target_windsurf() {
+        assert_eq!(
+            parse_target(Some("windsurf".to_string())),
+            TargetTool::Windsurf
+        );
+    }
+
+    #[test]
+    fn test_parse_target_gemini() {
+        assert_eq!(parse_target(Some("gemini".to_string())), TargetTool::Gemini);
+        assert_eq!(
+            parse_target(Some("gemini-cli".to_string())),
+            TargetTool::Gemini
+        );
+    }
+
     #[test]
     fn test_parse_target_unknown() {
         assert_eq!(
@@ -208,8 +227,8 @@ mod rules_tests {
 
     #[test]
     fn test_rules_count() {
-        // Should have 145 rules
-        assert_eq!(agnix_rules::rule_count(), 145);
+        // Should have 181 rules
+        assert_eq!(agnix_rules::rule_count(), 181);
     }
 
     #[test]
@@ -236,7 +255,7 @@ mod rules_tests {
 }
 
 mod output_format_tests {
-    use agnix_core::diagnostics::{Diagnostic, DiagnosticLevel, Fix};
+    use agnix_core::diagnostics::{Confidence, Diagnostic, DiagnosticLevel, Fix};
     use serde_json::Value;
     use std::path::PathBuf;
 
@@ -252,6 +271,11 @@ mod output_format_tests {
             suggestion: Some("Fix this".to_string()),
             fixes: vec![],
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         };
 
         let json = serde_json::to_string(&diagnostic);
@@ -283,8 +307,14 @@ mod output_format_tests {
                 replacement: "fixed".to_string(),
                 description: "Fix the issue".to_string(),
                 safe: true,
+                create_file: None,
             }],
             assumption: None,
+            confidence: Confidence::High,
+            end_line: None,
+            end_column: None,
+            related: Vec::new(),
+            related_rules: Vec::new(),
         };
 
         // Diagnostic should be fixable
@@ -414,6 +444,7 @@ mod json_schema_tests {
         path: String,
         tools: Option<TestToolsInput>,
         target: Option<String>,
+        max_files: Option<usize>,
     }
 
     #[allow(dead_code)]
@@ -440,6 +471,7 @@ mod json_schema_tests {
         assert!(json.contains("path"));
         assert!(json.contains("tools"));
         assert!(json.contains("target"));
+        assert!(json.contains("max_files"));
     }
 
     #[test]
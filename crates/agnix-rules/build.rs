@@ -7,6 +7,7 @@
 //! - `RULES_DATA`: All rule (id, name) tuples
 //! - `VALID_TOOLS`: Unique tool names from evidence.applies_to.tool
 //! - `TOOL_RULE_PREFIXES`: Mapping of (prefix, tool) for tool-specific rules
+//! - `RULE_PREFIX_CATEGORIES`: Mapping of (prefix, category) from rule categories
 
 use std::collections::{BTreeMap, BTreeSet};
 use std::env;
@@ -255,6 +256,41 @@ fn main() {
             ));
         }
     }
+    generated_code.push_str("];\n\n");
+
+    // =========================================================================
+    // Derive prefix-to-category mappings from rule categories
+    // =========================================================================
+    let mut prefix_categories: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for rule in rules_array {
+        let id = rule["id"].as_str().unwrap_or("");
+        let category = rule["category"].as_str().unwrap_or("");
+        if let Some(prefix) = extract_rule_prefix(id) {
+            prefix_categories
+                .entry(prefix)
+                .or_default()
+                .insert(category.to_string());
+        }
+    }
+
+    generated_code.push_str("/// Mapping of rule ID prefixes to their rule category.\n");
+    generated_code.push_str("/// \n");
+    generated_code
+        .push_str("/// Derived from rules.json: only includes prefixes where ALL rules share\n");
+    generated_code.push_str("/// the same non-empty category.\n");
+    generated_code.push_str("pub const RULE_PREFIX_CATEGORIES: &[(&str, &str)] = &[\n");
+    for (prefix, categories) in &prefix_categories {
+        if categories.len() == 1 {
+            let category = categories.iter().next().unwrap();
+            if !category.is_empty() {
+                generated_code.push_str(&format!(
+                    "    (\"{}\", \"{}\"),\n",
+                    escape_str(prefix),
+                    escape_str(category)
+                ));
+            }
+        }
+    }
     generated_code.push_str("];\n");
 
     // =========================================================================
@@ -51,6 +51,25 @@ pub fn get_rule_name(id: &str) -> Option<&'static str> {
         .map(|(_, name)| *name)
 }
 
+/// Base URL of the published per-rule documentation pages.
+pub const DOCS_BASE_URL: &str = "https://avifenesh.github.io/agnix/docs/rules/generated";
+
+/// Returns the documentation URL for a rule ID, if the rule exists.
+///
+/// # Example
+/// ```
+/// use agnix_rules::docs_url;
+///
+/// assert_eq!(
+///     docs_url("AS-004").as_deref(),
+///     Some("https://avifenesh.github.io/agnix/docs/rules/generated/as-004")
+/// );
+/// assert_eq!(docs_url("XX-999"), None);
+/// ```
+pub fn docs_url(id: &str) -> Option<String> {
+    get_rule_name(id).map(|_| format!("{}/{}", DOCS_BASE_URL, id.to_lowercase()))
+}
+
 /// Returns the list of valid tool names derived from rules.json.
 ///
 /// These are tools that have at least one rule specifically targeting them.
@@ -90,6 +109,45 @@ pub fn get_tool_for_prefix(prefix: &str) -> Option<&'static str> {
         .map(|(_, tool)| *tool)
 }
 
+/// Returns the prefix of a rule ID, including the trailing hyphen.
+///
+/// Returns `None` for IDs that do not end in a numeric suffix.
+///
+/// # Example
+/// ```
+/// use agnix_rules::rule_prefix;
+///
+/// assert_eq!(rule_prefix("CC-HK-001"), Some("CC-HK-"));
+/// assert_eq!(rule_prefix("MCP-002"), Some("MCP-"));
+/// assert_eq!(rule_prefix("file::read"), None);
+/// ```
+pub fn rule_prefix(id: &str) -> Option<&str> {
+    id.rsplit_once('-')
+        .filter(|(_, suffix)| !suffix.is_empty() && suffix.chars().all(|c| c.is_ascii_digit()))
+        .map(|(prefix, _)| &id[..prefix.len() + 1])
+}
+
+/// Returns the category (as used in rules.json) for a full rule ID.
+///
+/// The category is derived from the rule prefix, so IDs not yet listed in
+/// rules.json still resolve as long as their prefix is known.
+///
+/// # Example
+/// ```
+/// use agnix_rules::get_rule_category;
+///
+/// assert_eq!(get_rule_category("MCP-001"), Some("mcp"));
+/// assert_eq!(get_rule_category("CC-HK-001"), Some("claude-hooks"));
+/// assert_eq!(get_rule_category("XX-001"), None);
+/// ```
+pub fn get_rule_category(id: &str) -> Option<&'static str> {
+    let prefix = rule_prefix(id)?;
+    RULE_PREFIX_CATEGORIES
+        .iter()
+        .find(|(p, _)| *p == prefix)
+        .map(|(_, category)| *category)
+}
+
 /// Returns all rule prefixes associated with a tool.
 ///
 /// # Example
@@ -163,6 +221,18 @@ mod tests {
         assert!(name.is_none(), "Nonexistent rule should return None");
     }
 
+    #[test]
+    fn test_docs_url_uses_lowercase_slug() {
+        let url = docs_url("CC-HK-001").expect("CC-HK-001 should have a docs URL");
+        assert!(url.starts_with(DOCS_BASE_URL));
+        assert!(url.ends_with("/cc-hk-001"));
+    }
+
+    #[test]
+    fn test_docs_url_unknown_rule() {
+        assert!(docs_url("NONEXISTENT-999").is_none());
+    }
+
     #[test]
     fn test_no_duplicate_ids() {
         let mut ids: Vec<&str> = RULES_DATA.iter().map(|(id, _)| *id).collect();
@@ -504,4 +574,27 @@ mod tests {
             "Single character should not match"
         );
     }
+
+    #[test]
+    fn test_rule_prefix_edge_cases() {
+        assert_eq!(rule_prefix("AS-001"), Some("AS-"));
+        assert_eq!(rule_prefix("CC-MEM-012"), Some("CC-MEM-"));
+        assert_eq!(rule_prefix("AS-"), None);
+        assert_eq!(rule_prefix("AS-01a"), None);
+        assert_eq!(rule_prefix("config::glob"), None);
+    }
+
+    #[test]
+    fn test_every_rule_has_a_category() {
+        for (id, _) in RULES_DATA {
+            assert!(
+                get_rule_category(id).is_some(),
+                "Rule {} should resolve to a category",
+                id
+            );
+        }
+        assert_eq!(get_rule_category("CC-MEM-001"), Some("claude-memory"));
+        assert_eq!(get_rule_category("XP-001"), Some("cross-platform"));
+        assert_eq!(get_rule_category("file::read"), None);
+    }
 }
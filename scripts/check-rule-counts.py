@@ -126,6 +126,9 @@ def main() -> int:
         "gemini-cli": "Gemini CLI",
         "codex": "Codex CLI",
         "version-awareness": "Version Awareness",
+        "windsurf": "Windsurf",
+        "aider": "Aider",
+        "general": "General",
     }
 
     index_path = ROOT / "knowledge-base" / "INDEX.md"
@@ -211,6 +214,9 @@ def main() -> int:
         "Gemini CLI": ["gemini-cli"],
         "Codex CLI": ["codex"],
         "Version Awareness": ["version-awareness"],
+        "Windsurf": ["windsurf"],
+        "Aider": ["aider"],
+        "General": ["general"],
     }
     spec_sum = 0
     for label, categories in spec_map.items():